@@ -33,10 +33,11 @@ impl MessageFormatter {
             for participant in participants {
                 let is_me = participant.client_id == current_client_id;
                 let me_suffix = if is_me { " (me)" } else { "" };
+                let bot_suffix = if participant.is_bot { " [bot]" } else { "" };
                 let timestamp_str = timestamp_to_jst_rfc3339(participant.connected_at);
                 output.push_str(&format!(
-                    "{}{} - entered at {}\n",
-                    participant.client_id, me_suffix, timestamp_str
+                    "{}{}{} - entered at {}\n",
+                    participant.client_id, bot_suffix, me_suffix, timestamp_str
                 ));
             }
         }
@@ -165,6 +166,8 @@ mod tests {
         let participants = vec![ParticipantInfo {
             client_id: "alice".to_string(),
             connected_at: 1672498800000,
+            connected_at_iso: String::new(),
+            is_bot: false,
         }];
         let current_client_id = "alice";
 
@@ -179,16 +182,20 @@ mod tests {
 
     #[test]
     fn test_format_room_connected_with_multiple_participants() {
-        // テスト項目: 複数参加者の場合、全員が表示され自分にはマークが付く
+        // テスト項目: 複数参加者の場合、全員が表示され自分と bot にはマークが付く
         // given (前提条件):
         let participants = vec![
             ParticipantInfo {
                 client_id: "alice".to_string(),
                 connected_at: 1672498800000,
+                connected_at_iso: String::new(),
+                is_bot: false,
             },
             ParticipantInfo {
                 client_id: "bob".to_string(),
                 connected_at: 1672498900000,
+                connected_at_iso: String::new(),
+                is_bot: true,
             },
         ];
         let current_client_id = "alice";
@@ -198,7 +205,7 @@ mod tests {
 
         // then (期待する結果):
         assert!(result.contains("alice (me)"));
-        assert!(result.contains("bob - entered at"));
+        assert!(result.contains("bob [bot] - entered at"));
         assert!(!result.contains("bob (me)"));
     }
 
@@ -7,8 +7,8 @@ use tokio::sync::mpsc;
 use tokio_tungstenite::{connect_async, tungstenite::protocol::Message};
 
 use engawa_server::infrastructure::dto::websocket::{
-    ChatMessage, MessageType, ParticipantJoinedMessage, ParticipantLeftMessage,
-    RoomConnectedMessage,
+    ChatMessage, MessageType, ParticipantCountMessage, ParticipantJoinedMessage,
+    ParticipantLeftMessage, ParticipantsJoinedMessage, RoomConnectedMessage,
 };
 use engawa_shared::time::get_jst_timestamp;
 
@@ -64,11 +64,36 @@ pub async fn run_client_session(
         while let Some(message) = read.next().await {
             match message {
                 Ok(Message::Text(text)) => {
-                    // Try to parse as RoomConnectedMessage first
-                    if let Ok(room_msg) = serde_json::from_str::<RoomConnectedMessage>(&text) {
+                    // Try to parse as ParticipantsJoinedMessage first
+                    // (it also has a participant list, so it would parse as RoomConnectedMessage)
+                    if let Ok(joined_msg) = serde_json::from_str::<ParticipantsJoinedMessage>(&text)
+                        && joined_msg.r#type == MessageType::ParticipantsJoined
+                    {
+                        for participant in joined_msg
+                            .participants
+                            .iter()
+                            .filter(|p| p.client_id != client_id_for_read)
+                        {
+                            let formatted = MessageFormatter::format_participant_joined(
+                                &participant.client_id,
+                                participant.connected_at,
+                            );
+                            print!("{}", formatted);
+                        }
+                        redisplay_prompt(&client_id_for_read);
+                    }
+                    // Try to parse as RoomConnectedMessage
+                    else if let Ok(room_msg) = serde_json::from_str::<RoomConnectedMessage>(&text)
+                    {
+                        // The server may assign a different id (client_id collision suffixing)
+                        let own_client_id = if room_msg.assigned_client_id.is_empty() {
+                            &client_id_for_read
+                        } else {
+                            &room_msg.assigned_client_id
+                        };
                         let formatted = MessageFormatter::format_room_connected(
                             &room_msg.participants,
-                            &client_id_for_read,
+                            own_client_id,
                         );
                         print!("{}", formatted);
                         redisplay_prompt(&client_id_for_read);
@@ -95,6 +120,13 @@ pub async fn run_client_session(
                         print!("{}", formatted);
                         redisplay_prompt(&client_id_for_read);
                     }
+                    // The participant count is not displayed (joins and leaves already are)
+                    else if let Ok(count_msg) =
+                        serde_json::from_str::<ParticipantCountMessage>(&text)
+                        && count_msg.r#type == MessageType::ParticipantCount
+                    {
+                        tracing::debug!("Participant count: {}", count_msg.count);
+                    }
                     // Try to parse as ChatMessage
                     else if let Ok(chat_msg) = serde_json::from_str::<ChatMessage>(&text) {
                         let formatted = MessageFormatter::format_chat_message(
@@ -192,9 +224,14 @@ pub async fn run_client_session(
             // Create message with type "chat" and client_id
             let msg = ChatMessage {
                 r#type: MessageType::Chat,
+                message_id: None,
                 client_id: client_id.clone(),
                 content: line,
                 timestamp: get_jst_timestamp(),
+                detected_lang: None,
+                mentions: Vec::new(),
+                has_spoiler: false,
+                spoilers: Vec::new(),
             };
 
             let json = match serde_json::to_string(&msg) {
@@ -0,0 +1,34 @@
+-- Room 集約（Room / Participant / ChatMessage）を保存するテーブル
+
+CREATE TABLE IF NOT EXISTS rooms (
+    id TEXT PRIMARY KEY NOT NULL,
+    created_at INTEGER NOT NULL,
+    participant_capacity INTEGER NOT NULL,
+    message_capacity INTEGER NOT NULL,
+    locked INTEGER NOT NULL DEFAULT 0,
+    next_message_seq INTEGER NOT NULL,
+    message_quota_per_client INTEGER,
+    participants_version INTEGER NOT NULL DEFAULT 0,
+    closed INTEGER NOT NULL DEFAULT 0
+);
+
+CREATE TABLE IF NOT EXISTS participants (
+    room_id TEXT NOT NULL REFERENCES rooms (id) ON DELETE CASCADE,
+    position INTEGER NOT NULL,
+    client_id TEXT NOT NULL,
+    connected_at INTEGER NOT NULL,
+    is_bot INTEGER NOT NULL DEFAULT 0,
+    PRIMARY KEY (room_id, client_id)
+);
+
+CREATE TABLE IF NOT EXISTS messages (
+    room_id TEXT NOT NULL REFERENCES rooms (id) ON DELETE CASCADE,
+    position INTEGER NOT NULL,
+    message_id TEXT,
+    from_client_id TEXT NOT NULL,
+    content TEXT NOT NULL,
+    timestamp INTEGER NOT NULL,
+    edited_at INTEGER,
+    deleted INTEGER NOT NULL DEFAULT 0,
+    PRIMARY KEY (room_id, position)
+);
@@ -0,0 +1,3 @@
+-- ルームを作成したクライアントのテナント（テナントごとのルーム数を数えるために保持する）
+
+ALTER TABLE rooms ADD COLUMN tenant TEXT;
@@ -0,0 +1,22 @@
+//! 現在時刻の取得の抽象化
+//!
+//! ## 責務
+//!
+//! 時刻の取得には `engawa_shared::time::Clock` を使います。
+//! UseCase が時刻を直接取得せずに Clock を経由することで、テストでは `FixedClock` で時刻を固定して
+//! タイムスタンプや順序を決定的に検証できます。
+//! このモジュールは、Clock の時刻を Domain Model の Timestamp として取得する `ClockExt` を提供します。
+
+pub use engawa_shared::time::{Clock, FixedClock, SystemClock};
+
+use super::Timestamp;
+
+/// Clock の時刻を Timestamp として取得する拡張
+pub trait ClockExt: Clock {
+    /// 現在時刻を取得
+    fn now(&self) -> Timestamp {
+        Timestamp::new(self.now_jst_millis())
+    }
+}
+
+impl<C: Clock + ?Sized> ClockExt for C {}
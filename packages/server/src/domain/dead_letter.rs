@@ -0,0 +1,37 @@
+//! 配信できなかったメッセージ（デッドレター）の記録の抽象化
+//!
+//! ## 責務
+//!
+//! DeadLetterSink は「配信できなかったメッセージを記録する」責務を持ちます。
+//! 記録したデッドレターは再送や監査に利用します。
+//! 記録先（メモリ、DB、外部キューなど）は問いません。
+
+use async_trait::async_trait;
+
+use super::{ClientId, MessageId};
+
+/// 配信できなかったメッセージ
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeadLetter {
+    /// 配信できなかったメッセージの ID
+    pub message_id: MessageId,
+    /// 配信できなかった送信先のクライアント ID
+    pub target_client_id: ClientId,
+    /// 配信できなかった理由
+    pub reason: String,
+}
+
+/// デッドレターの記録先の抽象化
+///
+/// ## 実装
+///
+/// - `InMemoryDeadLetterSink`: 上限付きのバッファに保持する実装（`infrastructure/dead_letter/inmemory.rs`）
+#[async_trait]
+pub trait DeadLetterSink: Send + Sync {
+    /// デッドレターを記録
+    ///
+    /// # 引数
+    ///
+    /// - `dead_letter`: 配信できなかったメッセージと送信先、理由
+    async fn record(&self, dead_letter: DeadLetter);
+}
@@ -4,7 +4,7 @@ use serde::{Deserialize, Serialize};
 
 use super::{
     error::RoomError,
-    value_object::{ClientId, MessageContent, RoomId, Timestamp},
+    value_object::{ClientId, MessageContent, MessageId, RoomId, ShardId, Timestamp},
 };
 
 /// Default maximum number of participants allowed in a room
@@ -28,6 +28,40 @@ pub struct Room {
     pub participant_capacity: usize,
     /// Maximum number of messages allowed (default: 100)
     pub message_capacity: usize,
+    /// Whether the room is locked (participants cannot post messages)
+    #[serde(default)]
+    pub locked: bool,
+    /// Sequence number assigned to the next message (per-room monotonic counter)
+    #[serde(default)]
+    pub next_message_seq: u64,
+    /// Maximum number of stored messages authored by a single client (`None` = unlimited)
+    ///
+    /// When exceeded, the author's oldest message is evicted, so that no single client
+    /// can fill the room history.
+    #[serde(default)]
+    pub message_quota_per_client: Option<usize>,
+    /// Membership version, incremented whenever a participant joins, leaves or is updated
+    ///
+    /// Lets callers detect that a cached participant list is stale without comparing the lists.
+    #[serde(default)]
+    pub participants_version: u64,
+    /// Whether the room is closed (archived); no one can join until it is reopened
+    #[serde(default)]
+    pub closed: bool,
+    /// Shard prefixed to the message IDs assigned in this room (`None` = no prefix)
+    ///
+    /// Set when several server instances may host a room with the same ID, so that
+    /// their message IDs never collide.
+    #[serde(default)]
+    pub message_id_shard: Option<ShardId>,
+    /// Timestamp of the last message or membership change (`None` = no activity since creation)
+    #[serde(default)]
+    pub last_activity_at: Option<Timestamp>,
+    /// Tenant of the client that created the room (`None` = created by the server or the admin API)
+    ///
+    /// Used to count rooms per tenant in multi-tenant deployments.
+    #[serde(default)]
+    pub tenant: Option<String>,
 }
 
 impl Room {
@@ -40,6 +74,14 @@ impl Room {
             created_at,
             participant_capacity: DEFAULT_PARTICIPANT_CAPACITY,
             message_capacity: DEFAULT_MESSAGE_CAPACITY,
+            locked: false,
+            next_message_seq: 1,
+            message_quota_per_client: None,
+            participants_version: 0,
+            closed: false,
+            message_id_shard: None,
+            last_activity_at: None,
+            tenant: None,
         }
     }
 
@@ -57,43 +99,171 @@ impl Room {
             created_at,
             participant_capacity,
             message_capacity,
+            locked: false,
+            next_message_seq: 1,
+            message_quota_per_client: None,
+            participants_version: 0,
+            closed: false,
+            message_id_shard: None,
+            last_activity_at: None,
+            tenant: None,
         }
     }
 
+    /// Set the maximum number of stored messages authored by a single client
+    pub fn with_message_quota_per_client(mut self, quota: usize) -> Self {
+        self.message_quota_per_client = Some(quota);
+        self
+    }
+
+    /// Set the shard prefixed to the message IDs assigned in this room
+    pub fn with_message_id_shard(mut self, shard: ShardId) -> Self {
+        self.message_id_shard = Some(shard);
+        self
+    }
+
     /// Add a participant to the room
     ///
     /// # Errors
     ///
     /// Returns `RoomError::CapacityExceeded` if the room is at full capacity
     pub fn add_participant(&mut self, participant: Participant) -> Result<(), RoomError> {
+        if self.closed {
+            return Err(RoomError::RoomClosed);
+        }
         if self.participants.len() >= self.participant_capacity {
             return Err(RoomError::CapacityExceeded {
                 capacity: self.participant_capacity,
                 current: self.participants.len(),
             });
         }
+        self.last_activity_at = Some(participant.connected_at);
         self.participants.push(participant);
+        self.participants_version += 1;
         Ok(())
     }
 
     /// Remove a participant from the room by ID
-    pub fn remove_participant(&mut self, participant_id: &ClientId) {
+    ///
+    /// `left_at` is recorded as the last activity of the room if the participant was found.
+    pub fn remove_participant(&mut self, participant_id: &ClientId, left_at: Timestamp) {
+        let before = self.participants.len();
         self.participants.retain(|p| &p.id != participant_id);
+        if self.participants.len() != before {
+            self.participants_version += 1;
+            self.last_activity_at = Some(left_at);
+        }
     }
 
-    /// Add a message to the room history
+    /// Add a message to the room history and assign it a message ID
+    ///
+    /// # Returns
+    ///
+    /// The `MessageId` assigned to the message (room ID + per-room monotonic sequence)
+    ///
+    /// If the author has reached the per-client message quota, the author's oldest
+    /// message is evicted first.
     ///
     /// # Errors
     ///
     /// Returns `RoomError::MessageCapacityExceeded` if the room message history is at full capacity
-    pub fn add_message(&mut self, message: ChatMessage) -> Result<(), RoomError> {
+    pub fn add_message(&mut self, mut message: ChatMessage) -> Result<MessageId, RoomError> {
+        if let Some(quota) = self.message_quota_per_client {
+            let authored = self
+                .messages
+                .iter()
+                .filter(|m| m.from == message.from)
+                .count();
+            if authored >= quota
+                && let Some(oldest) = self.messages.iter().position(|m| m.from == message.from)
+            {
+                self.messages.remove(oldest);
+            }
+        }
         if self.messages.len() >= self.message_capacity {
             return Err(RoomError::MessageCapacityExceeded {
                 capacity: self.message_capacity,
                 current: self.messages.len(),
             });
         }
+        let message_id = match &self.message_id_shard {
+            Some(shard) => MessageId::new_in_shard(shard, &self.id, self.next_message_seq),
+            None => MessageId::new(&self.id, self.next_message_seq),
+        };
+        self.next_message_seq += 1;
+        message.id = Some(message_id.clone());
+        self.last_activity_at = Some(message.timestamp);
         self.messages.push(message);
+        Ok(message_id)
+    }
+
+    /// Remove the oldest message from the history (used to keep a budget shared by several rooms)
+    ///
+    /// # Returns
+    ///
+    /// The evicted message, or `None` if the history is empty
+    pub fn evict_oldest_message(&mut self) -> Option<ChatMessage> {
+        if self.messages.is_empty() {
+            None
+        } else {
+            Some(self.messages.remove(0))
+        }
+    }
+
+    /// Replace the content of a message in the history, keeping its position and ID
+    ///
+    /// # Returns
+    ///
+    /// The edited message
+    ///
+    /// # Errors
+    ///
+    /// Returns `RoomError::MessageNotFound` if no message has the ID (or it was deleted), or
+    /// `RoomError::NotMessageAuthor` if `editor` is not the author of the message
+    pub fn edit_message(
+        &mut self,
+        message_id: &MessageId,
+        editor: &ClientId,
+        content: MessageContent,
+        edited_at: Timestamp,
+    ) -> Result<ChatMessage, RoomError> {
+        let message = self
+            .messages
+            .iter_mut()
+            .find(|m| m.id.as_ref() == Some(message_id) && !m.deleted)
+            .ok_or_else(|| RoomError::MessageNotFound(message_id.to_string()))?;
+        if &message.from != editor {
+            return Err(RoomError::NotMessageAuthor(message_id.to_string()));
+        }
+        message.content = content;
+        message.edited_at = Some(edited_at);
+        Ok(message.clone())
+    }
+
+    /// Delete a message, leaving a tombstone (empty content, `deleted = true`) in its place
+    ///
+    /// The message keeps its position in the history, so that the indices of the other
+    /// messages do not change.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RoomError::MessageNotFound` if no message has the ID, or
+    /// `RoomError::NotMessageAuthor` if `requester` is not the author of the message
+    pub fn delete_message(
+        &mut self,
+        message_id: &MessageId,
+        requester: &ClientId,
+    ) -> Result<(), RoomError> {
+        let message = self
+            .messages
+            .iter_mut()
+            .find(|m| m.id.as_ref() == Some(message_id))
+            .ok_or_else(|| RoomError::MessageNotFound(message_id.to_string()))?;
+        if &message.from != requester {
+            return Err(RoomError::NotMessageAuthor(message_id.to_string()));
+        }
+        message.content = MessageContent::tombstone();
+        message.deleted = true;
         Ok(())
     }
 
@@ -101,42 +271,115 @@ impl Room {
     pub fn get_participant(&self, participant_id: &ClientId) -> Option<&Participant> {
         self.participants.iter().find(|p| &p.id == participant_id)
     }
+
+    /// Timestamp of the last activity (the creation timestamp if there has been none)
+    pub fn last_activity(&self) -> Timestamp {
+        self.last_activity_at.unwrap_or(self.created_at)
+    }
+
+    /// Whether the room has had no participants and no activity for `idle_timeout_ms` at `now`
+    pub fn is_idle(&self, now: Timestamp, idle_timeout_ms: i64) -> bool {
+        self.participants.is_empty()
+            && now.value() - self.last_activity().value() >= idle_timeout_ms
+    }
+
+    /// Lock the room so that participants cannot post messages
+    pub fn lock(&mut self) {
+        self.locked = true;
+    }
+
+    /// Unlock the room so that participants can post messages again
+    pub fn unlock(&mut self) {
+        self.locked = false;
+    }
+
+    /// Close (archive) the room so that no one can join it
+    ///
+    /// Participants who are already connected are not removed.
+    pub fn close(&mut self) {
+        self.closed = true;
+    }
+
+    /// Reopen a closed room so that participants can join again
+    pub fn reopen(&mut self) {
+        self.closed = false;
+    }
+
+    /// Tear down the room: close it and evict all participants and messages
+    ///
+    /// # Returns
+    ///
+    /// The participants who were in the room
+    pub fn tear_down(&mut self) -> Vec<Participant> {
+        self.close();
+        self.messages.clear();
+        let evicted = std::mem::take(&mut self.participants);
+        if !evicted.is_empty() {
+            self.participants_version += 1;
+        }
+        evicted
+    }
 }
 
 /// Represents a participant in a chat room
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Participant {
     /// Participant identifier (client_id)
     pub id: ClientId,
     /// Timestamp when the participant connected
     pub connected_at: Timestamp,
+    /// Whether the participant is a bot
+    #[serde(default)]
+    pub is_bot: bool,
 }
 
 impl Participant {
     /// Create a new participant
     pub fn new(id: ClientId, connected_at: Timestamp) -> Self {
-        Self { id, connected_at }
+        Self {
+            id,
+            connected_at,
+            is_bot: false,
+        }
+    }
+
+    /// Set whether the participant is a bot
+    pub fn with_bot(mut self, is_bot: bool) -> Self {
+        self.is_bot = is_bot;
+        self
     }
 }
 
 /// Represents a chat message in the domain model
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ChatMessage {
+    /// Message identifier (assigned when the message is added to a room)
+    #[serde(default)]
+    pub id: Option<MessageId>,
     /// Sender's participant ID
     pub from: ClientId,
     /// Message content
     pub content: MessageContent,
     /// Timestamp when the message was sent
     pub timestamp: Timestamp,
+    /// Timestamp of the last edit (`None` if the message has not been edited)
+    #[serde(default)]
+    pub edited_at: Option<Timestamp>,
+    /// Whether the message was deleted (its content is cleared, its position is kept)
+    #[serde(default)]
+    pub deleted: bool,
 }
 
 impl ChatMessage {
     /// Create a new chat message
     pub fn new(from: ClientId, content: MessageContent, timestamp: Timestamp) -> Self {
         Self {
+            id: None,
             from,
             content,
             timestamp,
+            edited_at: None,
+            deleted: false,
         }
     }
 }
@@ -203,7 +446,7 @@ mod tests {
 
         // when (操作):
         let alice_id = ClientId::new("alice".to_string()).unwrap();
-        room.remove_participant(&alice_id);
+        room.remove_participant(&alice_id, Timestamp::new(3000));
 
         // then (期待する結果):
         assert_eq!(room.participants.len(), 1);
@@ -240,6 +483,166 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_room_add_message_assigns_increasing_ids() {
+        // テスト項目: ルーム内のメッセージ ID は単調増加し、別のルームの ID とは重複しない
+        // given (前提条件):
+        let mut room1 = Room::new(RoomIdFactory::generate().unwrap(), Timestamp::new(0));
+        let mut room2 = Room::new(RoomIdFactory::generate().unwrap(), Timestamp::new(0));
+        let new_message = || {
+            ChatMessage::new(
+                ClientId::new("alice".to_string()).unwrap(),
+                MessageContent::new("Hello!".to_string()).unwrap(),
+                Timestamp::new(3000),
+            )
+        };
+
+        // when (操作):
+        let room1_ids: Vec<MessageId> = (0..12)
+            .map(|_| room1.add_message(new_message()).unwrap())
+            .collect();
+        let room2_id = room2.add_message(new_message()).unwrap();
+
+        // then (期待する結果):
+        assert!(room1_ids.windows(2).all(|pair| pair[0] < pair[1]));
+        assert_eq!(room1.messages[11].id.as_ref(), Some(&room1_ids[11]));
+        assert!(!room1_ids.contains(&room2_id));
+    }
+
+    #[test]
+    fn test_room_edit_message() {
+        // テスト項目: 作成者はメッセージを編集でき、位置と ID を保ったまま内容と編集日時が更新される
+        // given (前提条件):
+        let mut room = Room::new(RoomIdFactory::generate().unwrap(), Timestamp::new(0));
+        let alice = ClientId::new("alice".to_string()).unwrap();
+        let message_id = room
+            .add_message(ChatMessage::new(
+                alice.clone(),
+                MessageContent::new("Helo!".to_string()).unwrap(),
+                Timestamp::new(3000),
+            ))
+            .unwrap();
+
+        // when (操作):
+        let edited = room
+            .edit_message(
+                &message_id,
+                &alice,
+                MessageContent::new("Hello!".to_string()).unwrap(),
+                Timestamp::new(4000),
+            )
+            .unwrap();
+
+        // then (期待する結果):
+        assert_eq!(edited.id.as_ref(), Some(&message_id));
+        assert_eq!(edited.content.as_str(), "Hello!");
+        assert_eq!(edited.timestamp, Timestamp::new(3000));
+        assert_eq!(edited.edited_at, Some(Timestamp::new(4000)));
+        assert_eq!(room.messages, vec![edited]);
+    }
+
+    #[test]
+    fn test_room_edit_message_rejects_other_client_and_unknown_id() {
+        // テスト項目: 作成者以外による編集と、存在しないメッセージの編集は拒否され、内容は変わらない
+        // given (前提条件):
+        let mut room = Room::new(RoomIdFactory::generate().unwrap(), Timestamp::new(0));
+        let alice = ClientId::new("alice".to_string()).unwrap();
+        let bob = ClientId::new("bob".to_string()).unwrap();
+        let message_id = room
+            .add_message(ChatMessage::new(
+                alice.clone(),
+                MessageContent::new("Hello!".to_string()).unwrap(),
+                Timestamp::new(3000),
+            ))
+            .unwrap();
+        let unknown_id = MessageId::new(&room.id, 99);
+        let content = || MessageContent::new("Hacked".to_string()).unwrap();
+
+        // when (操作):
+        let by_bob = room.edit_message(&message_id, &bob, content(), Timestamp::new(4000));
+        let unknown = room.edit_message(&unknown_id, &alice, content(), Timestamp::new(4000));
+
+        // then (期待する結果):
+        assert_eq!(
+            by_bob,
+            Err(RoomError::NotMessageAuthor(message_id.to_string()))
+        );
+        assert_eq!(
+            unknown,
+            Err(RoomError::MessageNotFound(unknown_id.to_string()))
+        );
+        assert_eq!(room.messages[0].content.as_str(), "Hello!");
+        assert_eq!(room.messages[0].edited_at, None);
+    }
+
+    #[test]
+    fn test_room_delete_message_leaves_tombstone() {
+        // テスト項目: 作成者はメッセージを削除でき、位置を保ったまま内容が空になり、以降は編集できない
+        // given (前提条件):
+        let mut room = Room::new(RoomIdFactory::generate().unwrap(), Timestamp::new(0));
+        let alice = ClientId::new("alice".to_string()).unwrap();
+        let bob = ClientId::new("bob".to_string()).unwrap();
+        let mut ids = Vec::new();
+        for content in ["first", "second"] {
+            ids.push(
+                room.add_message(ChatMessage::new(
+                    alice.clone(),
+                    MessageContent::new(content.to_string()).unwrap(),
+                    Timestamp::new(3000),
+                ))
+                .unwrap(),
+            );
+        }
+
+        // when (操作):
+        let by_bob = room.delete_message(&ids[0], &bob);
+        let by_alice = room.delete_message(&ids[0], &alice);
+        let edit = room.edit_message(
+            &ids[0],
+            &alice,
+            MessageContent::new("again".to_string()).unwrap(),
+            Timestamp::new(4000),
+        );
+
+        // then (期待する結果):
+        assert_eq!(by_bob, Err(RoomError::NotMessageAuthor(ids[0].to_string())));
+        assert_eq!(by_alice, Ok(()));
+        assert_eq!(edit, Err(RoomError::MessageNotFound(ids[0].to_string())));
+        assert_eq!(room.messages.len(), 2);
+        assert!(room.messages[0].deleted);
+        assert_eq!(room.messages[0].content.as_str(), "");
+        assert_eq!(room.messages[0].id.as_ref(), Some(&ids[0]));
+        assert!(!room.messages[1].deleted);
+        assert_eq!(room.messages[1].content.as_str(), "second");
+    }
+
+    #[test]
+    fn test_rooms_with_different_shards_assign_disjoint_ids() {
+        // テスト項目: 同じ ID のルームでも、シャードが異なれば同じ連番のメッセージに異なる ID が割り当てられる
+        // given (前提条件):
+        let room_id = RoomIdFactory::generate().unwrap();
+        let shard = |id: &str| ShardId::new(id.to_string()).unwrap();
+        let mut room_a =
+            Room::new(room_id.clone(), Timestamp::new(0)).with_message_id_shard(shard("a"));
+        let mut room_b = Room::new(room_id, Timestamp::new(0)).with_message_id_shard(shard("b"));
+        let new_message = || {
+            ChatMessage::new(
+                ClientId::new("alice".to_string()).unwrap(),
+                MessageContent::new("Hello!".to_string()).unwrap(),
+                Timestamp::new(3000),
+            )
+        };
+
+        // when (操作):
+        let id_a = room_a.add_message(new_message()).unwrap();
+        let id_b = room_b.add_message(new_message()).unwrap();
+
+        // then (期待する結果):
+        assert_ne!(id_a, id_b);
+        assert!(id_a.as_str().starts_with("a-"));
+        assert!(id_b.as_str().starts_with("b-"));
+    }
+
     #[test]
     fn test_room_get_participant() {
         // テスト項目: ID で参加者を取得できる
@@ -354,6 +757,33 @@ mod tests {
         assert_eq!(room.messages.len(), 2);
     }
 
+    #[test]
+    fn test_room_message_quota_per_client_evicts_oldest_authored() {
+        // テスト項目: 1 人の投稿数が上限に達すると、その参加者の最も古いメッセージが削除され、他の参加者のメッセージと最近のメッセージは残る
+        // given (前提条件):
+        let mut room = Room::new(RoomIdFactory::generate().unwrap(), Timestamp::new(0))
+            .with_message_quota_per_client(2);
+        let post = |room: &mut Room, from: &str, content: &str| {
+            room.add_message(ChatMessage::new(
+                ClientId::new(from.to_string()).unwrap(),
+                MessageContent::new(content.to_string()).unwrap(),
+                Timestamp::new(1000),
+            ))
+            .unwrap();
+        };
+        post(&mut room, "alice", "alice 1");
+        post(&mut room, "bob", "bob 1");
+        post(&mut room, "alice", "alice 2");
+
+        // when (操作):
+        post(&mut room, "alice", "alice 3");
+        post(&mut room, "alice", "alice 4");
+
+        // then (期待する結果):
+        let contents: Vec<&str> = room.messages.iter().map(|m| m.content.as_str()).collect();
+        assert_eq!(contents, vec!["bob 1", "alice 3", "alice 4"]);
+    }
+
     #[test]
     fn test_room_default_capacities() {
         // テスト項目: デフォルトの上限値が正しく設定される
@@ -364,4 +794,103 @@ mod tests {
         assert_eq!(room.participant_capacity, DEFAULT_PARTICIPANT_CAPACITY);
         assert_eq!(room.message_capacity, DEFAULT_MESSAGE_CAPACITY);
     }
+
+    #[test]
+    fn test_room_lock_and_unlock() {
+        // テスト項目: Room をロック・ロック解除できる（初期状態はロックされていない）
+        // given (前提条件):
+        let mut room = Room::new(RoomIdFactory::generate().unwrap(), Timestamp::new(0));
+        assert!(!room.locked);
+
+        // when (操作):
+        room.lock();
+        let locked = room.locked;
+        room.unlock();
+
+        // then (期待する結果):
+        assert!(locked);
+        assert!(!room.locked);
+    }
+
+    #[test]
+    fn test_closed_room_rejects_participants_until_reopened() {
+        // テスト項目: 閉じた Room には参加者を追加できず、再開すると追加できる
+        // given (前提条件):
+        let mut room = Room::new(RoomIdFactory::generate().unwrap(), Timestamp::new(0));
+        let alice = Participant::new(
+            ClientId::new("alice".to_string()).unwrap(),
+            Timestamp::new(0),
+        );
+        room.close();
+
+        // when (操作):
+        let closed_result = room.add_participant(alice.clone());
+        room.reopen();
+        let reopened_result = room.add_participant(alice);
+
+        // then (期待する結果):
+        assert_eq!(closed_result, Err(RoomError::RoomClosed));
+        assert!(reopened_result.is_ok());
+        assert_eq!(room.participants.len(), 1);
+    }
+
+    #[test]
+    fn test_tear_down_evicts_participants_and_messages() {
+        // テスト項目: Room を取り壊すと参加者とメッセージが取り除かれ、Room は閉じられる
+        // given (前提条件):
+        let mut room = Room::new(RoomIdFactory::generate().unwrap(), Timestamp::new(0));
+        let alice = ClientId::new("alice".to_string()).unwrap();
+        room.add_participant(Participant::new(alice.clone(), Timestamp::new(0)))
+            .unwrap();
+        room.add_message(ChatMessage::new(
+            alice.clone(),
+            MessageContent::new("Hello".to_string()).unwrap(),
+            Timestamp::new(0),
+        ))
+        .unwrap();
+        let version = room.participants_version;
+
+        // when (操作):
+        let evicted = room.tear_down();
+
+        // then (期待する結果):
+        assert_eq!(evicted.len(), 1);
+        assert_eq!(evicted[0].id, alice);
+        assert!(room.participants.is_empty());
+        assert!(room.messages.is_empty());
+        assert!(room.closed);
+        assert!(room.participants_version > version);
+    }
+
+    #[test]
+    fn test_room_last_activity_tracks_messages_and_membership() {
+        // テスト項目: 最終活動時刻は作成時刻から始まり、入室・メッセージ・退室のたびに更新され、参加者がおらず最終活動から一定時間が経つと無活動と判定される
+        // given (前提条件):
+        let mut room = Room::new(RoomIdFactory::generate().unwrap(), Timestamp::new(1000));
+        let alice = ClientId::new("alice".to_string()).unwrap();
+        let created_activity = room.last_activity();
+
+        // when (操作):
+        room.add_participant(Participant::new(alice.clone(), Timestamp::new(2000)))
+            .unwrap();
+        let joined_activity = room.last_activity();
+        room.add_message(ChatMessage::new(
+            alice.clone(),
+            MessageContent::new("Hello".to_string()).unwrap(),
+            Timestamp::new(3000),
+        ))
+        .unwrap();
+        let message_activity = room.last_activity();
+        let idle_with_participant = room.is_idle(Timestamp::new(100_000), 1000);
+        room.remove_participant(&alice, Timestamp::new(4000));
+
+        // then (期待する結果):
+        assert_eq!(created_activity, Timestamp::new(1000));
+        assert_eq!(joined_activity, Timestamp::new(2000));
+        assert_eq!(message_activity, Timestamp::new(3000));
+        assert_eq!(room.last_activity(), Timestamp::new(4000));
+        assert!(!idle_with_participant);
+        assert!(!room.is_idle(Timestamp::new(4999), 1000));
+        assert!(room.is_idle(Timestamp::new(5000), 1000));
+    }
 }
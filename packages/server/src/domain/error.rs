@@ -17,6 +17,18 @@ pub enum ValueObjectError {
     #[error("ClientId cannot exceed {max} characters (got {actual})")]
     ClientIdTooLong { max: usize, actual: usize },
 
+    /// ClientId contains a character outside `[A-Za-z0-9_-]` error
+    #[error("ClientId must only contain [A-Za-z0-9_-] (found: {found:?})")]
+    ClientIdInvalidChars { found: char },
+
+    /// ClientId missing the required tenant prefix error
+    #[error("ClientId must start with tenant prefix '{expected}:'")]
+    ClientIdTenantPrefixMissing { expected: String },
+
+    /// ClientId tenant prefix mismatch error
+    #[error("ClientId tenant prefix must be '{expected}' (got: '{actual}')")]
+    ClientIdTenantPrefixMismatch { expected: String, actual: String },
+
     /// RoomId validation error
     #[error("RoomId cannot be empty")]
     RoomIdEmpty,
@@ -25,6 +37,14 @@ pub enum ValueObjectError {
     #[error("RoomId must be a valid UUID format (got: {0})")]
     RoomIdInvalidFormat(String),
 
+    /// MessageId invalid format error (not `[<shard>-]<room_id>:<sequence>`)
+    #[error("MessageId must be '[<shard>-]<room_id>:<sequence>' (got: {0})")]
+    MessageIdInvalidFormat(String),
+
+    /// ShardId validation error (empty, too long or not `[A-Za-z0-9_]`)
+    #[error("ShardId must be 1 to 16 characters of [A-Za-z0-9_] (got: {0})")]
+    ShardIdInvalid(String),
+
     /// MessageContent validation error
     #[error("MessageContent cannot be empty")]
     MessageContentEmpty,
@@ -32,6 +52,22 @@ pub enum ValueObjectError {
     /// MessageContent too long error
     #[error("MessageContent cannot exceed {max} characters (got {actual})")]
     MessageContentTooLong { max: usize, actual: usize },
+
+    /// MessageContent contains too many emoji error
+    #[error("MessageContent cannot contain more than {max} emoji (got {actual})")]
+    MessageContentTooManyEmoji { max: usize, actual: usize },
+
+    /// MessageContent contains a link to a denied domain error
+    #[error("MessageContent cannot contain links to '{domain}'")]
+    MessageContentDeniedLink { domain: String },
+
+    /// MessageContent contains too many mentions error
+    #[error("MessageContent cannot mention more than {max} participants (got {actual})")]
+    MessageContentTooManyMentions { max: usize, actual: usize },
+
+    /// Timestamp out of range error (negative or beyond the far-future bound)
+    #[error("Timestamp must be between 0 and {max} milliseconds (got {actual})")]
+    TimestampOutOfRange { max: i64, actual: i64 },
 }
 
 // ------------------------------------------------------------------------------------------------
@@ -48,6 +84,18 @@ pub enum RoomError {
     /// Message capacity exceeded error
     #[error("Message capacity exceeded: maximum {capacity} messages allowed (current: {current})")]
     MessageCapacityExceeded { capacity: usize, current: usize },
+
+    /// Room is closed (archived) and cannot be joined
+    #[error("Room is closed")]
+    RoomClosed,
+
+    /// Message not found in the room history
+    #[error("Message not found: {0}")]
+    MessageNotFound(String),
+
+    /// Message is being changed by a client other than its author
+    #[error("Only the author can change message {0}")]
+    NotMessageAuthor(String),
 }
 
 // ------------------------------------------------------------------------------------------------
@@ -68,6 +116,22 @@ pub enum RepositoryError {
     /// Room not found error
     #[error("Room not found")]
     RoomNotFound,
+
+    /// Room already exists error
+    #[error("Room already exists: {0}")]
+    RoomAlreadyExists(String),
+
+    /// Client id already connected to one of the rooms
+    #[error("Client id already connected: {0}")]
+    DuplicateClientId(String),
+
+    /// Storage backend error (e.g. a file or database failure, or a stored value failing validation)
+    #[error("Storage error: {0}")]
+    Storage(String),
+
+    /// Room domain rule violation (e.g. capacity exceeded)
+    #[error(transparent)]
+    Room(#[from] RoomError),
 }
 
 // ------------------------------------------------------------------------------------------------
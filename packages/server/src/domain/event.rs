@@ -0,0 +1,119 @@
+//! ドメインイベントの発行の抽象化
+//!
+//! ## 責務
+//!
+//! EventBus は「ドメインで発生した出来事（ルームの作成、メッセージの拒否など）を発行する」責務を持ちます。
+//! 発行したイベントは監査・分析や、不正利用のアラートに利用します。
+//! 発行先（ログ、メモリ、外部キューなど）は問いません。
+
+use async_trait::async_trait;
+
+use std::fmt;
+
+use super::{ClientId, RoomId, Timestamp};
+
+/// メッセージが拒否された理由
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MessageRejectionReason {
+    /// Room がロックされている
+    RoomLocked,
+    /// Room のメッセージ容量を超えている
+    CapacityExceeded,
+    /// 送信者のメッセージ送信レートの上限を超えている
+    RateLimited,
+    /// 内容が検証（長さ、絵文字数、リンクの拒否リストなど）に違反している
+    InvalidContent {
+        /// 違反の詳細
+        detail: String,
+    },
+}
+
+impl MessageRejectionReason {
+    /// 理由の種別を表すコード（`room_locked` など）
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::RoomLocked => "room_locked",
+            Self::CapacityExceeded => "capacity_exceeded",
+            Self::RateLimited => "rate_limited",
+            Self::InvalidContent { .. } => "invalid_content",
+        }
+    }
+}
+
+impl fmt::Display for MessageRejectionReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidContent { detail } => write!(f, "{}: {}", self.code(), detail),
+            _ => f.write_str(self.code()),
+        }
+    }
+}
+
+/// ドメインイベント
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DomainEvent {
+    /// ルームが作成された
+    RoomCreated {
+        /// 作成されたルームの ID
+        room_id: RoomId,
+        /// 参加者数の上限
+        participant_capacity: usize,
+        /// メッセージ数の上限
+        message_capacity: usize,
+        /// 作成日時
+        created_at: Timestamp,
+        /// 作成したクライアント（サーバが作成した場合は `None`）
+        creator: Option<ClientId>,
+    },
+    /// 無活動のルームが閉じられ、削除された
+    RoomClosed {
+        /// 閉じられたルームの ID
+        room_id: RoomId,
+        /// 最後の活動（メッセージ、または参加者の増減）の日時
+        last_activity_at: Timestamp,
+        /// 閉じられた日時
+        closed_at: Timestamp,
+    },
+    /// 参加者のメッセージが拒否された
+    MessageRejected {
+        /// メッセージを送信しようとした Room の ID
+        room_id: RoomId,
+        /// メッセージを送信したクライアント
+        client_id: ClientId,
+        /// 拒否した理由
+        reason: MessageRejectionReason,
+    },
+    /// 接続が終了した（事後分析用の接続の要約）
+    ConnectionEnded {
+        /// 切断したクライアント
+        client_id: ClientId,
+        /// 接続していた時間（ミリ秒）
+        duration_ms: u64,
+        /// クライアントが送信したチャットメッセージ数
+        messages_sent: u64,
+        /// クライアントに送信したフレーム数
+        messages_received: u64,
+        /// クライアントから受信したバイト数
+        bytes_in: u64,
+        /// クライアントに送信したバイト数
+        bytes_out: u64,
+        /// 切断の理由（`closed` / `timeout`）
+        reason: String,
+    },
+}
+
+/// ドメインイベントの発行先の抽象化
+///
+/// ## 実装
+///
+/// - `TracingEventBus`: 構造化ログとして出力する実装（`infrastructure/event_bus/tracing.rs`）
+/// - `InMemoryEventBus`: 発行されたイベントをメモリに保持する実装（`infrastructure/event_bus/inmemory.rs`）
+#[async_trait]
+pub trait EventBus: Send + Sync {
+    /// ドメインイベントを発行
+    ///
+    /// # 引数
+    ///
+    /// - `event`: 発行するイベント
+    async fn publish(&self, event: DomainEvent);
+}
@@ -1,5 +1,7 @@
 //! Domain factories for creating domain entities and value objects.
 
+use std::collections::HashSet;
+
 use super::{RoomId, error::ValueObjectError};
 
 /// Factory for generating RoomId instances.
@@ -20,8 +22,39 @@ impl RoomIdFactory {
     /// This method should not fail in practice, but returns Result for consistency
     /// with the domain error handling pattern.
     pub fn generate() -> Result<RoomId, ValueObjectError> {
-        let uuid = uuid::Uuid::new_v4();
-        RoomId::from_uuid(uuid)
+        RoomId::from_uuid(uuid::Uuid::new_v4())
+    }
+
+    /// Generate a new RoomId with a random UUID v4.
+    ///
+    /// Infallible variant of [`RoomIdFactory::generate`].
+    pub fn generate_uuid() -> RoomId {
+        RoomId::from(uuid::Uuid::new_v4())
+    }
+
+    /// Generate a new RoomId that does not collide with any of the existing ids.
+    ///
+    /// A UUID v4 collision is astronomically unlikely, but this guarantees uniqueness
+    /// by regenerating until an unused id is found.
+    ///
+    /// # Arguments
+    ///
+    /// * `existing` - Ids of the existing rooms
+    pub fn generate_unique(existing: &HashSet<RoomId>) -> RoomId {
+        Self::generate_unique_with(existing, uuid::Uuid::new_v4)
+    }
+
+    /// Generate a new RoomId not in `existing`, drawing UUIDs from `next_uuid`.
+    pub(crate) fn generate_unique_with(
+        existing: &HashSet<RoomId>,
+        mut next_uuid: impl FnMut() -> uuid::Uuid,
+    ) -> RoomId {
+        loop {
+            let room_id = RoomId::from(next_uuid());
+            if !existing.contains(&room_id) {
+                return room_id;
+            }
+        }
     }
 }
 
@@ -54,4 +87,21 @@ mod tests {
         // then (期待する結果):
         assert_ne!(room_id1, room_id2);
     }
+
+    #[test]
+    fn test_room_id_factory_generate_unique_avoids_existing_id() {
+        // テスト項目: 既存の ID と衝突した場合は、衝突しない ID が得られるまで生成し直す
+        // given (前提条件):
+        let colliding = uuid::Uuid::from_u128(1);
+        let fresh = uuid::Uuid::from_u128(2);
+        let existing = HashSet::from([RoomId::from(colliding)]);
+        let mut uuids = vec![fresh, colliding, colliding];
+
+        // when (操作):
+        let room_id = RoomIdFactory::generate_unique_with(&existing, || uuids.pop().unwrap());
+
+        // then (期待する結果):
+        assert_eq!(room_id, RoomId::from(fresh));
+        assert!(uuids.is_empty());
+    }
 }
@@ -0,0 +1,121 @@
+//! メッセージの編集・削除の監査ログの抽象化
+//!
+//! ## 責務
+//!
+//! MessageAuditLog は「メッセージの編集・削除の操作を不変な監査エントリとして記録する」責務を持ちます。
+//! 監査エントリは変更可能なメッセージとは別に保持し、記録後に変更・削除しません。
+//! 内容そのものではなく内容のハッシュを記録するため、監査ログから削除したメッセージの内容は復元できません。
+//! 記録先（メモリ、DB、外部ストレージなど）は問いません。
+//!
+//! ## テスト実装の作業記録
+//!
+//! ### 何をテストしているか
+//! - MessageAuditEntry::hash_content() による内容のハッシュ
+//!
+//! ### なぜこのテストが必要か
+//! - 監査エントリの変更前後のハッシュを外部のツールで検証できる形式（SHA-256 の 16 進表記）であることを保証
+//!
+//! ### どのような状況を想定しているか
+//! - 正常系：既知の内容のハッシュ、削除後の空の内容のハッシュ
+
+use async_trait::async_trait;
+use sha2::{Digest, Sha256};
+
+use super::{ClientId, MessageContent, MessageId, RoomId, Timestamp};
+
+/// 監査の対象となるメッセージの操作
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MessageAuditAction {
+    /// メッセージの編集
+    Edit,
+    /// メッセージの削除
+    Delete,
+}
+
+impl MessageAuditAction {
+    /// 操作の種別を表す文字列（`edit` / `delete`）
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Edit => "edit",
+            Self::Delete => "delete",
+        }
+    }
+}
+
+/// メッセージの編集・削除の監査エントリ
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MessageAuditEntry {
+    /// メッセージの Room の ID
+    pub room_id: RoomId,
+    /// 操作したメッセージの ID
+    pub message_id: MessageId,
+    /// 操作の種別
+    pub action: MessageAuditAction,
+    /// 操作したクライアントの ID
+    pub actor: ClientId,
+    /// 操作前の内容のハッシュ（[`MessageAuditEntry::hash_content`]）
+    pub before_hash: String,
+    /// 操作後の内容のハッシュ（削除の場合は空の内容のハッシュ）
+    pub after_hash: String,
+    /// 操作した時刻（サーバの Clock）
+    pub at: Timestamp,
+}
+
+impl MessageAuditEntry {
+    /// メッセージ内容のハッシュ（UTF-8 の内容の SHA-256 を小文字の 16 進表記にしたもの）
+    pub fn hash_content(content: &MessageContent) -> String {
+        Sha256::digest(content.as_str().as_bytes())
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect()
+    }
+}
+
+/// メッセージの編集・削除の監査ログの抽象化
+///
+/// ## 実装
+///
+/// - `InMemoryMessageAuditLog`: メモリに保持する実装（`infrastructure/message_audit/inmemory.rs`）
+#[async_trait]
+pub trait MessageAuditLog: Send + Sync {
+    /// 監査エントリを追記
+    ///
+    /// # 引数
+    ///
+    /// - `entry`: 記録する監査エントリ
+    async fn record(&self, entry: MessageAuditEntry);
+
+    /// メッセージの監査エントリを記録した順に取得
+    ///
+    /// # 引数
+    ///
+    /// - `room_id`: メッセージの Room の ID
+    /// - `message_id`: メッセージの ID
+    async fn history(&self, room_id: &RoomId, message_id: &MessageId) -> Vec<MessageAuditEntry>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hash_content_is_sha256_hex() {
+        // テスト項目: 内容のハッシュは SHA-256 の小文字の 16 進表記になる
+        // given (前提条件):
+        let content = MessageContent::new("abc".to_string()).unwrap();
+
+        // when (操作):
+        let hash = MessageAuditEntry::hash_content(&content);
+        let tombstone_hash = MessageAuditEntry::hash_content(&MessageContent::tombstone());
+
+        // then (期待する結果):
+        assert_eq!(
+            hash,
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+        assert_eq!(
+            tombstone_hash,
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+    }
+}
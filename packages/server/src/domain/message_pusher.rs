@@ -14,15 +14,148 @@
 //! - ADR: `docs/adr/0001-message-pusher-abstraction-and-placement.md`
 //! - タスク: `docs/tasks/20251112-032514_introduce-message-pusher.md`
 
+use std::str::FromStr;
+
 use async_trait::async_trait;
+use tokio::sync::mpsc::{
+    self, UnboundedReceiver, UnboundedSender,
+    error::{SendError, TryRecvError},
+};
 
 use super::{ClientId, MessagePushError};
 
-/// メッセージ送信用のチャネル型
+/// メッセージの配信優先度
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum MessagePriority {
+    /// 通常のメッセージ（チャットなど）
+    #[default]
+    Normal,
+    /// 優先するメッセージ（サーバからのお知らせなど）。未送信の通常のメッセージより先に送信される
+    High,
+}
+
+impl FromStr for MessagePriority {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "normal" => Ok(Self::Normal),
+            "high" => Ok(Self::High),
+            other => Err(format!(
+                "invalid message priority '{}' (expected 'normal' or 'high')",
+                other
+            )),
+        }
+    }
+}
+
+/// メッセージ送信用のチャネル
 ///
 /// WebSocket や他の通信プロトコルでメッセージを送信するための抽象化。
 /// 実装詳細（tokio の UnboundedSender）を隠蔽し、将来的な変更を容易にします。
-pub type PusherChannel = tokio::sync::mpsc::UnboundedSender<String>;
+///
+/// 優先度ごとの 2 段のチャネルで構成され、`MessagePriority::High` のメッセージは
+/// 未送信の `MessagePriority::Normal` のメッセージより先に受信されます。
+#[derive(Debug, Clone)]
+pub struct PusherChannel {
+    /// 通常のメッセージのチャネル
+    normal: UnboundedSender<String>,
+    /// 優先するメッセージのチャネル
+    high: UnboundedSender<String>,
+}
+
+impl PusherChannel {
+    /// 通常の優先度でメッセージを送信
+    pub fn send(&self, content: String) -> Result<(), SendError<String>> {
+        self.send_with_priority(content, MessagePriority::Normal)
+    }
+
+    /// 優先度を指定してメッセージを送信
+    pub fn send_with_priority(
+        &self,
+        content: String,
+        priority: MessagePriority,
+    ) -> Result<(), SendError<String>> {
+        match priority {
+            MessagePriority::Normal => self.normal.send(content),
+            MessagePriority::High => self.high.send(content),
+        }
+    }
+}
+
+/// メッセージ受信用のチャネル（`PusherChannel` の受信側）
+#[derive(Debug)]
+pub struct PusherReceiver {
+    /// 通常のメッセージのチャネル
+    normal: UnboundedReceiver<String>,
+    /// 優先するメッセージのチャネル
+    high: UnboundedReceiver<String>,
+}
+
+impl PusherReceiver {
+    /// 次のメッセージを受信（優先するメッセージを先に受信する）
+    ///
+    /// # 戻り値
+    ///
+    /// 送信側が全て破棄され、未受信のメッセージもない場合は `None`
+    pub async fn recv(&mut self) -> Option<String> {
+        tokio::select! {
+            biased;
+            Some(content) = self.high.recv() => Some(content),
+            content = self.normal.recv() => content,
+        }
+    }
+
+    /// 待たずに次のメッセージを受信（優先するメッセージを先に受信する）
+    pub fn try_recv(&mut self) -> Result<String, TryRecvError> {
+        self.high.try_recv().or_else(|_| self.normal.try_recv())
+    }
+}
+
+/// 新しいメッセージ送信用のチャネルを作成
+pub fn pusher_channel() -> (PusherChannel, PusherReceiver) {
+    let (normal_tx, normal_rx) = mpsc::unbounded_channel();
+    let (high_tx, high_rx) = mpsc::unbounded_channel();
+    (
+        PusherChannel {
+            normal: normal_tx,
+            high: high_tx,
+        },
+        PusherReceiver {
+            normal: normal_rx,
+            high: high_rx,
+        },
+    )
+}
+
+/// 送信に失敗した送信先
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeliveryFailure {
+    /// 送信先のクライアント ID
+    pub client_id: ClientId,
+    /// 失敗の理由
+    pub reason: String,
+}
+
+/// ブロードキャストの配信結果
+///
+/// 送信先の一覧と、そのうち実際に送信できた数、送信に失敗した送信先を保持します。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeliveryReport {
+    /// 送信先のクライアント ID リスト
+    pub targets: Vec<ClientId>,
+    /// 送信に成功した数
+    pub delivered_count: usize,
+    /// 送信に失敗した送信先
+    pub failures: Vec<DeliveryFailure>,
+}
+
+impl DeliveryReport {
+    /// 送信先の総数
+    pub fn total_targets(&self) -> usize {
+        self.targets.len()
+    }
+}
 
 /// メッセージ送信（通知）の抽象化
 ///
@@ -79,6 +212,10 @@ pub trait MessagePusher: Send + Sync {
     /// - `targets`: 送信先のクライアント ID のリスト
     /// - `content`: 送信するメッセージ内容（JSON 文字列など）
     ///
+    /// # 戻り値
+    ///
+    /// 送信先と、そのうち送信に成功した数（`DeliveryReport`）
+    ///
     /// # エラー
     ///
     /// - `MessagePushError::PushFailed`: 送信に失敗（一部の送信失敗は許容される実装もある）
@@ -91,5 +228,25 @@ pub trait MessagePusher: Send + Sync {
         &self,
         targets: Vec<ClientId>,
         content: &str,
-    ) -> Result<(), MessagePushError>;
+    ) -> Result<DeliveryReport, MessagePushError>;
+
+    /// 優先度を指定して複数のクライアントにメッセージをブロードキャスト
+    ///
+    /// # 引数
+    ///
+    /// - `targets`: 送信先のクライアント ID のリスト
+    /// - `content`: 送信するメッセージ内容（JSON 文字列など）
+    /// - `priority`: 配信優先度（`High` の場合は未送信の通常のメッセージより先に送信される）
+    ///
+    /// # 注意
+    ///
+    /// デフォルト実装は優先度を扱わず、`broadcast` と同じ順序で送信します。
+    async fn broadcast_with_priority(
+        &self,
+        targets: Vec<ClientId>,
+        content: &str,
+        _priority: MessagePriority,
+    ) -> Result<DeliveryReport, MessagePushError> {
+        self.broadcast(targets, content).await
+    }
 }
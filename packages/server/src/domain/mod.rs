@@ -3,16 +3,32 @@
 //! This module contains business logic that is independent of
 //! data transfer objects (DTOs) and infrastructure concerns.
 
+pub mod clock;
+pub mod dead_letter;
 pub mod entity;
 pub mod error;
+pub mod event;
 pub mod factory;
+pub mod message_audit;
 pub mod message_pusher;
 pub mod repository;
 pub mod value_object;
 
+pub use clock::{Clock, ClockExt, FixedClock, SystemClock};
+pub use dead_letter::{DeadLetter, DeadLetterSink};
 pub use entity::{ChatMessage, Participant, Room};
 pub use error::{MessagePushError, RepositoryError, RoomError, ValueObjectError};
+pub use event::{DomainEvent, EventBus, MessageRejectionReason};
 pub use factory::RoomIdFactory;
-pub use message_pusher::{MessagePusher, PusherChannel};
-pub use repository::RoomRepository;
-pub use value_object::{ClientId, MessageContent, RoomId, Timestamp};
+pub use message_audit::{MessageAuditAction, MessageAuditEntry, MessageAuditLog};
+pub use message_pusher::{
+    DeliveryFailure, DeliveryReport, MessagePriority, MessagePusher, PusherChannel, PusherReceiver,
+    pusher_channel,
+};
+pub use repository::{ClientIdResolver, RoomRepository};
+pub use value_object::{
+    ClientId, ClockSkew, DeniedLinkAction, LinkDenylist, MENTION_DEFAULT_MAX_LENGTH,
+    MENTION_PREFIX, MENTIONS_DEFAULT_MAX_COUNT, MESSAGE_CONTENT_MAX_LENGTH, MentionLimits,
+    MessageContent, MessageContentPolicy, MessageId, REMOVED_LINK_PLACEHOLDER, RoomId, ShardId,
+    TENANT_PREFIX_SEPARATOR, TIMESTAMP_MAX_MILLIS, TenantPrefixPolicy, Timestamp, TimestampUnit,
+};
@@ -5,7 +5,13 @@
 
 use async_trait::async_trait;
 
-use super::{ClientId, MessageContent, Participant, RepositoryError, Room, Timestamp};
+use super::{
+    ChatMessage, ClientId, MessageContent, MessageId, Participant, RepositoryError, Room, RoomId,
+    Timestamp,
+};
+
+/// 接続中の全てのクライアント ID から、新しい参加者に割り当てる ID を決める関数（割り当てられない場合は `None`）
+pub type ClientIdResolver<'a> = dyn Fn(&[ClientId]) -> Option<ClientId> + Send + Sync + 'a;
 
 /// Room Repository trait
 ///
@@ -19,33 +25,128 @@ use super::{ClientId, MessageContent, Participant, RepositoryError, Room, Timest
 /// - ドメイン層は Infrastructure 層に依存しない
 #[async_trait]
 pub trait RoomRepository: Send + Sync {
-    /// Room エンティティを取得
+    /// デフォルトの Room エンティティを取得
+    ///
+    /// デフォルトの Room は Repository の作成時に渡した Room で、`room_id` を指定しない接続の入室先になる。
     async fn get_room(&self) -> Result<Room, RepositoryError>;
 
-    /// 参加者を追加
+    /// 指定した ID の Room エンティティを取得
+    ///
+    /// 指定した ID の Room が存在しない場合は `RepositoryError::RoomNotFound` を返す。
+    async fn get_room_by_id(&self, room_id: &RoomId) -> Result<Room, RepositoryError>;
+
+    /// 空の Room を作成
+    ///
+    /// 定員・メッセージ容量などの上限はデフォルトの Room と同じ値になる。
+    /// `tenant` は作成したクライアントのテナント（テナントごとのルーム数を数えるために保持する）。
+    /// 同じ ID の Room が既に存在する場合は `RepositoryError::RoomAlreadyExists` を返す。
+    async fn create_room(
+        &self,
+        room_id: RoomId,
+        created_at: Timestamp,
+        tenant: Option<String>,
+    ) -> Result<(), RepositoryError>;
+
+    /// 全ての Room を作成順に取得
+    async fn list_rooms(&self) -> Vec<Room>;
+
+    /// 参加者が入室している Room の ID を取得（どの Room にも入室していない場合は `None`）
+    async fn find_participant_room(&self, client_id: &ClientId) -> Option<RoomId>;
+
+    /// 参加者を Room に追加（bot かどうかなどの属性も含めて 1 度に追加する）
     async fn add_participant(
         &self,
-        client_id: ClientId,
-        timestamp: Timestamp,
+        room_id: &RoomId,
+        participant: Participant,
     ) -> Result<(), RepositoryError>;
 
-    /// 参加者を削除
-    async fn remove_participant(&self, client_id: &ClientId) -> Result<(), RepositoryError>;
+    /// 全ての Room を通して一意な ID で参加者を Room に追加
+    ///
+    /// 接続中の ID の確認と追加を 1 つのロックの中で行い、同じ ID で同時に接続した参加者が
+    /// 別々の Room に両方とも追加されないようにする。
+    /// `resolve_client_id` は全ての Room に接続中のクライアント ID を受け取り、参加者に割り当てる ID を返す
+    /// （`None` の場合は追加せずに `RepositoryError::DuplicateClientId` を返す）。
+    ///
+    /// # 戻り値
+    ///
+    /// - `Ok(ClientId)`: 参加者に割り当てた ID
+    /// - `Err(RepositoryError::DuplicateClientId)`: 割り当てられる ID がない
+    /// - `Err(RepositoryError)`: Room が存在しない、定員を超えているなど
+    async fn reserve_participant(
+        &self,
+        room_id: &RoomId,
+        participant: Participant,
+        resolve_client_id: &ClientIdResolver<'_>,
+    ) -> Result<ClientId, RepositoryError>;
+
+    /// 参加者を削除（参加者が入室している Room から削除し、`left_at` を Room の最終活動時刻にする）
+    async fn remove_participant(
+        &self,
+        client_id: &ClientId,
+        left_at: Timestamp,
+    ) -> Result<(), RepositoryError>;
 
-    /// 接続中の全てのクライアント ID を取得
+    /// 全ての Room に接続中の全てのクライアント ID を取得
     async fn get_all_connected_client_ids(&self) -> Vec<ClientId>;
 
-    /// メッセージを Room に追加
+    /// メッセージを Room に追加し、割り当てられたメッセージ ID を返す
     async fn add_message(
         &self,
+        room_id: &RoomId,
         from_client_id: ClientId,
         content: MessageContent,
         timestamp: Timestamp,
+    ) -> Result<MessageId, RepositoryError>;
+
+    /// Room のメッセージ履歴のメッセージを編集し、編集後のメッセージを返す
+    ///
+    /// メッセージの作成者以外による編集は `RoomError::NotMessageAuthor` で拒否する。
+    async fn edit_message(
+        &self,
+        room_id: &RoomId,
+        message_id: &MessageId,
+        editor: &ClientId,
+        content: MessageContent,
+        edited_at: Timestamp,
+    ) -> Result<ChatMessage, RepositoryError>;
+
+    /// Room のメッセージ履歴のメッセージを削除（内容を空にし、削除済みにする）
+    ///
+    /// メッセージは履歴から取り除かず、位置を保つ。
+    /// メッセージの作成者以外による削除は `RoomError::NotMessageAuthor` で拒否する。
+    async fn delete_message(
+        &self,
+        room_id: &RoomId,
+        message_id: &MessageId,
+        requester: &ClientId,
     ) -> Result<(), RepositoryError>;
 
-    /// 接続中のクライアント数を取得
+    /// Room のメッセージ履歴のうち、タイムスタンプが `since` より後のメッセージを古い順に取得
+    ///
+    /// 指定した ID の Room が存在しない場合は空のリストを返す。
+    async fn messages_since(&self, room_id: &RoomId, since: Timestamp) -> Vec<ChatMessage>;
+
+    /// 全ての Room に接続中のクライアント数を取得
     async fn count_connected_clients(&self) -> usize;
 
-    /// Room の参加者リストを取得
-    async fn get_participants(&self) -> Vec<Participant>;
+    /// Room の参加者リストを取得（Room が存在しない場合は空のリスト）
+    async fn get_participants(&self, room_id: &RoomId) -> Vec<Participant>;
+
+    /// Room の参加者リストのバージョン（参加者の追加・削除・更新ごとに増加）を取得
+    async fn get_participants_version(&self, room_id: &RoomId) -> u64;
+
+    /// Room がロックされているかどうかを取得
+    async fn is_room_locked(&self, room_id: &RoomId) -> bool;
+
+    /// Room のロック状態を更新
+    async fn set_room_locked(&self, room_id: &RoomId, locked: bool) -> Result<(), RepositoryError>;
+
+    /// Room を閉じる（`true`）・再開する（`false`）
+    async fn set_room_closed(&self, room_id: &RoomId, closed: bool) -> Result<(), RepositoryError>;
+
+    /// Room を取り除き、参加していたクライアントの ID を返す
+    ///
+    /// 参加者とメッセージ履歴を取り除き、Room を閉じる。デフォルトの Room 以外は Room 自体も取り除く。
+    /// 指定した ID の Room が存在しない場合は `RepositoryError::RoomNotFound` を返す。
+    async fn remove_room(&self, room_id: &RoomId) -> Result<Vec<String>, RepositoryError>;
 }
@@ -4,13 +4,350 @@
 //! They are compared by their value, not by identity.
 
 use serde::{Deserialize, Serialize};
-use std::fmt;
+use std::{fmt, str::FromStr, time::Duration};
+use unicode_properties::{UnicodeEmoji, emoji::is_regional_indicator};
 
 use super::error::ValueObjectError;
 
+/// Separator between the tenant prefix and the rest of a ClientId (`<tenant>:<id>`).
+pub const TENANT_PREFIX_SEPARATOR: char = ':';
+
+/// Maximum length of message content in characters (Unicode scalar values).
+pub const MESSAGE_CONTENT_MAX_LENGTH: usize = 10000;
+
+/// Tenant prefix policy for ClientId.
+///
+/// Multi-tenant deployments namespace client ids as `<tenant>:<id>`,
+/// so that ids of different tenants never collide within a shared room.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum TenantPrefixPolicy {
+    /// No tenant prefix validation
+    #[default]
+    Disabled,
+    /// Ids may omit the prefix, but a present prefix must match the tenant
+    Optional(String),
+    /// Ids must start with `<tenant>:`
+    Required(String),
+}
+
+impl TenantPrefixPolicy {
+    /// Whether ids may carry a tenant prefix.
+    pub fn is_enabled(&self) -> bool {
+        !matches!(self, Self::Disabled)
+    }
+
+    /// Validate the tenant prefix of the given id.
+    fn validate(&self, id: &str) -> Result<(), ValueObjectError> {
+        let (tenant, required) = match self {
+            Self::Disabled => return Ok(()),
+            Self::Optional(tenant) => (tenant, false),
+            Self::Required(tenant) => (tenant, true),
+        };
+
+        match id.split_once(TENANT_PREFIX_SEPARATOR) {
+            Some((prefix, rest)) if prefix == tenant && !rest.is_empty() => Ok(()),
+            Some((prefix, _)) if prefix != tenant => {
+                Err(ValueObjectError::ClientIdTenantPrefixMismatch {
+                    expected: tenant.clone(),
+                    actual: prefix.to_string(),
+                })
+            }
+            None if !required => Ok(()),
+            _ => Err(ValueObjectError::ClientIdTenantPrefixMissing {
+                expected: tenant.clone(),
+            }),
+        }
+    }
+}
+
+/// Prefix of a mention in message content (`@alice`).
+pub const MENTION_PREFIX: char = '@';
+
+/// Default maximum number of mentions parsed from message content.
+pub const MENTIONS_DEFAULT_MAX_COUNT: usize = 50;
+
+/// Default maximum length (in characters) of a parsed mention.
+pub const MENTION_DEFAULT_MAX_LENGTH: usize = 64;
+
+/// Replacement for links to denied domains when they are removed.
+pub const REMOVED_LINK_PLACEHOLDER: &str = "[link removed]";
+
+/// Maximum value accepted by [`Timestamp::new_validated`] (9999-12-31T23:59:59.999Z in milliseconds)
+pub const TIMESTAMP_MAX_MILLIS: i64 = 253_402_300_799_999;
+
+/// How to handle links to denied domains in MessageContent.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DeniedLinkAction {
+    /// Replace the link with [`REMOVED_LINK_PLACEHOLDER`]
+    #[default]
+    Remove,
+    /// Reject the content
+    Reject,
+}
+
+impl FromStr for DeniedLinkAction {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "remove" => Ok(Self::Remove),
+            "reject" => Ok(Self::Reject),
+            other => Err(format!(
+                "invalid denied link action '{}' (expected 'remove' or 'reject')",
+                other
+            )),
+        }
+    }
+}
+
+/// Denylist of link domains in MessageContent (e.g. known phishing sites).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LinkDenylist {
+    /// Denied domains (subdomains are denied as well)
+    pub domains: Vec<String>,
+    /// How to handle links to denied domains
+    pub action: DeniedLinkAction,
+}
+
+impl LinkDenylist {
+    /// Find the denied domain the given link points to, if any.
+    fn denied_domain(&self, link: &str) -> Option<&str> {
+        let host = link_host(link)?;
+        self.domains
+            .iter()
+            .map(|domain| domain.trim_start_matches('.'))
+            .find(|domain| {
+                host.eq_ignore_ascii_case(domain)
+                    || host
+                        .to_ascii_lowercase()
+                        .ends_with(&format!(".{}", domain.to_ascii_lowercase()))
+            })
+    }
+
+    /// Replace links to denied domains with [`REMOVED_LINK_PLACEHOLDER`].
+    fn remove_denied_links(&self, text: &str) -> String {
+        text.split_inclusive(char::is_whitespace)
+            .map(|piece| {
+                let token = piece.trim_end_matches(char::is_whitespace);
+                match find_link(token) {
+                    Some(start) if self.denied_domain(&token[start..]).is_some() => format!(
+                        "{}{}{}",
+                        &token[..start],
+                        REMOVED_LINK_PLACEHOLDER,
+                        &piece[token.len()..]
+                    ),
+                    _ => piece.to_string(),
+                }
+            })
+            .collect()
+    }
+
+    /// Find the first denied domain linked from the text, if any.
+    fn find_denied_link(&self, text: &str) -> Option<&str> {
+        text.split_whitespace()
+            .filter_map(|token| find_link(token).map(|start| &token[start..]))
+            .find_map(|link| self.denied_domain(link))
+    }
+}
+
+/// Find the start of an `http://` or `https://` link in a whitespace-delimited token.
+fn find_link(token: &str) -> Option<usize> {
+    let lower = token.to_ascii_lowercase();
+    ["http://", "https://"]
+        .iter()
+        .filter_map(|scheme| lower.find(scheme))
+        .min()
+}
+
+/// Extract the host of an `http://` or `https://` link.
+fn link_host(link: &str) -> Option<&str> {
+    let (_, rest) = link.split_once("://")?;
+    let authority = rest.split(['/', '?', '#']).next().unwrap_or_default();
+    // Strip userinfo (`user@host`) and port (`host:8080`)
+    let host = authority.rsplit('@').next().unwrap_or_default();
+    let host = host.split(':').next().unwrap_or_default();
+    let host = host.trim_end_matches(|c: char| !c.is_alphanumeric());
+    (!host.is_empty()).then_some(host)
+}
+
+/// Bounds on the mentions parsed from message content.
+///
+/// Keeps the mentions attached to a message bounded even for pathological content
+/// (e.g. thousands of `@` tokens): names are truncated to `max_length` characters
+/// and parsing stops after `max_count` distinct names.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MentionLimits {
+    /// Maximum number of distinct mentions parsed
+    pub max_count: usize,
+    /// Maximum length of a mentioned name in characters (longer names are truncated)
+    pub max_length: usize,
+}
+
+impl MentionLimits {
+    /// No bounds (used to count the mentions in the content).
+    const UNLIMITED: Self = Self {
+        max_count: usize::MAX,
+        max_length: usize::MAX,
+    };
+}
+
+impl Default for MentionLimits {
+    fn default() -> Self {
+        Self {
+            max_count: MENTIONS_DEFAULT_MAX_COUNT,
+            max_length: MENTION_DEFAULT_MAX_LENGTH,
+        }
+    }
+}
+
+/// Content policy for MessageContent.
+///
+/// Transforms applied before, and limits applied on top of, the basic validation
+/// (non-empty, maximum length).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MessageContentPolicy {
+    /// Maximum number of emoji in the content (`None` = unlimited)
+    pub max_emoji: Option<usize>,
+    /// Collapse runs of whitespace into a single space (a single newline if the run contains one)
+    pub collapse_whitespace: bool,
+    /// Denylist of link domains (`None` = all links allowed)
+    pub link_denylist: Option<LinkDenylist>,
+    /// Maximum number of distinct mentions in the content (`None` = unlimited)
+    pub max_mentions: Option<usize>,
+    /// Bounds on the mentions attached to a broadcast message
+    pub mention_limits: MentionLimits,
+}
+
+impl MessageContentPolicy {
+    /// Apply the content transforms enabled by the policy.
+    fn normalize(&self, content: String) -> String {
+        let content = if self.collapse_whitespace {
+            collapse_whitespace(&content)
+        } else {
+            content
+        };
+        match &self.link_denylist {
+            Some(denylist) if denylist.action == DeniedLinkAction::Remove => {
+                denylist.remove_denied_links(&content)
+            }
+            _ => content,
+        }
+    }
+
+    /// Validate the content against the policy.
+    fn validate(&self, content: &str) -> Result<(), ValueObjectError> {
+        if let Some(max) = self.max_emoji {
+            let actual = count_emoji(content);
+            if actual > max {
+                return Err(ValueObjectError::MessageContentTooManyEmoji { max, actual });
+            }
+        }
+        if let Some(max) = self.max_mentions {
+            let actual = parse_mentions(content, &MentionLimits::UNLIMITED).len();
+            if actual > max {
+                return Err(ValueObjectError::MessageContentTooManyMentions { max, actual });
+            }
+        }
+        if let Some(denylist) = &self.link_denylist
+            && let Some(domain) = denylist.find_denied_link(content)
+        {
+            return Err(ValueObjectError::MessageContentDeniedLink {
+                domain: domain.to_string(),
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Collapse runs of whitespace in the given text.
+///
+/// A run containing a newline becomes a single newline, so that line breaks are preserved;
+/// any other run becomes a single space.
+fn collapse_whitespace(text: &str) -> String {
+    let mut collapsed = String::with_capacity(text.len());
+    let mut pending: Option<char> = None;
+    for c in text.chars() {
+        if c.is_whitespace() {
+            if c == '\n' || pending.is_none() {
+                pending = Some(if c == '\n' { '\n' } else { ' ' });
+            }
+            continue;
+        }
+        if let Some(separator) = pending.take() {
+            collapsed.push(separator);
+        }
+        collapsed.push(c);
+    }
+    if let Some(separator) = pending {
+        collapsed.push(separator);
+    }
+    collapsed
+}
+
+/// Whether the character can be part of a mentioned name.
+fn is_mention_char(c: char) -> bool {
+    c.is_alphanumeric() || matches!(c, '-' | '_' | '.' | TENANT_PREFIX_SEPARATOR)
+}
+
+/// Parse the distinct mentions (`@name`) in the given text, in order of appearance.
+///
+/// An `@` preceded by a name character (e.g. in an email address) is not a mention,
+/// and trailing punctuation (`@alice.` / `@alice:`) is not part of the name.
+/// Names are truncated and the result is capped according to `limits`.
+fn parse_mentions(text: &str, limits: &MentionLimits) -> Vec<String> {
+    let mut mentions: Vec<String> = Vec::new();
+    let mut previous: Option<char> = None;
+    for (index, c) in text.char_indices() {
+        if mentions.len() >= limits.max_count {
+            break;
+        }
+        if c == MENTION_PREFIX && !previous.is_some_and(is_mention_char) {
+            let rest = &text[index + c.len_utf8()..];
+            let end = rest
+                .find(|c: char| !is_mention_char(c))
+                .unwrap_or(rest.len());
+            let name = rest[..end].trim_end_matches(['.', TENANT_PREFIX_SEPARATOR]);
+            let name = name
+                .char_indices()
+                .nth(limits.max_length)
+                .map_or(name, |(truncated_end, _)| &name[..truncated_end]);
+            if !name.is_empty() && !mentions.iter().any(|m| m == name) {
+                mentions.push(name.to_string());
+            }
+        }
+        previous = Some(c);
+    }
+    mentions
+}
+
+/// Count the emoji in the given text.
+///
+/// Counts characters with `Emoji=Yes` that are not emoji components (so that skin tone
+/// modifiers, keycap digits and joiners are not counted separately), plus one per flag
+/// (pair of regional indicators).
+fn count_emoji(text: &str) -> usize {
+    let emoji = text
+        .chars()
+        .filter(|c| c.is_emoji_char() && !c.is_emoji_component())
+        .count();
+    let regional_indicators = text.chars().filter(|c| is_regional_indicator(*c)).count();
+    emoji + regional_indicators / 2
+}
+
+/// Whether the character is allowed in a client ID (`[A-Za-z0-9_-]`, plus the tenant prefix
+/// separator when tenant prefixes are in use).
+fn is_client_id_char(c: char, allow_tenant_separator: bool) -> bool {
+    c.is_ascii_alphanumeric()
+        || matches!(c, '_' | '-')
+        || (allow_tenant_separator && c == TENANT_PREFIX_SEPARATOR)
+}
+
 /// Client identifier value object.
 ///
 /// Represents a unique identifier for a chat client.
+/// Only `[A-Za-z0-9_-]` is allowed (plus `:` between a tenant prefix and the id when a tenant
+/// prefix policy is active), so that control characters and whitespace never reach log lines
+/// or JSON frames.
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct ClientId(String);
 
@@ -25,6 +362,46 @@ impl ClientId {
     ///
     /// A Result containing the ClientId or an error if validation fails
     pub fn new(id: String) -> Result<Self, ValueObjectError> {
+        Self::new_with_tenant_policy(id, &TenantPrefixPolicy::Disabled)
+    }
+
+    /// Create a new ClientId validated against a tenant prefix policy.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The client identifier string
+    /// * `policy` - The tenant prefix policy to apply
+    ///
+    /// # Returns
+    ///
+    /// A Result containing the ClientId or an error if validation fails
+    pub fn new_with_tenant_policy(
+        id: String,
+        policy: &TenantPrefixPolicy,
+    ) -> Result<Self, ValueObjectError> {
+        let client_id = Self::validate(id, policy.is_enabled())?;
+        policy.validate(&client_id.0)?;
+        Ok(client_id)
+    }
+
+    /// Restore a ClientId that was validated earlier (stored, or derived from a validated id).
+    ///
+    /// The id may carry a tenant prefix, since the tenant prefix policy was applied when it was
+    /// first validated.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The client identifier string
+    ///
+    /// # Returns
+    ///
+    /// A Result containing the ClientId or an error if validation fails
+    pub fn restore(id: String) -> Result<Self, ValueObjectError> {
+        Self::validate(id, true)
+    }
+
+    /// Validate the length and characters of the id.
+    fn validate(id: String, allow_tenant_separator: bool) -> Result<Self, ValueObjectError> {
         if id.is_empty() {
             return Err(ValueObjectError::ClientIdEmpty);
         }
@@ -35,6 +412,12 @@ impl ClientId {
                 actual: len,
             });
         }
+        if let Some(found) = id
+            .chars()
+            .find(|c| !is_client_id_char(*c, allow_tenant_separator))
+        {
+            return Err(ValueObjectError::ClientIdInvalidChars { found });
+        }
         Ok(Self(id))
     }
 
@@ -47,6 +430,13 @@ impl ClientId {
     pub fn into_string(self) -> String {
         self.0
     }
+
+    /// Get the tenant prefix (`<tenant>` of `<tenant>:<id>`), if any.
+    pub fn tenant(&self) -> Option<&str> {
+        self.0
+            .split_once(TENANT_PREFIX_SEPARATOR)
+            .map(|(tenant, _)| tenant)
+    }
 }
 
 impl fmt::Display for ClientId {
@@ -66,8 +456,11 @@ impl TryFrom<String> for ClientId {
 /// Room identifier value object.
 ///
 /// Represents a unique identifier for a chat room.
-/// Room IDs must be valid UUID format strings.
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+/// Generated and client-supplied room IDs must be valid UUID format strings;
+/// well-known ids fixed by the server (e.g. `"default"`) are created with [`RoomId::new_unchecked`].
+/// The default room itself has a generated UUID; the `"default"` alias clients pass when
+/// connecting is resolved to it before a RoomId is created.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub struct RoomId(String);
 
 impl RoomId {
@@ -100,6 +493,9 @@ impl RoomId {
 
     /// Create a RoomId from a Uuid.
     ///
+    /// Any Uuid is a valid room id, so this never fails; it returns a Result for consistency
+    /// with the other RoomId constructors. Use `RoomId::from` for an infallible conversion.
+    ///
     /// # Arguments
     ///
     /// * `uuid` - The UUID to convert to RoomId
@@ -111,6 +507,19 @@ impl RoomId {
         Ok(Self(uuid.to_string()))
     }
 
+    /// Create a RoomId for a well-known id fixed by the server, skipping UUID validation.
+    ///
+    /// [`RoomId::new`] always validates, as ids received from clients must go through it.
+    /// The server does not create such ids today: the `"default"` alias is resolved to the
+    /// default room's generated UUID instead.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The well-known room identifier (e.g. `"default"`)
+    pub fn new_unchecked(id: impl Into<String>) -> Self {
+        Self(id.into())
+    }
+
     /// Get the inner string value.
     pub fn as_str(&self) -> &str {
         &self.0
@@ -122,12 +531,140 @@ impl RoomId {
     }
 }
 
+impl From<uuid::Uuid> for RoomId {
+    fn from(uuid: uuid::Uuid) -> Self {
+        Self(uuid.to_string())
+    }
+}
+
 impl fmt::Display for RoomId {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}", self.0)
     }
 }
 
+/// Maximum length of a shard identifier
+pub const SHARD_ID_MAX_LENGTH: usize = 16;
+
+/// Shard (node) identifier value object.
+///
+/// Identifies a server instance in a cluster. It is prefixed to the message IDs generated by
+/// that instance so that two instances never assign the same ID, even to rooms with the same ID.
+/// Must be 1 to 16 characters of `[A-Za-z0-9_]`; `-` is reserved as the separator.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ShardId(String);
+
+impl ShardId {
+    /// Create a new ShardId.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The shard identifier string
+    ///
+    /// # Returns
+    ///
+    /// A Result containing the ShardId or an error if it is empty, too long or contains
+    /// characters outside `[A-Za-z0-9_]`
+    pub fn new(id: String) -> Result<Self, ValueObjectError> {
+        let valid = !id.is_empty()
+            && id.chars().count() <= SHARD_ID_MAX_LENGTH
+            && id.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+        if !valid {
+            return Err(ValueObjectError::ShardIdInvalid(id));
+        }
+        Ok(Self(id))
+    }
+
+    /// Get the inner string value.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for ShardId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Message identifier value object.
+///
+/// Combines the room ID and a per-room monotonic sequence number (`<room_id>:<sequence>`).
+/// The sequence is zero-padded so that IDs within a room sort in the order they were assigned,
+/// and the room ID prefix keeps IDs unique across rooms.
+/// IDs generated by a server configured with a shard are prefixed with it
+/// (`<shard>-<room_id>:<sequence>`), keeping them unique across the instances of a cluster.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct MessageId(String);
+
+impl MessageId {
+    /// Create a new MessageId from a room ID and a per-room sequence number.
+    ///
+    /// # Arguments
+    ///
+    /// * `room_id` - The room the message belongs to
+    /// * `sequence` - The per-room monotonic sequence number
+    pub fn new(room_id: &RoomId, sequence: u64) -> Self {
+        Self(format!("{}:{:020}", room_id.as_str(), sequence))
+    }
+
+    /// Create a new MessageId prefixed with the shard that generated it.
+    ///
+    /// # Arguments
+    ///
+    /// * `shard` - The shard (server instance) generating the ID
+    /// * `room_id` - The room the message belongs to
+    /// * `sequence` - The per-room monotonic sequence number
+    pub fn new_in_shard(shard: &ShardId, room_id: &RoomId, sequence: u64) -> Self {
+        Self(format!(
+            "{}-{}",
+            shard.as_str(),
+            Self::new(room_id, sequence).0
+        ))
+    }
+
+    /// Parse a MessageId received from a client.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing the MessageId or an error if it is not
+    /// `<room_id>:<sequence>` or `<shard>-<room_id>:<sequence>`
+    pub fn parse(id: String) -> Result<Self, ValueObjectError> {
+        let invalid = || ValueObjectError::MessageIdInvalidFormat(id.clone());
+        let (prefix, sequence) = id.rsplit_once(':').ok_or_else(invalid)?;
+        let sequence = sequence.parse::<u64>().map_err(|_| invalid())?;
+        let message_id = match RoomId::new(prefix.to_string()) {
+            Ok(room_id) => Self::new(&room_id, sequence),
+            Err(_) => {
+                let (shard, room_id) = prefix.split_once('-').ok_or_else(invalid)?;
+                let shard = ShardId::new(shard.to_string()).map_err(|_| invalid())?;
+                let room_id = RoomId::new(room_id.to_string()).map_err(|_| invalid())?;
+                Self::new_in_shard(&shard, &room_id, sequence)
+            }
+        };
+        if message_id.0 != id {
+            return Err(invalid());
+        }
+        Ok(message_id)
+    }
+
+    /// Get the inner string value.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Convert to owned String.
+    pub fn into_string(self) -> String {
+        self.0
+    }
+}
+
+impl fmt::Display for MessageId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
 /// Message content value object.
 ///
 /// Represents the content of a chat message with validation.
@@ -145,19 +682,47 @@ impl MessageContent {
     ///
     /// A Result containing the MessageContent or an error if validation fails
     pub fn new(content: String) -> Result<Self, ValueObjectError> {
+        Self::new_with_policy(content, &MessageContentPolicy::default())
+    }
+
+    /// Create a new MessageContent validated against the given content policy.
+    ///
+    /// # Arguments
+    ///
+    /// * `content` - The message content string
+    /// * `policy` - Additional limits applied to the content
+    ///
+    /// # Returns
+    ///
+    /// A Result containing the MessageContent or an error if validation fails
+    pub fn new_with_policy(
+        content: String,
+        policy: &MessageContentPolicy,
+    ) -> Result<Self, ValueObjectError> {
+        // Transforms run before validation, so that the length is checked on the final content
+        let content = policy.normalize(content);
         if content.is_empty() {
             return Err(ValueObjectError::MessageContentEmpty);
         }
-        let len = content.len();
-        if len > 10000 {
+        // The limit is on characters, so that multibyte text gets the same allowance as ASCII
+        let len = content.chars().count();
+        if len > MESSAGE_CONTENT_MAX_LENGTH {
             return Err(ValueObjectError::MessageContentTooLong {
-                max: 10000,
+                max: MESSAGE_CONTENT_MAX_LENGTH,
                 actual: len,
             });
         }
+        policy.validate(&content)?;
         Ok(Self(content))
     }
 
+    /// Create the empty content left in place of a deleted message.
+    ///
+    /// Bypasses the validation, which rejects empty content from clients.
+    pub fn tombstone() -> Self {
+        Self(String::new())
+    }
+
     /// Get the inner string value.
     pub fn as_str(&self) -> &str {
         &self.0
@@ -167,6 +732,37 @@ impl MessageContent {
     pub fn into_string(self) -> String {
         self.0
     }
+
+    /// Get the distinct names mentioned in the content (`@name`), in order of appearance.
+    ///
+    /// Bounded by the default [`MentionLimits`].
+    pub fn mentions(&self) -> Vec<String> {
+        self.mentions_within(&MentionLimits::default())
+    }
+
+    /// Get the distinct names mentioned in the content, bounded by `limits`.
+    pub fn mentions_within(&self, limits: &MentionLimits) -> Vec<String> {
+        parse_mentions(&self.0, limits)
+    }
+
+    /// Get the content with `<`, `>`, `&`, `"` and `'` HTML-escaped.
+    ///
+    /// For outbound DTOs rendered as HTML (e.g. a web UI showing the history).
+    /// The content itself is stored raw.
+    pub fn sanitized(&self) -> String {
+        let mut sanitized = String::with_capacity(self.0.len());
+        for c in self.0.chars() {
+            match c {
+                '<' => sanitized.push_str("&lt;"),
+                '>' => sanitized.push_str("&gt;"),
+                '&' => sanitized.push_str("&amp;"),
+                '"' => sanitized.push_str("&quot;"),
+                '\'' => sanitized.push_str("&#39;"),
+                c => sanitized.push(c),
+            }
+        }
+        sanitized
+    }
 }
 
 impl fmt::Display for MessageContent {
@@ -183,6 +779,41 @@ impl TryFrom<String> for MessageContent {
     }
 }
 
+/// Unit of a numeric Unix timestamp exchanged with clients.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TimestampUnit {
+    /// Milliseconds since the epoch
+    #[default]
+    Milliseconds,
+    /// Seconds since the epoch
+    Seconds,
+}
+
+impl TimestampUnit {
+    /// Short name of the unit (`ms` or `s`)
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Milliseconds => "ms",
+            Self::Seconds => "s",
+        }
+    }
+}
+
+impl FromStr for TimestampUnit {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "ms" | "milliseconds" => Ok(Self::Milliseconds),
+            "s" | "seconds" => Ok(Self::Seconds),
+            other => Err(format!(
+                "invalid timestamp unit '{}' (expected 'ms' or 's')",
+                other
+            )),
+        }
+    }
+}
+
 /// Timestamp value object.
 ///
 /// Represents a Unix timestamp in milliseconds (JST).
@@ -203,10 +834,55 @@ impl Timestamp {
         Self(value)
     }
 
+    /// Create a new Timestamp from an untrusted value (e.g. sent by a client).
+    ///
+    /// Unlike [`Timestamp::new`], which is meant for trusted values such as the server clock,
+    /// negative values and values beyond [`TIMESTAMP_MAX_MILLIS`] are rejected, since they
+    /// would corrupt ordering and cannot be represented as RFC 3339.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ValueObjectError::TimestampOutOfRange` if the value is out of range
+    pub fn new_validated(value: i64) -> Result<Self, ValueObjectError> {
+        if !(0..=TIMESTAMP_MAX_MILLIS).contains(&value) {
+            return Err(ValueObjectError::TimestampOutOfRange {
+                max: TIMESTAMP_MAX_MILLIS,
+                actual: value,
+            });
+        }
+        Ok(Self(value))
+    }
+
+    /// Create a new Timestamp from a value in the given unit.
+    pub fn from_unit(value: i64, unit: TimestampUnit) -> Self {
+        match unit {
+            TimestampUnit::Milliseconds => Self(value),
+            TimestampUnit::Seconds => Self(value.saturating_mul(1000)),
+        }
+    }
+
     /// Get the inner i64 value.
     pub fn value(&self) -> i64 {
         self.0
     }
+
+    /// Get the timestamp in milliseconds.
+    pub fn as_millis(&self) -> i64 {
+        self.0
+    }
+
+    /// Get the timestamp in seconds (truncated).
+    pub fn as_seconds(&self) -> i64 {
+        self.0.div_euclid(1000)
+    }
+
+    /// Get the timestamp in the given unit.
+    pub fn in_unit(&self, unit: TimestampUnit) -> i64 {
+        match unit {
+            TimestampUnit::Milliseconds => self.as_millis(),
+            TimestampUnit::Seconds => self.as_seconds(),
+        }
+    }
 }
 
 impl fmt::Display for Timestamp {
@@ -221,6 +897,30 @@ impl From<i64> for Timestamp {
     }
 }
 
+/// Clock Skew Value Object
+///
+/// Difference between a client's clock and the server clock, measured on a message as the
+/// client-provided timestamp minus the server timestamp (positive = the client clock is ahead).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ClockSkew(i64);
+
+impl ClockSkew {
+    /// Measure the skew of `client` against `server`.
+    pub fn between(client: Timestamp, server: Timestamp) -> Self {
+        Self(client.as_millis().saturating_sub(server.as_millis()))
+    }
+
+    /// Get the skew in milliseconds.
+    pub fn as_millis(&self) -> i64 {
+        self.0
+    }
+
+    /// Whether the skew (in either direction) is larger than `tolerance`.
+    pub fn exceeds(&self, tolerance: Duration) -> bool {
+        u128::from(self.0.unsigned_abs()) > tolerance.as_millis()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -273,6 +973,28 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_client_id_invalid_chars_fails() {
+        // テスト項目: タブ・改行・絵文字など `[A-Za-z0-9_-]` 以外の文字を含むクライアント ID は作成できない
+        // given (前提条件):
+        let cases = [("ali\tce", '\t'), ("alice\n", '\n'), ("alice😀", '😀')];
+
+        // when (操作):
+        let results: Vec<_> = cases
+            .iter()
+            .map(|(id, _)| ClientId::new(id.to_string()))
+            .collect();
+
+        // then (期待する結果):
+        for ((_, found), result) in cases.iter().zip(results) {
+            assert_eq!(
+                result,
+                Err(ValueObjectError::ClientIdInvalidChars { found: *found })
+            );
+        }
+        assert!(ClientId::new("helper-bot_2".to_string()).is_ok());
+    }
+
     #[test]
     fn test_client_id_equality() {
         // テスト項目: 同じ値を持つ ClientId は等価
@@ -286,6 +1008,109 @@ mod tests {
         assert_ne!(id1, id3);
     }
 
+    #[test]
+    fn test_client_id_with_tenant_prefix_success() {
+        // テスト項目: テナントプレフィックスが必須の場合、正しいプレフィックス付きの ID は作成できる
+        // given (前提条件):
+        let policy = TenantPrefixPolicy::Required("acme".to_string());
+
+        // when (操作):
+        let result = ClientId::new_with_tenant_policy("acme:alice".to_string(), &policy);
+
+        // then (期待する結果):
+        assert_eq!(result.unwrap().as_str(), "acme:alice");
+    }
+
+    #[test]
+    fn test_client_id_missing_tenant_prefix_fails() {
+        // テスト項目: テナントプレフィックスが必須の場合、プレフィックスのない ID は作成できない
+        // given (前提条件):
+        let policy = TenantPrefixPolicy::Required("acme".to_string());
+
+        // when (操作):
+        let without_prefix = ClientId::new_with_tenant_policy("alice".to_string(), &policy);
+        let empty_rest = ClientId::new_with_tenant_policy("acme:".to_string(), &policy);
+
+        // then (期待する結果):
+        let expected = ValueObjectError::ClientIdTenantPrefixMissing {
+            expected: "acme".to_string(),
+        };
+        assert_eq!(without_prefix.unwrap_err(), expected);
+        assert_eq!(empty_rest.unwrap_err(), expected);
+    }
+
+    #[test]
+    fn test_client_id_wrong_tenant_prefix_fails() {
+        // テスト項目: 別テナントのプレフィックスを持つ ID は、必須・任意どちらの場合も作成できない
+        // given (前提条件):
+        let required = TenantPrefixPolicy::Required("acme".to_string());
+        let optional = TenantPrefixPolicy::Optional("acme".to_string());
+
+        // when (操作):
+        let required_result =
+            ClientId::new_with_tenant_policy("other:alice".to_string(), &required);
+        let optional_result =
+            ClientId::new_with_tenant_policy("other:alice".to_string(), &optional);
+
+        // then (期待する結果):
+        let expected = ValueObjectError::ClientIdTenantPrefixMismatch {
+            expected: "acme".to_string(),
+            actual: "other".to_string(),
+        };
+        assert_eq!(required_result.unwrap_err(), expected);
+        assert_eq!(optional_result.unwrap_err(), expected);
+    }
+
+    #[test]
+    fn test_client_id_optional_tenant_prefix_allows_unprefixed() {
+        // テスト項目: テナントプレフィックスが任意の場合、プレフィックスのない ID は作成できる
+        // given (前提条件):
+        let policy = TenantPrefixPolicy::Optional("acme".to_string());
+
+        // when (操作):
+        let result = ClientId::new_with_tenant_policy("alice".to_string(), &policy);
+
+        // then (期待する結果):
+        assert_eq!(result.unwrap().as_str(), "alice");
+    }
+
+    #[test]
+    fn test_client_id_tenant_separator_requires_tenant_policy() {
+        // テスト項目: テナントプレフィックスのポリシーがない場合、区切り文字 `:` を含むクライアント ID は作成できない
+        // given (前提条件):
+        let policy = TenantPrefixPolicy::Optional("a".to_string());
+
+        // when (操作):
+        let without_policy = ClientId::new("a:b".to_string());
+        let with_policy = ClientId::new_with_tenant_policy("a:b".to_string(), &policy);
+        let restored = ClientId::restore("a:b".to_string());
+
+        // then (期待する結果): 保存済みの ID の復元では区切り文字を受け付ける
+        assert_eq!(
+            without_policy,
+            Err(ValueObjectError::ClientIdInvalidChars { found: ':' })
+        );
+        assert_eq!(with_policy.unwrap().as_str(), "a:b");
+        assert_eq!(restored.unwrap().as_str(), "a:b");
+    }
+
+    #[test]
+    fn test_client_id_tenant() {
+        // テスト項目: テナントプレフィックスを持つ ID はそのテナントを返し、持たない ID は None を返す
+        // given (前提条件):
+        let policy = TenantPrefixPolicy::Optional("acme".to_string());
+        let prefixed = ClientId::new_with_tenant_policy("acme:alice".to_string(), &policy).unwrap();
+        let unprefixed = ClientId::new_with_tenant_policy("alice".to_string(), &policy).unwrap();
+
+        // when (操作):
+        let prefixed_tenant = prefixed.tenant();
+        let unprefixed_tenant = unprefixed.tenant();
+
+        // then (期待する結果):
+        assert_eq!(prefixed_tenant, Some("acme"));
+        assert_eq!(unprefixed_tenant, None);
+    }
+
     #[test]
     fn test_room_id_new_success() {
         // テスト項目: 有効な UUID v4 形式のルーム ID を作成できる
@@ -346,6 +1171,103 @@ mod tests {
         assert_eq!(room_id.as_str(), uuid.to_string());
     }
 
+    #[test]
+    fn test_room_id_well_known_id() {
+        // テスト項目: "default" のような既知の ID は new_unchecked() でのみ作成でき、new() では UUID 形式として検証される
+        // given (前提条件):
+        let id = "default".to_string();
+
+        // when (操作):
+        let unchecked = RoomId::new_unchecked(id.as_str());
+        let validated = RoomId::new(id.clone());
+
+        // then (期待する結果):
+        assert_eq!(unchecked.as_str(), "default");
+        assert_eq!(validated, Err(ValueObjectError::RoomIdInvalidFormat(id)));
+    }
+
+    #[test]
+    fn test_message_id_sortable_within_room() {
+        // テスト項目: 同じルームの MessageId は連番の順にソートされる
+        // given (前提条件):
+        let room_id = RoomId::from_uuid(uuid::Uuid::new_v4()).unwrap();
+
+        // when (操作):
+        let id9 = MessageId::new(&room_id, 9);
+        let id10 = MessageId::new(&room_id, 10);
+
+        // then (期待する結果):
+        assert!(id9 < id10);
+        assert!(id10.as_str().starts_with(room_id.as_str()));
+    }
+
+    #[test]
+    fn test_message_ids_of_different_shards_are_disjoint() {
+        // テスト項目: 同じルーム ID・同じ連番でも、シャードが異なれば異なる MessageId になり、それぞれ解釈し直せる
+        // given (前提条件):
+        let room_id = RoomId::from_uuid(uuid::Uuid::new_v4()).unwrap();
+        let shard_a = ShardId::new("node_a".to_string()).unwrap();
+        let shard_b = ShardId::new("node_b".to_string()).unwrap();
+
+        // when (操作):
+        let id_a = MessageId::new_in_shard(&shard_a, &room_id, 1);
+        let id_b = MessageId::new_in_shard(&shard_b, &room_id, 1);
+        let unsharded = MessageId::new(&room_id, 1);
+
+        // then (期待する結果):
+        assert_ne!(id_a, id_b);
+        assert_ne!(id_a, unsharded);
+        assert!(id_a.as_str().starts_with("node_a-"));
+        assert_eq!(MessageId::parse(id_a.to_string()), Ok(id_a));
+        assert_eq!(MessageId::parse(id_b.to_string()), Ok(id_b));
+    }
+
+    #[test]
+    fn test_shard_id_rejects_invalid_characters() {
+        // テスト項目: 空文字列、長すぎる ID、区切り文字の `-` などを含む ID はシャード ID として拒否される
+        // given (前提条件):
+        let invalid = ["", "node-a", "node a", "abcdefghijklmnopq"];
+
+        // when (操作):
+        let results: Vec<_> = invalid
+            .iter()
+            .map(|id| ShardId::new(id.to_string()))
+            .collect();
+
+        // then (期待する結果):
+        for (id, result) in invalid.iter().zip(results) {
+            assert_eq!(
+                result,
+                Err(ValueObjectError::ShardIdInvalid(id.to_string()))
+            );
+        }
+        assert!(ShardId::new("node_1".to_string()).is_ok());
+    }
+
+    #[test]
+    fn test_message_id_parse() {
+        // テスト項目: `<room_id>:<sequence>` 形式の文字列のみ MessageId として解釈される
+        // given (前提条件):
+        let room_id = RoomId::from_uuid(uuid::Uuid::new_v4()).unwrap();
+        let message_id = MessageId::new(&room_id, 42);
+
+        // when (操作):
+        let parsed = MessageId::parse(message_id.to_string());
+        let unpadded = MessageId::parse(format!("{}:42", room_id.as_str()));
+        let no_room = MessageId::parse("42".to_string());
+
+        // then (期待する結果):
+        assert_eq!(parsed, Ok(message_id));
+        assert!(matches!(
+            unpadded,
+            Err(ValueObjectError::MessageIdInvalidFormat(_))
+        ));
+        assert!(matches!(
+            no_room,
+            Err(ValueObjectError::MessageIdInvalidFormat(_))
+        ));
+    }
+
     #[test]
     fn test_message_content_new_success() {
         // テスト項目: 有効なメッセージ内容を作成できる
@@ -394,6 +1316,261 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_message_content_length_counts_characters() {
+        // テスト項目: 長さはバイト数ではなく文字数で数え、マルチバイト文字 10000 文字は作成でき、10001 文字は作成できない
+        // given (前提条件):
+        let max = "あ".repeat(10000);
+        let over = "あ".repeat(10001);
+
+        // when (操作):
+        let accepted = MessageContent::new(max);
+        let rejected = MessageContent::new(over);
+
+        // then (期待する結果):
+        assert!(accepted.is_ok());
+        assert_eq!(
+            rejected.unwrap_err(),
+            ValueObjectError::MessageContentTooLong {
+                max: 10000,
+                actual: 10001
+            }
+        );
+    }
+
+    #[test]
+    fn test_message_content_too_many_emoji_fails() {
+        // テスト項目: 絵文字の上限を超えるメッセージ内容は作成できない
+        // given (前提条件):
+        let policy = MessageContentPolicy {
+            max_emoji: Some(3),
+            ..MessageContentPolicy::default()
+        };
+        let content = "🎉🎉🎉🎉🎉".to_string();
+
+        // when (操作):
+        let result = MessageContent::new_with_policy(content, &policy);
+
+        // then (期待する結果):
+        assert_eq!(
+            result.unwrap_err(),
+            ValueObjectError::MessageContentTooManyEmoji { max: 3, actual: 5 }
+        );
+    }
+
+    #[test]
+    fn test_message_content_few_emoji_passes() {
+        // テスト項目: 絵文字が上限以下のメッセージ内容は作成でき、数字や肌の色の修飾子は数えない
+        // given (前提条件):
+        let policy = MessageContentPolicy {
+            max_emoji: Some(3),
+            ..MessageContentPolicy::default()
+        };
+        let content = "Meeting at 10 👍🏽 see you 🇯🇵 😀".to_string();
+
+        // when (操作):
+        let result = MessageContent::new_with_policy(content.clone(), &policy);
+
+        // then (期待する結果):
+        assert_eq!(count_emoji(&content), 3);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_message_content_collapse_whitespace() {
+        // テスト項目: 空白の連続は 1 つの空白にまとめられ、改行は保持される
+        // given (前提条件):
+        let policy = MessageContentPolicy {
+            collapse_whitespace: true,
+            ..MessageContentPolicy::default()
+        };
+
+        // when (操作):
+        let spaced = MessageContent::new_with_policy("a    b".to_string(), &policy).unwrap();
+        let multiline =
+            MessageContent::new_with_policy("line 1\nline  2 \n\n\tline 3".to_string(), &policy)
+                .unwrap();
+        let untouched = MessageContent::new("a    b".to_string()).unwrap();
+
+        // then (期待する結果):
+        assert_eq!(spaced.as_str(), "a b");
+        assert_eq!(multiline.as_str(), "line 1\nline 2\nline 3");
+        assert_eq!(untouched.as_str(), "a    b");
+    }
+
+    #[test]
+    fn test_message_content_collapse_whitespace_before_length_check() {
+        // テスト項目: 空白をまとめた後の内容で長さが検証される
+        // given (前提条件):
+        let policy = MessageContentPolicy {
+            collapse_whitespace: true,
+            ..MessageContentPolicy::default()
+        };
+        let content = format!("a{}b", " ".repeat(MESSAGE_CONTENT_MAX_LENGTH));
+
+        // when (操作):
+        let result = MessageContent::new_with_policy(content.clone(), &policy);
+
+        // then (期待する結果):
+        assert_eq!(result.unwrap().as_str(), "a b");
+        assert!(MessageContent::new(content).is_err());
+    }
+
+    fn create_link_denylist_policy(action: DeniedLinkAction) -> MessageContentPolicy {
+        MessageContentPolicy {
+            link_denylist: Some(LinkDenylist {
+                domains: vec!["evil.example".to_string()],
+                action,
+            }),
+            ..MessageContentPolicy::default()
+        }
+    }
+
+    #[test]
+    fn test_message_content_denied_link_removed() {
+        // テスト項目: 拒否リストのドメイン（サブドメインを含む）へのリンクは置き換えられ、許可されたドメインのリンクはそのまま残る
+        // given (前提条件):
+        let policy = create_link_denylist_policy(DeniedLinkAction::Remove);
+        let content =
+            "login at https://Login.EVIL.example/reset?x=1 or (http://user@evil.example:8080) \
+                       see https://docs.example.com/evil.example"
+                .to_string();
+
+        // when (操作):
+        let result = MessageContent::new_with_policy(content, &policy).unwrap();
+
+        // then (期待する結果):
+        assert_eq!(
+            result.as_str(),
+            "login at [link removed] or ([link removed] see https://docs.example.com/evil.example"
+        );
+    }
+
+    #[test]
+    fn test_message_content_denied_link_rejected() {
+        // テスト項目: 拒否モードでは拒否リストのドメインへのリンクを含む内容は作成できず、許可されたドメインのリンクは通る
+        // given (前提条件):
+        let policy = create_link_denylist_policy(DeniedLinkAction::Reject);
+
+        // when (操作):
+        let denied =
+            MessageContent::new_with_policy("go https://evil.example/".to_string(), &policy);
+        let allowed =
+            MessageContent::new_with_policy("go https://notevil.example/".to_string(), &policy);
+
+        // then (期待する結果):
+        assert_eq!(
+            denied.unwrap_err(),
+            ValueObjectError::MessageContentDeniedLink {
+                domain: "evil.example".to_string()
+            }
+        );
+        assert_eq!(allowed.unwrap().as_str(), "go https://notevil.example/");
+    }
+
+    #[test]
+    fn test_message_content_mentions() {
+        // テスト項目: メンションが出現順・重複なしで抽出され、メールアドレスや末尾の句読点はメンションとして扱わない
+        // given (前提条件):
+        let content = MessageContent::new(
+            "@alice hi, @acme:bob. mail me at carol@example.com @alice @ @dave-2!".to_string(),
+        )
+        .unwrap();
+
+        // when (操作):
+        let mentions = content.mentions();
+
+        // then (期待する結果):
+        assert_eq!(mentions, vec!["alice", "acme:bob", "dave-2"]);
+    }
+
+    #[test]
+    fn test_message_content_mentions_are_bounded() {
+        // テスト項目: 大量の `@` トークンを含むメッセージ内容でも、メンションは件数の上限で打ち切られ、長い名前は切り詰められる
+        // given (前提条件):
+        let tokens: Vec<String> = (0..1000).map(|i| format!("@u{}", i)).collect();
+        let content =
+            MessageContent::new(format!("@{} {}", "x".repeat(100), tokens.join(" "))).unwrap();
+        let limits = MentionLimits {
+            max_count: 10,
+            max_length: 8,
+        };
+
+        // when (操作):
+        let mentions = content.mentions_within(&limits);
+        let default_mentions = content.mentions();
+
+        // then (期待する結果):
+        assert_eq!(mentions.len(), 10);
+        assert_eq!(mentions[0], "x".repeat(8));
+        assert_eq!(mentions[1], "u0");
+        assert_eq!(default_mentions.len(), MENTIONS_DEFAULT_MAX_COUNT);
+        assert_eq!(
+            default_mentions[0].chars().count(),
+            MENTION_DEFAULT_MAX_LENGTH
+        );
+    }
+
+    #[test]
+    fn test_message_content_too_many_mentions_fails() {
+        // テスト項目: メンションの上限を超えるメッセージ内容は作成できず、上限以下なら作成できる
+        // given (前提条件):
+        let policy = MessageContentPolicy {
+            max_mentions: Some(2),
+            ..MessageContentPolicy::default()
+        };
+
+        // when (操作):
+        let over = MessageContent::new_with_policy("@a @b @c".to_string(), &policy);
+        let within = MessageContent::new_with_policy("@a @b @a".to_string(), &policy);
+
+        // then (期待する結果):
+        assert_eq!(
+            over.unwrap_err(),
+            ValueObjectError::MessageContentTooManyMentions { max: 2, actual: 3 }
+        );
+        assert!(within.is_ok());
+    }
+
+    #[test]
+    fn test_message_content_sanitized_escapes_each_character() {
+        // テスト項目: sanitized() は <, >, &, ", ' をそれぞれ HTML エスケープする
+        // given (前提条件):
+        let cases = [
+            ("<", "&lt;"),
+            (">", "&gt;"),
+            ("&", "&amp;"),
+            ("\"", "&quot;"),
+            ("'", "&#39;"),
+        ];
+
+        for (raw, expected) in cases {
+            // when (操作):
+            let content = MessageContent::new(raw.to_string()).unwrap();
+
+            // then (期待する結果):
+            assert_eq!(content.sanitized(), expected);
+        }
+    }
+
+    #[test]
+    fn test_message_content_sanitized_mixed_string() {
+        // テスト項目: 混在した文字列は対象の文字だけがエスケープされ、元の内容は変わらない
+        // given (前提条件):
+        let raw = r#"<script>alert("x & 'y'")</script> こんにちは"#;
+        let content = MessageContent::new(raw.to_string()).unwrap();
+
+        // when (操作):
+        let sanitized = content.sanitized();
+
+        // then (期待する結果):
+        assert_eq!(
+            sanitized,
+            "&lt;script&gt;alert(&quot;x &amp; &#39;y&#39;&quot;)&lt;/script&gt; こんにちは"
+        );
+        assert_eq!(content.as_str(), raw);
+    }
+
     #[test]
     fn test_timestamp_new() {
         // テスト項目: タイムスタンプを作成できる
@@ -407,6 +1584,61 @@ mod tests {
         assert_eq!(timestamp.value(), value);
     }
 
+    #[test]
+    fn test_timestamp_new_validated() {
+        // テスト項目: 負の値や極端に未来の値は拒否され、通常の値は受け入れられる
+        // given (前提条件):
+        let normal = 1672498800000i64;
+        let negative = -1i64;
+        let absurd_future = i64::MAX;
+
+        // when (操作):
+        let normal_result = Timestamp::new_validated(normal);
+        let negative_result = Timestamp::new_validated(negative);
+        let future_result = Timestamp::new_validated(absurd_future);
+
+        // then (期待する結果):
+        assert_eq!(normal_result, Ok(Timestamp::new(normal)));
+        assert_eq!(
+            negative_result,
+            Err(ValueObjectError::TimestampOutOfRange {
+                max: TIMESTAMP_MAX_MILLIS,
+                actual: negative,
+            })
+        );
+        assert_eq!(
+            future_result,
+            Err(ValueObjectError::TimestampOutOfRange {
+                max: TIMESTAMP_MAX_MILLIS,
+                actual: absurd_future,
+            })
+        );
+        assert!(Timestamp::new_validated(TIMESTAMP_MAX_MILLIS).is_ok());
+        assert!(Timestamp::new_validated(TIMESTAMP_MAX_MILLIS + 1).is_err());
+    }
+
+    #[test]
+    fn test_timestamp_units_convert_to_same_rfc3339() {
+        // テスト項目: ミリ秒と秒で表した同じ時刻は、同じ RFC 3339 文字列に変換される
+        // given (前提条件):
+        let millis = Timestamp::from_unit(1_672_498_800_000, TimestampUnit::Milliseconds);
+        let seconds = Timestamp::from_unit(1_672_498_800, TimestampUnit::Seconds);
+
+        // when (操作):
+        let millis_rfc3339 = engawa_shared::time::timestamp_to_jst_rfc3339(millis.as_millis());
+        let seconds_rfc3339 = engawa_shared::time::timestamp_to_jst_rfc3339(seconds.as_millis());
+
+        // then (期待する結果):
+        assert_eq!(millis, seconds);
+        assert_eq!(millis_rfc3339, seconds_rfc3339);
+        assert_eq!(millis.in_unit(TimestampUnit::Seconds), 1_672_498_800);
+        assert_eq!(
+            Timestamp::new(1_672_498_800_999).in_unit(TimestampUnit::Seconds),
+            1_672_498_800
+        );
+        assert_eq!("s".parse::<TimestampUnit>(), Ok(TimestampUnit::Seconds));
+    }
+
     #[test]
     fn test_timestamp_ordering() {
         // テスト項目: タイムスタンプは順序付けできる
@@ -418,4 +1650,22 @@ mod tests {
         assert!(ts1 < ts2);
         assert!(ts2 > ts1);
     }
+
+    #[test]
+    fn test_clock_skew_exceeds_tolerance_in_either_direction() {
+        // テスト項目: クロックスキューはクライアント時刻からサーバ時刻を引いた値で、進み・遅れのどちらも許容値を超えたかを判定できる
+        // given (前提条件):
+        let server = Timestamp::new(10_000);
+        let tolerance = Duration::from_millis(500);
+
+        // when (操作):
+        let ahead = ClockSkew::between(Timestamp::new(10_800), server);
+        let behind = ClockSkew::between(Timestamp::new(9_500), server);
+
+        // then (期待する結果):
+        assert_eq!(ahead.as_millis(), 800);
+        assert!(ahead.exceeds(tolerance));
+        assert_eq!(behind.as_millis(), -500);
+        assert!(!behind.exceeds(tolerance));
+    }
 }
@@ -0,0 +1,85 @@
+//! InMemory DeadLetterSink 実装
+//!
+//! デッドレターを上限付きのバッファに保持します。
+//! 上限に達した場合は最も古いデッドレターを破棄します。
+
+use std::collections::VecDeque;
+
+use async_trait::async_trait;
+use tokio::sync::Mutex;
+
+use crate::domain::{DeadLetter, DeadLetterSink};
+
+/// インメモリ DeadLetterSink 実装
+pub struct InMemoryDeadLetterSink {
+    /// 保持するデッドレターの上限
+    capacity: usize,
+    /// 記録されたデッドレター（古い順）
+    entries: Mutex<VecDeque<DeadLetter>>,
+}
+
+impl InMemoryDeadLetterSink {
+    /// 新しい InMemoryDeadLetterSink を作成
+    ///
+    /// # 引数
+    ///
+    /// - `capacity`: 保持するデッドレターの上限
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: Mutex::new(VecDeque::with_capacity(capacity)),
+        }
+    }
+
+    /// 記録されたデッドレターを古い順に取得
+    pub async fn entries(&self) -> Vec<DeadLetter> {
+        self.entries.lock().await.iter().cloned().collect()
+    }
+}
+
+#[async_trait]
+impl DeadLetterSink for InMemoryDeadLetterSink {
+    async fn record(&self, dead_letter: DeadLetter) {
+        if self.capacity == 0 {
+            return;
+        }
+        let mut entries = self.entries.lock().await;
+        if entries.len() >= self.capacity {
+            entries.pop_front();
+        }
+        tracing::debug!(
+            "Recorded dead letter for message '{}' to '{}': {}",
+            dead_letter.message_id,
+            dead_letter.target_client_id,
+            dead_letter.reason
+        );
+        entries.push_back(dead_letter);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{ClientId, MessageId, RoomIdFactory};
+
+    #[tokio::test]
+    async fn test_record_drops_oldest_when_full() {
+        // テスト項目: 上限に達した場合は最も古いデッドレターが破棄される
+        // given (前提条件):
+        let sink = InMemoryDeadLetterSink::new(2);
+        let room_id = RoomIdFactory::generate().unwrap();
+        let dead_letter = |sequence| DeadLetter {
+            message_id: MessageId::new(&room_id, sequence),
+            target_client_id: ClientId::new("bob".to_string()).unwrap(),
+            reason: "channel closed".to_string(),
+        };
+
+        // when (操作):
+        for sequence in 1..=3 {
+            sink.record(dead_letter(sequence)).await;
+        }
+
+        // then (期待する結果):
+        assert_eq!(sink.entries().await, vec![dead_letter(2), dead_letter(3)]);
+    }
+}
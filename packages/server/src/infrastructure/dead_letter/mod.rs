@@ -0,0 +1,7 @@
+//! デッドレターの記録先の実装
+//!
+//! ドメイン層が定義する DeadLetterSink trait の具体的な実装を提供します。
+
+mod inmemory;
+
+pub use inmemory::InMemoryDeadLetterSink;
@@ -2,9 +2,11 @@
 
 use crate::domain::{
     entity,
-    value_object::{ClientId, MessageContent, Timestamp},
+    value_object::{ClientId, MessageContent, MessageId, Timestamp, TimestampUnit},
 };
 use crate::infrastructure::dto::websocket as dto;
+use chrono::FixedOffset;
+use engawa_shared::time::{jst_offset, timestamp_to_rfc3339};
 
 // ========================================
 // DTO → Domain Entity
@@ -13,10 +15,13 @@ use crate::infrastructure::dto::websocket as dto;
 impl From<dto::ChatMessage> for entity::ChatMessage {
     fn from(dto: dto::ChatMessage) -> Self {
         Self {
-            from: ClientId::new(dto.client_id).expect("ClientId should be valid in DTO"),
+            id: None,
+            from: ClientId::restore(dto.client_id).expect("ClientId should be valid in DTO"),
             content: MessageContent::new(dto.content)
                 .expect("MessageContent should be valid in DTO"),
             timestamp: Timestamp::new(dto.timestamp),
+            edited_at: None,
+            deleted: false,
         }
     }
 }
@@ -24,8 +29,9 @@ impl From<dto::ChatMessage> for entity::ChatMessage {
 impl From<dto::ParticipantInfo> for entity::Participant {
     fn from(dto: dto::ParticipantInfo) -> Self {
         Self {
-            id: ClientId::new(dto.client_id).expect("ClientId should be valid in DTO"),
+            id: ClientId::restore(dto.client_id).expect("ClientId should be valid in DTO"),
             connected_at: Timestamp::new(dto.connected_at),
+            is_bot: dto.is_bot,
         }
     }
 }
@@ -36,20 +42,46 @@ impl From<dto::ParticipantInfo> for entity::Participant {
 
 impl From<entity::ChatMessage> for dto::ChatMessage {
     fn from(model: entity::ChatMessage) -> Self {
+        Self::from_entity(model, TimestampUnit::Milliseconds)
+    }
+}
+
+impl dto::ChatMessage {
+    /// Convert a domain message, representing its timestamp in `unit`
+    pub fn from_entity(model: entity::ChatMessage, unit: TimestampUnit) -> Self {
         Self {
             r#type: dto::MessageType::Chat,
+            message_id: model.id.map(MessageId::into_string),
             client_id: model.from.into_string(),
+            mentions: model.content.mentions(),
             content: model.content.into_string(),
-            timestamp: model.timestamp.value(),
+            timestamp: model.timestamp.in_unit(unit),
+            detected_lang: None,
+            has_spoiler: false,
+            spoilers: Vec::new(),
         }
     }
 }
 
 impl From<entity::Participant> for dto::ParticipantInfo {
     fn from(model: entity::Participant) -> Self {
+        Self::from_entity(model, TimestampUnit::Milliseconds, jst_offset())
+    }
+}
+
+impl dto::ParticipantInfo {
+    /// Convert a domain participant, representing its connection time in `unit`
+    /// (and as RFC 3339 in the time zone `utc_offset`)
+    pub fn from_entity(
+        model: entity::Participant,
+        unit: TimestampUnit,
+        utc_offset: FixedOffset,
+    ) -> Self {
         Self {
             client_id: model.id.into_string(),
-            connected_at: model.connected_at.value(),
+            connected_at: model.connected_at.in_unit(unit),
+            connected_at_iso: timestamp_to_rfc3339(model.connected_at.as_millis(), utc_offset),
+            is_bot: model.is_bot,
         }
     }
 }
@@ -57,6 +89,7 @@ impl From<entity::Participant> for dto::ParticipantInfo {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::value_object::RoomId;
 
     #[test]
     fn test_dto_chat_message_to_domain() {
@@ -64,9 +97,14 @@ mod tests {
         // given (前提条件):
         let dto_msg = dto::ChatMessage {
             r#type: dto::MessageType::Chat,
+            message_id: None,
             client_id: "alice".to_string(),
             content: "Hello!".to_string(),
             timestamp: 1000,
+            detected_lang: None,
+            mentions: Vec::new(),
+            has_spoiler: false,
+            spoilers: Vec::new(),
         };
 
         // when (操作):
@@ -85,10 +123,14 @@ mod tests {
     fn test_domain_chat_message_to_dto() {
         // テスト項目: ドメインエンティティの ChatMessage が DTO に変換される
         // given (前提条件):
+        let room_id = RoomId::from_uuid(uuid::Uuid::new_v4()).unwrap();
         let domain_msg = entity::ChatMessage {
+            id: Some(MessageId::new(&room_id, 7)),
             from: ClientId::new("bob".to_string()).unwrap(),
             content: MessageContent::new("Hi!".to_string()).unwrap(),
             timestamp: Timestamp::new(2000),
+            edited_at: None,
+            deleted: false,
         };
 
         // when (操作):
@@ -98,6 +140,10 @@ mod tests {
         assert_eq!(dto_msg.client_id, "bob");
         assert_eq!(dto_msg.content, "Hi!");
         assert_eq!(dto_msg.timestamp, 2000);
+        assert_eq!(
+            dto_msg.message_id,
+            Some(MessageId::new(&room_id, 7).into_string())
+        );
         assert!(matches!(dto_msg.r#type, dto::MessageType::Chat));
     }
 
@@ -108,6 +154,8 @@ mod tests {
         let dto_participant = dto::ParticipantInfo {
             client_id: "alice".to_string(),
             connected_at: 1000,
+            connected_at_iso: String::new(),
+            is_bot: true,
         };
 
         // when (操作):
@@ -119,6 +167,7 @@ mod tests {
             ClientId::new("alice".to_string()).unwrap()
         );
         assert_eq!(domain_participant.connected_at, Timestamp::new(1000));
+        assert!(domain_participant.is_bot);
     }
 
     #[test]
@@ -128,6 +177,7 @@ mod tests {
         let domain_participant = entity::Participant {
             id: ClientId::new("bob".to_string()).unwrap(),
             connected_at: Timestamp::new(2000),
+            is_bot: false,
         };
 
         // when (操作):
@@ -136,5 +186,9 @@ mod tests {
         // then (期待する結果):
         assert_eq!(dto_participant.client_id, "bob");
         assert_eq!(dto_participant.connected_at, 2000);
+        assert_eq!(
+            dto_participant.connected_at_iso,
+            timestamp_to_rfc3339(2000, jst_offset())
+        );
     }
 }
@@ -16,6 +16,35 @@ pub struct RoomDetailDto {
     pub id: String,
     pub participants: Vec<ParticipantDetailDto>,
     pub created_at: String, // ISO 8601
+    pub locked: bool,
+    /// Whether the room is closed (archived); connects are rejected with 410 Gone
+    #[serde(default)]
+    pub closed: bool,
+    /// Page of the message history (oldest first), selected by `limit` and `offset`
+    #[serde(default)]
+    pub messages: Vec<MessageDetailDto>,
+    /// Number of messages in the whole history, for paginating
+    #[serde(default)]
+    pub total: usize,
+}
+
+/// Query parameters for the message history page of room detail endpoint
+///
+/// `offset` counts back from the most recent message, so `offset=0` is the latest page.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MessagePageQuery {
+    #[serde(default)]
+    pub limit: Option<usize>,
+    #[serde(default)]
+    pub offset: Option<usize>,
+}
+
+/// Response body for room removal endpoint
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RemoveRoomResponseDto {
+    pub id: String,
+    /// Clients that were in the room; their connections are closed
+    pub evicted_client_ids: Vec<String>,
 }
 
 /// Participant detail for room detail endpoint
@@ -24,3 +53,92 @@ pub struct ParticipantDetailDto {
     pub client_id: String,
     pub connected_at: String, // ISO 8601
 }
+
+/// Message detail for room detail endpoint
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MessageDetailDto {
+    pub message_id: Option<String>,
+    pub client_id: String,
+    pub content: String,
+    pub timestamp: String, // ISO 8601
+    /// Time of the last edit (ISO 8601), `None` if the message has not been edited
+    #[serde(default)]
+    pub edited_at: Option<String>,
+    /// Whether the message was deleted (its content is empty)
+    #[serde(default)]
+    pub deleted: bool,
+}
+
+/// Edit and delete history (audit trail) of a message for the message history endpoint
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MessageHistoryDto {
+    pub room_id: String,
+    pub message_id: String,
+    /// Audit entries, in the order the operations were made
+    pub entries: Vec<MessageAuditEntryDto>,
+}
+
+/// Audit entry of an edit or delete for the message history endpoint
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MessageAuditEntryDto {
+    /// `edit` or `delete`
+    pub action: String,
+    /// Client that made the operation
+    pub actor: String,
+    /// SHA-256 (hex) of the content before the operation
+    pub before_hash: String,
+    /// SHA-256 (hex) of the content after the operation (of the empty content for a delete)
+    pub after_hash: String,
+    pub at: String, // ISO 8601
+}
+
+/// Server capabilities for capability endpoint
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CapabilitiesDto {
+    pub protocol_version: String,
+    pub codecs: Vec<String>,
+    /// Maximum chat message content length in characters
+    pub max_message_size: usize,
+    /// Unit of the numeric timestamps generated by the server in WebSocket frames (`ms` or `s`)
+    pub timestamp_unit: String,
+    pub auth_required: bool,
+    pub features: FeaturesDto,
+}
+
+/// Enabled features for capability endpoint
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FeaturesDto {
+    pub reactions: bool,
+    pub edits: bool,
+    pub direct_messages: bool,
+    pub delivery_receipts: bool,
+    pub delivery_acks: bool,
+    pub message_ids: bool,
+    pub language_detection: bool,
+    pub strict_inbound_schema: bool,
+}
+
+/// Request body for the room creation endpoint
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CreateRoomRequestDto {
+    /// Client creating the room; its tenant prefix counts toward the per-tenant room limit
+    #[serde(default)]
+    pub client_id: Option<String>,
+}
+
+/// Request body for the kick endpoint
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct KickRequestDto {
+    /// Reason shown to the kicked participant
+    #[serde(default)]
+    pub reason: Option<String>,
+}
+
+/// Request body for room update endpoint
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateRoomRequestDto {
+    pub locked: Option<bool>,
+    /// Close (`true`) or reopen (`false`) the room
+    #[serde(default)]
+    pub closed: Option<bool>,
+}
@@ -2,14 +2,40 @@
 
 use serde::{Deserialize, Serialize};
 
+/// Version of the WebSocket message protocol
+pub const PROTOCOL_VERSION: &str = "1";
+
+/// Message codecs supported on WebSocket connections
+pub const SUPPORTED_CODECS: &[&str] = &["json"];
+
 /// Message type enum
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 pub enum MessageType {
     RoomConnected,
     ParticipantJoined,
+    ParticipantsJoined,
     ParticipantLeft,
+    ParticipantCount,
     Chat,
+    Backfill,
+    RoomLocked,
+    RoomUnlocked,
+    ServerShutdown,
+    Kicked,
+    DeliveryReceipt,
+    DeliveryAck,
+    JoinRequest,
+    JoinDecision,
+    Mention,
+    Whisper,
+    Edit,
+    Delete,
+    Typing,
+    SubscribeStats,
+    UnsubscribeStats,
+    RoomStats,
+    Error,
 }
 
 /// Participant information including client_id and connection timestamp
@@ -18,12 +44,21 @@ pub struct ParticipantInfo {
     pub client_id: String,
     /// Unix timestamp (milliseconds since epoch) in JST
     pub connected_at: i64,
+    /// `connected_at` as an RFC 3339 string in the server's time zone (same format as the HTTP API)
+    #[serde(default)]
+    pub connected_at_iso: String,
+    /// Whether the participant is a bot
+    #[serde(default)]
+    pub is_bot: bool,
 }
 
 /// Room connected participants message sent when a client connects (initial)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RoomConnectedMessage {
     pub r#type: MessageType,
+    /// client_id assigned to the connecting client (may differ from the requested one)
+    #[serde(default)]
+    pub assigned_client_id: String,
     pub participants: Vec<ParticipantInfo>,
 }
 
@@ -33,6 +68,20 @@ pub struct ParticipantJoinedMessage {
     pub r#type: MessageType,
     pub client_id: String,
     pub connected_at: i64,
+    /// `connected_at` as an RFC 3339 string in the server's time zone (same format as the HTTP API)
+    #[serde(default)]
+    pub connected_at_iso: String,
+    /// Whether the participant is a bot
+    #[serde(default)]
+    pub is_bot: bool,
+}
+
+/// Batched participant joined notification (several joins within a short window)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ParticipantsJoinedMessage {
+    pub r#type: MessageType,
+    /// Joined participants in join order (may include the recipient itself)
+    pub participants: Vec<ParticipantInfo>,
 }
 
 /// Participant left notification
@@ -41,13 +90,379 @@ pub struct ParticipantLeftMessage {
     pub r#type: MessageType,
     pub client_id: String,
     pub disconnected_at: i64,
+    /// `disconnected_at` as an RFC 3339 string in the server's time zone (same format as the HTTP API)
+    #[serde(default)]
+    pub disconnected_at_iso: String,
+}
+
+/// Number of participants in the room, broadcast to the whole room after every join/leave
+///
+/// Lets clients show the count without recomputing it from join/leave events (which drifts
+/// if an event is missed).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ParticipantCountMessage {
+    pub r#type: MessageType,
+    pub count: usize,
 }
 
 /// Chat message sent and received between clients
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ChatMessage {
     pub r#type: MessageType,
+    /// Message id assigned by the server (`<room_id>:<sequence>`), absent in client-sent frames
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message_id: Option<String>,
     pub client_id: String,
     pub content: String,
+    /// Time the server stored the message; ignored (and optional) in client-sent frames
+    #[serde(default)]
+    pub timestamp: i64,
+    /// Detected language of the content (ISO 639-1), attached by the server when enabled
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub detected_lang: Option<String>,
+    /// Names mentioned in the content (`@name`), attached by the server
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub mentions: Vec<String>,
+    /// Whether the content contains `||spoiler||` spans, attached by the server when enabled
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub has_spoiler: bool,
+    /// Spoiler spans of the content (the raw content is not modified)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub spoilers: Vec<SpoilerRange>,
+}
+
+/// Messages missed while disconnected, sent on reconnect (`?since=<timestamp_ms>`)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BackfillMessage {
+    pub r#type: MessageType,
+    /// Room messages newer than `since`, oldest first
+    pub messages: Vec<ChatMessage>,
+    /// Whether older messages were dropped because the backfill is capped
+    pub truncated: bool,
+}
+
+/// Spoiler span of a chat message (character offsets of the content, markers excluded)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SpoilerRange {
+    pub start: usize,
+    pub end: usize,
+}
+
+/// Inbound chat frame sent by a client (strict schema)
+///
+/// Unlike [`ChatMessage`], unknown fields are rejected so that client bugs surface early.
+/// `client_id` and `timestamp` are optional because the server uses the connection's id
+/// and its own clock.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct InboundChatMessage {
+    pub r#type: MessageType,
+    #[serde(default)]
+    pub client_id: Option<String>,
+    pub content: String,
+    #[serde(default)]
     pub timestamp: i64,
 }
+
+impl InboundChatMessage {
+    /// Parse and validate an inbound chat frame.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(InboundChatMessage)` - The frame is a valid chat frame
+    /// * `Err(ErrorMessage)` - Error frame describing the problem, to be sent back to the client
+    pub fn parse(text: &str) -> Result<Self, ErrorMessage> {
+        let message = serde_json::from_str::<Self>(text).map_err(|e| {
+            let code = match e.classify() {
+                serde_json::error::Category::Data => {
+                    let detail = e.to_string();
+                    if detail.starts_with("unknown field") {
+                        "unknown_field"
+                    } else if detail.starts_with("missing field") {
+                        "missing_field"
+                    } else {
+                        "invalid_field"
+                    }
+                }
+                _ => "invalid_json",
+            };
+            ErrorMessage::new(code, e.to_string())
+        })?;
+
+        if message.r#type != MessageType::Chat {
+            return Err(ErrorMessage::new(
+                "unexpected_type",
+                format!("expected type `chat`, got `{:?}`", message.r#type),
+            ));
+        }
+        Ok(message)
+    }
+}
+
+/// Room lock state changed notification (`room-locked` / `room-unlocked`)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RoomLockChangedMessage {
+    pub r#type: MessageType,
+    pub room_id: String,
+    pub changed_at: i64,
+}
+
+/// Server shutdown notification sent before the server closes the connection
+///
+/// Sent to every client once the server stops accepting connections; the remaining
+/// connections are closed after `grace_period_ms`, giving clients time to reconnect elsewhere.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ServerShutdownMessage {
+    pub r#type: MessageType,
+    pub reason: String,
+    /// Time until the remaining connections are closed (milliseconds)
+    pub grace_period_ms: u64,
+}
+
+/// Notification sent to a participant removed from the room by an admin
+///
+/// Sent right before the server closes the connection; the rest of the room
+/// receives the usual `participant-left`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KickedMessage {
+    pub r#type: MessageType,
+    pub reason: String,
+}
+
+/// Delivery receipt sent back to the sender of a broadcast message
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeliveryReceiptMessage {
+    pub r#type: MessageType,
+    /// Message id of the chat message this receipt refers to
+    pub message_id: String,
+    /// Timestamp of the chat message this receipt refers to
+    pub timestamp: i64,
+    /// Number of recipients the message was delivered to
+    pub delivered_count: usize,
+    /// Number of recipients the message was sent to
+    pub total_targets: usize,
+    /// Client `timestamp` of the chat frame minus the server timestamp, in milliseconds
+    /// (only when the server reports clock skew and the frame had a `timestamp`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub clock_skew_ms: Option<i64>,
+    /// Whether `clock_skew_ms` is beyond the server's tolerance
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub clock_skew_exceeded: bool,
+}
+
+/// Delivery acknowledgment sent by a recipient that opted in to acks
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeliveryAckMessage {
+    pub r#type: MessageType,
+    /// Message id of the acknowledged chat message
+    pub message_id: String,
+}
+
+/// Mention notification sent to a participant mentioned in a chat message
+/// (in addition to the `chat` broadcast)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MentionMessage {
+    pub r#type: MessageType,
+    /// Message id of the chat message containing the mention
+    pub message_id: String,
+    /// Client id of the author of the chat message
+    pub client_id: String,
+    pub content: String,
+    pub timestamp: i64,
+}
+
+/// Whisper: a message to a single participant, echoed back to the sender
+///
+/// Not added to the room's message history. In client-sent frames only `to` and `content`
+/// are required; the server fills in `from` and `timestamp`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WhisperMessage {
+    pub r#type: MessageType,
+    /// Client id of the sender
+    #[serde(default)]
+    pub from: String,
+    /// Client id of the recipient
+    pub to: String,
+    pub content: String,
+    #[serde(default)]
+    pub timestamp: i64,
+}
+
+/// Edit of a chat message, broadcast to the room so that clients can update it in place
+///
+/// Only the author of a message can edit it. In client-sent frames only `message_id` and
+/// `content` are required; the server fills in `client_id` and `edited_at`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EditMessage {
+    pub r#type: MessageType,
+    pub message_id: String,
+    /// Client id of the author of the message
+    #[serde(default)]
+    pub client_id: String,
+    pub content: String,
+    #[serde(default)]
+    pub edited_at: i64,
+    /// Time the client made the edit (client-sent frames only)
+    ///
+    /// Edits older than the original message are rejected. `edited_at` always comes from the
+    /// server clock.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<i64>,
+}
+
+/// Deletion of a chat message, broadcast to the room so that clients can remove it
+///
+/// Only the author of a message can delete it. In client-sent frames only `message_id` is
+/// required; the server fills in `client_id`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeleteMessage {
+    pub r#type: MessageType,
+    pub message_id: String,
+    /// Client id of the author of the message
+    #[serde(default)]
+    pub client_id: String,
+}
+
+/// Typing indicator, relayed to the other participants of the room
+///
+/// Not added to the room's message history. In client-sent frames only `is_typing` is
+/// required; the server fills in `client_id`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TypingMessage {
+    pub r#type: MessageType,
+    /// Client id of the participant who is typing
+    #[serde(default)]
+    pub client_id: String,
+    pub is_typing: bool,
+}
+
+/// Join request sent to the room admin when a client asks to join a room requiring approval
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JoinRequestMessage {
+    pub r#type: MessageType,
+    /// Client id of the client waiting for approval
+    pub client_id: String,
+    pub is_bot: bool,
+}
+
+/// Approval or denial of a join request sent by the room admin
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JoinDecisionMessage {
+    pub r#type: MessageType,
+    /// Client id of the client waiting for approval
+    pub client_id: String,
+    pub approved: bool,
+}
+
+/// Subscription (`subscribe-stats`) or unsubscription (`unsubscribe-stats`) of periodic room stats
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StatsSubscriptionMessage {
+    pub r#type: MessageType,
+}
+
+/// Periodic room stats sent only to clients subscribed to room stats
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RoomStatsMessage {
+    pub r#type: MessageType,
+    pub participant_count: usize,
+    /// Number of chat messages posted within the last minute
+    pub messages_per_minute: usize,
+    pub timestamp: i64,
+}
+
+/// Error frame sent back to the client that caused the error
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ErrorMessage {
+    pub r#type: MessageType,
+    /// Machine-readable error code (e.g. `unexpected_binary`)
+    pub code: String,
+    pub message: String,
+    /// Time to wait before retrying, for errors that are lifted over time (e.g. `rate_limited`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retry_after_ms: Option<u64>,
+}
+
+impl ErrorMessage {
+    /// Create a new error frame
+    pub fn new(code: &str, message: impl Into<String>) -> Self {
+        Self {
+            r#type: MessageType::Error,
+            code: code.to_string(),
+            message: message.into(),
+            retry_after_ms: None,
+        }
+    }
+
+    /// Set the time to wait before retrying
+    pub fn with_retry_after_ms(mut self, retry_after_ms: u64) -> Self {
+        self.retry_after_ms = Some(retry_after_ms);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_inbound_chat_message_valid_frame() {
+        // テスト項目: 正しいチャットフレームは受理される
+        // given (前提条件):
+        let text = r#"{"type":"chat","client_id":"alice","content":"Hello!","timestamp":1000}"#;
+
+        // when (操作):
+        let result = InboundChatMessage::parse(text);
+
+        // then (期待する結果):
+        let message = result.unwrap();
+        assert_eq!(message.content, "Hello!");
+        assert_eq!(message.timestamp, 1000);
+    }
+
+    #[test]
+    fn test_inbound_chat_message_unknown_field_rejected() {
+        // テスト項目: 未知のフィールドを含むフレームは、フィールド名を含むエラーで拒否される
+        // given (前提条件):
+        let text = r#"{"type":"chat","content":"Hello!","colour":"red"}"#;
+
+        // when (操作):
+        let result = InboundChatMessage::parse(text);
+
+        // then (期待する結果):
+        let error = result.unwrap_err();
+        assert_eq!(error.r#type, MessageType::Error);
+        assert_eq!(error.code, "unknown_field");
+        assert!(error.message.contains("colour"), "{}", error.message);
+    }
+
+    #[test]
+    fn test_inbound_chat_message_missing_field_rejected() {
+        // テスト項目: 必須フィールドが欠けているフレームは拒否される
+        // given (前提条件):
+        let text = r#"{"type":"chat","client_id":"alice"}"#;
+
+        // when (操作):
+        let result = InboundChatMessage::parse(text);
+
+        // then (期待する結果):
+        let error = result.unwrap_err();
+        assert_eq!(error.code, "missing_field");
+        assert!(error.message.contains("content"), "{}", error.message);
+    }
+
+    #[test]
+    fn test_inbound_chat_message_invalid_json_and_type_rejected() {
+        // テスト項目: JSON でないフレームや chat 以外の type のフレームは拒否される
+        // given (前提条件):
+        let not_json = "hello";
+        let wrong_type = r#"{"type":"room-locked","content":"Hello!"}"#;
+
+        // when (操作):
+        let not_json_result = InboundChatMessage::parse(not_json);
+        let wrong_type_result = InboundChatMessage::parse(wrong_type);
+
+        // then (期待する結果):
+        assert_eq!(not_json_result.unwrap_err().code, "invalid_json");
+        assert_eq!(wrong_type_result.unwrap_err().code, "unexpected_type");
+    }
+}
@@ -0,0 +1,34 @@
+//! InMemory EventBus 実装
+//!
+//! 発行されたイベントを発行順にメモリに保持します。
+
+use async_trait::async_trait;
+use tokio::sync::Mutex;
+
+use crate::domain::{DomainEvent, EventBus};
+
+/// インメモリ EventBus 実装
+#[derive(Debug, Default)]
+pub struct InMemoryEventBus {
+    /// 発行されたイベント（古い順）
+    events: Mutex<Vec<DomainEvent>>,
+}
+
+impl InMemoryEventBus {
+    /// 新しい InMemoryEventBus を作成
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 発行されたイベントを古い順に取得
+    pub async fn events(&self) -> Vec<DomainEvent> {
+        self.events.lock().await.clone()
+    }
+}
+
+#[async_trait]
+impl EventBus for InMemoryEventBus {
+    async fn publish(&self, event: DomainEvent) {
+        self.events.lock().await.push(event);
+    }
+}
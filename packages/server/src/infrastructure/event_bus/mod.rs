@@ -0,0 +1,9 @@
+//! ドメインイベントの発行先の実装
+//!
+//! ドメイン層が定義する EventBus trait の具体的な実装を提供します。
+
+mod inmemory;
+mod tracing;
+
+pub use inmemory::InMemoryEventBus;
+pub use tracing::TracingEventBus;
@@ -0,0 +1,77 @@
+//! Tracing EventBus 実装
+//!
+//! 発行されたイベントを `event` ターゲットの構造化ログとして出力します。
+//! ログの収集基盤で監査・分析に利用することを想定しています。
+
+use async_trait::async_trait;
+
+use crate::domain::{DomainEvent, EventBus};
+
+/// 構造化ログに出力する EventBus 実装
+#[derive(Debug, Default)]
+pub struct TracingEventBus;
+
+#[async_trait]
+impl EventBus for TracingEventBus {
+    async fn publish(&self, event: DomainEvent) {
+        match event {
+            DomainEvent::RoomCreated {
+                room_id,
+                participant_capacity,
+                message_capacity,
+                created_at,
+                creator,
+            } => tracing::info!(
+                target: "event",
+                event = "room_created",
+                room_id = room_id.as_str(),
+                participant_capacity,
+                message_capacity,
+                created_at = created_at.value(),
+                creator = creator.as_ref().map(|id| id.as_str()),
+            ),
+            DomainEvent::RoomClosed {
+                room_id,
+                last_activity_at,
+                closed_at,
+            } => tracing::info!(
+                target: "event",
+                event = "room_closed",
+                room_id = room_id.as_str(),
+                last_activity_at = last_activity_at.value(),
+                closed_at = closed_at.value(),
+            ),
+            DomainEvent::MessageRejected {
+                room_id,
+                client_id,
+                reason,
+            } => tracing::warn!(
+                target: "event",
+                event = "message_rejected",
+                room_id = room_id.as_str(),
+                client_id = client_id.as_str(),
+                reason = reason.code(),
+                detail = %reason,
+            ),
+            DomainEvent::ConnectionEnded {
+                client_id,
+                duration_ms,
+                messages_sent,
+                messages_received,
+                bytes_in,
+                bytes_out,
+                reason,
+            } => tracing::info!(
+                target: "event",
+                event = "connection_ended",
+                client_id = client_id.as_str(),
+                duration_ms,
+                messages_sent,
+                messages_received,
+                bytes_in,
+                bytes_out,
+                reason,
+            ),
+        }
+    }
+}
@@ -0,0 +1,147 @@
+//! Language detection for chat message content.
+//!
+//! Uses `whatlang` to attach an ISO 639-1 language tag to chat messages.
+//! Short or ambiguous content (e.g. a single emoji) is not tagged.
+
+use whatlang::Lang;
+
+/// Minimum number of alphabetic characters required to attempt detection
+const MIN_ALPHABETIC_CHARS: usize = 8;
+
+/// Detect the language of the given content.
+///
+/// # Returns
+///
+/// * `Some(code)` - ISO 639-1 language code (e.g. `"en"`) when detection is reliable
+/// * `None` - The content is too short or ambiguous
+pub fn detect_language(content: &str) -> Option<&'static str> {
+    let alphabetic_chars = content.chars().filter(|c| c.is_alphabetic()).count();
+    if alphabetic_chars < MIN_ALPHABETIC_CHARS {
+        return None;
+    }
+
+    let info = whatlang::detect(content)?;
+    if !info.is_reliable() {
+        return None;
+    }
+    Some(iso_639_1(info.lang()))
+}
+
+/// Convert a `whatlang` language (ISO 639-3) into an ISO 639-1 code.
+fn iso_639_1(lang: Lang) -> &'static str {
+    match lang {
+        Lang::Epo => "eo",
+        Lang::Eng => "en",
+        Lang::Rus => "ru",
+        Lang::Cmn => "zh",
+        Lang::Spa => "es",
+        Lang::Por => "pt",
+        Lang::Ita => "it",
+        Lang::Ben => "bn",
+        Lang::Fra => "fr",
+        Lang::Deu => "de",
+        Lang::Ukr => "uk",
+        Lang::Kat => "ka",
+        Lang::Ara => "ar",
+        Lang::Hin => "hi",
+        Lang::Jpn => "ja",
+        Lang::Heb => "he",
+        Lang::Yid => "yi",
+        Lang::Pol => "pl",
+        Lang::Amh => "am",
+        Lang::Jav => "jv",
+        Lang::Kor => "ko",
+        Lang::Nob => "nb",
+        Lang::Dan => "da",
+        Lang::Swe => "sv",
+        Lang::Fin => "fi",
+        Lang::Tur => "tr",
+        Lang::Nld => "nl",
+        Lang::Hun => "hu",
+        Lang::Ces => "cs",
+        Lang::Ell => "el",
+        Lang::Bul => "bg",
+        Lang::Bel => "be",
+        Lang::Mar => "mr",
+        Lang::Kan => "kn",
+        Lang::Ron => "ro",
+        Lang::Slv => "sl",
+        Lang::Hrv => "hr",
+        Lang::Srp => "sr",
+        Lang::Mkd => "mk",
+        Lang::Lit => "lt",
+        Lang::Lav => "lv",
+        Lang::Est => "et",
+        Lang::Tam => "ta",
+        Lang::Vie => "vi",
+        Lang::Urd => "ur",
+        Lang::Tha => "th",
+        Lang::Guj => "gu",
+        Lang::Uzb => "uz",
+        Lang::Pan => "pa",
+        Lang::Aze => "az",
+        Lang::Ind => "id",
+        Lang::Tel => "te",
+        Lang::Pes => "fa",
+        Lang::Mal => "ml",
+        Lang::Ori => "or",
+        Lang::Mya => "my",
+        Lang::Nep => "ne",
+        Lang::Sin => "si",
+        Lang::Khm => "km",
+        Lang::Tuk => "tk",
+        Lang::Aka => "ak",
+        Lang::Zul => "zu",
+        Lang::Sna => "sn",
+        Lang::Afr => "af",
+        Lang::Lat => "la",
+        Lang::Slk => "sk",
+        Lang::Cat => "ca",
+        Lang::Tgl => "tl",
+        Lang::Hye => "hy",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect_language_english() {
+        // テスト項目: 明らかに英語のメッセージは en と判定される
+        // given (前提条件):
+        let content = "The quick brown fox jumps over the lazy dog while everyone watches.";
+
+        // when (操作):
+        let result = detect_language(content);
+
+        // then (期待する結果):
+        assert_eq!(result, Some("en"));
+    }
+
+    #[test]
+    fn test_detect_language_single_emoji() {
+        // テスト項目: 絵文字 1 文字のメッセージは判定されない
+        // given (前提条件):
+        let content = "👍";
+
+        // when (操作):
+        let result = detect_language(content);
+
+        // then (期待する結果):
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn test_detect_language_short_content() {
+        // テスト項目: 短すぎるメッセージは判定されない
+        // given (前提条件):
+        let content = "ok";
+
+        // when (操作):
+        let result = detect_language(content);
+
+        // then (期待する結果):
+        assert_eq!(result, None);
+    }
+}
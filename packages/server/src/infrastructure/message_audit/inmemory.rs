@@ -0,0 +1,91 @@
+//! InMemory MessageAuditLog 実装
+//!
+//! 監査エントリをメッセージごとに追記のみのリストとして保持します。
+//! 記録したエントリは変更・削除せず、Room が削除された後も保持します。
+
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use tokio::sync::Mutex;
+
+use crate::domain::{MessageAuditEntry, MessageAuditLog, MessageId, RoomId};
+
+/// インメモリ MessageAuditLog 実装
+#[derive(Default)]
+pub struct InMemoryMessageAuditLog {
+    /// 監査エントリ（Room とメッセージの ID ごと、記録した順）
+    entries: Mutex<HashMap<(RoomId, MessageId), Vec<MessageAuditEntry>>>,
+}
+
+impl InMemoryMessageAuditLog {
+    /// 新しい InMemoryMessageAuditLog を作成
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl MessageAuditLog for InMemoryMessageAuditLog {
+    async fn record(&self, entry: MessageAuditEntry) {
+        tracing::debug!(
+            "Recorded {} of message '{}' by '{}'",
+            entry.action.as_str(),
+            entry.message_id,
+            entry.actor
+        );
+        self.entries
+            .lock()
+            .await
+            .entry((entry.room_id.clone(), entry.message_id.clone()))
+            .or_default()
+            .push(entry);
+    }
+
+    async fn history(&self, room_id: &RoomId, message_id: &MessageId) -> Vec<MessageAuditEntry> {
+        self.entries
+            .lock()
+            .await
+            .get(&(room_id.clone(), message_id.clone()))
+            .cloned()
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{ClientId, MessageAuditAction, RoomIdFactory, Timestamp};
+
+    #[tokio::test]
+    async fn test_history_returns_entries_of_message_in_order() {
+        // テスト項目: メッセージの監査エントリが記録した順に返され、他のメッセージのエントリは含まれない
+        // given (前提条件):
+        let log = InMemoryMessageAuditLog::new();
+        let room_id = RoomIdFactory::generate().unwrap();
+        let entry = |sequence, at| MessageAuditEntry {
+            room_id: room_id.clone(),
+            message_id: MessageId::new(&room_id, sequence),
+            action: MessageAuditAction::Edit,
+            actor: ClientId::new("alice".to_string()).unwrap(),
+            before_hash: "before".to_string(),
+            after_hash: "after".to_string(),
+            at: Timestamp::new(at),
+        };
+
+        // when (操作):
+        log.record(entry(1, 1000)).await;
+        log.record(entry(2, 2000)).await;
+        log.record(entry(1, 3000)).await;
+
+        // then (期待する結果):
+        assert_eq!(
+            log.history(&room_id, &MessageId::new(&room_id, 1)).await,
+            vec![entry(1, 1000), entry(1, 3000)]
+        );
+        assert!(
+            log.history(&room_id, &MessageId::new(&room_id, 3))
+                .await
+                .is_empty()
+        );
+    }
+}
@@ -0,0 +1,7 @@
+//! メッセージの編集・削除の監査ログの実装
+//!
+//! ドメイン層が定義する MessageAuditLog trait の具体的な実装を提供します。
+
+mod inmemory;
+
+pub use inmemory::InMemoryMessageAuditLog;
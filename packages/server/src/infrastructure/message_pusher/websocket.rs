@@ -19,7 +19,13 @@ use std::{collections::HashMap, sync::Arc};
 use async_trait::async_trait;
 use tokio::sync::Mutex;
 
-use crate::domain::{ClientId, MessagePushError, MessagePusher, PusherChannel};
+use crate::domain::{
+    ClientId, DeliveryFailure, DeliveryReport, MessagePriority, MessagePushError, MessagePusher,
+    PusherChannel,
+};
+
+/// ブロードキャストで他のタスクに譲るまでに送信する送信先の数
+const BROADCAST_CHUNK_SIZE: usize = 256;
 
 /// WebSocket を使った MessagePusher 実装
 ///
@@ -100,37 +106,75 @@ impl MessagePusher for WebSocketMessagePusher {
         &self,
         targets: Vec<ClientId>,
         content: &str,
-    ) -> Result<(), MessagePushError> {
-        let clients = self.clients.lock().await;
+    ) -> Result<DeliveryReport, MessagePushError> {
+        self.broadcast_with_priority(targets, content, MessagePriority::Normal)
+            .await
+    }
 
-        for target in targets {
-            if let Some(sender) = clients.get(target.as_str()) {
-                // ブロードキャストでは一部の送信失敗を許容
-                if let Err(e) = sender.send(content.to_string()) {
+    async fn broadcast_with_priority(
+        &self,
+        targets: Vec<ClientId>,
+        content: &str,
+        priority: MessagePriority,
+    ) -> Result<DeliveryReport, MessagePushError> {
+        // 送信先の sender をスナップショットし、ロックは短時間で解放する
+        let senders: Vec<(ClientId, Option<PusherChannel>)> = {
+            let clients = self.clients.lock().await;
+            targets
+                .iter()
+                .map(|target| (target.clone(), clients.get(target.as_str()).cloned()))
+                .collect()
+        };
+
+        let mut delivered_count = 0;
+        let mut failures = Vec::new();
+
+        // 大量の送信先でもスケジューラを占有しないよう、チャンクごとに他のタスクに譲る
+        for chunk in senders.chunks(BROADCAST_CHUNK_SIZE) {
+            for (target, sender) in chunk {
+                if let Some(sender) = sender {
+                    // ブロードキャストでは一部の送信失敗を許容
+                    if let Err(e) = sender.send_with_priority(content.to_string(), priority) {
+                        tracing::warn!(
+                            "Failed to push message to client '{}': {}",
+                            target.as_str(),
+                            e
+                        );
+                        failures.push(DeliveryFailure {
+                            client_id: target.clone(),
+                            reason: e.to_string(),
+                        });
+                    } else {
+                        delivered_count += 1;
+                        tracing::debug!("Broadcasted message to client '{}'", target.as_str());
+                    }
+                } else {
                     tracing::warn!(
-                        "Failed to push message to client '{}': {}",
-                        target.as_str(),
-                        e
+                        "Client '{}' not found during broadcast, skipping",
+                        target.as_str()
                     );
-                } else {
-                    tracing::debug!("Broadcasted message to client '{}'", target.as_str());
+                    failures.push(DeliveryFailure {
+                        client_id: target.clone(),
+                        reason: MessagePushError::ClientNotFound(target.as_str().to_string())
+                            .to_string(),
+                    });
                 }
-            } else {
-                tracing::warn!(
-                    "Client '{}' not found during broadcast, skipping",
-                    target.as_str()
-                );
             }
+            tokio::task::yield_now().await;
         }
 
-        Ok(())
+        Ok(DeliveryReport {
+            targets,
+            delivered_count,
+            failures,
+        })
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use tokio::sync::mpsc;
+    use crate::domain::pusher_channel;
 
     // ========================================
     // テスト作業記録
@@ -151,6 +195,8 @@ mod tests {
     // 2. push_to の失敗ケース（クライアントが存在しない）
     // 3. broadcast の成功ケース（複数クライアント）
     // 4. broadcast の部分失敗ケース（一部のクライアントが存在しない）
+    // 5. 大量の送信先へのブロードキャスト中に他の操作がブロックされない
+    // 6. 優先度の高いメッセージが未送信の通常のメッセージより先に受信される
     // ========================================
 
     fn create_test_pusher() -> (
@@ -167,7 +213,7 @@ mod tests {
         // テスト項目: 特定のクライアントにメッセージを送信できる
         // given (前提条件):
         let (pusher, clients) = create_test_pusher();
-        let (tx, mut rx) = mpsc::unbounded_channel();
+        let (tx, mut rx) = pusher_channel();
         let client_id = ClientId::new("alice".to_string()).unwrap();
 
         {
@@ -184,6 +230,35 @@ mod tests {
         assert_eq!(received, Some("Hello".to_string()));
     }
 
+    #[tokio::test]
+    async fn test_broadcast_high_priority_jumps_queue() {
+        // テスト項目: 通常のメッセージの後に送った優先度の高いメッセージが先に受信される
+        // given (前提条件):
+        let (pusher, clients) = create_test_pusher();
+        let (tx, mut rx) = pusher_channel();
+        let alice = ClientId::new("alice".to_string()).unwrap();
+        clients.lock().await.insert(alice.as_str().to_string(), tx);
+
+        // when (操作):
+        for content in ["chat-1", "chat-2", "chat-3"] {
+            pusher
+                .broadcast(vec![alice.clone()], content)
+                .await
+                .unwrap();
+        }
+        pusher
+            .broadcast_with_priority(vec![alice.clone()], "announcement", MessagePriority::High)
+            .await
+            .unwrap();
+
+        // then (期待する結果):
+        let mut received = Vec::new();
+        for _ in 0..4 {
+            received.push(rx.recv().await.unwrap());
+        }
+        assert_eq!(received, vec!["announcement", "chat-1", "chat-2", "chat-3"]);
+    }
+
     #[tokio::test]
     async fn test_push_to_client_not_found() {
         // テスト項目: 存在しないクライアントへの送信はエラーを返す
@@ -207,8 +282,8 @@ mod tests {
         // テスト項目: 複数のクライアントにメッセージをブロードキャストできる
         // given (前提条件):
         let (pusher, clients) = create_test_pusher();
-        let (tx1, mut rx1) = mpsc::unbounded_channel();
-        let (tx2, mut rx2) = mpsc::unbounded_channel();
+        let (tx1, mut rx1) = pusher_channel();
+        let (tx2, mut rx2) = pusher_channel();
         let alice = ClientId::new("alice".to_string()).unwrap();
         let bob = ClientId::new("bob".to_string()).unwrap();
 
@@ -223,7 +298,7 @@ mod tests {
         let result = pusher.broadcast(targets, "Broadcast message").await;
 
         // then (期待する結果):
-        assert!(result.is_ok());
+        assert_eq!(result.unwrap().delivered_count, 2);
         assert_eq!(rx1.recv().await, Some("Broadcast message".to_string()));
         assert_eq!(rx2.recv().await, Some("Broadcast message".to_string()));
     }
@@ -233,7 +308,7 @@ mod tests {
         // テスト項目: ブロードキャスト時、一部のクライアントが存在しなくても成功する
         // given (前提条件):
         let (pusher, clients) = create_test_pusher();
-        let (tx1, mut rx1) = mpsc::unbounded_channel();
+        let (tx1, mut rx1) = pusher_channel();
         let alice = ClientId::new("alice".to_string()).unwrap();
         let nonexistent = ClientId::new("nonexistent".to_string()).unwrap();
 
@@ -248,6 +323,11 @@ mod tests {
 
         // then (期待する結果):
         assert!(result.is_ok()); // ブロードキャストは部分失敗を許容
+        let report = result.unwrap();
+        assert_eq!(report.delivered_count, 1);
+        assert_eq!(report.total_targets(), 2);
+        assert_eq!(report.failures.len(), 1);
+        assert_eq!(report.failures[0].client_id.as_str(), "nonexistent");
         assert_eq!(rx1.recv().await, Some("Broadcast message".to_string()));
     }
 
@@ -263,4 +343,46 @@ mod tests {
         // then (期待する結果):
         assert!(result.is_ok());
     }
+
+    #[tokio::test]
+    async fn test_broadcast_to_many_clients_does_not_block_other_operations() {
+        // テスト項目: 大量の送信先へのブロードキャスト中でも、クライアントの登録がブロードキャストの完了を待たない
+        // given (前提条件):
+        let (pusher, clients) = create_test_pusher();
+        let client_count = BROADCAST_CHUNK_SIZE * 8;
+        let mut targets = Vec::with_capacity(client_count);
+        let mut receivers = Vec::with_capacity(client_count);
+        {
+            let mut clients_lock = clients.lock().await;
+            for i in 0..client_count {
+                let client_id = ClientId::new(format!("client-{}", i)).unwrap();
+                let (tx, rx) = pusher_channel();
+                clients_lock.insert(client_id.as_str().to_string(), tx);
+                targets.push(client_id);
+                receivers.push(rx);
+            }
+        }
+        let completed = std::sync::Mutex::new(Vec::new());
+
+        // when (操作):
+        let (report, _) = tokio::join!(
+            async {
+                let report = pusher.broadcast(targets, "Broadcast message").await;
+                completed.lock().unwrap().push("broadcast");
+                report
+            },
+            async {
+                let (tx, _rx) = pusher_channel();
+                pusher
+                    .register_client(ClientId::new("newcomer".to_string()).unwrap(), tx)
+                    .await;
+                completed.lock().unwrap().push("register");
+            }
+        );
+
+        // then (期待する結果):
+        assert_eq!(report.unwrap().delivered_count, client_count);
+        assert_eq!(*completed.lock().unwrap(), vec!["register", "broadcast"]);
+        assert_eq!(clients.lock().await.len(), client_count + 1);
+    }
 }
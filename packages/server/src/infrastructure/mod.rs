@@ -1,3 +1,9 @@
+pub mod dead_letter;
 pub mod dto;
+pub mod event_bus;
+pub mod language;
+pub mod message_audit;
 pub mod message_pusher;
 pub mod repository;
+pub mod snapshot;
+pub mod spoiler;
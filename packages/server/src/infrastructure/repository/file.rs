@@ -0,0 +1,371 @@
+//! File Room Repository 実装
+//!
+//! 単一ノードの構成で再起動後もルームを引き継げるよう、InMemoryRoomRepository をラップし、
+//! Room を変更するたびに全ての Room を JSON ファイルに書き出します。起動時は `load` で読み込んだ Room から
+//! InMemoryRoomRepository を組み立てます。
+//!
+//! 参加者は接続の状態のため保存しません（再起動後には接続していない）。
+//! WebSocket の sender（接続中のクライアント）も、引き続き MessagePusher がメモリ上で管理します。
+
+use std::{
+    io,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use async_trait::async_trait;
+use tokio::sync::Mutex;
+
+use crate::domain::{
+    ChatMessage, ClientId, ClientIdResolver, MessageContent, MessageId, Participant,
+    RepositoryError, Room, RoomId, RoomRepository, Timestamp,
+};
+
+use super::InMemoryRoomRepository;
+
+/// File Room Repository 実装
+///
+/// 読み出しはラップした InMemoryRoomRepository に委譲し、Room の変更（Room の作成・削除、メッセージの追加・編集・削除、
+/// ロック・クローズ）が成功するたびに、全ての Room を作成順の JSON 配列としてファイルに書き出します。
+pub struct FileRoomRepository {
+    /// 状態を保持する InMemoryRoomRepository
+    inner: Arc<InMemoryRoomRepository>,
+    /// 保存先のファイルのパス
+    path: PathBuf,
+    /// Room の読み出しから書き込みまでを直列化するロック（古い状態が新しい状態を上書きしないようにする）
+    write_lock: Mutex<()>,
+}
+
+impl FileRoomRepository {
+    /// InMemoryRoomRepository をラップし、`path` に Room を保存する FileRoomRepository を作成
+    ///
+    /// 作成時点ではファイルに書き込まないため、必要に応じて `save` を呼び出す。
+    pub fn new(inner: Arc<InMemoryRoomRepository>, path: impl Into<PathBuf>) -> Self {
+        Self {
+            inner,
+            path: path.into(),
+            write_lock: Mutex::new(()),
+        }
+    }
+
+    /// 保存先のファイルのパス
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// ファイルに保存された Room を作成順に読み込む
+    ///
+    /// # 戻り値
+    ///
+    /// - `Ok(Vec<Room>)`: 読み込んだ Room（先頭がデフォルトの Room。ファイルが存在しない場合は空）
+    /// - `Err(RepositoryError::Storage)`: 読み込み、または JSON の解析に失敗
+    pub async fn load(path: impl AsRef<Path>) -> Result<Vec<Room>, RepositoryError> {
+        let json = match tokio::fs::read(path.as_ref()).await {
+            Ok(json) => json,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(storage_error(e)),
+        };
+        serde_json::from_slice(&json).map_err(storage_error)
+    }
+
+    /// 全ての Room（参加者を除く）をファイルに保存
+    ///
+    /// 書き込み途中でクラッシュしても直前のファイルが壊れないよう、一時ファイルに書き込んでから置き換えます。
+    pub async fn save(&self) -> Result<(), RepositoryError> {
+        let _guard = self.write_lock.lock().await;
+        let mut rooms = self.inner.list_rooms().await;
+        for room in &mut rooms {
+            room.participants.clear();
+        }
+        let json = serde_json::to_vec_pretty(&rooms).map_err(storage_error)?;
+        let tmp_path = self.path.with_extension("tmp");
+        tokio::fs::write(&tmp_path, json)
+            .await
+            .map_err(storage_error)?;
+        tokio::fs::rename(&tmp_path, &self.path)
+            .await
+            .map_err(storage_error)
+    }
+
+    /// Room の変更後にファイルへ保存
+    ///
+    /// 変更はメモリ上では既に適用されているため、保存に失敗しても操作は失敗にせず、ログに記録する。
+    async fn persist(&self) {
+        if let Err(e) = self.save().await {
+            tracing::error!("Failed to save rooms to {}: {}", self.path.display(), e);
+        }
+    }
+}
+
+fn storage_error(error: impl std::fmt::Display) -> RepositoryError {
+    RepositoryError::Storage(error.to_string())
+}
+
+#[async_trait]
+impl RoomRepository for FileRoomRepository {
+    async fn get_room(&self) -> Result<Room, RepositoryError> {
+        self.inner.get_room().await
+    }
+
+    async fn get_room_by_id(&self, room_id: &RoomId) -> Result<Room, RepositoryError> {
+        self.inner.get_room_by_id(room_id).await
+    }
+
+    async fn create_room(
+        &self,
+        room_id: RoomId,
+        created_at: Timestamp,
+        tenant: Option<String>,
+    ) -> Result<(), RepositoryError> {
+        self.inner.create_room(room_id, created_at, tenant).await?;
+        self.persist().await;
+        Ok(())
+    }
+
+    async fn list_rooms(&self) -> Vec<Room> {
+        self.inner.list_rooms().await
+    }
+
+    async fn find_participant_room(&self, client_id: &ClientId) -> Option<RoomId> {
+        self.inner.find_participant_room(client_id).await
+    }
+
+    async fn add_participant(
+        &self,
+        room_id: &RoomId,
+        participant: Participant,
+    ) -> Result<(), RepositoryError> {
+        self.inner.add_participant(room_id, participant).await
+    }
+
+    async fn reserve_participant(
+        &self,
+        room_id: &RoomId,
+        participant: Participant,
+        resolve_client_id: &ClientIdResolver<'_>,
+    ) -> Result<ClientId, RepositoryError> {
+        self.inner
+            .reserve_participant(room_id, participant, resolve_client_id)
+            .await
+    }
+
+    async fn remove_participant(
+        &self,
+        client_id: &ClientId,
+        left_at: Timestamp,
+    ) -> Result<(), RepositoryError> {
+        self.inner.remove_participant(client_id, left_at).await
+    }
+
+    async fn get_all_connected_client_ids(&self) -> Vec<ClientId> {
+        self.inner.get_all_connected_client_ids().await
+    }
+
+    async fn add_message(
+        &self,
+        room_id: &RoomId,
+        from_client_id: ClientId,
+        content: MessageContent,
+        timestamp: Timestamp,
+    ) -> Result<MessageId, RepositoryError> {
+        let message_id = self
+            .inner
+            .add_message(room_id, from_client_id, content, timestamp)
+            .await?;
+        self.persist().await;
+        Ok(message_id)
+    }
+
+    async fn edit_message(
+        &self,
+        room_id: &RoomId,
+        message_id: &MessageId,
+        editor: &ClientId,
+        content: MessageContent,
+        edited_at: Timestamp,
+    ) -> Result<ChatMessage, RepositoryError> {
+        let message = self
+            .inner
+            .edit_message(room_id, message_id, editor, content, edited_at)
+            .await?;
+        self.persist().await;
+        Ok(message)
+    }
+
+    async fn delete_message(
+        &self,
+        room_id: &RoomId,
+        message_id: &MessageId,
+        requester: &ClientId,
+    ) -> Result<(), RepositoryError> {
+        self.inner
+            .delete_message(room_id, message_id, requester)
+            .await?;
+        self.persist().await;
+        Ok(())
+    }
+
+    async fn messages_since(&self, room_id: &RoomId, since: Timestamp) -> Vec<ChatMessage> {
+        self.inner.messages_since(room_id, since).await
+    }
+
+    async fn count_connected_clients(&self) -> usize {
+        self.inner.count_connected_clients().await
+    }
+
+    async fn get_participants(&self, room_id: &RoomId) -> Vec<Participant> {
+        self.inner.get_participants(room_id).await
+    }
+
+    async fn get_participants_version(&self, room_id: &RoomId) -> u64 {
+        self.inner.get_participants_version(room_id).await
+    }
+
+    async fn is_room_locked(&self, room_id: &RoomId) -> bool {
+        self.inner.is_room_locked(room_id).await
+    }
+
+    async fn set_room_locked(&self, room_id: &RoomId, locked: bool) -> Result<(), RepositoryError> {
+        self.inner.set_room_locked(room_id, locked).await?;
+        self.persist().await;
+        Ok(())
+    }
+
+    async fn set_room_closed(&self, room_id: &RoomId, closed: bool) -> Result<(), RepositoryError> {
+        self.inner.set_room_closed(room_id, closed).await?;
+        self.persist().await;
+        Ok(())
+    }
+
+    async fn remove_room(&self, room_id: &RoomId) -> Result<Vec<String>, RepositoryError> {
+        let removed = self.inner.remove_room(room_id).await?;
+        self.persist().await;
+        Ok(removed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::RoomIdFactory;
+
+    fn create_test_path() -> PathBuf {
+        let file_name = format!(
+            "engawa-rooms-{}.json",
+            RoomIdFactory::generate().unwrap().as_str()
+        );
+        std::env::temp_dir().join(file_name)
+    }
+
+    /// 保存された Room（なければ `room`）から FileRoomRepository を組み立てる
+    async fn open_repository(path: &Path, room: Room) -> FileRoomRepository {
+        let mut rooms = FileRoomRepository::load(path).await.unwrap();
+        let default_room = if rooms.is_empty() {
+            room
+        } else {
+            rooms.remove(0)
+        };
+        let inner = InMemoryRoomRepository::new(Arc::new(Mutex::new(default_room)));
+        for room in rooms {
+            inner.insert_room(room).await.unwrap();
+        }
+        FileRoomRepository::new(Arc::new(inner), path)
+    }
+
+    #[tokio::test]
+    async fn test_rooms_survive_reopening_the_file() {
+        // テスト項目: メッセージの追加・編集と Room の作成がファイルに保存され、Repository を作り直すとメッセージ履歴と Room が復元され、参加者は保存されない
+        // given (前提条件):
+        let path = create_test_path();
+        let repository = open_repository(
+            &path,
+            Room::new(RoomIdFactory::generate().unwrap(), Timestamp::new(1000)),
+        )
+        .await;
+        let room_id = repository.get_room().await.unwrap().id;
+        let other_room_id = RoomIdFactory::generate().unwrap();
+        let alice = ClientId::new("alice".to_string()).unwrap();
+        repository
+            .add_participant(
+                &room_id,
+                Participant::new(alice.clone(), Timestamp::new(2000)),
+            )
+            .await
+            .unwrap();
+        let mut message_ids = Vec::new();
+        for content in ["Helo", "World"] {
+            message_ids.push(
+                repository
+                    .add_message(
+                        &room_id,
+                        alice.clone(),
+                        MessageContent::new(content.to_string()).unwrap(),
+                        Timestamp::new(3000),
+                    )
+                    .await
+                    .unwrap(),
+            );
+        }
+        repository
+            .edit_message(
+                &room_id,
+                &message_ids[0],
+                &alice,
+                MessageContent::new("Hello".to_string()).unwrap(),
+                Timestamp::new(4000),
+            )
+            .await
+            .unwrap();
+        repository
+            .create_room(other_room_id.clone(), Timestamp::new(5000), None)
+            .await
+            .unwrap();
+        let messages = repository.get_room().await.unwrap().messages;
+
+        // when (操作):
+        drop(repository);
+        let reopened = open_repository(
+            &path,
+            Room::new(RoomIdFactory::generate().unwrap(), Timestamp::new(6000)),
+        )
+        .await;
+
+        // then (期待する結果):
+        let room = reopened.get_room().await.unwrap();
+        assert_eq!(room.id, room_id);
+        assert_eq!(room.messages, messages);
+        assert_eq!(room.messages[0].content.as_str(), "Hello");
+        assert!(room.participants.is_empty());
+        let room_ids: Vec<RoomId> = reopened
+            .list_rooms()
+            .await
+            .into_iter()
+            .map(|r| r.id)
+            .collect();
+        assert_eq!(room_ids, vec![room_id.clone(), other_room_id]);
+        let next_id = reopened
+            .add_message(
+                &room_id,
+                alice,
+                MessageContent::new("Again".to_string()).unwrap(),
+                Timestamp::new(7000),
+            )
+            .await
+            .unwrap();
+        assert!(next_id > message_ids[1]);
+        tokio::fs::remove_file(&path).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_load_missing_file_returns_no_rooms() {
+        // テスト項目: ファイルが存在しない場合は Room を読み込まない
+        // given (前提条件):
+        let path = create_test_path();
+
+        // when (操作):
+        let rooms = FileRoomRepository::load(&path).await.unwrap();
+
+        // then (期待する結果):
+        assert!(rooms.is_empty());
+    }
+}
@@ -4,4 +4,4 @@
 
 mod room;
 
-pub use room::InMemoryRoomRepository;
+pub use room::{ConsistencyError, InMemoryRoomRepository, spawn_consistency_check};
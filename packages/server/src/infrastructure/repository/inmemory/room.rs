@@ -13,86 +13,528 @@
 //! DB Row/JSON → RoomData (DTO) → Room (ドメインモデル)
 //! ```
 //!
-//! PostgreSQL 実装時に対応予定。
+//! SQLite 実装（`sqlite` feature の `SqliteRoomRepository`）では、この変換層を `sqlite::record` として実装済み。
 
-use std::sync::Arc;
+use std::{
+    cmp::Reverse,
+    collections::{BTreeSet, HashMap, hash_map::Entry},
+    fmt,
+    sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    },
+    time::Duration,
+};
 
 use async_trait::async_trait;
-use tokio::sync::Mutex;
+use tokio::{sync::Mutex, task::JoinHandle};
 
 use crate::domain::{
-    ChatMessage, ClientId, MessageContent, Participant, RepositoryError, Room, RoomRepository,
-    Timestamp,
+    ChatMessage, ClientId, ClientIdResolver, MessageContent, MessageId, Participant, PusherChannel,
+    RepositoryError, Room, RoomId, RoomRepository, Timestamp,
 };
 
 /// インメモリ Room Repository 実装
 ///
 /// Room ドメインモデルを保持し、ドメイン層の RoomRepository trait を実装します（依存性の逆転）。
 pub struct InMemoryRoomRepository {
-    /// Room ドメインモデル
-    room: Arc<Mutex<Room>>,
+    /// Room ドメインモデル（Room の ID ごと）
+    rooms: Mutex<HashMap<RoomId, Arc<Mutex<Room>>>>,
+    /// デフォルトの Room の ID
+    default_room_id: RoomId,
+    /// MessagePusher と共有する接続中のクライアントの sender マップ（整合性の検査に使用）
+    connected_clients: Option<Arc<Mutex<HashMap<String, PusherChannel>>>>,
+    /// 全ての Room で保存するメッセージの合計の上限（`None` の場合は無制限）
+    message_budget: Option<usize>,
+    /// 全ての Room で保存しているメッセージの合計
+    stored_messages: AtomicUsize,
+    /// Room ごとのメッセージ数の索引（上限を超えたときに削除する Room の選択に使用）
+    message_index: Mutex<MessageCountIndex>,
+}
+
+/// 削除する Room を選ぶ順序のキー（メッセージ数, 最も古いメッセージの時刻の逆順）
+type MessageCountKey = (usize, Reverse<Timestamp>);
+
+/// Room ごとのメッセージ数の索引
+///
+/// 全ての Room のメッセージの合計が上限を超えたときに、全ての Room のロックを取らずに
+/// 最もメッセージの多い Room を選ぶために使う。メッセージのない Room は含まない。
+#[derive(Debug, Default)]
+struct MessageCountIndex {
+    /// Room ごとのキー
+    keys: HashMap<RoomId, MessageCountKey>,
+    /// キーの順に並べた Room（末尾が削除の対象）
+    order: BTreeSet<(MessageCountKey, RoomId)>,
+}
+
+impl MessageCountIndex {
+    /// Room の現在のメッセージを索引に反映
+    fn update(&mut self, room: &Room) {
+        self.remove(&room.id);
+        if let Some(oldest) = room.messages.first() {
+            let key = (room.messages.len(), Reverse(oldest.timestamp));
+            self.keys.insert(room.id.clone(), key);
+            self.order.insert((key, room.id.clone()));
+        }
+    }
+
+    /// Room を索引から取り除く
+    fn remove(&mut self, room_id: &RoomId) {
+        if let Some(key) = self.keys.remove(room_id) {
+            self.order.remove(&(key, room_id.clone()));
+        }
+    }
+
+    /// 最もメッセージの多い Room（同じ数の場合は最も古いメッセージがより古い Room）
+    fn busiest(&self) -> Option<RoomId> {
+        self.order.last().map(|(_, room_id)| room_id.clone())
+    }
+}
+
+/// Room の参加者と接続中のクライアントの不整合
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConsistencyError {
+    /// 接続中だが Room の参加者にいないクライアント
+    pub connected_without_participant: Vec<String>,
+    /// Room の参加者だが接続中でないクライアント
+    pub participant_without_connection: Vec<String>,
 }
 
+impl fmt::Display for ConsistencyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "connected without participant: {:?}, participant without connection: {:?}",
+            self.connected_without_participant, self.participant_without_connection
+        )
+    }
+}
+
+impl std::error::Error for ConsistencyError {}
+
 impl InMemoryRoomRepository {
     /// 新しい InMemoryRoomRepository を作成
+    ///
+    /// `room` はデフォルトの Room になる（作成時に他のタスクからロックされていないこと）。
     pub fn new(room: Arc<Mutex<Room>>) -> Self {
-        Self { room }
+        let mut message_index = MessageCountIndex::default();
+        let (default_room_id, stored_messages) = {
+            let room = room
+                .try_lock()
+                .expect("Default room must not be locked while creating the repository");
+            message_index.update(&room);
+            (room.id.clone(), room.messages.len())
+        };
+        Self {
+            rooms: Mutex::new(HashMap::from([(default_room_id.clone(), room)])),
+            default_room_id,
+            connected_clients: None,
+            message_budget: None,
+            stored_messages: AtomicUsize::new(stored_messages),
+            message_index: Mutex::new(message_index),
+        }
+    }
+
+    /// 全ての Room で保存するメッセージの合計の上限を設定
+    ///
+    /// 上限を超えた場合は、最もメッセージの多い Room から古い順に削除する（Room ごとの上限とは別）。
+    pub fn with_message_budget(mut self, message_budget: usize) -> Self {
+        self.message_budget = Some(message_budget);
+        self
+    }
+
+    /// 整合性の検査に使う、MessagePusher と共有する sender マップを設定
+    pub fn with_connected_clients(
+        mut self,
+        connected_clients: Arc<Mutex<HashMap<String, PusherChannel>>>,
+    ) -> Self {
+        self.connected_clients = Some(connected_clients);
+        self
+    }
+
+    /// 保存されていた Room を追加（起動時の復元など）
+    ///
+    /// 同じ ID の Room が既に存在する場合は `RepositoryError::RoomAlreadyExists` を返す。
+    pub async fn insert_room(&self, room: Room) -> Result<(), RepositoryError> {
+        // 存在の確認と追加を同じロックの中で行い、同じ ID の Room が同時に追加されないようにする
+        match self.rooms.lock().await.entry(room.id.clone()) {
+            Entry::Occupied(_) => Err(RepositoryError::RoomAlreadyExists(
+                room.id.as_str().to_string(),
+            )),
+            Entry::Vacant(entry) => {
+                self.stored_messages
+                    .fetch_add(room.messages.len(), Ordering::SeqCst);
+                self.message_index.lock().await.update(&room);
+                entry.insert(Arc::new(Mutex::new(room)));
+                Ok(())
+            }
+        }
+    }
+
+    /// 全ての Room で保存しているメッセージの合計
+    pub fn stored_message_count(&self) -> usize {
+        self.stored_messages.load(Ordering::SeqCst)
+    }
+
+    /// Room のメッセージ数の変化をメッセージの合計と索引に反映（Room のロックを保持したまま呼ぶ）
+    async fn record_message_count_change(&self, before: usize, room: &Room) {
+        self.message_index.lock().await.update(room);
+        let after = room.messages.len();
+        if after >= before {
+            self.stored_messages
+                .fetch_add(after - before, Ordering::SeqCst);
+        } else {
+            self.stored_messages
+                .fetch_sub(before - after, Ordering::SeqCst);
+        }
+    }
+
+    /// メッセージの合計が上限を下回るまで、最もメッセージの多い Room から最も古いメッセージを削除
+    ///
+    /// メッセージの数が同じ Room の間では、最も古いメッセージがより古い Room から削除される。
+    async fn enforce_message_budget(&self, message_budget: usize) {
+        // 削除する Room は索引から選び、一覧のロックは Room を引く間だけ、Room のロックは削除する間だけ取る
+        while self.stored_message_count() > message_budget {
+            let Some(busiest_id) = self.message_index.lock().await.busiest() else {
+                return;
+            };
+            let Some(busiest) = self.find_room(&busiest_id).await else {
+                // 取り除かれた Room が索引に残っている場合は、索引から取り除いて選び直す
+                self.message_index.lock().await.remove(&busiest_id);
+                continue;
+            };
+            let mut busiest = busiest.lock().await;
+            let before = busiest.messages.len();
+            busiest.evict_oldest_message();
+            self.record_message_count_change(before, &busiest).await;
+        }
+    }
+
+    /// 指定した ID の Room を取得（Room のロックを取る前に一覧のロックを解放するため、Arc を複製して返す）
+    async fn find_room(&self, room_id: &RoomId) -> Option<Arc<Mutex<Room>>> {
+        self.rooms.lock().await.get(room_id).cloned()
+    }
+
+    /// 指定した ID の Room を取得（存在しない場合は `RepositoryError::RoomNotFound`）
+    async fn room(&self, room_id: &RoomId) -> Result<Arc<Mutex<Room>>, RepositoryError> {
+        self.find_room(room_id)
+            .await
+            .ok_or(RepositoryError::RoomNotFound)
+    }
+
+    /// 全ての Room を取得（順序は不定）
+    async fn all_rooms(&self) -> Vec<Arc<Mutex<Room>>> {
+        self.rooms.lock().await.values().cloned().collect()
+    }
+
+    /// 参加者が入室している Room を取得
+    async fn participant_room(&self, client_id: &ClientId) -> Option<Arc<Mutex<Room>>> {
+        for room in self.all_rooms().await {
+            if room.lock().await.get_participant(client_id).is_some() {
+                return Some(room);
+            }
+        }
+        None
+    }
+
+    /// Room の参加者と接続中のクライアントが一致しているかを検査
+    ///
+    /// 接続中のクライアント（sender マップのキー）には必ずいずれかの Room の参加者が対応し、その逆も成り立つことを確認する。
+    /// sender マップが設定されていない場合は常に成功する。
+    ///
+    /// # 戻り値
+    ///
+    /// - `Ok(())`: 一致している
+    /// - `Err(ConsistencyError)`: 一致していないクライアントの一覧
+    pub async fn verify_consistency(&self) -> Result<(), ConsistencyError> {
+        let Some(connected_clients) = &self.connected_clients else {
+            return Ok(());
+        };
+        let participants: BTreeSet<String> = self
+            .get_all_connected_client_ids()
+            .await
+            .into_iter()
+            .map(ClientId::into_string)
+            .collect();
+        let clients = connected_clients.lock().await;
+        let connected: BTreeSet<String> = clients.keys().cloned().collect();
+
+        let error = ConsistencyError {
+            connected_without_participant: connected.difference(&participants).cloned().collect(),
+            participant_without_connection: participants.difference(&connected).cloned().collect(),
+        };
+        if error.connected_without_participant.is_empty()
+            && error.participant_without_connection.is_empty()
+        {
+            Ok(())
+        } else {
+            Err(error)
+        }
     }
 }
 
+/// Room の参加者と接続中のクライアントの整合性を定期的に検査するタスクを起動
+///
+/// 接続・切断の処理中は一時的に一致しないことがあるため、不整合はエラーにせずログに出力します。
+///
+/// # 引数
+///
+/// - `repository`: 検査する Repository（`with_connected_clients` で sender マップを設定したもの）
+/// - `interval`: 検査する間隔
+pub fn spawn_consistency_check(
+    repository: Arc<InMemoryRoomRepository>,
+    interval: Duration,
+) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        // 最初の tick は即座に完了するため読み飛ばす
+        ticker.tick().await;
+        loop {
+            ticker.tick().await;
+            match repository.verify_consistency().await {
+                Ok(()) => tracing::debug!("Room participants and connected clients are consistent"),
+                Err(e) => tracing::warn!("Room participants and connected clients differ: {}", e),
+            }
+        }
+    })
+}
+
 #[async_trait]
 impl RoomRepository for InMemoryRoomRepository {
     async fn get_room(&self) -> Result<Room, RepositoryError> {
-        let room = self.room.lock().await;
+        self.get_room_by_id(&self.default_room_id).await
+    }
+
+    async fn get_room_by_id(&self, room_id: &RoomId) -> Result<Room, RepositoryError> {
+        let room = self.room(room_id).await?;
+        let room = room.lock().await;
         Ok(room.clone())
     }
 
-    async fn add_participant(
+    async fn create_room(
         &self,
-        client_id: ClientId,
-        timestamp: Timestamp,
+        room_id: RoomId,
+        created_at: Timestamp,
+        tenant: Option<String>,
     ) -> Result<(), RepositoryError> {
-        let participant = Participant::new(client_id.clone(), timestamp);
+        let default_room = self.get_room().await?;
+        let mut room = Room::with_capacity(
+            room_id,
+            created_at,
+            default_room.participant_capacity,
+            default_room.message_capacity,
+        );
+        room.message_quota_per_client = default_room.message_quota_per_client;
+        room.message_id_shard = default_room.message_id_shard;
+        room.tenant = tenant;
+        self.insert_room(room).await
+    }
 
-        let mut room = self.room.lock().await;
-        room.add_participant(participant)
-            .map_err(|_| RepositoryError::ParticipantNotFound(client_id.as_str().to_string()))?;
+    async fn list_rooms(&self) -> Vec<Room> {
+        let mut rooms = Vec::new();
+        for room in self.all_rooms().await {
+            rooms.push(room.lock().await.clone());
+        }
+        // デフォルトの Room を先頭に、残りは作成順に並べる
+        rooms.sort_by(|a, b| {
+            (a.id != self.default_room_id, a.created_at, a.id.as_str()).cmp(&(
+                b.id != self.default_room_id,
+                b.created_at,
+                b.id.as_str(),
+            ))
+        });
+        rooms
+    }
+
+    async fn find_participant_room(&self, client_id: &ClientId) -> Option<RoomId> {
+        let room = self.participant_room(client_id).await?;
+        let room = room.lock().await;
+        Some(room.id.clone())
+    }
+
+    async fn add_participant(
+        &self,
+        room_id: &RoomId,
+        participant: Participant,
+    ) -> Result<(), RepositoryError> {
+        // reserve_participant と同じく一覧のロックを保持したまま追加し、ID の確認と追加の間に割り込まない
+        let rooms = self.rooms.lock().await;
+        let room = rooms.get(room_id).ok_or(RepositoryError::RoomNotFound)?;
+        room.lock().await.add_participant(participant)?;
 
         Ok(())
     }
 
-    async fn remove_participant(&self, client_id: &ClientId) -> Result<(), RepositoryError> {
-        let mut room = self.room.lock().await;
-        room.remove_participant(client_id);
+    async fn reserve_participant(
+        &self,
+        room_id: &RoomId,
+        mut participant: Participant,
+        resolve_client_id: &ClientIdResolver<'_>,
+    ) -> Result<ClientId, RepositoryError> {
+        // 一覧のロックを追加まで保持し、同じ ID の参加者が別の Room に同時に追加されないようにする
+        let rooms = self.rooms.lock().await;
+        let room = rooms.get(room_id).ok_or(RepositoryError::RoomNotFound)?;
+        let mut connected_ids = Vec::new();
+        for other in rooms.values() {
+            let other = other.lock().await;
+            connected_ids.extend(other.participants.iter().map(|p| p.id.clone()));
+        }
+        let client_id = resolve_client_id(&connected_ids).ok_or_else(|| {
+            RepositoryError::DuplicateClientId(participant.id.as_str().to_string())
+        })?;
+        participant.id = client_id.clone();
+        room.lock().await.add_participant(participant)?;
+
+        Ok(client_id)
+    }
+
+    async fn remove_participant(
+        &self,
+        client_id: &ClientId,
+        left_at: Timestamp,
+    ) -> Result<(), RepositoryError> {
+        if let Some(room) = self.participant_room(client_id).await {
+            room.lock().await.remove_participant(client_id, left_at);
+        }
         Ok(())
     }
 
     async fn get_all_connected_client_ids(&self) -> Vec<ClientId> {
-        let room = self.room.lock().await;
-        room.participants.iter().map(|p| p.id.clone()).collect()
+        let mut client_ids = Vec::new();
+        for room in self.all_rooms().await {
+            let room = room.lock().await;
+            client_ids.extend(room.participants.iter().map(|p| p.id.clone()));
+        }
+        client_ids
     }
 
     async fn add_message(
         &self,
+        room_id: &RoomId,
         from_client_id: ClientId,
         content: MessageContent,
         timestamp: Timestamp,
+    ) -> Result<MessageId, RepositoryError> {
+        let message_id = {
+            let room = self.room(room_id).await?;
+            let mut room = room.lock().await;
+            let before = room.messages.len();
+            let message = ChatMessage::new(from_client_id, content, timestamp);
+            let message_id = room.add_message(message)?;
+            self.record_message_count_change(before, &room).await;
+            message_id
+        };
+        if let Some(message_budget) = self.message_budget {
+            self.enforce_message_budget(message_budget).await;
+        }
+        Ok(message_id)
+    }
+
+    async fn edit_message(
+        &self,
+        room_id: &RoomId,
+        message_id: &MessageId,
+        editor: &ClientId,
+        content: MessageContent,
+        edited_at: Timestamp,
+    ) -> Result<ChatMessage, RepositoryError> {
+        let room = self.room(room_id).await?;
+        let mut room = room.lock().await;
+        Ok(room.edit_message(message_id, editor, content, edited_at)?)
+    }
+
+    async fn delete_message(
+        &self,
+        room_id: &RoomId,
+        message_id: &MessageId,
+        requester: &ClientId,
     ) -> Result<(), RepositoryError> {
-        let mut room = self.room.lock().await;
-        let message = ChatMessage::new(from_client_id, content, timestamp);
-        room.add_message(message)
-            .map_err(|_| RepositoryError::RoomNotFound)?;
-        Ok(())
+        let room = self.room(room_id).await?;
+        let mut room = room.lock().await;
+        Ok(room.delete_message(message_id, requester)?)
+    }
+
+    async fn messages_since(&self, room_id: &RoomId, since: Timestamp) -> Vec<ChatMessage> {
+        let Some(room) = self.find_room(room_id).await else {
+            return Vec::new();
+        };
+        let room = room.lock().await;
+        room.messages
+            .iter()
+            .filter(|message| message.timestamp > since)
+            .cloned()
+            .collect()
     }
 
     async fn count_connected_clients(&self) -> usize {
-        let room = self.room.lock().await;
-        room.participants.len()
+        let mut count = 0;
+        for room in self.all_rooms().await {
+            count += room.lock().await.participants.len();
+        }
+        count
+    }
+
+    async fn get_participants(&self, room_id: &RoomId) -> Vec<Participant> {
+        match self.find_room(room_id).await {
+            Some(room) => room.lock().await.participants.clone(),
+            None => Vec::new(),
+        }
+    }
+
+    async fn get_participants_version(&self, room_id: &RoomId) -> u64 {
+        match self.find_room(room_id).await {
+            Some(room) => room.lock().await.participants_version,
+            None => 0,
+        }
     }
 
-    async fn get_participants(&self) -> Vec<Participant> {
-        let room = self.room.lock().await;
-        room.participants.clone()
+    async fn is_room_locked(&self, room_id: &RoomId) -> bool {
+        match self.find_room(room_id).await {
+            Some(room) => room.lock().await.locked,
+            None => false,
+        }
+    }
+
+    async fn set_room_locked(&self, room_id: &RoomId, locked: bool) -> Result<(), RepositoryError> {
+        let room = self.room(room_id).await?;
+        let mut room = room.lock().await;
+        if locked {
+            room.lock();
+        } else {
+            room.unlock();
+        }
+        Ok(())
+    }
+
+    async fn set_room_closed(&self, room_id: &RoomId, closed: bool) -> Result<(), RepositoryError> {
+        let room = self.room(room_id).await?;
+        let mut room = room.lock().await;
+        if closed {
+            room.close();
+        } else {
+            room.reopen();
+        }
+        Ok(())
+    }
+
+    async fn remove_room(&self, room_id: &RoomId) -> Result<Vec<String>, RepositoryError> {
+        let room = self.room(room_id).await?;
+        let removed = {
+            let mut room = room.lock().await;
+            let before = room.messages.len();
+            let removed = room.tear_down();
+            self.record_message_count_change(before, &room).await;
+            removed
+                .into_iter()
+                .map(|participant| participant.id.into_string())
+                .collect()
+        };
+        // デフォルトの Room は接続先として残し、空にして閉じるだけにする
+        if room_id != &self.default_room_id {
+            self.rooms.lock().await.remove(room_id);
+            self.message_index.lock().await.remove(room_id);
+        }
+        Ok(removed)
     }
 }
 
@@ -121,6 +563,8 @@ mod tests {
     // 3. 存在しない参加者の削除（エラーケース）
     // 4. クライアント情報取得の成功ケース
     // 5. 接続中クライアント数のカウント
+    // 6. 全ての Room のメッセージの合計の上限（最もメッセージの多い Room から削除）
+    // 7. 合計の上限での削除は、削除しない Room のロックを待たない
     // ========================================
 
     fn create_test_repository() -> InMemoryRoomRepository {
@@ -131,24 +575,32 @@ mod tests {
         InMemoryRoomRepository::new(room)
     }
 
+    async fn default_room_id(repo: &InMemoryRoomRepository) -> RoomId {
+        repo.get_room().await.unwrap().id
+    }
+
     #[tokio::test]
     async fn test_add_participant_success() {
         // テスト項目: 参加者を追加すると room に反映される
         // given (前提条件):
         let repo = create_test_repository();
+        let room_id = default_room_id(&repo).await;
         let timestamp = get_jst_timestamp();
 
         // when (操作):
         let client_id = ClientId::new("alice".to_string()).unwrap();
         let result = repo
-            .add_participant(client_id, Timestamp::new(timestamp))
+            .add_participant(
+                &room_id,
+                Participant::new(client_id, Timestamp::new(timestamp)),
+            )
             .await;
 
         // then (期待する結果):
         assert!(result.is_ok());
         assert_eq!(repo.count_connected_clients().await, 1);
 
-        let participants = repo.get_participants().await;
+        let participants = repo.get_participants(&room_id).await;
         assert_eq!(participants.len(), 1);
         assert_eq!(participants[0].id.as_str(), "alice");
         assert_eq!(participants[0].connected_at.value(), timestamp);
@@ -159,20 +611,26 @@ mod tests {
         // テスト項目: 参加者を削除すると room から削除される
         // given (前提条件):
         let repo = create_test_repository();
+        let room_id = default_room_id(&repo).await;
         let timestamp = get_jst_timestamp();
         let client_id = ClientId::new("alice".to_string()).unwrap();
-        repo.add_participant(client_id.clone(), Timestamp::new(timestamp))
-            .await
-            .unwrap();
+        repo.add_participant(
+            &room_id,
+            Participant::new(client_id.clone(), Timestamp::new(timestamp)),
+        )
+        .await
+        .unwrap();
 
         // when (操作):
-        let result = repo.remove_participant(&client_id).await;
+        let result = repo
+            .remove_participant(&client_id, Timestamp::new(2000))
+            .await;
 
         // then (期待する結果):
         assert!(result.is_ok());
         assert_eq!(repo.count_connected_clients().await, 0);
 
-        let participants = repo.get_participants().await;
+        let participants = repo.get_participants(&room_id).await;
         assert_eq!(participants.len(), 0);
     }
 
@@ -184,7 +642,9 @@ mod tests {
 
         // when (操作):
         let nonexistent = ClientId::new("nonexistent".to_string()).unwrap();
-        let result = repo.remove_participant(&nonexistent).await;
+        let result = repo
+            .remove_participant(&nonexistent, Timestamp::new(2000))
+            .await;
 
         // then (期待する結果): エラーにならず、問題なく処理される
         assert!(result.is_ok());
@@ -195,15 +655,16 @@ mod tests {
         // テスト項目: 接続中のクライアント数を正しくカウントできる
         // given (前提条件):
         let repo = create_test_repository();
+        let room_id = default_room_id(&repo).await;
         let timestamp = get_jst_timestamp();
 
         // when (操作):
         let alice = ClientId::new("alice".to_string()).unwrap();
         let bob = ClientId::new("bob".to_string()).unwrap();
-        repo.add_participant(alice, Timestamp::new(timestamp))
+        repo.add_participant(&room_id, Participant::new(alice, Timestamp::new(timestamp)))
             .await
             .unwrap();
-        repo.add_participant(bob, Timestamp::new(timestamp))
+        repo.add_participant(&room_id, Participant::new(bob, Timestamp::new(timestamp)))
             .await
             .unwrap();
 
@@ -216,17 +677,24 @@ mod tests {
         // テスト項目: 接続中の全てのクライアント ID を取得できる
         // given (前提条件):
         let repo = create_test_repository();
+        let room_id = default_room_id(&repo).await;
         let timestamp = get_jst_timestamp();
 
         // when (操作):
         let alice = ClientId::new("alice".to_string()).unwrap();
         let bob = ClientId::new("bob".to_string()).unwrap();
-        repo.add_participant(alice.clone(), Timestamp::new(timestamp))
-            .await
-            .unwrap();
-        repo.add_participant(bob.clone(), Timestamp::new(timestamp))
-            .await
-            .unwrap();
+        repo.add_participant(
+            &room_id,
+            Participant::new(alice.clone(), Timestamp::new(timestamp)),
+        )
+        .await
+        .unwrap();
+        repo.add_participant(
+            &room_id,
+            Participant::new(bob.clone(), Timestamp::new(timestamp)),
+        )
+        .await
+        .unwrap();
         let client_ids = repo.get_all_connected_client_ids().await;
 
         // then (期待する結果):
@@ -240,18 +708,22 @@ mod tests {
         // テスト項目: メッセージを Room に追加できる
         // given (前提条件):
         let repo = create_test_repository();
+        let room_id = default_room_id(&repo).await;
         let timestamp = get_jst_timestamp();
         let client_id = ClientId::new("alice".to_string()).unwrap();
-        repo.add_participant(client_id.clone(), Timestamp::new(timestamp))
-            .await
-            .unwrap();
+        repo.add_participant(
+            &room_id,
+            Participant::new(client_id.clone(), Timestamp::new(timestamp)),
+        )
+        .await
+        .unwrap();
 
         let content = MessageContent::new("Hello".to_string()).unwrap();
         let msg_timestamp = Timestamp::new(timestamp);
 
         // when (操作):
         let result = repo
-            .add_message(client_id.clone(), content, msg_timestamp)
+            .add_message(&room_id, client_id.clone(), content, msg_timestamp)
             .await;
 
         // then (期待する結果):
@@ -261,4 +733,367 @@ mod tests {
         assert_eq!(room.messages.len(), 1);
         assert_eq!(room.messages[0].from, client_id);
     }
+
+    #[tokio::test]
+    async fn test_messages_since() {
+        // テスト項目: 指定した時刻より後のメッセージだけが古い順に取得でき、別の Room ID では空になる
+        // given (前提条件):
+        let repo = create_test_repository();
+        let room_id = default_room_id(&repo).await;
+        let client_id = ClientId::new("alice".to_string()).unwrap();
+        for (content, timestamp) in [("one", 1000), ("two", 2000), ("three", 3000)] {
+            repo.add_message(
+                &room_id,
+                client_id.clone(),
+                MessageContent::new(content.to_string()).unwrap(),
+                Timestamp::new(timestamp),
+            )
+            .await
+            .unwrap();
+        }
+
+        // when (操作):
+        let since = repo.messages_since(&room_id, Timestamp::new(1000)).await;
+        let other_room = repo
+            .messages_since(&RoomIdFactory::generate().unwrap(), Timestamp::new(0))
+            .await;
+
+        // then (期待する結果):
+        let contents: Vec<&str> = since.iter().map(|m| m.content.as_str()).collect();
+        assert_eq!(contents, vec!["two", "three"]);
+        assert!(other_room.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_set_room_locked() {
+        // テスト項目: Room のロック状態を更新・取得できる
+        // given (前提条件):
+        let repo = create_test_repository();
+        let room_id = default_room_id(&repo).await;
+        assert!(!repo.is_room_locked(&room_id).await);
+
+        // when (操作):
+        let result = repo.set_room_locked(&room_id, true).await;
+
+        // then (期待する結果):
+        assert!(result.is_ok());
+        assert!(repo.is_room_locked(&room_id).await);
+        assert!(repo.get_room().await.unwrap().locked);
+    }
+
+    #[tokio::test]
+    async fn test_verify_consistency() {
+        // テスト項目: 参加者と接続中のクライアントが一致していれば成功し、片方にしかいないクライアントは不整合として検出される
+        // given (前提条件):
+        let connected_clients = Arc::new(Mutex::new(HashMap::new()));
+        let repo = create_test_repository().with_connected_clients(connected_clients.clone());
+        let room_id = default_room_id(&repo).await;
+        let alice = ClientId::new("alice".to_string()).unwrap();
+        repo.add_participant(
+            &room_id,
+            Participant::new(alice.clone(), Timestamp::new(get_jst_timestamp())),
+        )
+        .await
+        .unwrap();
+        connected_clients
+            .lock()
+            .await
+            .insert("alice".to_string(), crate::domain::pusher_channel().0);
+        let consistent = repo.verify_consistency().await;
+
+        // when (操作): 参加者と接続中のクライアントを意図的にずらす
+        repo.add_participant(
+            &room_id,
+            Participant::new(
+                ClientId::new("bob".to_string()).unwrap(),
+                Timestamp::new(get_jst_timestamp()),
+            ),
+        )
+        .await
+        .unwrap();
+        connected_clients
+            .lock()
+            .await
+            .insert("charlie".to_string(), crate::domain::pusher_channel().0);
+        let desynced = repo.verify_consistency().await;
+
+        // then (期待する結果):
+        assert!(consistent.is_ok());
+        assert_eq!(
+            desynced.unwrap_err(),
+            ConsistencyError {
+                connected_without_participant: vec!["charlie".to_string()],
+                participant_without_connection: vec!["bob".to_string()],
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_remove_room() {
+        // テスト項目: Room を取り除くと参加していたクライアントの ID が返され、存在しない Room は RoomNotFound になる
+        // given (前提条件):
+        let repo = create_test_repository();
+        let room_id = default_room_id(&repo).await;
+        repo.add_participant(
+            &room_id,
+            Participant::new(
+                ClientId::new("alice".to_string()).unwrap(),
+                Timestamp::new(get_jst_timestamp()),
+            ),
+        )
+        .await
+        .unwrap();
+
+        // when (操作):
+        let removed = repo.remove_room(&room_id).await;
+        let missing = repo.remove_room(&RoomIdFactory::generate().unwrap()).await;
+
+        // then (期待する結果):
+        assert_eq!(removed.unwrap(), vec!["alice".to_string()]);
+        assert!(matches!(missing, Err(RepositoryError::RoomNotFound)));
+        assert_eq!(repo.count_connected_clients().await, 0);
+        assert!(repo.get_room().await.unwrap().closed);
+    }
+
+    #[tokio::test]
+    async fn test_create_room_is_independent_of_default_room() {
+        // テスト項目: 作成した Room はデフォルトの Room と上限を共有し、参加者・メッセージ・ロック状態は独立している
+        // given (前提条件):
+        let repo = create_test_repository();
+        let default_id = default_room_id(&repo).await;
+        let other_id = RoomIdFactory::generate().unwrap();
+        let alice = ClientId::new("alice".to_string()).unwrap();
+        let bob = ClientId::new("bob".to_string()).unwrap();
+
+        // when (操作):
+        repo.create_room(other_id.clone(), Timestamp::new(1000), None)
+            .await
+            .unwrap();
+        let duplicate = repo
+            .create_room(other_id.clone(), Timestamp::new(2000), None)
+            .await;
+        repo.add_participant(
+            &default_id,
+            Participant::new(alice.clone(), Timestamp::new(3000)),
+        )
+        .await
+        .unwrap();
+        repo.add_participant(
+            &other_id,
+            Participant::new(bob.clone(), Timestamp::new(3000)),
+        )
+        .await
+        .unwrap();
+        repo.add_message(
+            &other_id,
+            bob.clone(),
+            MessageContent::new("Hello".to_string()).unwrap(),
+            Timestamp::new(4000),
+        )
+        .await
+        .unwrap();
+        repo.set_room_locked(&other_id, true).await.unwrap();
+
+        // then (期待する結果):
+        assert!(matches!(
+            duplicate,
+            Err(RepositoryError::RoomAlreadyExists(id)) if id == other_id.as_str()
+        ));
+        let rooms = repo.list_rooms().await;
+        assert_eq!(
+            rooms.iter().map(|room| room.id.clone()).collect::<Vec<_>>(),
+            vec![default_id.clone(), other_id.clone()]
+        );
+        assert_eq!(rooms[1].participant_capacity, rooms[0].participant_capacity);
+        assert_eq!(
+            repo.find_participant_room(&alice).await,
+            Some(default_id.clone())
+        );
+        assert_eq!(
+            repo.find_participant_room(&bob).await,
+            Some(other_id.clone())
+        );
+        assert_eq!(repo.get_participants(&default_id).await.len(), 1);
+        assert_eq!(repo.count_connected_clients().await, 2);
+        assert!(repo.get_room().await.unwrap().messages.is_empty());
+        assert_eq!(
+            repo.messages_since(&other_id, Timestamp::new(0))
+                .await
+                .len(),
+            1
+        );
+        assert!(!repo.is_room_locked(&default_id).await);
+        assert!(repo.is_room_locked(&other_id).await);
+    }
+
+    #[tokio::test]
+    async fn test_remove_participant_from_created_room() {
+        // テスト項目: 作成した Room の参加者を削除でき、作成した Room を取り除くと一覧からも取り除かれる
+        // given (前提条件):
+        let repo = create_test_repository();
+        let other_id = RoomIdFactory::generate().unwrap();
+        repo.create_room(other_id.clone(), Timestamp::new(1000), None)
+            .await
+            .unwrap();
+        let bob = ClientId::new("bob".to_string()).unwrap();
+        let carol = ClientId::new("carol".to_string()).unwrap();
+        for client_id in [bob.clone(), carol.clone()] {
+            repo.add_participant(&other_id, Participant::new(client_id, Timestamp::new(2000)))
+                .await
+                .unwrap();
+        }
+
+        // when (操作):
+        repo.remove_participant(&bob, Timestamp::new(2000))
+            .await
+            .unwrap();
+        let removed = repo.remove_room(&other_id).await;
+
+        // then (期待する結果):
+        assert_eq!(removed.unwrap(), vec!["carol".to_string()]);
+        assert_eq!(repo.find_participant_room(&bob).await, None);
+        assert_eq!(repo.list_rooms().await.len(), 1);
+        assert!(matches!(
+            repo.get_room_by_id(&other_id).await,
+            Err(RepositoryError::RoomNotFound)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_message_budget_evicts_oldest_message_from_busiest_room() {
+        // テスト項目: 全ての Room のメッセージの合計が上限を超えると、最もメッセージの多い Room の最も古いメッセージが削除され、Room を取り除くとその分の合計も減る
+        // given (前提条件):
+        let room = Room::new(RoomIdFactory::generate().unwrap(), Timestamp::new(0));
+        let repo = InMemoryRoomRepository::new(Arc::new(Mutex::new(room))).with_message_budget(4);
+        let busy_id = default_room_id(&repo).await;
+        let quiet_id = RoomIdFactory::generate().unwrap();
+        repo.create_room(quiet_id.clone(), Timestamp::new(0), None)
+            .await
+            .unwrap();
+        let alice = ClientId::new("alice".to_string()).unwrap();
+        for (room_id, content, timestamp) in [
+            (&busy_id, "busy-1", 1000),
+            (&busy_id, "busy-2", 2000),
+            (&quiet_id, "quiet-1", 3000),
+            (&busy_id, "busy-3", 4000),
+        ] {
+            repo.add_message(
+                room_id,
+                alice.clone(),
+                MessageContent::new(content.to_string()).unwrap(),
+                Timestamp::new(timestamp),
+            )
+            .await
+            .unwrap();
+        }
+        assert_eq!(repo.stored_message_count(), 4);
+
+        // when (操作):
+        let result = repo
+            .add_message(
+                &quiet_id,
+                alice.clone(),
+                MessageContent::new("quiet-2".to_string()).unwrap(),
+                Timestamp::new(5000),
+            )
+            .await;
+
+        // then (期待する結果):
+        let contents = |room: Room| -> Vec<String> {
+            room.messages
+                .iter()
+                .map(|m| m.content.as_str().to_string())
+                .collect()
+        };
+        assert!(result.is_ok());
+        assert_eq!(repo.stored_message_count(), 4);
+        assert_eq!(
+            contents(repo.get_room_by_id(&busy_id).await.unwrap()),
+            vec!["busy-2", "busy-3"]
+        );
+        assert_eq!(
+            contents(repo.get_room_by_id(&quiet_id).await.unwrap()),
+            vec!["quiet-1", "quiet-2"]
+        );
+        repo.remove_room(&quiet_id).await.unwrap();
+        assert_eq!(repo.stored_message_count(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_message_budget_does_not_wait_for_other_room_locks() {
+        // テスト項目: 合計の上限を超えたときの削除は、別のタスクがロックしている（削除しない）Room を待たずに完了する
+        // given (前提条件):
+        let room = Room::new(RoomIdFactory::generate().unwrap(), Timestamp::new(0));
+        let repo = InMemoryRoomRepository::new(Arc::new(Mutex::new(room))).with_message_budget(2);
+        let busy_id = default_room_id(&repo).await;
+        let quiet_id = RoomIdFactory::generate().unwrap();
+        repo.create_room(quiet_id.clone(), Timestamp::new(0), None)
+            .await
+            .unwrap();
+        let alice = ClientId::new("alice".to_string()).unwrap();
+        for (content, timestamp) in [("busy-1", 1000), ("busy-2", 2000)] {
+            repo.add_message(
+                &busy_id,
+                alice.clone(),
+                MessageContent::new(content.to_string()).unwrap(),
+                Timestamp::new(timestamp),
+            )
+            .await
+            .unwrap();
+        }
+        let quiet_room = repo.find_room(&quiet_id).await.unwrap();
+        let _quiet_guard = quiet_room.lock().await;
+
+        // when (操作):
+        let result = tokio::time::timeout(
+            Duration::from_secs(1),
+            repo.add_message(
+                &busy_id,
+                alice.clone(),
+                MessageContent::new("busy-3".to_string()).unwrap(),
+                Timestamp::new(3000),
+            ),
+        )
+        .await;
+
+        // then (期待する結果):
+        assert!(matches!(result, Ok(Ok(_))));
+        assert_eq!(repo.stored_message_count(), 2);
+        assert_eq!(
+            repo.message_index.lock().await.busiest(),
+            Some(busy_id.clone())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_create_room_with_same_id() {
+        // テスト項目: 同じ ID の Room を同時に作成しても 1 つだけが作成され、残りは RoomAlreadyExists になる
+        // given (前提条件):
+        let repo = Arc::new(create_test_repository());
+        let room_id = RoomIdFactory::generate().unwrap();
+
+        // when (操作):
+        let handles: Vec<_> = (0..8)
+            .map(|i| {
+                let repo = repo.clone();
+                let room_id = room_id.clone();
+                tokio::spawn(
+                    async move { repo.create_room(room_id, Timestamp::new(i), None).await },
+                )
+            })
+            .collect();
+        let mut results = Vec::new();
+        for handle in handles {
+            results.push(handle.await.unwrap());
+        }
+
+        // then (期待する結果):
+        assert_eq!(results.iter().filter(|result| result.is_ok()).count(), 1);
+        assert!(results.iter().all(|result| match result {
+            Ok(()) => true,
+            Err(RepositoryError::RoomAlreadyExists(id)) => id == room_id.as_str(),
+            Err(_) => false,
+        }));
+        assert_eq!(repo.list_rooms().await.len(), 2);
+    }
 }
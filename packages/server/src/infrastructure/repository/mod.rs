@@ -3,6 +3,12 @@
 //! ドメイン層が定義する Repository trait の具体的な実装を提供します。
 //! UseCase 層は trait（ドメイン層）に依存し、この実装に直接依存しません（依存性の逆転）。
 
+pub mod file;
 pub mod inmemory;
+#[cfg(feature = "sqlite")]
+pub mod sqlite;
 
-pub use inmemory::InMemoryRoomRepository;
+pub use file::FileRoomRepository;
+pub use inmemory::{ConsistencyError, InMemoryRoomRepository, spawn_consistency_check};
+#[cfg(feature = "sqlite")]
+pub use sqlite::SqliteRoomRepository;
@@ -0,0 +1,15 @@
+//! SQLite Repository 実装（`sqlite` feature）
+//!
+//! sqlx を使って Room 集約を SQLite に保存する Repository 実装。
+
+mod record;
+mod room;
+
+pub use room::SqliteRoomRepository;
+
+use crate::domain::RepositoryError;
+
+/// ストレージのエラーを RepositoryError に変換
+fn storage_error(error: impl std::fmt::Display) -> RepositoryError {
+    RepositoryError::Storage(error.to_string())
+}
@@ -0,0 +1,255 @@
+//! SQLite の行とドメインモデルの変換
+//!
+//! InMemory 実装の「技術的負債」に記載した変換層（DB Row → Record (DTO) → Room (ドメインモデル)）。
+//! テーブルの行を Record として読み込み、値オブジェクトの検証を通して Room 集約を組み立てます。
+
+use sqlx::{Row, sqlite::SqliteRow};
+
+use crate::domain::{
+    ChatMessage, ClientId, MessageContent, MessageId, Participant, RepositoryError, Room, RoomId,
+    Timestamp,
+};
+
+use super::storage_error;
+
+/// `rooms` テーブルの行
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct RoomRecord {
+    pub id: String,
+    pub created_at: i64,
+    pub participant_capacity: i64,
+    pub message_capacity: i64,
+    pub locked: bool,
+    pub next_message_seq: i64,
+    pub message_quota_per_client: Option<i64>,
+    pub participants_version: i64,
+    pub closed: bool,
+    pub tenant: Option<String>,
+}
+
+/// `participants` テーブルの行
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct ParticipantRecord {
+    pub client_id: String,
+    pub connected_at: i64,
+    pub is_bot: bool,
+}
+
+/// `messages` テーブルの行
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct MessageRecord {
+    pub message_id: Option<String>,
+    pub from_client_id: String,
+    pub content: String,
+    pub timestamp: i64,
+    pub edited_at: Option<i64>,
+    pub deleted: bool,
+}
+
+impl RoomRecord {
+    /// 行から読み込む
+    pub fn from_row(row: &SqliteRow) -> Result<Self, RepositoryError> {
+        Ok(Self {
+            id: row.try_get("id").map_err(storage_error)?,
+            created_at: row.try_get("created_at").map_err(storage_error)?,
+            participant_capacity: row.try_get("participant_capacity").map_err(storage_error)?,
+            message_capacity: row.try_get("message_capacity").map_err(storage_error)?,
+            locked: row.try_get("locked").map_err(storage_error)?,
+            next_message_seq: row.try_get("next_message_seq").map_err(storage_error)?,
+            message_quota_per_client: row
+                .try_get("message_quota_per_client")
+                .map_err(storage_error)?,
+            participants_version: row.try_get("participants_version").map_err(storage_error)?,
+            closed: row.try_get("closed").map_err(storage_error)?,
+            tenant: row.try_get("tenant").map_err(storage_error)?,
+        })
+    }
+
+    /// Room ドメインモデルから変換
+    pub fn from_room(room: &Room) -> Self {
+        Self {
+            id: room.id.as_str().to_string(),
+            created_at: room.created_at.value(),
+            participant_capacity: room.participant_capacity as i64,
+            message_capacity: room.message_capacity as i64,
+            locked: room.locked,
+            next_message_seq: room.next_message_seq as i64,
+            message_quota_per_client: room.message_quota_per_client.map(|quota| quota as i64),
+            participants_version: room.participants_version as i64,
+            closed: room.closed,
+            tenant: room.tenant.clone(),
+        }
+    }
+
+    /// 参加者とメッセージの行と合わせて Room ドメインモデルに変換
+    ///
+    /// # 戻り値
+    ///
+    /// - `Ok(Room)`: 組み立てた Room
+    /// - `Err(RepositoryError::Storage)`: 保存された値が値オブジェクトの検証を通らない
+    pub fn into_room(
+        self,
+        participants: Vec<ParticipantRecord>,
+        messages: Vec<MessageRecord>,
+    ) -> Result<Room, RepositoryError> {
+        let id = RoomId::new(self.id).map_err(storage_error)?;
+        let mut room = Room::with_capacity(
+            id,
+            Timestamp::new(self.created_at),
+            self.participant_capacity as usize,
+            self.message_capacity as usize,
+        );
+        room.locked = self.locked;
+        room.next_message_seq = self.next_message_seq as u64;
+        room.message_quota_per_client = self.message_quota_per_client.map(|quota| quota as usize);
+        room.participants_version = self.participants_version as u64;
+        room.closed = self.closed;
+        room.tenant = self.tenant;
+        room.participants = participants
+            .into_iter()
+            .map(ParticipantRecord::into_participant)
+            .collect::<Result<_, _>>()?;
+        room.messages = messages
+            .into_iter()
+            .map(MessageRecord::into_message)
+            .collect::<Result<_, _>>()?;
+        Ok(room)
+    }
+}
+
+impl ParticipantRecord {
+    /// 行から読み込む
+    pub fn from_row(row: &SqliteRow) -> Result<Self, RepositoryError> {
+        Ok(Self {
+            client_id: row.try_get("client_id").map_err(storage_error)?,
+            connected_at: row.try_get("connected_at").map_err(storage_error)?,
+            is_bot: row.try_get("is_bot").map_err(storage_error)?,
+        })
+    }
+
+    /// Participant ドメインモデルから変換
+    pub fn from_participant(participant: &Participant) -> Self {
+        Self {
+            client_id: participant.id.as_str().to_string(),
+            connected_at: participant.connected_at.value(),
+            is_bot: participant.is_bot,
+        }
+    }
+
+    fn into_participant(self) -> Result<Participant, RepositoryError> {
+        let id = ClientId::restore(self.client_id).map_err(storage_error)?;
+        Ok(Participant::new(id, Timestamp::new(self.connected_at)).with_bot(self.is_bot))
+    }
+}
+
+impl MessageRecord {
+    /// 行から読み込む
+    pub fn from_row(row: &SqliteRow) -> Result<Self, RepositoryError> {
+        Ok(Self {
+            message_id: row.try_get("message_id").map_err(storage_error)?,
+            from_client_id: row.try_get("from_client_id").map_err(storage_error)?,
+            content: row.try_get("content").map_err(storage_error)?,
+            timestamp: row.try_get("timestamp").map_err(storage_error)?,
+            edited_at: row.try_get("edited_at").map_err(storage_error)?,
+            deleted: row.try_get("deleted").map_err(storage_error)?,
+        })
+    }
+
+    /// ChatMessage ドメインモデルから変換
+    pub fn from_message(message: &ChatMessage) -> Self {
+        Self {
+            message_id: message.id.as_ref().map(|id| id.as_str().to_string()),
+            from_client_id: message.from.as_str().to_string(),
+            content: message.content.as_str().to_string(),
+            timestamp: message.timestamp.value(),
+            edited_at: message.edited_at.map(|t| t.value()),
+            deleted: message.deleted,
+        }
+    }
+
+    /// ChatMessage ドメインモデルに変換
+    pub fn into_message(self) -> Result<ChatMessage, RepositoryError> {
+        // A deleted message keeps its row with empty content, which the validation rejects
+        let content = if self.deleted {
+            MessageContent::tombstone()
+        } else {
+            MessageContent::new(self.content).map_err(storage_error)?
+        };
+        let mut message = ChatMessage::new(
+            ClientId::restore(self.from_client_id).map_err(storage_error)?,
+            content,
+            Timestamp::new(self.timestamp),
+        );
+        message.id = self
+            .message_id
+            .map(MessageId::parse)
+            .transpose()
+            .map_err(storage_error)?;
+        message.edited_at = self.edited_at.map(Timestamp::new);
+        message.deleted = self.deleted;
+        Ok(message)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::RoomIdFactory;
+
+    #[test]
+    fn test_room_record_round_trip() {
+        // テスト項目: Room を Record に変換して戻すと、参加者・メッセージ（編集日時・削除済みのメッセージを含む）・設定が元の Room と一致する
+        // given (前提条件):
+        let mut room = Room::new(RoomIdFactory::generate().unwrap(), Timestamp::new(1000))
+            .with_message_quota_per_client(3);
+        let alice = ClientId::new("alice".to_string()).unwrap();
+        room.add_participant(Participant::new(alice.clone(), Timestamp::new(2000)).with_bot(true))
+            .unwrap();
+        let message_id = room
+            .add_message(ChatMessage::new(
+                alice.clone(),
+                MessageContent::new("Helo".to_string()).unwrap(),
+                Timestamp::new(3000),
+            ))
+            .unwrap();
+        room.edit_message(
+            &message_id,
+            &alice,
+            MessageContent::new("Hello".to_string()).unwrap(),
+            Timestamp::new(4000),
+        )
+        .unwrap();
+        let deleted_id = room
+            .add_message(ChatMessage::new(
+                alice.clone(),
+                MessageContent::new("Oops".to_string()).unwrap(),
+                Timestamp::new(5000),
+            ))
+            .unwrap();
+        room.delete_message(&deleted_id, &alice).unwrap();
+        room.lock();
+
+        // when (操作):
+        let restored = RoomRecord::from_room(&room)
+            .into_room(
+                room.participants
+                    .iter()
+                    .map(ParticipantRecord::from_participant)
+                    .collect(),
+                room.messages
+                    .iter()
+                    .map(MessageRecord::from_message)
+                    .collect(),
+            )
+            .unwrap();
+
+        // then (期待する結果):
+        assert_eq!(restored.id, room.id);
+        assert_eq!(restored.participants, room.participants);
+        assert_eq!(restored.messages, room.messages);
+        assert_eq!(restored.next_message_seq, room.next_message_seq);
+        assert_eq!(restored.message_quota_per_client, Some(3));
+        assert_eq!(restored.participants_version, room.participants_version);
+        assert!(restored.locked);
+    }
+}
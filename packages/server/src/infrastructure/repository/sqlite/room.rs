@@ -0,0 +1,714 @@
+//! SQLite Room Repository 実装
+//!
+//! ドメイン層が定義する RoomRepository trait の SQLite による実装（`sqlite` feature）。
+//! Room 集約を `rooms` / `participants` / `messages` テーブルに保存し、更新のたびに集約を読み込んで
+//! ドメインモデルのルール（定員・メッセージ容量・クォータなど）を適用してから、同じトランザクションで書き戻します。
+//!
+//! WebSocket の sender（接続中のクライアント）はプロセスごとの状態のため、
+//! 引き続き MessagePusher がメモリ上で管理します。
+
+use async_trait::async_trait;
+use sqlx::{Connection, SqliteConnection, SqlitePool};
+use tokio::sync::Mutex;
+
+use crate::domain::{
+    ChatMessage, ClientId, ClientIdResolver, MessageContent, MessageId, Participant,
+    RepositoryError, Room, RoomId, RoomRepository, ShardId, Timestamp,
+};
+
+use super::{
+    record::{MessageRecord, ParticipantRecord, RoomRecord},
+    storage_error,
+};
+
+/// テーブルを作成・変更するマイグレーション（適用した数を `PRAGMA user_version` に記録する）
+const MIGRATIONS: &[&str] = &[
+    include_str!("../../../../migrations/sqlite/20261016000000_create_rooms.sql"),
+    include_str!("../../../../migrations/sqlite/20261017000000_add_rooms_tenant.sql"),
+];
+
+/// SQLite Room Repository 実装
+///
+/// `rooms` テーブルの全ての Room を対象とし、ドメイン層の RoomRepository trait を実装します（依存性の逆転）。
+pub struct SqliteRoomRepository {
+    /// コネクションプール
+    pool: SqlitePool,
+    /// デフォルトの Room の ID
+    default_room_id: RoomId,
+    /// メッセージ ID に付けるシャード（サーバの設定のため保存せず、読み込んだ全ての Room に適用する）
+    message_id_shard: Option<ShardId>,
+    /// 集約の読み込みから書き戻しまでを直列化するロック（同じプロセス内の更新が互いを上書きしないようにする）
+    write_lock: Mutex<()>,
+}
+
+impl SqliteRoomRepository {
+    /// マイグレーションを実行し、Room をデフォルトの Room とする SqliteRoomRepository を作成
+    ///
+    /// 同じ ID の Room が既に保存されている場合は、保存されている状態を引き継ぎます（再起動時など）。
+    ///
+    /// # 引数
+    ///
+    /// - `pool`: コネクションプール（`sqlite::memory:` の場合は接続数を 1 にする）
+    /// - `room`: デフォルトの Room（保存されていない場合の初期状態）
+    pub async fn open(pool: SqlitePool, room: Room) -> Result<Self, RepositoryError> {
+        Self::migrate(&pool).await?;
+        let repository = Self {
+            pool,
+            default_room_id: room.id.clone(),
+            message_id_shard: room.message_id_shard.clone(),
+            write_lock: Mutex::new(()),
+        };
+        let mut conn = repository.pool.acquire().await.map_err(storage_error)?;
+        match load_room(&mut conn, &repository.default_room_id).await {
+            Ok(_) => {}
+            Err(RepositoryError::RoomNotFound) => save_room(&mut conn, &room).await?,
+            Err(e) => return Err(e),
+        }
+        Ok(repository)
+    }
+
+    /// 未適用のマイグレーションを順に実行（適用済みのものは `PRAGMA user_version` で飛ばす）
+    pub async fn migrate(pool: &SqlitePool) -> Result<(), RepositoryError> {
+        let mut conn = pool.acquire().await.map_err(storage_error)?;
+        let applied: i64 = sqlx::query_scalar("PRAGMA user_version")
+            .fetch_one(&mut *conn)
+            .await
+            .map_err(storage_error)?;
+        for (version, migration) in MIGRATIONS.iter().enumerate().skip(applied as usize) {
+            let mut tx = conn.begin().await.map_err(storage_error)?;
+            sqlx::raw_sql(migration)
+                .execute(&mut *tx)
+                .await
+                .map_err(storage_error)?;
+            sqlx::raw_sql(&format!("PRAGMA user_version = {}", version + 1))
+                .execute(&mut *tx)
+                .await
+                .map_err(storage_error)?;
+            tx.commit().await.map_err(storage_error)?;
+        }
+        Ok(())
+    }
+
+    /// Room 集約を読み込み、`update` を適用して書き戻す
+    ///
+    /// `update` がエラーを返した場合は何も書き戻しません。
+    async fn update<T>(
+        &self,
+        room_id: &RoomId,
+        update: impl FnOnce(&mut Room) -> Result<T, RepositoryError> + Send,
+    ) -> Result<T, RepositoryError> {
+        let _guard = self.write_lock.lock().await;
+        let mut tx = self.pool.begin().await.map_err(storage_error)?;
+        let mut room = load_room(&mut tx, room_id).await?;
+        room.message_id_shard = self.message_id_shard.clone();
+        let result = update(&mut room)?;
+        save_room(&mut tx, &room).await?;
+        tx.commit().await.map_err(storage_error)?;
+        Ok(result)
+    }
+
+    /// 戻り値に Result を持たないメソッド用に Room を読み込む（失敗した場合はログに出力して `None`）
+    async fn load_or_log(&self, room_id: &RoomId) -> Option<Room> {
+        match self.get_room_by_id(room_id).await {
+            Ok(room) => Some(room),
+            Err(e) => {
+                tracing::warn!("Failed to load room {}: {}", room_id.as_str(), e);
+                None
+            }
+        }
+    }
+
+    /// 全ての Room の ID を作成順に取得
+    async fn room_ids(&self) -> Result<Vec<RoomId>, RepositoryError> {
+        let mut conn = self.pool.acquire().await.map_err(storage_error)?;
+        sqlx::query_scalar::<_, String>("SELECT id FROM rooms ORDER BY created_at, rowid")
+            .fetch_all(&mut *conn)
+            .await
+            .map_err(storage_error)?
+            .into_iter()
+            .map(|id| RoomId::new(id).map_err(storage_error))
+            .collect()
+    }
+
+    /// 参加者が入室している Room の ID を取得
+    async fn participant_room_id(
+        &self,
+        client_id: &ClientId,
+    ) -> Result<Option<RoomId>, RepositoryError> {
+        let mut conn = self.pool.acquire().await.map_err(storage_error)?;
+        sqlx::query_scalar::<_, String>("SELECT room_id FROM participants WHERE client_id = ?")
+            .bind(client_id.as_str())
+            .fetch_optional(&mut *conn)
+            .await
+            .map_err(storage_error)?
+            .map(|id| RoomId::new(id).map_err(storage_error))
+            .transpose()
+    }
+}
+
+/// Room 集約を読み込む
+async fn load_room(conn: &mut SqliteConnection, room_id: &RoomId) -> Result<Room, RepositoryError> {
+    let row = sqlx::query("SELECT * FROM rooms WHERE id = ?")
+        .bind(room_id.as_str())
+        .fetch_optional(&mut *conn)
+        .await
+        .map_err(storage_error)?
+        .ok_or(RepositoryError::RoomNotFound)?;
+    let room = RoomRecord::from_row(&row)?;
+
+    let participants = sqlx::query(
+        "SELECT client_id, connected_at, is_bot FROM participants WHERE room_id = ? ORDER BY position",
+    )
+    .bind(room_id.as_str())
+    .fetch_all(&mut *conn)
+    .await
+    .map_err(storage_error)?
+    .iter()
+    .map(ParticipantRecord::from_row)
+    .collect::<Result<_, _>>()?;
+
+    let messages = sqlx::query(
+        "SELECT message_id, from_client_id, content, timestamp, edited_at, deleted FROM messages WHERE room_id = ? ORDER BY position",
+    )
+    .bind(room_id.as_str())
+    .fetch_all(&mut *conn)
+    .await
+    .map_err(storage_error)?
+    .iter()
+    .map(MessageRecord::from_row)
+    .collect::<Result<_, _>>()?;
+
+    room.into_room(participants, messages)
+}
+
+/// Room 集約を書き戻す（参加者とメッセージの行は置き換える）
+async fn save_room(conn: &mut SqliteConnection, room: &Room) -> Result<(), RepositoryError> {
+    let record = RoomRecord::from_room(room);
+    sqlx::query(
+        "INSERT INTO rooms (id, created_at, participant_capacity, message_capacity, locked, \
+         next_message_seq, message_quota_per_client, participants_version, closed, tenant) \
+         VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?) \
+         ON CONFLICT (id) DO UPDATE SET \
+         participant_capacity = excluded.participant_capacity, \
+         message_capacity = excluded.message_capacity, \
+         locked = excluded.locked, \
+         next_message_seq = excluded.next_message_seq, \
+         message_quota_per_client = excluded.message_quota_per_client, \
+         participants_version = excluded.participants_version, \
+         closed = excluded.closed, \
+         tenant = excluded.tenant",
+    )
+    .bind(&record.id)
+    .bind(record.created_at)
+    .bind(record.participant_capacity)
+    .bind(record.message_capacity)
+    .bind(record.locked)
+    .bind(record.next_message_seq)
+    .bind(record.message_quota_per_client)
+    .bind(record.participants_version)
+    .bind(record.closed)
+    .bind(&record.tenant)
+    .execute(&mut *conn)
+    .await
+    .map_err(storage_error)?;
+
+    sqlx::query("DELETE FROM participants WHERE room_id = ?")
+        .bind(&record.id)
+        .execute(&mut *conn)
+        .await
+        .map_err(storage_error)?;
+    for (position, participant) in room.participants.iter().enumerate() {
+        let participant = ParticipantRecord::from_participant(participant);
+        sqlx::query(
+            "INSERT INTO participants (room_id, position, client_id, connected_at, is_bot) \
+             VALUES (?, ?, ?, ?, ?)",
+        )
+        .bind(&record.id)
+        .bind(position as i64)
+        .bind(participant.client_id)
+        .bind(participant.connected_at)
+        .bind(participant.is_bot)
+        .execute(&mut *conn)
+        .await
+        .map_err(storage_error)?;
+    }
+
+    sqlx::query("DELETE FROM messages WHERE room_id = ?")
+        .bind(&record.id)
+        .execute(&mut *conn)
+        .await
+        .map_err(storage_error)?;
+    for (position, message) in room.messages.iter().enumerate() {
+        let message = MessageRecord::from_message(message);
+        sqlx::query(
+            "INSERT INTO messages (room_id, position, message_id, from_client_id, content, timestamp, edited_at, deleted) \
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
+        )
+        .bind(&record.id)
+        .bind(position as i64)
+        .bind(message.message_id)
+        .bind(message.from_client_id)
+        .bind(message.content)
+        .bind(message.timestamp)
+        .bind(message.edited_at)
+        .bind(message.deleted)
+        .execute(&mut *conn)
+        .await
+        .map_err(storage_error)?;
+    }
+    Ok(())
+}
+
+#[async_trait]
+impl RoomRepository for SqliteRoomRepository {
+    async fn get_room(&self) -> Result<Room, RepositoryError> {
+        self.get_room_by_id(&self.default_room_id).await
+    }
+
+    async fn get_room_by_id(&self, room_id: &RoomId) -> Result<Room, RepositoryError> {
+        let mut conn = self.pool.acquire().await.map_err(storage_error)?;
+        load_room(&mut conn, room_id).await
+    }
+
+    async fn create_room(
+        &self,
+        room_id: RoomId,
+        created_at: Timestamp,
+        tenant: Option<String>,
+    ) -> Result<(), RepositoryError> {
+        let _guard = self.write_lock.lock().await;
+        let mut tx = self.pool.begin().await.map_err(storage_error)?;
+        match load_room(&mut tx, &room_id).await {
+            Ok(_) => {
+                return Err(RepositoryError::RoomAlreadyExists(
+                    room_id.as_str().to_string(),
+                ));
+            }
+            Err(RepositoryError::RoomNotFound) => {}
+            Err(e) => return Err(e),
+        }
+        let default_room = load_room(&mut tx, &self.default_room_id).await?;
+        let mut room = Room::with_capacity(
+            room_id,
+            created_at,
+            default_room.participant_capacity,
+            default_room.message_capacity,
+        );
+        room.message_quota_per_client = default_room.message_quota_per_client;
+        room.tenant = tenant;
+        save_room(&mut tx, &room).await?;
+        tx.commit().await.map_err(storage_error)?;
+        Ok(())
+    }
+
+    async fn list_rooms(&self) -> Vec<Room> {
+        let room_ids = self.room_ids().await.unwrap_or_else(|e| {
+            tracing::warn!("Failed to list rooms: {}", e);
+            Vec::new()
+        });
+        let mut rooms = Vec::new();
+        for room_id in room_ids {
+            rooms.extend(self.load_or_log(&room_id).await);
+        }
+        rooms
+    }
+
+    async fn find_participant_room(&self, client_id: &ClientId) -> Option<RoomId> {
+        self.participant_room_id(client_id)
+            .await
+            .unwrap_or_else(|e| {
+                tracing::warn!("Failed to find the room of {}: {}", client_id.as_str(), e);
+                None
+            })
+    }
+
+    async fn add_participant(
+        &self,
+        room_id: &RoomId,
+        participant: Participant,
+    ) -> Result<(), RepositoryError> {
+        self.update(room_id, |room| Ok(room.add_participant(participant)?))
+            .await
+    }
+
+    async fn reserve_participant(
+        &self,
+        room_id: &RoomId,
+        mut participant: Participant,
+        resolve_client_id: &ClientIdResolver<'_>,
+    ) -> Result<ClientId, RepositoryError> {
+        // 接続中の ID の読み込みから追加の書き戻しまでを書き込みのロックとトランザクションで直列化する
+        let _guard = self.write_lock.lock().await;
+        let mut tx = self.pool.begin().await.map_err(storage_error)?;
+        let mut room = load_room(&mut tx, room_id).await?;
+        room.message_id_shard = self.message_id_shard.clone();
+        let connected_ids = sqlx::query_scalar::<_, String>("SELECT client_id FROM participants")
+            .fetch_all(&mut *tx)
+            .await
+            .map_err(storage_error)?
+            .into_iter()
+            .map(|id| ClientId::restore(id).map_err(storage_error))
+            .collect::<Result<Vec<_>, _>>()?;
+        let client_id = resolve_client_id(&connected_ids).ok_or_else(|| {
+            RepositoryError::DuplicateClientId(participant.id.as_str().to_string())
+        })?;
+        participant.id = client_id.clone();
+        room.add_participant(participant)?;
+        save_room(&mut tx, &room).await?;
+        tx.commit().await.map_err(storage_error)?;
+        Ok(client_id)
+    }
+
+    async fn remove_participant(
+        &self,
+        client_id: &ClientId,
+        left_at: Timestamp,
+    ) -> Result<(), RepositoryError> {
+        let Some(room_id) = self.participant_room_id(client_id).await? else {
+            return Ok(());
+        };
+        self.update(&room_id, |room| {
+            room.remove_participant(client_id, left_at);
+            Ok(())
+        })
+        .await
+    }
+
+    async fn get_all_connected_client_ids(&self) -> Vec<ClientId> {
+        self.list_rooms()
+            .await
+            .into_iter()
+            .flat_map(|room| room.participants.into_iter().map(|p| p.id))
+            .collect()
+    }
+
+    async fn add_message(
+        &self,
+        room_id: &RoomId,
+        from_client_id: ClientId,
+        content: MessageContent,
+        timestamp: Timestamp,
+    ) -> Result<MessageId, RepositoryError> {
+        let message = ChatMessage::new(from_client_id, content, timestamp);
+        self.update(room_id, |room| Ok(room.add_message(message)?))
+            .await
+    }
+
+    async fn edit_message(
+        &self,
+        room_id: &RoomId,
+        message_id: &MessageId,
+        editor: &ClientId,
+        content: MessageContent,
+        edited_at: Timestamp,
+    ) -> Result<ChatMessage, RepositoryError> {
+        self.update(room_id, |room| {
+            Ok(room.edit_message(message_id, editor, content, edited_at)?)
+        })
+        .await
+    }
+
+    async fn delete_message(
+        &self,
+        room_id: &RoomId,
+        message_id: &MessageId,
+        requester: &ClientId,
+    ) -> Result<(), RepositoryError> {
+        self.update(room_id, |room| {
+            Ok(room.delete_message(message_id, requester)?)
+        })
+        .await
+    }
+
+    async fn messages_since(&self, room_id: &RoomId, since: Timestamp) -> Vec<ChatMessage> {
+        let result = async {
+            let mut conn = self.pool.acquire().await.map_err(storage_error)?;
+            sqlx::query(
+                "SELECT message_id, from_client_id, content, timestamp, edited_at, deleted FROM messages WHERE room_id = ? AND timestamp > ? ORDER BY position",
+            )
+            .bind(room_id.as_str())
+            .bind(since.value())
+            .fetch_all(&mut *conn)
+            .await
+            .map_err(storage_error)?
+            .iter()
+            .map(|row| MessageRecord::from_row(row)?.into_message())
+            .collect::<Result<Vec<_>, RepositoryError>>()
+        };
+        result.await.unwrap_or_else(|e| {
+            tracing::warn!(
+                "Failed to load messages of room {}: {}",
+                room_id.as_str(),
+                e
+            );
+            Vec::new()
+        })
+    }
+
+    async fn count_connected_clients(&self) -> usize {
+        self.list_rooms()
+            .await
+            .iter()
+            .map(|room| room.participants.len())
+            .sum()
+    }
+
+    async fn get_participants(&self, room_id: &RoomId) -> Vec<Participant> {
+        self.load_or_log(room_id)
+            .await
+            .map(|room| room.participants)
+            .unwrap_or_default()
+    }
+
+    async fn get_participants_version(&self, room_id: &RoomId) -> u64 {
+        self.load_or_log(room_id)
+            .await
+            .map_or(0, |room| room.participants_version)
+    }
+
+    async fn is_room_locked(&self, room_id: &RoomId) -> bool {
+        self.load_or_log(room_id)
+            .await
+            .is_some_and(|room| room.locked)
+    }
+
+    async fn set_room_locked(&self, room_id: &RoomId, locked: bool) -> Result<(), RepositoryError> {
+        self.update(room_id, |room| {
+            if locked {
+                room.lock();
+            } else {
+                room.unlock();
+            }
+            Ok(())
+        })
+        .await
+    }
+
+    async fn set_room_closed(&self, room_id: &RoomId, closed: bool) -> Result<(), RepositoryError> {
+        self.update(room_id, |room| {
+            if closed {
+                room.close();
+            } else {
+                room.reopen();
+            }
+            Ok(())
+        })
+        .await
+    }
+
+    async fn remove_room(&self, room_id: &RoomId) -> Result<Vec<String>, RepositoryError> {
+        let removed = self
+            .update(room_id, |room| {
+                Ok(room
+                    .tear_down()
+                    .into_iter()
+                    .map(|participant| participant.id.into_string())
+                    .collect())
+            })
+            .await?;
+        // InMemory 実装と同じく、デフォルトの Room の行は残し、空にして閉じるだけにする
+        if room_id != &self.default_room_id {
+            sqlx::query("DELETE FROM rooms WHERE id = ?")
+                .bind(room_id.as_str())
+                .execute(&self.pool)
+                .await
+                .map_err(storage_error)?;
+        }
+        Ok(removed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{RoomError, RoomIdFactory};
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    async fn create_test_repository(room: Room) -> SqliteRoomRepository {
+        // インメモリ DB は接続ごとに別の DB になるため、接続数を 1 にする
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .expect("Failed to open in-memory sqlite db");
+        SqliteRoomRepository::open(pool, room)
+            .await
+            .expect("Failed to open repository")
+    }
+
+    #[tokio::test]
+    async fn test_add_messages_and_read_back() {
+        // テスト項目: インメモリの SQLite DB にメッセージを追加すると、get_room で ID の順に読み出せ、messages_since で時刻より後のものだけを読み出せる
+        // given (前提条件):
+        let repo = create_test_repository(Room::new(
+            RoomIdFactory::generate().unwrap(),
+            Timestamp::new(1000),
+        ))
+        .await;
+        let room_id = repo.get_room().await.unwrap().id;
+        let alice = ClientId::new("alice".to_string()).unwrap();
+        repo.add_participant(
+            &room_id,
+            Participant::new(alice.clone(), Timestamp::new(2000)),
+        )
+        .await
+        .unwrap();
+
+        // when (操作):
+        let first = repo
+            .add_message(
+                &room_id,
+                alice.clone(),
+                MessageContent::new("Hello".to_string()).unwrap(),
+                Timestamp::new(3000),
+            )
+            .await
+            .unwrap();
+        let second = repo
+            .add_message(
+                &room_id,
+                alice.clone(),
+                MessageContent::new("World".to_string()).unwrap(),
+                Timestamp::new(4000),
+            )
+            .await
+            .unwrap();
+        let room = repo.get_room().await.unwrap();
+        let since = repo.messages_since(&room.id, Timestamp::new(3000)).await;
+
+        // then (期待する結果):
+        assert!(first < second);
+        assert_eq!(room.messages.len(), 2);
+        assert_eq!(room.messages[0].id, Some(first));
+        assert_eq!(room.messages[0].content.as_str(), "Hello");
+        assert_eq!(room.messages[1].id, Some(second));
+        assert_eq!(room.messages[1].from, alice);
+        assert_eq!(room.next_message_seq, 3);
+        assert_eq!(repo.get_participants(&room_id).await.len(), 1);
+        assert_eq!(since.len(), 1);
+        assert_eq!(since[0].content.as_str(), "World");
+    }
+
+    #[tokio::test]
+    async fn test_domain_rules_are_applied() {
+        // テスト項目: 定員を超える参加者の追加はドメインのエラーになり、書き戻されない
+        // given (前提条件):
+        let repo = create_test_repository(Room::with_capacity(
+            RoomIdFactory::generate().unwrap(),
+            Timestamp::new(1000),
+            1,
+            10,
+        ))
+        .await;
+        let room_id = repo.get_room().await.unwrap().id;
+        repo.add_participant(
+            &room_id,
+            Participant::new(
+                ClientId::new("alice".to_string()).unwrap(),
+                Timestamp::new(2000),
+            ),
+        )
+        .await
+        .unwrap();
+
+        // when (操作):
+        let result = repo
+            .add_participant(
+                &room_id,
+                Participant::new(
+                    ClientId::new("bob".to_string()).unwrap(),
+                    Timestamp::new(3000),
+                ),
+            )
+            .await;
+
+        // then (期待する結果):
+        assert!(matches!(
+            result,
+            Err(RepositoryError::Room(RoomError::CapacityExceeded { .. }))
+        ));
+        assert_eq!(repo.count_connected_clients().await, 1);
+    }
+
+    #[tokio::test]
+    async fn test_create_room_and_move_between_rooms() {
+        // テスト項目: 作成した Room は一覧に作成順に含まれ、参加者が入室している Room を引ける
+        // given (前提条件):
+        let repo = create_test_repository(Room::new(
+            RoomIdFactory::generate().unwrap(),
+            Timestamp::new(1000),
+        ))
+        .await;
+        let default_id = repo.get_room().await.unwrap().id;
+        let other_id = RoomIdFactory::generate().unwrap();
+        let alice = ClientId::new("alice".to_string()).unwrap();
+
+        // when (操作):
+        repo.create_room(
+            other_id.clone(),
+            Timestamp::new(2000),
+            Some("acme".to_string()),
+        )
+        .await
+        .unwrap();
+        let duplicate = repo
+            .create_room(other_id.clone(), Timestamp::new(3000), None)
+            .await;
+        repo.add_participant(
+            &other_id,
+            Participant::new(alice.clone(), Timestamp::new(4000)),
+        )
+        .await
+        .unwrap();
+
+        // then (期待する結果):
+        assert!(matches!(
+            duplicate,
+            Err(RepositoryError::RoomAlreadyExists(_))
+        ));
+        let rooms = repo.list_rooms().await;
+        assert_eq!(
+            rooms.iter().map(|room| room.id.clone()).collect::<Vec<_>>(),
+            vec![default_id.clone(), other_id.clone()]
+        );
+        assert_eq!(rooms[1].tenant.as_deref(), Some("acme"));
+        assert_eq!(
+            repo.find_participant_room(&alice).await,
+            Some(other_id.clone())
+        );
+        assert!(repo.get_participants(&default_id).await.is_empty());
+        assert_eq!(repo.count_connected_clients().await, 1);
+    }
+
+    #[tokio::test]
+    async fn test_migrate_upgrades_existing_database() {
+        // テスト項目: 最初のマイグレーションのみ適用済みの DB には残りのマイグレーションだけを適用し、再実行しても失敗しない
+        // given (前提条件):
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        sqlx::raw_sql(MIGRATIONS[0]).execute(&pool).await.unwrap();
+
+        // when (操作):
+        let first = SqliteRoomRepository::migrate(&pool).await;
+        let second = SqliteRoomRepository::migrate(&pool).await;
+
+        // then (期待する結果):
+        assert!(first.is_ok());
+        assert!(second.is_ok());
+        let version: i64 = sqlx::query_scalar("PRAGMA user_version")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(version, MIGRATIONS.len() as i64);
+        let repo = SqliteRoomRepository::open(
+            pool,
+            Room::new(RoomIdFactory::generate().unwrap(), Timestamp::new(0)),
+        )
+        .await
+        .unwrap();
+        assert_eq!(repo.get_room().await.unwrap().tenant, None);
+    }
+}
@@ -0,0 +1,241 @@
+//! ルーム状態のスナップショット
+//!
+//! データベースを使わずにクラッシュから復旧できるよう、全ての Room（メッセージ履歴）を
+//! 定期的に JSON ファイルへ保存し、起動時に最新のスナップショットを読み込みます。
+
+use std::{
+    io,
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::Duration,
+};
+
+use tokio::task::JoinHandle;
+
+use crate::domain::{Room, RoomRepository};
+
+/// ルーム状態のスナップショットファイル
+#[derive(Debug, Clone)]
+pub struct RoomSnapshotStore {
+    /// スナップショットファイルのパス
+    path: PathBuf,
+}
+
+impl RoomSnapshotStore {
+    /// 新しい RoomSnapshotStore を作成
+    ///
+    /// # 引数
+    ///
+    /// - `path`: スナップショットファイルのパス
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    /// スナップショットファイルのパス
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// 全ての Room のスナップショットを保存
+    ///
+    /// Room は渡された順（先頭がデフォルトの Room）の JSON 配列として保存します。
+    /// 書き込み途中でクラッシュしても直前のスナップショットが壊れないよう、
+    /// 一時ファイルに書き込んでから置き換えます。
+    pub async fn save(&self, rooms: &[Room]) -> io::Result<()> {
+        let json = serde_json::to_vec_pretty(rooms).map_err(io::Error::other)?;
+        let tmp_path = self.path.with_extension("tmp");
+        tokio::fs::write(&tmp_path, json).await?;
+        tokio::fs::rename(&tmp_path, &self.path).await
+    }
+
+    /// 最新のスナップショットを読み込む
+    ///
+    /// スナップショット時に接続していた参加者は再起動後には接続していないため、取り除きます。
+    /// 単一の Room を保存していた以前の形式のスナップショットも読み込めます。
+    ///
+    /// # 戻り値
+    ///
+    /// - `Ok(Vec<Room>)`: 読み込んだ Room（先頭がデフォルトの Room。ファイルが存在しない場合は空）
+    /// - `Err(io::Error)`: 読み込み、または JSON の解析に失敗
+    pub async fn load(&self) -> io::Result<Vec<Room>> {
+        let json = match tokio::fs::read(&self.path).await {
+            Ok(json) => json,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(e),
+        };
+        let mut rooms: Vec<Room> = match serde_json::from_slice(&json) {
+            Ok(rooms) => rooms,
+            Err(_) => vec![
+                serde_json::from_slice(&json)
+                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?,
+            ],
+        };
+        for room in &mut rooms {
+            room.participants.clear();
+        }
+        Ok(rooms)
+    }
+}
+
+/// 全ての Room のスナップショットを定期的に保存するタスクを起動
+///
+/// # 引数
+///
+/// - `repository`: スナップショットを取る Repository
+/// - `store`: スナップショットの保存先
+/// - `interval`: 保存する間隔
+pub fn spawn_periodic_snapshot(
+    repository: Arc<dyn RoomRepository>,
+    store: RoomSnapshotStore,
+    interval: Duration,
+) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        // 最初の tick は即座に完了するため読み飛ばす
+        ticker.tick().await;
+        loop {
+            ticker.tick().await;
+            let rooms = repository.list_rooms().await;
+            match store.save(&rooms).await {
+                Ok(()) => tracing::debug!(
+                    "Saved snapshot of {} rooms to {}",
+                    rooms.len(),
+                    store.path().display()
+                ),
+                Err(e) => tracing::warn!(
+                    "Failed to save snapshot to {}: {}",
+                    store.path().display(),
+                    e
+                ),
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        domain::{ClientId, ClockExt, FixedClock, MessageContent, RoomIdFactory},
+        infrastructure::repository::InMemoryRoomRepository,
+    };
+    use tokio::sync::Mutex;
+
+    fn create_test_store() -> RoomSnapshotStore {
+        let file_name = format!(
+            "engawa-snapshot-{}.json",
+            RoomIdFactory::generate().unwrap().as_str()
+        );
+        RoomSnapshotStore::new(std::env::temp_dir().join(file_name))
+    }
+
+    #[tokio::test]
+    async fn test_snapshot_round_trips_message_history() {
+        // テスト項目: スナップショットを保存して読み込むと、メッセージ履歴とメッセージ ID の連番が復元され、参加者は取り除かれる
+        // given (前提条件):
+        let store = create_test_store();
+        let clock = FixedClock::new(1000);
+        let mut room = Room::new(RoomIdFactory::generate().unwrap(), clock.now());
+        let alice = ClientId::new("alice".to_string()).unwrap();
+        room.add_participant(crate::domain::Participant::new(alice.clone(), clock.now()))
+            .unwrap();
+        for content in ["Hello", "World"] {
+            room.add_message(crate::domain::ChatMessage::new(
+                alice.clone(),
+                MessageContent::new(content.to_string()).unwrap(),
+                clock.now(),
+            ))
+            .unwrap();
+        }
+
+        // when (操作):
+        store.save(std::slice::from_ref(&room)).await.unwrap();
+        let loaded = store.load().await.unwrap().remove(0);
+
+        // then (期待する結果):
+        assert_eq!(loaded.id, room.id);
+        assert_eq!(loaded.messages, room.messages);
+        assert_eq!(loaded.next_message_seq, room.next_message_seq);
+        assert!(loaded.participants.is_empty());
+        tokio::fs::remove_file(store.path()).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_load_missing_snapshot_returns_no_rooms() {
+        // テスト項目: スナップショットファイルが存在しない場合は空の一覧を返す
+        // given (前提条件):
+        let store = create_test_store();
+
+        // when (操作):
+        let loaded = store.load().await.unwrap();
+
+        // then (期待する結果):
+        assert!(loaded.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_load_single_room_snapshot() {
+        // テスト項目: 単一の Room を保存していた以前の形式のスナップショットも読み込める
+        // given (前提条件):
+        let store = create_test_store();
+        let room = Room::new(
+            RoomIdFactory::generate().unwrap(),
+            FixedClock::new(1000).now(),
+        );
+        tokio::fs::write(store.path(), serde_json::to_vec(&room).unwrap())
+            .await
+            .unwrap();
+
+        // when (操作):
+        let loaded = store.load().await.unwrap();
+
+        // then (期待する結果):
+        assert_eq!(loaded.len(), 1);
+        assert_eq!(loaded[0].id, room.id);
+        tokio::fs::remove_file(store.path()).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_periodic_snapshot_saves_and_restores_all_rooms() {
+        // テスト項目: 定期スナップショットのタスクがデフォルトの Room と API で作成した Room の両方を保存し、読み込んだ Room から Repository を復元できる
+        // given (前提条件):
+        let store = create_test_store();
+        let clock = FixedClock::new(1000);
+        let room = Room::new(RoomIdFactory::generate().unwrap(), clock.now());
+        let default_room_id = room.id.clone();
+        let repository = Arc::new(InMemoryRoomRepository::new(Arc::new(Mutex::new(room))));
+        let created_room_id = RoomIdFactory::generate().unwrap();
+        repository
+            .create_room(created_room_id.clone(), clock.now(), None)
+            .await
+            .unwrap();
+        repository
+            .add_message(
+                &created_room_id,
+                ClientId::new("alice".to_string()).unwrap(),
+                MessageContent::new("Hello".to_string()).unwrap(),
+                clock.now(),
+            )
+            .await
+            .unwrap();
+
+        // when (操作):
+        let handle = spawn_periodic_snapshot(repository, store.clone(), Duration::from_millis(20));
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        handle.abort();
+        let mut loaded = store.load().await.unwrap();
+        let restored = InMemoryRoomRepository::new(Arc::new(Mutex::new(loaded.remove(0))));
+        for room in loaded {
+            restored.insert_room(room).await.unwrap();
+        }
+
+        // then (期待する結果):
+        let rooms = restored.list_rooms().await;
+        assert_eq!(
+            rooms.iter().map(|room| room.id.clone()).collect::<Vec<_>>(),
+            vec![default_room_id, created_room_id]
+        );
+        assert_eq!(rooms[1].messages.len(), 1);
+        tokio::fs::remove_file(store.path()).await.unwrap();
+    }
+}
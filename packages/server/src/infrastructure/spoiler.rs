@@ -0,0 +1,71 @@
+//! Spoiler tagging for chat message content.
+//!
+//! Finds `||spoiler||` spans so that clients can blur them.
+//! The content itself is not modified; the spans are attached as metadata.
+
+use std::ops::Range;
+
+/// Marker opening and closing a spoiler span
+const SPOILER_MARKER: &str = "||";
+
+/// Find the spoiler spans in the given content.
+///
+/// Each range covers the text between a pair of markers (markers excluded), in character offsets
+/// of the raw content. An unclosed marker or an empty span (`||||`) is not a spoiler.
+///
+/// # Returns
+///
+/// The spoiler spans in order of appearance (empty if the content has none)
+pub fn find_spoilers(content: &str) -> Vec<Range<usize>> {
+    let mut spoilers = Vec::new();
+    let mut rest = content;
+    let mut offset = 0;
+    while let Some(open) = rest.find(SPOILER_MARKER) {
+        let after_open = &rest[open + SPOILER_MARKER.len()..];
+        let Some(close) = after_open.find(SPOILER_MARKER) else {
+            break;
+        };
+        let start = offset + rest[..open].chars().count() + SPOILER_MARKER.len();
+        let end = start + after_open[..close].chars().count();
+        if start < end {
+            spoilers.push(start..end);
+        }
+        offset = end + SPOILER_MARKER.len();
+        rest = &after_open[close + SPOILER_MARKER.len()..];
+    }
+    spoilers
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_spoilers_ranges() {
+        // テスト項目: スポイラーの範囲がマーカーを除いた文字単位のオフセットで検出される
+        // given (前提条件):
+        let content = "犯人は||執事||で、結末は||hidden||";
+
+        // when (操作):
+        let result = find_spoilers(content);
+
+        // then (期待する結果):
+        assert_eq!(result, vec![5..7, 16..22]);
+        let chars: Vec<char> = content.chars().collect();
+        assert_eq!(chars[5..7].iter().collect::<String>(), "執事");
+        assert_eq!(chars[16..22].iter().collect::<String>(), "hidden");
+    }
+
+    #[test]
+    fn test_find_spoilers_none() {
+        // テスト項目: マーカーのないメッセージ、閉じられていないマーカーや空のスポイラーは検出されない
+        // given (前提条件):
+        let contents = ["Hello, world!", "a || b", "||||"];
+
+        // when (操作):
+        let results: Vec<_> = contents.iter().map(|c| find_spoilers(c)).collect();
+
+        // then (期待する結果):
+        assert!(results.iter().all(Vec::is_empty));
+    }
+}
@@ -0,0 +1,298 @@
+//! Command line arguments of the server.
+//!
+//! Parsed by the server binary and turned into the running server by [`ServerBuilder`](super::ServerBuilder).
+
+use std::path::PathBuf;
+
+use clap::Parser;
+use engawa_shared::time::JST_OFFSET_HOURS;
+
+use crate::{
+    domain::{
+        DeniedLinkAction, MENTION_DEFAULT_MAX_LENGTH, MENTIONS_DEFAULT_MAX_COUNT, MessagePriority,
+        TimestampUnit, entity::DEFAULT_MESSAGE_CAPACITY,
+    },
+    usecase::{
+        BotRecipientPolicy, ClientIdCollisionPolicy, DEFAULT_JOIN_BATCH_THRESHOLD, DEFAULT_LOCALE,
+        SelfMessagePolicy,
+    },
+};
+
+use super::{
+    BinaryFramePolicy, DEFAULT_DRAIN_MAX_FRAMES, DEFAULT_DRAIN_TIMEOUT, DEFAULT_JSON_MAX_DEPTH,
+    DEFAULT_JSON_MAX_ELEMENTS, DEFAULT_STATS_INTERVAL,
+};
+
+/// Command line arguments of the server
+#[derive(Parser, Debug, Clone)]
+#[command(name = "server")]
+#[command(about = "WebSocket chat server with broadcast support", long_about = None)]
+pub struct ServerArgs {
+    /// Host address to bind the server to
+    #[arg(short = 'H', long, default_value = "127.0.0.1")]
+    pub host: String,
+
+    /// Port number to bind the server to
+    #[arg(short = 'p', long, default_value = "8080")]
+    pub port: u16,
+
+    /// Grace period (milliseconds) between the shutdown notice and closing the connections
+    #[arg(long, default_value = "1000")]
+    pub shutdown_grace_ms: u64,
+
+    /// Tenant name used to namespace client ids as `<tenant>:<id>`
+    #[arg(long)]
+    pub tenant: Option<String>,
+
+    /// Reject client ids without the tenant prefix (requires --tenant)
+    #[arg(long, requires = "tenant")]
+    pub require_tenant_prefix: bool,
+
+    /// Maximum number of rooms a tenant can create through `POST /api/rooms` (unlimited if not set)
+    #[arg(long)]
+    pub max_rooms_per_tenant: Option<usize>,
+
+    /// How to handle unexpected binary frames: "reject" (error frame) or "close"
+    #[arg(long, default_value = "reject")]
+    pub binary_frame_policy: BinaryFramePolicy,
+
+    /// Delivery priority of server announcements (room-locked, server-shutdown): "high" (sent before queued chat) or "normal"
+    #[arg(long, default_value = "high")]
+    pub announcement_priority: MessagePriority,
+
+    /// Attach the detected language (ISO 639-1) to broadcast chat messages
+    #[arg(long)]
+    pub detect_language: bool,
+
+    /// Attach `has_spoiler` and the `||spoiler||` spans to broadcast chat messages
+    #[arg(long)]
+    pub tag_spoilers: bool,
+
+    /// How to handle a duplicate client_id: "reject" (HTTP 409) or "suffix" (alice → alice-2)
+    #[arg(long, default_value = "reject")]
+    pub client_id_collision: ClientIdCollisionPolicy,
+
+    /// Cache the sorted participant list sent on connect until someone joins or leaves
+    #[arg(long)]
+    pub cache_participant_list: bool,
+
+    /// Reject inbound chat frames with missing or unknown fields with an error frame
+    #[arg(long)]
+    pub strict_inbound_schema: bool,
+
+    /// Whether bots receive broadcast messages ('include', 'exclude' or 'only')
+    #[arg(long, default_value = "include")]
+    pub bot_recipients: BotRecipientPolicy,
+
+    /// Whether a client may whisper to itself as a self-note ('allow' or 'reject')
+    #[arg(long, default_value = "allow")]
+    pub self_messages: SelfMessagePolicy,
+
+    /// Record undeliverable messages in an in-memory dead-letter buffer of this size
+    #[arg(long)]
+    pub dead_letter_capacity: Option<usize>,
+
+    /// Keep an audit trail of message edits and deletes, served by the message history endpoint
+    #[arg(long)]
+    pub audit_message_edits: bool,
+
+    /// Measure the delivery latency of chat messages and serve it as a histogram on /metrics
+    #[arg(long)]
+    pub latency_metrics: bool,
+
+    /// Maximum chat messages per second per client, enforced with a token bucket (unlimited if not set)
+    #[arg(long)]
+    pub max_messages_per_sec: Option<u32>,
+
+    /// Number of chat messages a client can send in a burst (with --max-messages-per-sec; defaults to the rate)
+    #[arg(long, requires = "max_messages_per_sec")]
+    pub message_burst: Option<u32>,
+
+    /// Maximum chat messages per client within --message-burst-window-ms (unlimited if not set)
+    #[arg(long)]
+    pub max_messages_per_burst_window: Option<u32>,
+
+    /// Length of the short window for --max-messages-per-burst-window (milliseconds)
+    #[arg(long, default_value_t = 100)]
+    pub message_burst_window_ms: u64,
+
+    /// Time to wait for a delivery ack from clients that opted in to acks (milliseconds)
+    #[arg(long, default_value_t = 5000)]
+    pub ack_timeout_ms: u64,
+
+    /// Maximum number of emoji in a chat message (unlimited if not set)
+    #[arg(long)]
+    pub max_emoji: Option<usize>,
+
+    /// Collapse runs of whitespace in chat messages into a single space (newlines are preserved)
+    #[arg(long)]
+    pub collapse_whitespace: bool,
+
+    /// Maximum number of distinct @mentions in a chat message (unlimited if not set)
+    #[arg(long)]
+    pub max_mentions: Option<usize>,
+
+    /// Maximum number of @mentions attached to a broadcast chat message (the rest are not parsed)
+    #[arg(long, default_value_t = MENTIONS_DEFAULT_MAX_COUNT)]
+    pub max_parsed_mentions: usize,
+
+    /// Maximum length of an attached @mention in characters (longer names are truncated)
+    #[arg(long, default_value_t = MENTION_DEFAULT_MAX_LENGTH)]
+    pub max_mention_length: usize,
+
+    /// Domain whose links are denied in chat messages (repeatable; subdomains are denied as well)
+    #[arg(long = "deny-link-domain")]
+    pub denied_link_domains: Vec<String>,
+
+    /// How to handle links to denied domains ("remove" or "reject")
+    #[arg(long, default_value = "remove")]
+    pub denied_link_action: DeniedLinkAction,
+
+    /// Disconnect clients whose socket does not accept a frame within this time (milliseconds, unlimited if not set)
+    #[arg(long)]
+    pub send_timeout_ms: Option<u64>,
+
+    /// Maximum number of queued frames flushed to a client after its connection starts closing
+    #[arg(long, default_value_t = DEFAULT_DRAIN_MAX_FRAMES)]
+    pub drain_max_frames: usize,
+
+    /// Time to wait for queued frames to be flushed before the disconnect cleanup (milliseconds)
+    #[arg(long, default_value_t = DEFAULT_DRAIN_TIMEOUT.as_millis() as u64)]
+    pub drain_timeout_ms: u64,
+
+    /// Drop an outbound frame identical to the previous frame sent to the same client
+    #[arg(long)]
+    pub dedup_consecutive_frames: bool,
+
+    /// Maximum number of already queued frames written to a client with a single flush (1 = one frame at a time)
+    #[arg(long, default_value_t = 1)]
+    pub send_batch_max_frames: usize,
+
+    /// Log a summary (duration, messages and bytes in/out, reason) when a connection ends
+    #[arg(long)]
+    pub connection_summary: bool,
+
+    /// Interval between `room-stats` frames sent to clients subscribed to room stats (milliseconds)
+    #[arg(long, default_value_t = DEFAULT_STATS_INTERVAL.as_millis() as u64)]
+    pub stats_interval_ms: u64,
+
+    /// Origin allowed to open a WebSocket connection (repeatable; all origins are allowed if not set)
+    #[arg(long = "allowed-origin")]
+    pub allowed_origins: Vec<String>,
+
+    /// Bearer token required by the room API endpoints (/api/rooms, /debug/room); open if not set
+    #[arg(long)]
+    pub api_token: Option<String>,
+
+    /// Maximum connection attempts per client_id within a minute (unlimited if not set)
+    #[arg(long)]
+    pub max_reconnects_per_minute: Option<usize>,
+
+    /// Maximum open WebSocket connections per peer IP; further connections are rejected with 429 (unlimited if not set)
+    #[arg(long)]
+    pub max_connections_per_ip: Option<usize>,
+
+    /// Maximum frames read from a client's socket per second; reading pauses until the next second when exceeded (unlimited if not set)
+    #[arg(long)]
+    pub max_inbound_frames_per_sec: Option<u32>,
+
+    /// Maximum nesting depth of an inbound JSON frame; deeper frames are rejected before parsing
+    #[arg(long, default_value_t = DEFAULT_JSON_MAX_DEPTH)]
+    pub max_inbound_json_depth: usize,
+
+    /// Maximum number of elements in a single array/object of an inbound JSON frame
+    #[arg(long, default_value_t = DEFAULT_JSON_MAX_ELEMENTS)]
+    pub max_inbound_json_elements: usize,
+
+    /// Interval between WebSocket pings sent to each client, in seconds (heartbeat disabled if not set)
+    #[arg(long)]
+    pub heartbeat_interval_secs: Option<u64>,
+
+    /// Time without any frame from a client after which it is disconnected, in seconds (with --heartbeat-interval-secs)
+    #[arg(long, default_value_t = 60)]
+    pub heartbeat_timeout_secs: u64,
+
+    /// Name of the welcome bot greeting new participants (disabled if not set)
+    #[arg(long)]
+    pub welcome_bot: Option<String>,
+
+    /// Greeting template of the welcome bot in the default locale (`{name}` is replaced with the participant's id; the built-in greeting if not set)
+    #[arg(long)]
+    pub welcome_message: Option<String>,
+
+    /// Unit of the numeric timestamps generated by the server in WebSocket frames ("ms" or "s")
+    #[arg(long, default_value = "ms")]
+    pub timestamp_unit: TimestampUnit,
+
+    /// Report the skew between the client `timestamp` of chat frames and the server clock in delivery receipts, flagging skews beyond this tolerance (milliseconds)
+    #[arg(long)]
+    pub clock_skew_tolerance_ms: Option<u64>,
+
+    /// UTC offset in hours of the RFC 3339 timestamps in HTTP responses and WebSocket frames (e.g. 1 for CET)
+    #[arg(long, env = "TZ_OFFSET_HOURS", default_value_t = JST_OFFSET_HOURS, value_parser = clap::value_parser!(i32).range(-23..=23), allow_negative_numbers = true)]
+    pub tz_offset_hours: i32,
+
+    /// Locale of system text for clients that do not request one with `?locale=` (and for unknown locales)
+    #[arg(long, default_value = DEFAULT_LOCALE)]
+    pub default_locale: String,
+
+    /// Room admin who can post in locked rooms, identified by its client_id (with --api-token, connecting as the admin requires the token; disabled if not set)
+    #[arg(long)]
+    pub room_admin: Option<String>,
+
+    /// Admin whose approval is required for other clients to join, identified by its client_id (with --api-token, connecting as the admin requires the token; disabled if not set)
+    #[arg(long)]
+    pub join_approval_admin: Option<String>,
+
+    /// Time to wait for the room admin to answer a join request (milliseconds)
+    #[arg(long, default_value_t = 30000)]
+    pub join_approval_timeout_ms: u64,
+
+    /// Coalesce joins within this window into one participants-joined frame (milliseconds, disabled if not set)
+    #[arg(long)]
+    pub join_batch_window_ms: Option<u64>,
+
+    /// Minimum number of joins within the window to send a single participants-joined frame
+    #[arg(long, default_value_t = DEFAULT_JOIN_BATCH_THRESHOLD)]
+    pub join_batch_threshold: usize,
+
+    /// Maximum number of messages stored in the default room created on startup (restored rooms keep their own capacity)
+    #[arg(long, default_value_t = DEFAULT_MESSAGE_CAPACITY)]
+    pub message_capacity: usize,
+
+    /// Maximum number of stored messages authored by a single client (oldest evicted first, unlimited if not set)
+    #[arg(long)]
+    pub message_quota_per_client: Option<usize>,
+
+    /// Maximum number of stored messages across all rooms (oldest message of the busiest room evicted first, unlimited if not set)
+    #[arg(long)]
+    pub max_total_messages: Option<usize>,
+
+    /// Shard (node) id prefixed to generated message ids, unique per server instance in a cluster ([A-Za-z0-9_], up to 16 characters)
+    #[arg(long, env = "MESSAGE_ID_SHARD")]
+    pub message_id_shard: Option<String>,
+
+    /// Periodically save all rooms to this JSON file and restore them on startup (disabled if not set)
+    #[arg(long)]
+    pub snapshot_path: Option<PathBuf>,
+
+    /// Save the rooms to this JSON file on every change and restore them on startup (in-memory only if not set)
+    #[arg(long, env = "ROOM_FILE", conflicts_with = "snapshot_path")]
+    pub room_file: Option<PathBuf>,
+
+    /// Interval between room state snapshots (seconds)
+    #[arg(long, default_value_t = 60)]
+    pub snapshot_interval_secs: u64,
+
+    /// Periodically check that room participants and connected clients match, logging any mismatch (seconds, disabled if not set)
+    #[arg(long)]
+    pub consistency_check_interval_secs: Option<u64>,
+
+    /// Remove rooms (except the default room) that have had no participants and no activity for this long (seconds, disabled if not set)
+    #[arg(long)]
+    pub room_idle_timeout_secs: Option<u64>,
+
+    /// Interval between scans for idle rooms (seconds, with --room-idle-timeout-secs)
+    #[arg(long, default_value_t = 60)]
+    pub room_reap_interval_secs: u64,
+}
@@ -0,0 +1,80 @@
+//! Bearer token protection of the HTTP API.
+//!
+//! When an API token is configured, the endpoints exposing room and participant information
+//! (`/api/rooms`, `/debug/room`) require `Authorization: Bearer <token>`.
+//! `/api/health` (readiness probes), `/api/capabilities` (discovery) and `/ws` stay public,
+//! except that a WebSocket connection claiming an admin client_id must present the token.
+
+use std::sync::Arc;
+
+use axum::{
+    extract::{Request, State},
+    http::{HeaderMap, StatusCode, header::AUTHORIZATION},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+
+use crate::ui::state::AppState;
+
+/// Scheme prefix of the `Authorization` header value
+const BEARER_PREFIX: &str = "Bearer ";
+
+/// Middleware rejecting requests without the configured bearer token with 401
+pub async fn require_api_token(
+    State(state): State<Arc<AppState>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let Some(expected) = &state.config.api_token else {
+        return next.run(request).await;
+    };
+    if !has_bearer_token(request.headers(), expected) {
+        tracing::warn!(
+            "Rejecting unauthenticated request to '{}'",
+            request.uri().path()
+        );
+        return StatusCode::UNAUTHORIZED.into_response();
+    }
+    next.run(request).await
+}
+
+/// Whether the headers carry `Authorization: Bearer <expected>`
+pub fn has_bearer_token(headers: &HeaderMap, expected: &str) -> bool {
+    headers
+        .get(AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix(BEARER_PREFIX))
+        .is_some_and(|token| tokens_match(token, expected))
+}
+
+/// Compare tokens without returning early on the first differing byte (no timing side channel)
+fn tokens_match(provided: &str, expected: &str) -> bool {
+    provided.len() == expected.len()
+        && provided
+            .bytes()
+            .zip(expected.bytes())
+            .fold(0u8, |diff, (a, b)| diff | (a ^ b))
+            == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tokens_match() {
+        // テスト項目: トークンは完全に一致する場合のみ一致と判定される
+        // given (前提条件):
+        let expected = "s3cret";
+
+        // when (操作):
+        let same = tokens_match("s3cret", expected);
+        let different = tokens_match("s3creT", expected);
+        let prefix = tokens_match("s3c", expected);
+
+        // then (期待する結果):
+        assert!(same);
+        assert!(!different);
+        assert!(!prefix);
+    }
+}
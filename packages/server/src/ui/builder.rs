@@ -0,0 +1,377 @@
+//! Server assembly from the command line arguments.
+//!
+//! Wires the repository, the message pusher, the use cases and the runtime
+//! configuration in one place, so that the server binary and the integration
+//! tests run the same configuration.
+
+use std::{collections::HashMap, sync::Arc, time::Duration};
+
+use engawa_shared::time::utc_offset_from_hours;
+use tokio::sync::Mutex;
+
+use crate::{
+    domain::{
+        ClientId, Clock, EventBus, LinkDenylist, MentionLimits, MessageContentPolicy,
+        RoomRepository, ShardId, SystemClock, TenantPrefixPolicy,
+        entity::DEFAULT_PARTICIPANT_CAPACITY,
+    },
+    infrastructure::{
+        dead_letter::InMemoryDeadLetterSink,
+        event_bus::TracingEventBus,
+        message_audit::InMemoryMessageAuditLog,
+        message_pusher::WebSocketMessagePusher,
+        repository::{FileRoomRepository, InMemoryRoomRepository, spawn_consistency_check},
+        snapshot::{RoomSnapshotStore, spawn_periodic_snapshot},
+    },
+    usecase::{
+        BurstLimiter, ConnectParticipantUseCase, CreateRoomUseCase, DisconnectParticipantUseCase,
+        GetMessageHistoryUseCase, GetRoomDetailUseCase, GetRoomStateUseCase, GetRoomStatsUseCase,
+        GetRoomsUseCase, JoinApproval, JoinBatching, Localizer, MSG_WELCOME, MessageBurstCap,
+        MessageRateLimit, RateLimiter, RemoveRoomUseCase, SendMessageUseCase,
+        ShutdownServerUseCase, UpdateRoomUseCase, WelcomeBot, spawn_idle_room_reaper,
+    },
+};
+
+use super::{HeartbeatConfig, JsonLimits, ReconnectLimit, Server, ServerArgs, ServerConfig};
+
+/// Builder of a [`Server`] configured by [`ServerArgs`]
+pub struct ServerBuilder {
+    args: ServerArgs,
+    clock: Arc<dyn Clock>,
+    event_bus: Arc<dyn EventBus>,
+}
+
+impl ServerBuilder {
+    /// Create a new builder from the command line arguments
+    pub fn new(args: ServerArgs) -> Self {
+        Self {
+            args,
+            clock: Arc::new(SystemClock),
+            event_bus: Arc::new(TracingEventBus),
+        }
+    }
+
+    /// Override the clock of the use cases and the handlers (defaults to `SystemClock`)
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Override the EventBus receiving the domain events (defaults to `TracingEventBus`)
+    pub fn with_event_bus(mut self, event_bus: Arc<dyn EventBus>) -> Self {
+        self.event_bus = event_bus;
+        self
+    }
+
+    /// Build the server
+    ///
+    /// Restores the rooms from the room file or the latest snapshot, and starts the
+    /// background tasks (snapshot, consistency check, idle room reaper) enabled by the arguments.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the room file cannot be loaded or saved, or if an argument is invalid
+    /// (message id shard, welcome bot name, room admin, join approval admin).
+    pub async fn build(self) -> Server {
+        let Self {
+            args,
+            clock,
+            event_bus,
+        } = self;
+
+        // Initialize dependencies in order:
+        // 1. Repository
+        // 2. MessagePusher
+        // 3. UseCases
+        // 4. Server
+
+        // 1. Create Repository (in-memory database, restored from the room file or the latest snapshot if any)
+        let create_room_usecase =
+            CreateRoomUseCase::new(event_bus.clone()).with_clock(clock.clone());
+        let snapshot_store = args.snapshot_path.map(RoomSnapshotStore::new);
+        let mut stored_rooms = match (&args.room_file, &snapshot_store) {
+            (Some(path), _) => FileRoomRepository::load(path)
+                .await
+                .unwrap_or_else(|e| panic!("Failed to load rooms from {}: {}", path.display(), e)),
+            (None, Some(store)) => store.load().await.unwrap_or_else(|e| {
+                tracing::warn!(
+                    "Failed to load snapshot from {}: {}",
+                    store.path().display(),
+                    e
+                );
+                Vec::new()
+            }),
+            (None, None) => Vec::new(),
+        };
+        let mut room = if stored_rooms.is_empty() {
+            let room = create_room_usecase
+                .execute(DEFAULT_PARTICIPANT_CAPACITY, args.message_capacity, None)
+                .await;
+            tracing::info!("Room {} created!", room.id.as_str());
+            room
+        } else {
+            let room = stored_rooms.remove(0);
+            tracing::info!(
+                "Room {} restored ({} messages)",
+                room.id.as_str(),
+                room.messages.len()
+            );
+            room
+        };
+        let message_id_shard = args
+            .message_id_shard
+            .map(|shard| ShardId::new(shard).expect("Invalid message id shard"));
+        for room in std::iter::once(&mut room).chain(stored_rooms.iter_mut()) {
+            room.message_quota_per_client = args.message_quota_per_client;
+            room.message_id_shard = message_id_shard.clone();
+        }
+        let room = Arc::new(Mutex::new(room));
+        let message_pusher_clients = Arc::new(Mutex::new(HashMap::new()));
+        let mut in_memory_repository = InMemoryRoomRepository::new(room)
+            .with_connected_clients(message_pusher_clients.clone());
+        if let Some(max_total_messages) = args.max_total_messages {
+            in_memory_repository = in_memory_repository.with_message_budget(max_total_messages);
+        }
+        let in_memory_repository = Arc::new(in_memory_repository);
+        for room in stored_rooms {
+            tracing::info!(
+                "Room {} restored ({} messages)",
+                room.id.as_str(),
+                room.messages.len()
+            );
+            in_memory_repository
+                .insert_room(room)
+                .await
+                .expect("Duplicate room in the stored rooms");
+        }
+        let repository: Arc<dyn RoomRepository> = match args.room_file {
+            Some(path) => {
+                let file_repository = FileRoomRepository::new(in_memory_repository.clone(), path);
+                file_repository.save().await.unwrap_or_else(|e| {
+                    panic!(
+                        "Failed to save rooms to {}: {}",
+                        file_repository.path().display(),
+                        e
+                    )
+                });
+                Arc::new(file_repository)
+            }
+            None => in_memory_repository.clone(),
+        };
+        if let Some(store) = snapshot_store {
+            spawn_periodic_snapshot(
+                repository.clone(),
+                store,
+                Duration::from_secs(args.snapshot_interval_secs),
+            );
+        }
+        if let Some(interval_secs) = args.consistency_check_interval_secs {
+            spawn_consistency_check(in_memory_repository, Duration::from_secs(interval_secs));
+        }
+
+        // 2. Create MessagePusher (WebSocket implementation)
+        let message_pusher = Arc::new(WebSocketMessagePusher::new(message_pusher_clients));
+
+        // 3. Create UseCases
+        let mut localizer = Localizer::new(&args.default_locale);
+        if let Some(template) = &args.welcome_message {
+            localizer = localizer.with_template(&args.default_locale, MSG_WELCOME, template);
+        }
+        let tenant_prefix_policy = match args.tenant {
+            Some(tenant) if args.require_tenant_prefix => TenantPrefixPolicy::Required(tenant),
+            Some(tenant) => TenantPrefixPolicy::Optional(tenant),
+            None => TenantPrefixPolicy::Disabled,
+        };
+        let mut connect_participant_usecase =
+            ConnectParticipantUseCase::new(repository.clone(), message_pusher.clone())
+                .with_collision_policy(args.client_id_collision)
+                .with_localizer(localizer.clone())
+                .with_clock(clock.clone());
+        if args.cache_participant_list {
+            connect_participant_usecase = connect_participant_usecase.with_participant_list_cache();
+        }
+        if let Some(name) = args.welcome_bot {
+            connect_participant_usecase =
+                connect_participant_usecase.with_welcome_bot(WelcomeBot {
+                    name: ClientId::new_with_tenant_policy(name, &tenant_prefix_policy)
+                        .expect("Invalid welcome bot name"),
+                    template: localizer
+                        .template(None, MSG_WELCOME)
+                        .unwrap_or(WelcomeBot::NAME_PLACEHOLDER)
+                        .to_string(),
+                });
+        }
+        let room_admin = args.room_admin.map(|admin| {
+            ClientId::new_with_tenant_policy(admin, &tenant_prefix_policy)
+                .expect("Invalid room admin")
+        });
+        let join_approval_admin = args.join_approval_admin.map(|admin| {
+            ClientId::new_with_tenant_policy(admin, &tenant_prefix_policy)
+                .expect("Invalid join approval admin")
+        });
+        if let Some(admin) = join_approval_admin.clone() {
+            connect_participant_usecase =
+                connect_participant_usecase.with_join_approval(JoinApproval {
+                    admin,
+                    timeout: Duration::from_millis(args.join_approval_timeout_ms),
+                });
+        }
+        if let Some(window_ms) = args.join_batch_window_ms {
+            connect_participant_usecase =
+                connect_participant_usecase.with_join_batching(JoinBatching {
+                    window: Duration::from_millis(window_ms),
+                    threshold: args.join_batch_threshold,
+                });
+        }
+        let connect_participant_usecase = Arc::new(connect_participant_usecase);
+        let disconnect_participant_usecase = Arc::new(
+            DisconnectParticipantUseCase::new(repository.clone(), message_pusher.clone())
+                .with_event_bus(event_bus.clone())
+                .with_clock(clock.clone()),
+        );
+        let mut send_message_usecase =
+            SendMessageUseCase::new(repository.clone(), message_pusher.clone())
+                .with_bot_recipient_policy(args.bot_recipients)
+                .with_self_message_policy(args.self_messages)
+                .with_ack_timeout(Duration::from_millis(args.ack_timeout_ms))
+                .with_event_bus(event_bus.clone())
+                .with_clock(clock.clone());
+        if let Some(messages_per_sec) = args.max_messages_per_sec {
+            send_message_usecase =
+                send_message_usecase.with_rate_limiter(RateLimiter::new(MessageRateLimit {
+                    messages_per_sec,
+                    burst: args.message_burst.unwrap_or(messages_per_sec),
+                }));
+        }
+        if let Some(max_messages) = args.max_messages_per_burst_window {
+            send_message_usecase =
+                send_message_usecase.with_burst_limiter(BurstLimiter::new(MessageBurstCap {
+                    max_messages,
+                    window_ms: args.message_burst_window_ms,
+                }));
+        }
+        if let Some(admin) = room_admin.clone() {
+            send_message_usecase = send_message_usecase.with_admin(admin);
+        }
+        if args.latency_metrics {
+            send_message_usecase = send_message_usecase.with_latency_histogram();
+        }
+        if let Some(capacity) = args.dead_letter_capacity {
+            send_message_usecase = send_message_usecase
+                .with_dead_letter_sink(Arc::new(InMemoryDeadLetterSink::new(capacity)));
+        }
+        let mut get_message_history_usecase = GetMessageHistoryUseCase::new(repository.clone());
+        if args.audit_message_edits {
+            let audit_log = Arc::new(InMemoryMessageAuditLog::new());
+            send_message_usecase = send_message_usecase.with_audit_log(audit_log.clone());
+            get_message_history_usecase = get_message_history_usecase.with_audit_log(audit_log);
+        }
+        let send_message_usecase = Arc::new(send_message_usecase);
+        let get_message_history_usecase = Arc::new(get_message_history_usecase);
+        let get_room_state_usecase = Arc::new(GetRoomStateUseCase::new(repository.clone()));
+        let get_room_stats_usecase = Arc::new(GetRoomStatsUseCase::new(
+            repository.clone(),
+            message_pusher.clone(),
+        ));
+        let get_rooms_usecase = Arc::new(GetRoomsUseCase::new(repository.clone()));
+        let get_room_detail_usecase = Arc::new(GetRoomDetailUseCase::new(repository.clone()));
+        let mut create_room_usecase = create_room_usecase.with_repository(repository.clone());
+        if let Some(limit) = args.max_rooms_per_tenant {
+            create_room_usecase = create_room_usecase.with_tenant_room_limit(limit);
+        }
+        let create_room_usecase = Arc::new(create_room_usecase);
+        let update_room_usecase = Arc::new(
+            UpdateRoomUseCase::new(repository.clone(), message_pusher.clone())
+                .with_announcement_priority(args.announcement_priority),
+        );
+
+        let remove_room_usecase = Arc::new(
+            RemoveRoomUseCase::new(repository.clone(), message_pusher.clone())
+                .with_event_bus(event_bus)
+                .with_clock(clock.clone()),
+        );
+        if let Some(idle_timeout_secs) = args.room_idle_timeout_secs {
+            spawn_idle_room_reaper(
+                remove_room_usecase.clone(),
+                Duration::from_secs(idle_timeout_secs),
+                Duration::from_secs(args.room_reap_interval_secs),
+            );
+        }
+
+        let shutdown_server_usecase = Arc::new(
+            ShutdownServerUseCase::new(repository, message_pusher)
+                .with_announcement_priority(args.announcement_priority),
+        );
+
+        // 4. Create the server
+        Server::new(
+            connect_participant_usecase,
+            disconnect_participant_usecase,
+            send_message_usecase,
+            get_room_state_usecase,
+            get_room_stats_usecase,
+            get_rooms_usecase,
+            get_room_detail_usecase,
+            get_message_history_usecase,
+            create_room_usecase,
+            update_room_usecase,
+            remove_room_usecase,
+            shutdown_server_usecase,
+        )
+        .with_config(ServerConfig {
+            shutdown_grace_period: Duration::from_millis(args.shutdown_grace_ms),
+            tenant_prefix_policy,
+            binary_frame_policy: args.binary_frame_policy,
+            detect_language: args.detect_language,
+            tag_spoilers: args.tag_spoilers,
+            strict_inbound_schema: args.strict_inbound_schema,
+            inbound_json_limits: JsonLimits {
+                max_depth: args.max_inbound_json_depth,
+                max_elements: args.max_inbound_json_elements,
+            },
+            message_content_policy: MessageContentPolicy {
+                max_emoji: args.max_emoji,
+                collapse_whitespace: args.collapse_whitespace,
+                max_mentions: args.max_mentions,
+                mention_limits: MentionLimits {
+                    max_count: args.max_parsed_mentions,
+                    max_length: args.max_mention_length,
+                },
+                link_denylist: (!args.denied_link_domains.is_empty()).then_some(LinkDenylist {
+                    domains: args.denied_link_domains,
+                    action: args.denied_link_action,
+                }),
+            },
+            send_timeout: args.send_timeout_ms.map(Duration::from_millis),
+            allowed_origins: args.allowed_origins,
+            api_token: args.api_token,
+            admin_client_ids: room_admin.into_iter().chain(join_approval_admin).collect(),
+            reconnect_limit: args
+                .max_reconnects_per_minute
+                .map(|max_attempts| ReconnectLimit {
+                    max_attempts,
+                    window: Duration::from_secs(60),
+                }),
+            max_connections_per_ip: args.max_connections_per_ip,
+            max_inbound_frames_per_sec: args.max_inbound_frames_per_sec,
+            heartbeat: args
+                .heartbeat_interval_secs
+                .map(|interval_secs| HeartbeatConfig {
+                    interval: Duration::from_secs(interval_secs),
+                    timeout: Duration::from_secs(args.heartbeat_timeout_secs),
+                }),
+            drain_max_frames: args.drain_max_frames,
+            drain_timeout: Duration::from_millis(args.drain_timeout_ms),
+            dedup_consecutive_frames: args.dedup_consecutive_frames,
+            send_batch_max_frames: args.send_batch_max_frames,
+            connection_summary: args.connection_summary,
+            stats_interval: Duration::from_millis(args.stats_interval_ms),
+            timestamp_unit: args.timestamp_unit,
+            clock_skew_tolerance: args.clock_skew_tolerance_ms.map(Duration::from_millis),
+            utc_offset: utc_offset_from_hours(args.tz_offset_hours)
+                .expect("tz_offset_hours is within -23..=23"),
+            localizer,
+        })
+        .with_clock(clock)
+    }
+}
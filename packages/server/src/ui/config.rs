@@ -0,0 +1,173 @@
+//! Server runtime configuration.
+
+use std::{str::FromStr, time::Duration};
+
+use chrono::FixedOffset;
+use engawa_shared::time::jst_offset;
+
+use crate::{
+    domain::{ClientId, MessageContentPolicy, TenantPrefixPolicy, TimestampUnit},
+    usecase::Localizer,
+};
+
+use super::{heartbeat::HeartbeatConfig, json_limit::JsonLimits, reconnect_limit::ReconnectLimit};
+
+/// Default grace period between the shutdown notice and closing the remaining connections
+pub const DEFAULT_SHUTDOWN_GRACE_PERIOD: Duration = Duration::from_millis(1000);
+
+/// Default maximum number of queued frames flushed to a client after its connection starts closing
+pub const DEFAULT_DRAIN_MAX_FRAMES: usize = 64;
+
+/// Default time to wait for queued frames to be flushed before running the disconnect cleanup
+pub const DEFAULT_DRAIN_TIMEOUT: Duration = Duration::from_millis(500);
+
+/// Default interval between `room-stats` frames sent to a subscribed client
+pub const DEFAULT_STATS_INTERVAL: Duration = Duration::from_secs(5);
+
+/// How to handle binary frames received on a JSON (text) connection
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BinaryFramePolicy {
+    /// Keep the connection and reply with an `unexpected_binary` error frame
+    #[default]
+    Reject,
+    /// Close the connection
+    Close,
+}
+
+impl FromStr for BinaryFramePolicy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "reject" => Ok(Self::Reject),
+            "close" => Ok(Self::Close),
+            other => Err(format!(
+                "invalid binary frame policy '{}' (expected 'reject' or 'close')",
+                other
+            )),
+        }
+    }
+}
+
+/// Runtime configuration of the server
+#[derive(Debug, Clone)]
+pub struct ServerConfig {
+    /// Time to wait after broadcasting `server-shutdown` so that in-flight messages can be flushed
+    pub shutdown_grace_period: Duration,
+    /// Tenant prefix policy applied to client ids (`<tenant>:<id>`)
+    pub tenant_prefix_policy: TenantPrefixPolicy,
+    /// How to handle unexpected binary frames
+    pub binary_frame_policy: BinaryFramePolicy,
+    /// Attach `detected_lang` to broadcast chat messages
+    pub detect_language: bool,
+    /// Attach `has_spoiler` and the `||spoiler||` spans to broadcast chat messages
+    pub tag_spoilers: bool,
+    /// Reject inbound chat frames with missing or unknown fields (error frame instead of best-effort parsing)
+    pub strict_inbound_schema: bool,
+    /// Limits on the nesting depth and width of inbound JSON frames, checked before parsing
+    pub inbound_json_limits: JsonLimits,
+    /// Additional limits on chat message content (e.g. maximum emoji count)
+    pub message_content_policy: MessageContentPolicy,
+    /// Time to wait for a frame to be written to a client's socket before treating the client as dead (`None` = unlimited)
+    pub send_timeout: Option<Duration>,
+    /// Origins allowed to open a WebSocket connection (empty = allow all, for development)
+    ///
+    /// Requests without an `Origin` header (non-browser clients) are always allowed,
+    /// since cross-site WebSocket hijacking can only be performed from a browser.
+    pub allowed_origins: Vec<String>,
+    /// Bearer token required by the HTTP API endpoints exposing room information (`None` = open)
+    ///
+    /// `/api/health` and `/api/capabilities` stay public.
+    pub api_token: Option<String>,
+    /// Client ids with admin privileges (the room admin posting in locked rooms and the
+    /// join approval admin, who joins without approval)
+    ///
+    /// An admin is identified by its client_id only. With an API token configured, a WebSocket
+    /// connection under one of these ids must present the token (`Authorization: Bearer`);
+    /// without one, anyone connecting under the id is treated as the admin.
+    pub admin_client_ids: Vec<ClientId>,
+    /// Maximum connection attempts per client_id within a sliding window (`None` = unlimited)
+    pub reconnect_limit: Option<ReconnectLimit>,
+    /// Maximum number of open WebSocket connections per peer IP (`None` = unlimited)
+    ///
+    /// Counted on the TCP peer address, so behind a reverse proxy all clients share one IP.
+    pub max_connections_per_ip: Option<usize>,
+    /// Maximum number of frames read from a client's socket per second (`None` = unlimited)
+    ///
+    /// A client over the limit is not read from until the next second (TCP backpressure).
+    pub max_inbound_frames_per_sec: Option<u32>,
+    /// Send a `Ping` to each client every interval and disconnect clients silent for the timeout
+    /// (`None` = disabled)
+    pub heartbeat: Option<HeartbeatConfig>,
+    /// Maximum number of queued frames flushed to a client after its receive loop ends
+    pub drain_max_frames: usize,
+    /// Time to wait for the queued frames to be flushed before running the disconnect cleanup
+    pub drain_timeout: Duration,
+    /// Drop an outbound frame identical to the previous frame sent to the same client
+    /// (e.g. a frame delivered twice by a batch or a retry)
+    pub dedup_consecutive_frames: bool,
+    /// Maximum number of already queued frames written to a client with a single flush
+    /// (`1` = write and flush one frame at a time)
+    pub send_batch_max_frames: usize,
+    /// Log a summary (duration, messages and bytes in/out, reason) when a connection ends
+    pub connection_summary: bool,
+    /// Interval between `room-stats` frames sent to a client subscribed to room stats
+    pub stats_interval: Duration,
+    /// Unit of the numeric timestamps generated by the server in WebSocket frames
+    /// (e.g. `connected_at`, `disconnected_at`); RFC 3339 strings are unaffected
+    pub timestamp_unit: TimestampUnit,
+    /// Largest accepted difference between the `timestamp` of a client's chat frame and the server
+    /// clock; when set, delivery receipts report the skew and flag it beyond this tolerance
+    pub clock_skew_tolerance: Option<Duration>,
+    /// Time zone of the RFC 3339 timestamps in HTTP responses and WebSocket frames (default: JST)
+    pub utc_offset: FixedOffset,
+    /// Localized system text (error frames and announcements) with the server's default locale
+    pub localizer: Localizer,
+}
+
+impl ServerConfig {
+    /// Whether a WebSocket upgrade with the given `Origin` header is allowed
+    pub fn is_origin_allowed(&self, origin: Option<&str>) -> bool {
+        let Some(origin) = origin else {
+            return true;
+        };
+        self.allowed_origins.is_empty()
+            || self
+                .allowed_origins
+                .iter()
+                .any(|allowed| allowed.trim_end_matches('/').eq_ignore_ascii_case(origin))
+    }
+}
+
+impl Default for ServerConfig {
+    fn default() -> Self {
+        Self {
+            shutdown_grace_period: DEFAULT_SHUTDOWN_GRACE_PERIOD,
+            tenant_prefix_policy: TenantPrefixPolicy::default(),
+            binary_frame_policy: BinaryFramePolicy::default(),
+            detect_language: false,
+            tag_spoilers: false,
+            strict_inbound_schema: false,
+            inbound_json_limits: JsonLimits::default(),
+            message_content_policy: MessageContentPolicy::default(),
+            send_timeout: None,
+            allowed_origins: Vec::new(),
+            api_token: None,
+            admin_client_ids: Vec::new(),
+            reconnect_limit: None,
+            max_connections_per_ip: None,
+            max_inbound_frames_per_sec: None,
+            heartbeat: None,
+            drain_max_frames: DEFAULT_DRAIN_MAX_FRAMES,
+            drain_timeout: DEFAULT_DRAIN_TIMEOUT,
+            dedup_consecutive_frames: false,
+            send_batch_max_frames: 1,
+            connection_summary: false,
+            stats_interval: DEFAULT_STATS_INTERVAL,
+            timestamp_unit: TimestampUnit::default(),
+            clock_skew_tolerance: None,
+            utc_offset: jst_offset(),
+            localizer: Localizer::default(),
+        }
+    }
+}
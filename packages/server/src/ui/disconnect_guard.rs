@@ -0,0 +1,104 @@
+//! Per-connection disconnect guards.
+//!
+//! A connection can end for several causes at once: the client closing, a send or heartbeat
+//! timeout, an admin kick, or the removal of its room. Each connection owns a single
+//! [`DisconnectGuard`] shared by all of them, so the participant is removed and
+//! `participant-left` is broadcast exactly once.
+
+use std::{
+    collections::HashMap,
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicBool, Ordering},
+    },
+};
+
+use crate::domain::ClientId;
+
+/// Disconnect flag of a single connection
+#[derive(Debug, Default)]
+pub struct DisconnectGuard {
+    disconnected: AtomicBool,
+}
+
+impl DisconnectGuard {
+    /// Mark the connection as disconnected
+    ///
+    /// Returns `true` only for the first call.
+    pub fn claim(&self) -> bool {
+        !self.disconnected.swap(true, Ordering::SeqCst)
+    }
+}
+
+/// Guards of the open connections, by client id
+///
+/// Lets server-side disconnect causes (kick, room removal) claim the guard of the
+/// connection they end.
+#[derive(Debug, Default)]
+pub struct DisconnectGuards {
+    guards: Mutex<HashMap<ClientId, Arc<DisconnectGuard>>>,
+}
+
+impl DisconnectGuards {
+    /// Create the guard of a new connection of `client_id`
+    ///
+    /// Replaces the guard of an older connection of the same client.
+    pub fn register(&self, client_id: &ClientId) -> Arc<DisconnectGuard> {
+        let guard = Arc::new(DisconnectGuard::default());
+        self.guards
+            .lock()
+            .unwrap()
+            .insert(client_id.clone(), guard.clone());
+        guard
+    }
+
+    /// Mark the open connection of `client_id` as disconnected
+    ///
+    /// Returns `false` if the connection has already been disconnected by another cause.
+    /// Returns `true` if no connection of the client is open (nothing else will clean it up).
+    pub fn claim(&self, client_id: &ClientId) -> bool {
+        match self.guards.lock().unwrap().get(client_id) {
+            Some(guard) => guard.claim(),
+            None => true,
+        }
+    }
+
+    /// Forget the guard of a closed connection
+    ///
+    /// Kept if a newer connection of the same client has replaced it.
+    pub fn release(&self, client_id: &ClientId, guard: &Arc<DisconnectGuard>) {
+        let mut guards = self.guards.lock().unwrap();
+        if guards
+            .get(client_id)
+            .is_some_and(|current| Arc::ptr_eq(current, guard))
+        {
+            guards.remove(client_id);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_guard_claimed_once_and_kept_for_newer_connection() {
+        // テスト項目: 接続のガードは最初の 1 回だけ取得でき、古い接続の解放で新しい接続のガードは取り除かれない
+        // given (前提条件):
+        let guards = DisconnectGuards::default();
+        let alice = ClientId::new("alice".to_string()).unwrap();
+        let old = guards.register(&alice);
+
+        // when (操作):
+        let by_kick = guards.claim(&alice);
+        let by_close = old.claim();
+        let new = guards.register(&alice);
+        guards.release(&alice, &old);
+
+        // then (期待する結果):
+        assert!(by_kick);
+        assert!(!by_close);
+        assert!(guards.claim(&alice));
+        assert!(!new.claim());
+    }
+}
@@ -4,16 +4,37 @@ use std::sync::Arc;
 
 use axum::{
     Json,
-    extract::{Path, State},
-    http::StatusCode,
+    extract::{Path, Query, State},
+    http::{StatusCode, header},
 };
 
 use crate::{
-    domain::Room,
-    infrastructure::dto::http::{ParticipantDetailDto, RoomDetailDto, RoomSummaryDto},
-    ui::state::AppState,
+    domain::{ClientId, ClockExt, MESSAGE_CONTENT_MAX_LENGTH, Room},
+    infrastructure::dto::{
+        http::{
+            CapabilitiesDto, CreateRoomRequestDto, FeaturesDto, KickRequestDto,
+            MessageAuditEntryDto, MessageDetailDto, MessageHistoryDto, MessagePageQuery,
+            ParticipantDetailDto, RemoveRoomResponseDto, RoomDetailDto, RoomSummaryDto,
+            UpdateRoomRequestDto,
+        },
+        websocket::{
+            KickedMessage, MessageType, PROTOCOL_VERSION, RoomLockChangedMessage, SUPPORTED_CODECS,
+        },
+    },
+    ui::{
+        handler::websocket::{announce_departure, claim_disconnect},
+        state::AppState,
+    },
+    usecase::{CreateRoomError, LatencyHistogramSnapshot},
 };
-use engawa_shared::time::timestamp_to_jst_rfc3339;
+use chrono::FixedOffset;
+use engawa_shared::time::timestamp_to_rfc3339;
+
+/// Number of messages returned by the room detail endpoint when `limit` is not given
+const DEFAULT_MESSAGE_PAGE_LIMIT: usize = 50;
+
+/// Maximum number of messages returned by the room detail endpoint (larger limits are clamped)
+const MAX_MESSAGE_PAGE_LIMIT: usize = 200;
 
 /// Debug endpoint to get current room state (for testing purposes)
 pub async fn debug_room_state(State(state): State<Arc<AppState>>) -> Json<Room> {
@@ -26,8 +47,82 @@ pub async fn debug_room_state(State(state): State<Arc<AppState>>) -> Json<Room>
 }
 
 /// Health check endpoint
-pub async fn health_check() -> Json<serde_json::Value> {
-    Json(serde_json::json!({"status": "ok"}))
+///
+/// Also serves as the readiness endpoint: once the shutdown sequence has started,
+/// it responds with 503 and `status: "shutting_down"`.
+pub async fn health_check(
+    State(state): State<Arc<AppState>>,
+) -> (StatusCode, Json<serde_json::Value>) {
+    if state.shutdown.is_shutting_down() {
+        return (
+            StatusCode::SERVICE_UNAVAILABLE,
+            Json(serde_json::json!({"status": "shutting_down"})),
+        );
+    }
+    (StatusCode::OK, Json(serde_json::json!({"status": "ok"})))
+}
+
+/// Server capabilities endpoint
+///
+/// Lets clients discover the protocol version, limits and enabled features before connecting.
+pub async fn get_capabilities(State(state): State<Arc<AppState>>) -> Json<CapabilitiesDto> {
+    Json(CapabilitiesDto {
+        protocol_version: PROTOCOL_VERSION.to_string(),
+        codecs: SUPPORTED_CODECS.iter().map(|c| c.to_string()).collect(),
+        max_message_size: MESSAGE_CONTENT_MAX_LENGTH,
+        timestamp_unit: state.config.timestamp_unit.as_str().to_string(),
+        auth_required: state.config.api_token.is_some(),
+        features: FeaturesDto {
+            reactions: false,
+            edits: true,
+            direct_messages: true,
+            delivery_receipts: true,
+            delivery_acks: true,
+            message_ids: true,
+            language_detection: state.config.detect_language,
+            strict_inbound_schema: state.config.strict_inbound_schema,
+        },
+    })
+}
+
+/// Metrics endpoint in the Prometheus text exposition format
+///
+/// Serves the delivery latency histogram of chat messages. Responds with 404 when the latency
+/// is not measured.
+pub async fn get_metrics(
+    State(state): State<Arc<AppState>>,
+) -> Result<([(header::HeaderName, &'static str); 1], String), StatusCode> {
+    let snapshot = state
+        .send_message_usecase
+        .latency_snapshot()
+        .ok_or(StatusCode::NOT_FOUND)?;
+    Ok((
+        [(header::CONTENT_TYPE, "text/plain; version=0.0.4")],
+        render_latency_histogram(&snapshot),
+    ))
+}
+
+/// Render the delivery latency histogram as a Prometheus histogram in seconds
+fn render_latency_histogram(snapshot: &LatencyHistogramSnapshot) -> String {
+    const NAME: &str = "engawa_message_delivery_latency_seconds";
+    let seconds = |millis: u64| millis as f64 / 1000.0;
+    let mut text = format!(
+        "# HELP {NAME} Time from receiving a chat message to handing it to the last recipient's channel.\n# TYPE {NAME} histogram\n"
+    );
+    for (upper_ms, cumulative_count) in &snapshot.buckets {
+        text.push_str(&format!(
+            "{NAME}_bucket{{le=\"{}\"}} {}\n",
+            seconds(*upper_ms),
+            cumulative_count
+        ));
+    }
+    text.push_str(&format!(
+        "{NAME}_bucket{{le=\"+Inf\"}} {}\n{NAME}_sum {}\n{NAME}_count {}\n",
+        snapshot.count,
+        seconds(snapshot.sum_ms),
+        snapshot.count
+    ));
+    text
 }
 
 /// Get list of rooms
@@ -48,38 +143,275 @@ pub async fn get_rooms(State(state): State<Arc<AppState>>) -> Json<Vec<RoomSumma
                 .iter()
                 .map(|p| p.id.as_str().to_string())
                 .collect(),
-            created_at: timestamp_to_jst_rfc3339(room.created_at.value()),
+            created_at: timestamp_to_rfc3339(room.created_at.as_millis(), state.config.utc_offset),
         })
         .collect();
 
     Json(room_summaries)
 }
 
+/// Create an empty room
+///
+/// The room gets the same limits as the default room; clients join it with
+/// `/ws?room_id=<id>`. The JSON body (`{"client_id": "..."}`) is optional and names
+/// the creator, whose tenant prefix counts toward `--max-rooms-per-tenant`
+/// (429 Too Many Requests once the tenant reaches it).
+pub async fn create_room(
+    State(state): State<Arc<AppState>>,
+    request: Option<Json<CreateRoomRequestDto>>,
+) -> Result<(StatusCode, Json<RoomDetailDto>), StatusCode> {
+    let creator = match request.and_then(|Json(request)| request.client_id) {
+        Some(client_id) => Some(
+            ClientId::new_with_tenant_policy(client_id, &state.config.tenant_prefix_policy)
+                .map_err(|_| StatusCode::BAD_REQUEST)?,
+        ),
+        None => None,
+    };
+    match state.create_room_usecase.add_room(creator).await {
+        Ok(room) => Ok((
+            StatusCode::CREATED,
+            Json(room_to_detail_dto(
+                &room,
+                &MessagePageQuery::default(),
+                state.config.utc_offset,
+            )),
+        )),
+        Err(CreateRoomError::TenantRoomLimit) => Err(StatusCode::TOO_MANY_REQUESTS),
+        Err(e) => {
+            tracing::error!("Failed to create room: {:?}", e);
+            Err(StatusCode::INTERNAL_SERVER_ERROR)
+        }
+    }
+}
+
 /// Get room detail by ID
+///
+/// The message history is paginated with `?limit=` (default 50, at most 200) and
+/// `?offset=`, counted back from the most recent message.
 pub async fn get_room_detail(
     State(state): State<Arc<AppState>>,
     Path(room_id): Path<String>,
+    Query(page): Query<MessagePageQuery>,
 ) -> Result<Json<RoomDetailDto>, StatusCode> {
     match state.get_room_detail_usecase.execute(room_id).await {
-        Ok(room) => {
-            // Domain Model から DTO への変換
-            let room_detail = RoomDetailDto {
-                id: room.id.as_str().to_string(),
-                participants: room
-                    .participants
-                    .iter()
-                    .map(|p| ParticipantDetailDto {
-                        client_id: p.id.as_str().to_string(),
-                        connected_at: timestamp_to_jst_rfc3339(p.connected_at.value()),
-                    })
-                    .collect(),
-                created_at: timestamp_to_jst_rfc3339(room.created_at.value()),
-            };
-            Ok(Json(room_detail))
-        }
+        Ok(room) => Ok(Json(room_to_detail_dto(
+            &room,
+            &page,
+            state.config.utc_offset,
+        ))),
         Err(crate::usecase::GetRoomDetailError::RoomNotFound) => Err(StatusCode::NOT_FOUND),
         Err(crate::usecase::GetRoomDetailError::RepositoryError) => {
             Err(StatusCode::INTERNAL_SERVER_ERROR)
         }
     }
 }
+
+/// Get the edit and delete history (audit trail) of a message
+///
+/// Returns 404 if the room or the message does not exist, or if edits are not audited
+/// (`--audit-message-edits`).
+pub async fn get_message_history(
+    State(state): State<Arc<AppState>>,
+    Path((room_id, message_id)): Path<(String, String)>,
+) -> Result<Json<MessageHistoryDto>, StatusCode> {
+    match state
+        .get_message_history_usecase
+        .execute(room_id.clone(), message_id.clone())
+        .await
+    {
+        Ok(entries) => Ok(Json(MessageHistoryDto {
+            room_id,
+            message_id,
+            entries: entries
+                .into_iter()
+                .map(|entry| MessageAuditEntryDto {
+                    action: entry.action.as_str().to_string(),
+                    actor: entry.actor.into_string(),
+                    before_hash: entry.before_hash,
+                    after_hash: entry.after_hash,
+                    at: timestamp_to_rfc3339(entry.at.as_millis(), state.config.utc_offset),
+                })
+                .collect(),
+        })),
+        Err(crate::usecase::GetMessageHistoryError::RepositoryError) => {
+            Err(StatusCode::INTERNAL_SERVER_ERROR)
+        }
+        Err(_) => Err(StatusCode::NOT_FOUND),
+    }
+}
+
+/// Update room settings (e.g. lock state, closed state)
+pub async fn update_room(
+    State(state): State<Arc<AppState>>,
+    Path(room_id): Path<String>,
+    Json(request): Json<UpdateRoomRequestDto>,
+) -> Result<Json<RoomDetailDto>, StatusCode> {
+    match state
+        .update_room_usecase
+        .execute(room_id, request.locked, request.closed)
+        .await
+    {
+        Ok(room) => {
+            // Notify participants when the lock state was requested
+            if request.locked.is_some() {
+                let lock_msg = RoomLockChangedMessage {
+                    r#type: if room.locked {
+                        MessageType::RoomLocked
+                    } else {
+                        MessageType::RoomUnlocked
+                    },
+                    room_id: room.id.as_str().to_string(),
+                    changed_at: state.clock.now().in_unit(state.config.timestamp_unit),
+                };
+
+                let lock_json = serde_json::to_string(&lock_msg).unwrap();
+                if let Err(e) = state
+                    .update_room_usecase
+                    .broadcast_room_updated(&room.id, &lock_json)
+                    .await
+                {
+                    tracing::warn!("Failed to broadcast room lock state: {}", e);
+                } else {
+                    tracing::info!(
+                        "Room '{}' is now {}",
+                        room.id.as_str(),
+                        if room.locked { "locked" } else { "unlocked" }
+                    );
+                }
+            }
+
+            Ok(Json(room_to_detail_dto(
+                &room,
+                &MessagePageQuery::default(),
+                state.config.utc_offset,
+            )))
+        }
+        Err(crate::usecase::UpdateRoomError::RoomNotFound) => Err(StatusCode::NOT_FOUND),
+        Err(crate::usecase::UpdateRoomError::RepositoryError) => {
+            Err(StatusCode::INTERNAL_SERVER_ERROR)
+        }
+    }
+}
+
+/// Remove a room and disconnect the clients that were in it
+pub async fn remove_room(
+    State(state): State<Arc<AppState>>,
+    Path(room_id): Path<String>,
+) -> Result<Json<RemoveRoomResponseDto>, StatusCode> {
+    match state.remove_room_usecase.execute(room_id.clone()).await {
+        Ok(evicted_client_ids) => {
+            // The evicted participants are already gone; their connections skip the cleanup
+            for client_id in &evicted_client_ids {
+                if let Ok(client_id) = ClientId::restore(client_id.clone()) {
+                    claim_disconnect(&state, &client_id);
+                }
+            }
+            Ok(Json(RemoveRoomResponseDto {
+                id: room_id,
+                evicted_client_ids,
+            }))
+        }
+        Err(crate::usecase::RemoveRoomError::RoomNotFound) => Err(StatusCode::NOT_FOUND),
+        Err(crate::usecase::RemoveRoomError::RepositoryError) => {
+            Err(StatusCode::INTERNAL_SERVER_ERROR)
+        }
+    }
+}
+
+/// Reason sent to a kicked participant when the request does not give one
+const DEFAULT_KICK_REASON: &str = "Removed by an admin";
+
+/// Forcibly disconnect a participant
+///
+/// The participant receives a `kicked` frame before the server closes the connection;
+/// the rest of the room receives the usual `participant-left`. The JSON body
+/// (`{"reason": "..."}`) is optional.
+pub async fn kick_participant(
+    State(state): State<Arc<AppState>>,
+    Path((room_id, client_id)): Path<(String, String)>,
+    request: Option<Json<KickRequestDto>>,
+) -> StatusCode {
+    let room = match state.get_room_detail_usecase.execute(room_id).await {
+        Ok(room) => room,
+        Err(crate::usecase::GetRoomDetailError::RoomNotFound) => return StatusCode::NOT_FOUND,
+        Err(crate::usecase::GetRoomDetailError::RepositoryError) => {
+            return StatusCode::INTERNAL_SERVER_ERROR;
+        }
+    };
+    let Ok(client_id) =
+        ClientId::new_with_tenant_policy(client_id, &state.config.tenant_prefix_policy)
+    else {
+        return StatusCode::BAD_REQUEST;
+    };
+    // Skip participants of other rooms and connections already being disconnected
+    if !room.participants.iter().any(|p| p.id == client_id) || !claim_disconnect(&state, &client_id)
+    {
+        return StatusCode::NOT_FOUND;
+    }
+    let room_id = room.id;
+    let reason = request
+        .and_then(|Json(request)| request.reason)
+        .unwrap_or_else(|| DEFAULT_KICK_REASON.to_string());
+
+    match state
+        .disconnect_participant_usecase
+        .force_disconnect(&room_id, client_id.clone(), reason, |reason| {
+            serde_json::to_string(&KickedMessage {
+                r#type: MessageType::Kicked,
+                reason: reason.to_string(),
+            })
+            .unwrap()
+        })
+        .await
+    {
+        Ok(notify_targets) => {
+            announce_departure(&state, &room_id, &client_id, notify_targets).await;
+            StatusCode::NO_CONTENT
+        }
+        Err(_) => StatusCode::NOT_FOUND,
+    }
+}
+
+/// Domain Model から DTO への変換
+fn room_to_detail_dto(
+    room: &Room,
+    page: &MessagePageQuery,
+    utc_offset: FixedOffset,
+) -> RoomDetailDto {
+    // The page ends `offset` messages before the most recent one
+    let limit = page
+        .limit
+        .unwrap_or(DEFAULT_MESSAGE_PAGE_LIMIT)
+        .min(MAX_MESSAGE_PAGE_LIMIT);
+    let end = room.messages.len().saturating_sub(page.offset.unwrap_or(0));
+    let start = end.saturating_sub(limit);
+
+    RoomDetailDto {
+        id: room.id.as_str().to_string(),
+        participants: room
+            .participants
+            .iter()
+            .map(|p| ParticipantDetailDto {
+                client_id: p.id.as_str().to_string(),
+                connected_at: timestamp_to_rfc3339(p.connected_at.as_millis(), utc_offset),
+            })
+            .collect(),
+        created_at: timestamp_to_rfc3339(room.created_at.as_millis(), utc_offset),
+        locked: room.locked,
+        closed: room.closed,
+        messages: room.messages[start..end]
+            .iter()
+            .map(|m| MessageDetailDto {
+                message_id: m.id.as_ref().map(|id| id.as_str().to_string()),
+                client_id: m.from.as_str().to_string(),
+                content: m.content.sanitized(),
+                timestamp: timestamp_to_rfc3339(m.timestamp.as_millis(), utc_offset),
+                edited_at: m
+                    .edited_at
+                    .map(|t| timestamp_to_rfc3339(t.as_millis(), utc_offset)),
+                deleted: m.deleted,
+            })
+            .collect(),
+        total: room.messages.len(),
+    }
+}
@@ -4,7 +4,10 @@ pub mod http;
 pub mod websocket;
 
 // Re-export HTTP handlers
-pub use http::{debug_room_state, get_room_detail, get_rooms, health_check};
+pub use http::{
+    create_room, debug_room_state, get_capabilities, get_message_history, get_metrics,
+    get_room_detail, get_rooms, health_check, kick_participant, remove_room, update_room,
+};
 
 // Re-export WebSocket handlers
 pub use websocket::websocket_handler;
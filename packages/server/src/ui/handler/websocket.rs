@@ -1,74 +1,244 @@
 //! WebSocket connection handlers.
 
-use std::sync::Arc;
+use std::{
+    hash::{DefaultHasher, Hash, Hasher},
+    net::SocketAddr,
+    sync::{
+        Arc,
+        atomic::{AtomicU64, Ordering},
+    },
+    time::{Duration, Instant},
+};
 
+use crate::{
+    domain::{
+        ClientId, ClockExt, ClockSkew, MessageContent, MessageId, MessageRejectionReason,
+        Participant, PusherReceiver, RoomId, Timestamp, pusher_channel,
+    },
+    infrastructure::{
+        dto::websocket::{
+            BackfillMessage, ChatMessage, DeleteMessage, DeliveryAckMessage,
+            DeliveryReceiptMessage, EditMessage, ErrorMessage, InboundChatMessage,
+            JoinDecisionMessage, JoinRequestMessage, MentionMessage, MessageType,
+            ParticipantCountMessage, ParticipantInfo, ParticipantJoinedMessage,
+            ParticipantLeftMessage, ParticipantsJoinedMessage, RoomConnectedMessage,
+            RoomStatsMessage, SpoilerRange, StatsSubscriptionMessage, TypingMessage,
+            WhisperMessage,
+        },
+        language::detect_language,
+        spoiler::find_spoilers,
+    },
+    ui::{
+        auth::has_bearer_token,
+        config::BinaryFramePolicy,
+        disconnect_guard::DisconnectGuard,
+        heartbeat::{Heartbeat, Liveness},
+        read_rate_limit::ReadRateLimiter,
+        state::AppState,
+    },
+    usecase::{ConnectionSummary, DisconnectReason, MSG_UNEXPECTED_BINARY, SendMessageError},
+};
 use axum::{
     extract::{
-        Query, State,
+        ConnectInfo, Query, State,
         ws::{Message, WebSocket, WebSocketUpgrade},
     },
-    http::StatusCode,
-    response::IntoResponse,
+    http::{HeaderMap, StatusCode, header::ORIGIN},
+    response::{IntoResponse, Response},
 };
-use futures_util::{sink::SinkExt, stream::StreamExt};
-use tokio::sync::mpsc;
-
-use crate::{
-    domain::{ClientId, MessageContent, Timestamp},
-    infrastructure::dto::websocket::{
-        ChatMessage, MessageType, ParticipantJoinedMessage, ParticipantLeftMessage,
-        RoomConnectedMessage,
-    },
-    ui::state::AppState,
+use engawa_shared::time::timestamp_to_rfc3339;
+use futures_util::{
+    sink::{Sink, SinkExt},
+    stream::StreamExt,
 };
-use engawa_shared::time::get_jst_timestamp;
+use tokio::sync::oneshot;
 
 use serde::Deserialize;
 
+/// Reason sent with `410 Gone` when the room is closed (archived)
+const ROOM_CLOSED_REASON: &str = "room is closed";
+
+/// Reason sent with `403 Forbidden` when the room admin denies the join (or is not connected)
+const JOIN_DENIED_REASON: &str = "join denied";
+
+/// Reason sent with `403 Forbidden` when the room admin does not answer the join request in time
+const JOIN_APPROVAL_TIMEOUT_REASON: &str = "join approval timed out";
+
 /// Query parameters for WebSocket connection
 #[derive(Debug, Deserialize)]
 pub struct ConnectQuery {
     pub client_id: String,
+    /// Room to join (the default room if not set or `"default"`)
+    #[serde(default)]
+    pub room_id: Option<String>,
+    /// Send a `delivery-receipt` back to this client for each broadcast message
+    #[serde(default)]
+    pub delivery_receipts: bool,
+    /// Mark this client as a bot (tagged in participant lists)
+    #[serde(default)]
+    pub is_bot: bool,
+    /// This client sends a `delivery-ack` for each chat message it receives
+    #[serde(default)]
+    pub acks: bool,
+    /// Locale of the system text sent to this client (e.g. `fr`; the server's default locale if not set)
+    #[serde(default)]
+    pub locale: Option<String>,
+    /// Timestamp (milliseconds) of the last message the client received before reconnecting;
+    /// the messages after it are sent in a `backfill` frame
+    #[serde(default)]
+    pub since: Option<i64>,
 }
 
 pub async fn websocket_handler(
     ws: WebSocketUpgrade,
     State(state): State<Arc<AppState>>,
     Query(query): Query<ConnectQuery>,
-) -> Result<impl IntoResponse, StatusCode> {
+    ConnectInfo(peer): ConnectInfo<SocketAddr>,
+    headers: HeaderMap,
+) -> Result<Response, Response> {
+    // Reject upgrades from pages on other sites (cross-site WebSocket hijacking)
+    let origin = headers.get(ORIGIN).and_then(|value| value.to_str().ok());
+    if !state.config.is_origin_allowed(origin) {
+        tracing::warn!(
+            "Rejected WebSocket upgrade from disallowed origin {:?}",
+            origin
+        );
+        return Err(StatusCode::FORBIDDEN.into_response());
+    }
+
     let client_id_str = query.client_id;
+    let delivery_receipts = query.delivery_receipts;
+    let is_bot = query.is_bot;
+    let acks = query.acks;
+    let locale = query.locale;
+    let since = query.since.map(Timestamp::new);
 
     // Convert String -> ClientId (Domain Model)
-    let client_id = match ClientId::try_from(client_id_str.clone()) {
+    let client_id = match ClientId::new_with_tenant_policy(
+        client_id_str.clone(),
+        &state.config.tenant_prefix_policy,
+    ) {
         Ok(id) => id,
         Err(_) => {
-            tracing::warn!("Invalid client_id format: '{}'", client_id_str);
-            return Err(StatusCode::BAD_REQUEST);
+            tracing::warn!("Invalid client_id format: {:?}", client_id_str);
+            return Err(StatusCode::BAD_REQUEST.into_response());
+        }
+    };
+
+    // Admins are identified by their client_id only; with an API token configured,
+    // claiming an admin id requires the token so that nobody else can act as the admin
+    if state.config.admin_client_ids.contains(&client_id)
+        && let Some(api_token) = &state.config.api_token
+        && !has_bearer_token(&headers, api_token)
+    {
+        tracing::warn!(
+            "Rejected connection claiming admin id '{}' without the API token",
+            client_id_str
+        );
+        return Err(StatusCode::UNAUTHORIZED.into_response());
+    }
+
+    // Reject clients reconnecting too often (e.g. stuck in a reconnect loop)
+    if let Some(limit) = state.config.reconnect_limit
+        && !state
+            .reconnect_limiter
+            .try_acquire(client_id.as_str(), limit, Instant::now())
+    {
+        tracing::warn!(
+            "Client '{}' exceeded the reconnect limit ({} per {:?})",
+            client_id_str,
+            limit.max_attempts,
+            limit.window
+        );
+        return Err(StatusCode::TOO_MANY_REQUESTS.into_response());
+    }
+
+    // Reject addresses holding too many open connections (e.g. one host opening
+    // connections under many client_ids). The permit lives as long as the connection.
+    let ip_permit = match state.config.max_connections_per_ip {
+        Some(max) => match state.ip_connection_limiter.try_acquire(peer.ip(), max) {
+            Some(permit) => Some(permit),
+            None => {
+                tracing::warn!(
+                    "Address {} exceeded the connection limit ({}). Rejecting '{}'",
+                    peer.ip(),
+                    max,
+                    client_id_str
+                );
+                return Err(StatusCode::TOO_MANY_REQUESTS.into_response());
+            }
+        },
+        None => None,
+    };
+
+    // Resolve the room to join (the default room unless another `room_id` is given)
+    let room_id = match state
+        .connect_participant_usecase
+        .resolve_room_id(query.room_id)
+        .await
+    {
+        Ok(room_id) => room_id,
+        Err(e) => {
+            tracing::warn!("Room to join not found for '{}': {:?}", client_id_str, e);
+            return Err(StatusCode::NOT_FOUND.into_response());
         }
     };
 
     // Create a channel for this client to receive messages
-    let (tx, rx) = mpsc::unbounded_channel();
+    let (tx, rx) = pusher_channel();
 
-    // Use ConnectParticipantUseCase to handle connection
-    // (register_client is called inside the UseCase)
-    let client_id_for_handle = client_id.clone();
-    match state
+    // Wait for the room admin to approve the join (if approval is required), then use
+    // ConnectParticipantUseCase to handle connection (register_client is called inside the UseCase).
+    // The HTTP upgrade is held open while waiting: the client gets no response until the admin
+    // decides or the approval times out (`--join-approval-timeout-ms`).
+    let join_request = JoinRequestMessage {
+        r#type: MessageType::JoinRequest,
+        client_id: client_id.as_str().to_string(),
+        is_bot,
+    };
+    let join_request_json = serde_json::to_string(&join_request).unwrap();
+    let connect_result = match state
         .connect_participant_usecase
-        .execute(client_id, tx)
+        .request_approval(&client_id, &join_request_json)
         .await
     {
-        Ok(connected_at) => {
-            tracing::info!("Client '{}' connected and registered", client_id_str);
-            Ok(ws.on_upgrade(move |socket| {
+        Ok(()) => {
+            state
+                .connect_participant_usecase
+                .execute(&room_id, client_id, tx, is_bot)
+                .await
+        }
+        Err(e) => Err(e),
+    };
+    match connect_result {
+        Ok(participant) => {
+            if acks {
+                state
+                    .send_message_usecase
+                    .require_acks(participant.id.clone());
+            }
+            // The assigned id may differ from the requested one (client_id collision suffixing)
+            let assigned_client_id_str = participant.id.as_str().to_string();
+            tracing::info!(
+                "Client '{}' connected and registered as '{}'",
+                client_id_str,
+                assigned_client_id_str
+            );
+            Ok(ws.on_upgrade(move |socket| async move {
                 handle_socket(
                     socket,
                     state,
-                    client_id_str,
+                    assigned_client_id_str,
                     rx,
-                    connected_at,
-                    client_id_for_handle,
+                    participant,
+                    room_id,
+                    delivery_receipts,
+                    locale,
+                    since,
                 )
+                .await;
+                // Release the per-IP slot once the connection is gone
+                drop(ip_permit);
             }))
         }
         Err(crate::usecase::ConnectError::DuplicateClientId(_)) => {
@@ -76,14 +246,34 @@ pub async fn websocket_handler(
                 "Client with ID '{}' is already connected. Rejecting connection.",
                 client_id_str
             );
-            Err(StatusCode::CONFLICT)
+            Err(StatusCode::CONFLICT.into_response())
         }
         Err(crate::usecase::ConnectError::RoomCapacityExceeded) => {
             tracing::warn!(
                 "Room capacity exceeded. Cannot add participant '{}'",
                 client_id_str
             );
-            Err(StatusCode::SERVICE_UNAVAILABLE)
+            Err(StatusCode::SERVICE_UNAVAILABLE.into_response())
+        }
+        Err(crate::usecase::ConnectError::RoomNotFound) => {
+            tracing::warn!("Room not found. Cannot add participant '{}'", client_id_str);
+            Err(StatusCode::NOT_FOUND.into_response())
+        }
+        Err(crate::usecase::ConnectError::RoomClosed) => {
+            tracing::warn!("Room is closed. Cannot add participant '{}'", client_id_str);
+            Err((StatusCode::GONE, ROOM_CLOSED_REASON).into_response())
+        }
+        Err(crate::usecase::ConnectError::JoinDenied) => {
+            tracing::warn!("Join of '{}' was denied", client_id_str);
+            Err((StatusCode::FORBIDDEN, JOIN_DENIED_REASON).into_response())
+        }
+        Err(crate::usecase::ConnectError::JoinApprovalTimeout) => {
+            tracing::warn!("Join request of '{}' timed out", client_id_str);
+            Err((StatusCode::FORBIDDEN, JOIN_APPROVAL_TIMEOUT_REASON).into_response())
+        }
+        Err(crate::usecase::ConnectError::RepositoryError(e)) => {
+            tracing::error!("Failed to add participant '{}': {}", client_id_str, e);
+            Err(StatusCode::INTERNAL_SERVER_ERROR.into_response())
         }
     }
 }
@@ -97,94 +287,444 @@ pub async fn websocket_handler(
 ///
 /// * `rx` - Channel receiver for messages from other clients
 /// * `sender` - WebSocket sink to send messages to this client
+/// * `send_timeout` - Time to wait for a single frame to be written (`None` = unlimited)
+/// * `stop` - Signalled when the connection is closing; frames already queued are flushed
+///   (up to `drain_max_frames`) before the loop ends
+/// * `drain_max_frames` - Maximum number of queued frames flushed after `stop`
+/// * `dedup_consecutive_frames` - Drop a frame identical to the previous frame sent to this client
+/// * `send_batch_max_frames` - Maximum number of already queued frames written per iteration
+///   with a single flush (`1` = write and flush one frame at a time)
+/// * `counters` - Traffic counters of the connection (frames and bytes sent are added)
+/// * `heartbeat` - Sends a `Ping` every interval and ends the loop with
+///   `DisconnectReason::HeartbeatTimeout` once the client has been silent for the timeout
+///   (`None` = disabled)
 ///
 /// # Returns
 ///
-/// A `JoinHandle` for the spawned task
-fn pusher_loop(
-    mut rx: mpsc::UnboundedReceiver<String>,
-    mut sender: futures_util::stream::SplitSink<WebSocket, Message>,
-) -> tokio::task::JoinHandle<()> {
+/// A `JoinHandle` for the spawned task, resolving to the reason the loop ended
+#[allow(clippy::too_many_arguments)]
+fn pusher_loop<S>(
+    mut rx: PusherReceiver,
+    mut sender: S,
+    send_timeout: Option<Duration>,
+    mut stop: oneshot::Receiver<()>,
+    drain_max_frames: usize,
+    dedup_consecutive_frames: bool,
+    send_batch_max_frames: usize,
+    counters: Arc<ConnectionCounters>,
+    mut heartbeat: Option<Heartbeat>,
+) -> tokio::task::JoinHandle<DisconnectReason>
+where
+    S: Sink<Message> + Unpin + Send + 'static,
+{
     tokio::spawn(async move {
-        while let Some(msg) = rx.recv().await {
-            // Send the message to this client
-            if sender.send(Message::Text(msg.into())).await.is_err() {
-                break;
+        let mut last_frame_hash = None;
+        loop {
+            let msg = tokio::select! {
+                msg = rx.recv() => match msg {
+                    Some(msg) => msg,
+                    None => break,
+                },
+                _ = &mut stop => {
+                    // Flush the frames that were queued before the connection started closing
+                    let mut drained = 0;
+                    while drained < drain_max_frames
+                        && let Ok(msg) = rx.try_recv()
+                    {
+                        if dedup_consecutive_frames && is_repeated_frame(&mut last_frame_hash, &msg) {
+                            continue;
+                        }
+                        let len = msg.len();
+                        if let Err(reason) = send_frame(&mut sender, msg, send_timeout).await {
+                            return reason;
+                        }
+                        counters.record_outbound(len);
+                        drained += 1;
+                    }
+                    tracing::debug!("Drained {} queued frames before closing", drained);
+                    break;
+                }
+                alive = next_heartbeat(&mut heartbeat) => {
+                    if !alive {
+                        tracing::warn!("No frame from client within the heartbeat timeout");
+                        return DisconnectReason::HeartbeatTimeout;
+                    }
+                    match send_frame(&mut sender, Message::Ping(Default::default()), send_timeout).await {
+                        Ok(()) => continue,
+                        Err(DisconnectReason::Closed) => break,
+                        Err(reason) => return reason,
+                    }
+                }
+            };
+            // Take the frames that are already queued so that a burst is written with one flush
+            let mut batch = vec![msg];
+            while batch.len() < send_batch_max_frames
+                && let Ok(msg) = rx.try_recv()
+            {
+                batch.push(msg);
+            }
+            if dedup_consecutive_frames {
+                batch.retain(|msg| {
+                    let repeated = is_repeated_frame(&mut last_frame_hash, msg);
+                    if repeated {
+                        tracing::debug!("Suppressed a frame identical to the previous one");
+                    }
+                    !repeated
+                });
+            }
+            let lens: Vec<usize> = batch.iter().map(String::len).collect();
+            let result = match batch.len() {
+                0 => continue,
+                1 => send_frame(&mut sender, batch.pop().unwrap(), send_timeout).await,
+                _ => send_frames(&mut sender, batch, send_timeout).await,
+            };
+            match result {
+                Ok(()) => lens
+                    .into_iter()
+                    .for_each(|len| counters.record_outbound(len)),
+                Err(DisconnectReason::Closed) => break,
+                Err(reason) => return reason,
             }
         }
+        // The channel is closed once the client is unregistered (e.g. on server shutdown)
+        match send_timeout {
+            Some(timeout) => {
+                let _ = tokio::time::timeout(timeout, sender.close()).await;
+            }
+            None => {
+                let _ = sender.close().await;
+            }
+        }
+        DisconnectReason::Closed
     })
 }
 
+/// Wait for the next heartbeat tick (never completes when the heartbeat is disabled)
+///
+/// Returns `false` if the client has been silent for longer than the heartbeat timeout.
+async fn next_heartbeat(heartbeat: &mut Option<Heartbeat>) -> bool {
+    match heartbeat {
+        Some(heartbeat) => heartbeat.tick().await,
+        None => std::future::pending().await,
+    }
+}
+
+/// Whether `frame` is identical to the previous frame (compared by hash)
+///
+/// `frame` becomes the previous frame for the next call. Chat frames carry their message id,
+/// so distinct messages with the same text are never treated as repeated.
+fn is_repeated_frame(last_frame_hash: &mut Option<u64>, frame: &str) -> bool {
+    let mut hasher = DefaultHasher::new();
+    frame.hash(&mut hasher);
+    let hash = hasher.finish();
+    last_frame_hash.replace(hash) == Some(hash)
+}
+
+/// Write a single frame to the client
+///
+/// A client that stops reading would otherwise stall the sender forever,
+/// so the write gives up after `send_timeout`.
+///
+/// # Returns
+///
+/// * `Ok(())` - The frame was written
+/// * `Err(DisconnectReason::Closed)` - The socket is closed
+/// * `Err(DisconnectReason::Timeout)` - The frame could not be written within `send_timeout`
+async fn send_frame<S>(
+    sender: &mut S,
+    msg: impl Into<Message>,
+    send_timeout: Option<Duration>,
+) -> Result<(), DisconnectReason>
+where
+    S: Sink<Message> + Unpin,
+{
+    let send = sender.send(msg.into());
+    let result = match send_timeout {
+        Some(timeout) => match tokio::time::timeout(timeout, send).await {
+            Ok(result) => result,
+            Err(_) => {
+                tracing::warn!("Sending to client timed out after {:?}", timeout);
+                return Err(DisconnectReason::Timeout);
+            }
+        },
+        None => send.await,
+    };
+    result.map_err(|_| DisconnectReason::Closed)
+}
+
+/// Write a batch of frames to the client with a single flush
+///
+/// `send_timeout` applies to the whole batch.
+///
+/// # Returns
+///
+/// Same as [`send_frame`]
+async fn send_frames<S>(
+    sender: &mut S,
+    frames: Vec<String>,
+    send_timeout: Option<Duration>,
+) -> Result<(), DisconnectReason>
+where
+    S: Sink<Message> + Unpin,
+{
+    let write = async {
+        for frame in frames {
+            sender.feed(Message::Text(frame.into())).await?;
+        }
+        sender.flush().await
+    };
+    let result = match send_timeout {
+        Some(timeout) => match tokio::time::timeout(timeout, write).await {
+            Ok(result) => result,
+            Err(_) => {
+                tracing::warn!("Sending to client timed out after {:?}", timeout);
+                return Err(DisconnectReason::Timeout);
+            }
+        },
+        None => write.await,
+    };
+    result.map_err(|_| DisconnectReason::Closed)
+}
+
+#[allow(clippy::too_many_arguments)]
 async fn handle_socket(
     socket: WebSocket,
     state: Arc<AppState>,
     client_id_str: String,
-    rx: mpsc::UnboundedReceiver<String>,
-    connected_at: Timestamp,
-    client_id: ClientId,
+    rx: PusherReceiver,
+    participant: Participant,
+    room_id: RoomId,
+    delivery_receipts: bool,
+    locale: Option<String>,
+    since: Option<Timestamp>,
 ) {
+    let client_id = participant.id.clone();
+    // Shared by every cause that can end this connection (close, timeouts, kick, room removal)
+    let guard = state.disconnect_guards.register(&client_id);
     let (mut sender, mut receiver) = socket.split();
+    let started_at = Instant::now();
+    let counters = Arc::new(ConnectionCounters::default());
+    let liveness = Arc::new(Liveness::new(Instant::now()));
 
     // Send current room participants to the newly connected client
     {
         // Use ConnectParticipantUseCase to build participant list
         let participants = state
             .connect_participant_usecase
-            .build_participant_list()
+            .build_participant_list(&room_id)
             .await;
 
         // Domain Model から DTO への変換
-        let participant_infos: Vec<crate::infrastructure::dto::websocket::ParticipantInfo> =
-            participants
-                .into_iter()
-                .map(|p| crate::infrastructure::dto::websocket::ParticipantInfo {
-                    client_id: p.id.as_str().to_string(),
-                    connected_at: p.connected_at.value(),
-                })
-                .collect();
+        let participant_infos: Vec<ParticipantInfo> = participants
+            .into_iter()
+            .map(|p| {
+                ParticipantInfo::from_entity(
+                    p,
+                    state.config.timestamp_unit,
+                    state.config.utc_offset,
+                )
+            })
+            .collect();
 
         let room_msg = RoomConnectedMessage {
             r#type: MessageType::RoomConnected,
+            assigned_client_id: client_id_str.clone(),
             participants: participant_infos,
         };
 
         let room_json = serde_json::to_string(&room_msg).unwrap();
+        let room_json_len = room_json.len();
         if let Err(e) = sender.send(Message::Text(room_json.into())).await {
             tracing::error!(
                 "Failed to send room connected to '{}': {}",
                 client_id_str,
                 e
             );
+            finish_connection(
+                &state,
+                &room_id,
+                client_id,
+                &guard,
+                DisconnectReason::Closed,
+                &counters,
+                started_at,
+            )
+            .await;
             return;
         }
+        counters.record_outbound(room_json_len);
         tracing::info!("Sent room connected list to '{}'", client_id_str);
     }
 
+    // Send the messages missed since the given timestamp to a reconnecting client
+    if let Some(since) = since {
+        match state
+            .connect_participant_usecase
+            .backfill(&room_id, since)
+            .await
+        {
+            Ok(backfill) => {
+                let backfill_msg = BackfillMessage {
+                    r#type: MessageType::Backfill,
+                    messages: backfill
+                        .messages
+                        .into_iter()
+                        .map(|m| ChatMessage::from_entity(m, state.config.timestamp_unit))
+                        .collect(),
+                    truncated: backfill.truncated,
+                };
+                let backfill_json = serde_json::to_string(&backfill_msg).unwrap();
+                let backfill_json_len = backfill_json.len();
+                if let Err(e) = sender.send(Message::Text(backfill_json.into())).await {
+                    tracing::error!("Failed to send backfill to '{}': {}", client_id_str, e);
+                    finish_connection(
+                        &state,
+                        &room_id,
+                        client_id,
+                        &guard,
+                        DisconnectReason::Closed,
+                        &counters,
+                        started_at,
+                    )
+                    .await;
+                    return;
+                }
+                counters.record_outbound(backfill_json_len);
+                tracing::info!(
+                    "Sent {} backfill messages to '{}'",
+                    backfill_msg.messages.len(),
+                    client_id_str
+                );
+            }
+            Err(e) => tracing::warn!("Failed to backfill '{}': {:?}", client_id_str, e),
+        }
+    }
+
     // Broadcast participant-joined to all other clients
-    {
-        let joined_msg = ParticipantJoinedMessage {
-            r#type: MessageType::ParticipantJoined,
-            client_id: client_id_str.clone(),
-            connected_at: connected_at.value(),
+    // (joins within the batching window are announced together by the first joiner)
+    let (joined, batched) = state
+        .connect_participant_usecase
+        .collect_joins(&room_id, participant.clone())
+        .await;
+    if batched {
+        let joined_msg = ParticipantsJoinedMessage {
+            r#type: MessageType::ParticipantsJoined,
+            participants: joined
+                .iter()
+                .cloned()
+                .map(|p| {
+                    ParticipantInfo::from_entity(
+                        p,
+                        state.config.timestamp_unit,
+                        state.config.utc_offset,
+                    )
+                })
+                .collect(),
         };
 
         let joined_json = serde_json::to_string(&joined_msg).unwrap();
         if let Err(e) = state
             .connect_participant_usecase
-            .broadcast_participant_joined(&client_id, &joined_json)
+            .broadcast_participants_joined(&room_id, &joined_json)
             .await
         {
-            tracing::warn!("Failed to broadcast participant-joined: {}", e);
+            tracing::warn!("Failed to broadcast participants-joined: {}", e);
         } else {
-            tracing::info!("Broadcasted participant-joined for '{}'", client_id_str);
+            tracing::info!(
+                "Broadcasted participants-joined for {} participants",
+                joined_msg.participants.len()
+            );
+        }
+    } else {
+        for joined_participant in joined.iter().cloned() {
+            let joined_msg = ParticipantJoinedMessage {
+                r#type: MessageType::ParticipantJoined,
+                client_id: joined_participant.id.as_str().to_string(),
+                connected_at: joined_participant
+                    .connected_at
+                    .in_unit(state.config.timestamp_unit),
+                connected_at_iso: timestamp_to_rfc3339(
+                    joined_participant.connected_at.as_millis(),
+                    state.config.utc_offset,
+                ),
+                is_bot: joined_participant.is_bot,
+            };
+
+            let joined_json = serde_json::to_string(&joined_msg).unwrap();
+            if let Err(e) = state
+                .connect_participant_usecase
+                .broadcast_participant_joined(&room_id, &joined_participant.id, &joined_json)
+                .await
+            {
+                tracing::warn!("Failed to broadcast participant-joined: {}", e);
+            } else {
+                tracing::info!(
+                    "Broadcasted participant-joined for '{}'",
+                    joined_msg.client_id
+                );
+            }
+        }
+    }
+
+    // Broadcast the new participant count to the whole room
+    // (only by the call that announced the joins, so a batch of joins sends a single count)
+    if !joined.is_empty() {
+        let count_msg = ParticipantCountMessage {
+            r#type: MessageType::ParticipantCount,
+            count: state
+                .connect_participant_usecase
+                .count_participants(&room_id)
+                .await,
+        };
+        let count_json = serde_json::to_string(&count_msg).unwrap();
+        if let Err(e) = state
+            .connect_participant_usecase
+            .broadcast_participant_count(&room_id, &count_json)
+            .await
+        {
+            tracing::warn!("Failed to broadcast participant-count: {}", e);
         }
     }
 
+    // Let the welcome bot greet the new participant (if configured)
+    match state
+        .connect_participant_usecase
+        .greet(&room_id, &participant, locale.as_deref())
+        .await
+    {
+        Ok(Some(greeting)) => {
+            let greeting_json = serde_json::to_string(&ChatMessage::from_entity(
+                greeting,
+                state.config.timestamp_unit,
+            ))
+            .unwrap();
+            if let Err(e) = state
+                .connect_participant_usecase
+                .broadcast_greeting(&room_id, &greeting_json)
+                .await
+            {
+                tracing::warn!("Failed to broadcast greeting: {}", e);
+            }
+        }
+        Ok(None) => {}
+        Err(e) => tracing::warn!("Failed to greet '{}': {:?}", client_id_str, e),
+    }
+
     let client_id_str_clone = client_id_str.clone();
+    let client_id_clone = client_id.clone();
+    let room_id_clone = room_id.clone();
     let state_clone = state.clone();
+    let counters_clone = counters.clone();
+    let liveness_clone = liveness.clone();
 
     // Spawn a task to receive messages from this client
     let mut recv_task = tokio::spawn(async move {
+        // Dropped together with this task, so the stats stream also stops on disconnect
+        let mut stats_subscription: Option<StatsSubscription> = None;
+        let mut read_rate_limiter = state_clone
+            .config
+            .max_inbound_frames_per_sec
+            .map(ReadRateLimiter::new);
         while let Some(msg) = receiver.next().await {
             let msg = match msg {
                 Ok(msg) => msg,
@@ -194,34 +734,290 @@ async fn handle_socket(
                 }
             };
 
+            // Any frame (a pong to the heartbeat ping included) shows that the client is alive
+            liveness_clone.touch(Instant::now());
+
+            // Stop reading (instead of processing) while the client is over its read rate
+            if let Some(limiter) = &mut read_rate_limiter {
+                limiter.throttle().await;
+            }
+
+            // Count inbound traffic for the connection summary
+            match &msg {
+                Message::Text(text) => counters_clone.record_inbound(text.len()),
+                Message::Binary(data) => counters_clone.record_inbound(data.len()),
+                _ => {}
+            }
+
             match msg {
                 Message::Text(text) => {
                     tracing::info!("Received text: {}", text);
 
+                    // Reject pathological JSON (deeply nested or very wide) before any parse attempt
+                    if let Err(e) = state_clone.config.inbound_json_limits.check(&text) {
+                        tracing::warn!("Rejected frame from '{}': {}", client_id_str_clone, e);
+                        let error_msg = ErrorMessage::new("json_too_complex", e.to_string());
+                        let error_json = serde_json::to_string(&error_msg).unwrap();
+                        if let Err(e) = state_clone
+                            .send_message_usecase
+                            .push_to_sender(&client_id_clone, &error_json)
+                            .await
+                        {
+                            tracing::warn!("Failed to send error frame: {}", e);
+                        }
+                        continue;
+                    }
+
+                    // Delivery acks are handled separately from chat frames
+                    if let Ok(ack) = serde_json::from_str::<DeliveryAckMessage>(&text)
+                        && ack.r#type == MessageType::DeliveryAck
+                    {
+                        match MessageId::parse(ack.message_id) {
+                            Ok(message_id) => {
+                                if !state_clone
+                                    .send_message_usecase
+                                    .acknowledge(&client_id_clone, &message_id)
+                                {
+                                    tracing::debug!(
+                                        "Ignoring ack for '{}' from '{}' (not pending)",
+                                        message_id,
+                                        client_id_str_clone
+                                    );
+                                }
+                            }
+                            Err(e) => tracing::warn!(
+                                "Invalid delivery-ack from '{}': {}",
+                                client_id_str_clone,
+                                e
+                            ),
+                        }
+                        continue;
+                    }
+
+                    // Join decisions from the room admin are handled separately from chat frames
+                    if let Ok(decision) = serde_json::from_str::<JoinDecisionMessage>(&text)
+                        && decision.r#type == MessageType::JoinDecision
+                    {
+                        let decided = ClientId::new_with_tenant_policy(
+                            decision.client_id,
+                            &state_clone.config.tenant_prefix_policy,
+                        )
+                        .is_ok_and(|joiner| {
+                            state_clone.connect_participant_usecase.decide_join(
+                                &client_id_clone,
+                                &joiner,
+                                decision.approved,
+                            )
+                        });
+                        if !decided {
+                            tracing::warn!(
+                                "Ignoring join decision from '{}' (not admin or not pending)",
+                                client_id_str_clone
+                            );
+                        }
+                        continue;
+                    }
+
+                    // Room stats subscriptions are handled separately from chat frames
+                    if let Ok(subscription) =
+                        serde_json::from_str::<StatsSubscriptionMessage>(&text)
+                    {
+                        match subscription.r#type {
+                            MessageType::SubscribeStats => {
+                                if stats_subscription.is_none() {
+                                    stats_subscription = Some(StatsSubscription::spawn(
+                                        state_clone.clone(),
+                                        room_id_clone.clone(),
+                                        client_id_clone.clone(),
+                                    ));
+                                }
+                                continue;
+                            }
+                            MessageType::UnsubscribeStats => {
+                                stats_subscription = None;
+                                continue;
+                            }
+                            _ => {}
+                        }
+                    }
+
+                    // Edits replace the content of a stored message and are broadcast to the room
+                    if let Ok(edit) = serde_json::from_str::<EditMessage>(&text)
+                        && edit.r#type == MessageType::Edit
+                    {
+                        handle_edit(&state_clone, &client_id_clone, edit).await;
+                        continue;
+                    }
+
+                    // Deletions clear a stored message and are broadcast to the room
+                    if let Ok(delete) = serde_json::from_str::<DeleteMessage>(&text)
+                        && delete.r#type == MessageType::Delete
+                    {
+                        handle_delete(&state_clone, &client_id_clone, delete).await;
+                        continue;
+                    }
+
+                    // Typing indicators are relayed to the room and bypass the room history
+                    if let Ok(typing) = serde_json::from_str::<TypingMessage>(&text)
+                        && typing.r#type == MessageType::Typing
+                    {
+                        let typing = TypingMessage {
+                            r#type: MessageType::Typing,
+                            client_id: client_id_str_clone.clone(),
+                            is_typing: typing.is_typing,
+                        };
+                        let typing_json = serde_json::to_string(&typing).unwrap();
+                        if let Err(e) = state_clone
+                            .send_message_usecase
+                            .relay_typing(&client_id_clone, &typing_json)
+                            .await
+                        {
+                            tracing::warn!("Failed to relay typing indicator: {:?}", e);
+                        }
+                        continue;
+                    }
+
+                    // Whispers go to a single participant and bypass the room history
+                    if let Ok(whisper) = serde_json::from_str::<WhisperMessage>(&text)
+                        && whisper.r#type == MessageType::Whisper
+                    {
+                        handle_whisper(&state_clone, &client_id_clone, whisper).await;
+                        continue;
+                    }
+
                     // Parse the incoming message
-                    let chat_msg = match serde_json::from_str::<ChatMessage>(&text) {
-                        Ok(msg) => msg,
-                        Err(e) => {
-                            tracing::warn!("Failed to parse message as JSON: {}", e);
-                            // If not JSON, treat as plain text and wrap it
-                            ChatMessage {
+                    let chat_msg = if state_clone.config.strict_inbound_schema {
+                        // Strict mode: reject frames with missing or unknown fields
+                        match InboundChatMessage::parse(&text) {
+                            Ok(msg) => ChatMessage {
                                 r#type: MessageType::Chat,
-                                client_id: "unknown".to_string(),
-                                content: text.to_string(),
-                                timestamp: 0,
+                                message_id: None,
+                                client_id: msg.client_id.unwrap_or_default(),
+                                content: msg.content,
+                                timestamp: msg.timestamp,
+                                detected_lang: None,
+                                mentions: Vec::new(),
+                                has_spoiler: false,
+                                spoilers: Vec::new(),
+                            },
+                            Err(error_msg) => {
+                                tracing::warn!(
+                                    "Rejected invalid frame from '{}': {}",
+                                    client_id_str_clone,
+                                    error_msg.message
+                                );
+                                let error_json = serde_json::to_string(&error_msg).unwrap();
+                                if let Err(e) = state_clone
+                                    .send_message_usecase
+                                    .push_to_sender(&client_id_clone, &error_json)
+                                    .await
+                                {
+                                    tracing::warn!("Failed to send error frame: {}", e);
+                                }
+                                continue;
+                            }
+                        }
+                    } else {
+                        match serde_json::from_str::<ChatMessage>(&text) {
+                            Ok(msg) => msg,
+                            Err(e) => {
+                                tracing::warn!("Failed to parse message as JSON: {}", e);
+                                // If not JSON, treat as plain text and wrap it
+                                ChatMessage {
+                                    r#type: MessageType::Chat,
+                                    message_id: None,
+                                    client_id: "unknown".to_string(),
+                                    content: text.to_string(),
+                                    timestamp: 0,
+                                    detected_lang: None,
+                                    mentions: Vec::new(),
+                                    has_spoiler: false,
+                                    spoilers: Vec::new(),
+                                }
+                            }
+                        }
+                    };
+
+                    // Validate right after parsing, so that a rejected frame is answered with an
+                    // error frame before any response is built
+                    // Convert String -> Domain Models
+                    let Ok(client_id_vo) = ClientId::new_with_tenant_policy(
+                        client_id_str_clone.clone(),
+                        &state_clone.config.tenant_prefix_policy,
+                    ) else {
+                        tracing::warn!("Invalid client_id format: '{}'", client_id_str_clone);
+                        continue;
+                    };
+                    let client_timestamp = chat_msg.timestamp;
+                    let content_len = chat_msg.content.chars().count();
+                    let content_vo = match MessageContent::new_with_policy(
+                        chat_msg.content,
+                        &state_clone.config.message_content_policy,
+                    ) {
+                        Ok(content_vo) => content_vo,
+                        Err(e) => {
+                            tracing::warn!(
+                                "Invalid message content (length: {}): {}",
+                                content_len,
+                                e
+                            );
+                            state_clone
+                                .send_message_usecase
+                                .report_rejection(
+                                    &client_id_clone,
+                                    MessageRejectionReason::InvalidContent {
+                                        detail: e.to_string(),
+                                    },
+                                )
+                                .await;
+                            let error_msg = ErrorMessage::new("invalid_content", e.to_string());
+                            let error_json = serde_json::to_string(&error_msg).unwrap();
+                            if let Err(e) = state_clone
+                                .send_message_usecase
+                                .push_to_sender(&client_id_clone, &error_json)
+                                .await
+                            {
+                                tracing::warn!("Failed to send error frame: {}", e);
                             }
+                            continue;
                         }
                     };
 
-                    // Create response with type "chat" and preserve client_id
-                    let response = ChatMessage {
+                    // Create response with type "chat" and the client_id of this connection
+                    // (the assigned id may differ from the one the client put in the frame).
+                    // The content is broadcast as stored (after the content policy transforms)
+                    let content = content_vo.as_str().to_string();
+                    let spoilers: Vec<SpoilerRange> = if state_clone.config.tag_spoilers {
+                        find_spoilers(&content)
+                            .into_iter()
+                            .map(|range| SpoilerRange {
+                                start: range.start,
+                                end: range.end,
+                            })
+                            .collect()
+                    } else {
+                        Vec::new()
+                    };
+                    let mut response = ChatMessage {
                         r#type: MessageType::Chat,
-                        client_id: chat_msg.client_id.clone(),
-                        content: chat_msg.content.clone(),
-                        timestamp: chat_msg.timestamp,
+                        message_id: None,
+                        client_id: client_id_str_clone.clone(),
+                        // Set from the stored message (the client-provided timestamp is not trusted)
+                        timestamp: 0,
+                        // Language tag is attached by the server only (client-provided values are ignored)
+                        detected_lang: if state_clone.config.detect_language {
+                            detect_language(&content).map(str::to_string)
+                        } else {
+                            None
+                        },
+                        content,
+                        mentions: content_vo.mentions_within(
+                            &state_clone.config.message_content_policy.mention_limits,
+                        ),
+                        has_spoiler: !spoilers.is_empty(),
+                        spoilers,
                     };
 
-                    let response_json = serde_json::to_string(&response).unwrap();
                     tracing::info!(
                         "Broadcasting message from '{}' to other clients: {}",
                         response.client_id,
@@ -229,36 +1025,130 @@ async fn handle_socket(
                     );
 
                     // Use SendMessageUseCase to handle message sending
-                    // Convert String -> Domain Models
-                    let client_id_result = ClientId::try_from(response.client_id.clone());
-                    let content_result = MessageContent::try_from(response.content.clone());
-
-                    match (client_id_result, content_result) {
-                        (Ok(client_id_vo), Ok(content_vo)) => {
-                            match state_clone
-                                .send_message_usecase
-                                .execute(client_id_vo, content_vo, response_json)
-                                .await
-                            {
-                                Ok(_broadcast_targets) => {
-                                    // Broadcast is handled by UseCase
+                    match state_clone
+                        .send_message_usecase
+                        .execute_and_return(client_id_vo, content_vo, |message| {
+                            // The message id and the server timestamp are assigned when the
+                            // message is added to the room
+                            response.message_id = message.id.as_ref().map(ToString::to_string);
+                            response.timestamp =
+                                message.timestamp.in_unit(state_clone.config.timestamp_unit);
+                            serde_json::to_string(&response).unwrap()
+                        })
+                        .await
+                    {
+                        Ok((_, sent)) => {
+                            // Broadcast is handled by UseCase
+                            counters_clone.messages_sent.fetch_add(1, Ordering::Relaxed);
+                            if !response.mentions.is_empty() {
+                                let mention = MentionMessage {
+                                    r#type: MessageType::Mention,
+                                    message_id: sent.message_id.to_string(),
+                                    client_id: response.client_id.clone(),
+                                    content: response.content.clone(),
+                                    timestamp: response.timestamp,
+                                };
+                                let mention_json = serde_json::to_string(&mention).unwrap();
+                                if let Err(e) = state_clone
+                                    .send_message_usecase
+                                    .notify_mentions(
+                                        &client_id_clone,
+                                        &response.mentions,
+                                        &mention_json,
+                                    )
+                                    .await
+                                {
+                                    tracing::warn!("Failed to send mention: {}", e);
                                 }
-                                Err(e) => {
-                                    tracing::warn!("Failed to send message: {:?}", e);
+                            }
+                            // Compare the client clock with the server clock that stamped the
+                            // message (a frame without `timestamp` has no client reading)
+                            let clock_skew = state_clone
+                                .config
+                                .clock_skew_tolerance
+                                .filter(|_| client_timestamp != 0)
+                                .map(|tolerance| {
+                                    let unit = state_clone.config.timestamp_unit;
+                                    let skew = ClockSkew::between(
+                                        Timestamp::from_unit(client_timestamp, unit),
+                                        Timestamp::from_unit(response.timestamp, unit),
+                                    );
+                                    let exceeded = skew.exceeds(tolerance);
+                                    if exceeded {
+                                        tracing::warn!(
+                                            "Clock skew of '{}' is {} ms (tolerance {} ms)",
+                                            client_id_str_clone,
+                                            skew.as_millis(),
+                                            tolerance.as_millis()
+                                        );
+                                    }
+                                    (skew, exceeded)
+                                });
+                            if delivery_receipts {
+                                let receipt = DeliveryReceiptMessage {
+                                    r#type: MessageType::DeliveryReceipt,
+                                    message_id: sent.message_id.into_string(),
+                                    timestamp: response.timestamp,
+                                    delivered_count: sent.delivery.delivered_count,
+                                    total_targets: sent.delivery.total_targets(),
+                                    clock_skew_ms: clock_skew.map(|(skew, _)| skew.as_millis()),
+                                    clock_skew_exceeded: clock_skew
+                                        .is_some_and(|(_, exceeded)| exceeded),
+                                };
+                                let receipt_json = serde_json::to_string(&receipt).unwrap();
+                                if let Err(e) = state_clone
+                                    .send_message_usecase
+                                    .push_to_sender(&client_id_clone, &receipt_json)
+                                    .await
+                                {
+                                    tracing::warn!("Failed to send delivery receipt: {}", e);
                                 }
                             }
                         }
-                        (Err(_), _) => {
-                            tracing::warn!("Invalid client_id format: '{}'", response.client_id);
-                        }
-                        (_, Err(_)) => {
+                        Err(e) => {
                             tracing::warn!(
-                                "Invalid message content (length: {})",
-                                response.content.len()
+                                "Failed to send message from '{}': {:?}",
+                                client_id_str_clone,
+                                e
                             );
+                            push_error(&state_clone, &client_id_clone, send_error_message(&e))
+                                .await;
                         }
                     }
                 }
+                Message::Binary(data) => match state_clone.config.binary_frame_policy {
+                    BinaryFramePolicy::Reject => {
+                        tracing::warn!(
+                            "Rejected unexpected binary frame ({} bytes) from '{}'",
+                            data.len(),
+                            client_id_str_clone
+                        );
+                        let error_msg = ErrorMessage::new(
+                            "unexpected_binary",
+                            state_clone.config.localizer.localize(
+                                locale.as_deref(),
+                                MSG_UNEXPECTED_BINARY,
+                                &[],
+                            ),
+                        );
+                        let error_json = serde_json::to_string(&error_msg).unwrap();
+                        if let Err(e) = state_clone
+                            .send_message_usecase
+                            .push_to_sender(&client_id_clone, &error_json)
+                            .await
+                        {
+                            tracing::warn!("Failed to send error frame: {}", e);
+                        }
+                    }
+                    BinaryFramePolicy::Close => {
+                        tracing::warn!(
+                            "Closing connection of '{}' due to unexpected binary frame ({} bytes)",
+                            client_id_str_clone,
+                            data.len()
+                        );
+                        break;
+                    }
+                },
                 Message::Ping(_) => {
                     tracing::debug!("Received ping");
                     // Ping/pong is handled automatically by the WebSocket protocol
@@ -273,48 +1163,886 @@ async fn handle_socket(
     });
 
     // Spawn a task to receive messages from other clients and send to this client
-    let mut send_task = pusher_loop(rx, sender);
+    let (stop_tx, stop_rx) = oneshot::channel();
+    let mut send_task = pusher_loop(
+        rx,
+        sender,
+        state.config.send_timeout,
+        stop_rx,
+        state.config.drain_max_frames,
+        state.config.dedup_consecutive_frames,
+        state.config.send_batch_max_frames,
+        counters.clone(),
+        state
+            .config
+            .heartbeat
+            .map(|config| Heartbeat::new(config, liveness)),
+    );
 
-    // If any one of the tasks completes, abort the other
-    tokio::select! {
-        _ = &mut recv_task => send_task.abort(),
-        _ = &mut send_task => recv_task.abort(),
+    // If any one of the tasks completes, stop the other
+    let reason = tokio::select! {
+        _ = &mut recv_task => {
+            // Give the sender a bounded chance to flush already-queued frames before cleanup
+            let _ = stop_tx.send(());
+            match tokio::time::timeout(state.config.drain_timeout, &mut send_task).await {
+                Ok(Ok(reason)) => reason,
+                Ok(Err(_)) => DisconnectReason::Closed,
+                Err(_) => {
+                    tracing::warn!(
+                        "Draining queued frames for '{}' timed out after {:?}",
+                        client_id_str,
+                        state.config.drain_timeout
+                    );
+                    send_task.abort();
+                    DisconnectReason::Closed
+                }
+            }
+        }
+        reason = &mut send_task => {
+            recv_task.abort();
+            reason.unwrap_or(DisconnectReason::Closed)
+        }
     };
 
+    finish_connection(
+        &state, &room_id, client_id, &guard, reason, &counters, started_at,
+    )
+    .await;
+}
+
+/// End a connection: run the disconnect cleanup, release its guard and report its summary
+///
+/// Every exit of `handle_socket` after the guard is registered goes through here, so a
+/// connection that fails before its session starts (e.g. while sending `room-connected` or the
+/// backfill) does not leave its participant and guard behind.
+async fn finish_connection(
+    state: &AppState,
+    room_id: &RoomId,
+    client_id: ClientId,
+    guard: &Arc<DisconnectGuard>,
+    reason: DisconnectReason,
+    counters: &ConnectionCounters,
+    started_at: Instant,
+) {
+    // Run the disconnect cleanup (exactly once per connection)
+    cleanup_connection(state, room_id, &client_id, reason, guard).await;
+    state.disconnect_guards.release(&client_id, guard);
+
+    if state.config.connection_summary {
+        state
+            .disconnect_participant_usecase
+            .report_connection_summary(counters.summary(client_id, started_at.elapsed(), reason))
+            .await;
+    }
+}
+
+/// Deliver a whisper from `client_id` to its recipient (and echo it back to the sender)
+///
+/// Invalid content and unknown recipients are answered with an error frame.
+async fn handle_whisper(state: &AppState, client_id: &ClientId, whisper: WhisperMessage) {
+    let content_vo = match MessageContent::new_with_policy(
+        whisper.content,
+        &state.config.message_content_policy,
+    ) {
+        Ok(content_vo) => content_vo,
+        Err(e) => {
+            tracing::warn!("Invalid whisper content from '{}': {}", client_id, e);
+            state
+                .send_message_usecase
+                .report_rejection(
+                    client_id,
+                    MessageRejectionReason::InvalidContent {
+                        detail: e.to_string(),
+                    },
+                )
+                .await;
+            push_error(
+                state,
+                client_id,
+                ErrorMessage::new("invalid_content", e.to_string()),
+            )
+            .await;
+            return;
+        }
+    };
+    let result = match ClientId::new_with_tenant_policy(
+        whisper.to.clone(),
+        &state.config.tenant_prefix_policy,
+    ) {
+        Ok(to_client_id) => {
+            state
+                .send_message_usecase
+                .execute_whisper(
+                    client_id.clone(),
+                    to_client_id.clone(),
+                    content_vo,
+                    |content, timestamp| {
+                        serde_json::to_string(&WhisperMessage {
+                            r#type: MessageType::Whisper,
+                            from: client_id.to_string(),
+                            to: to_client_id.to_string(),
+                            content: content.as_str().to_string(),
+                            timestamp: timestamp.in_unit(state.config.timestamp_unit),
+                        })
+                        .unwrap()
+                    },
+                )
+                .await
+        }
+        Err(_) => Err(SendMessageError::RecipientNotFound),
+    };
+    match result {
+        Ok(()) => {}
+        Err(SendMessageError::RecipientNotFound) => {
+            tracing::warn!(
+                "Whisper from '{}' to unknown recipient '{}'",
+                client_id,
+                whisper.to
+            );
+            push_error(
+                state,
+                client_id,
+                ErrorMessage::new(
+                    "recipient_not_found",
+                    format!("'{}' is not connected", whisper.to),
+                ),
+            )
+            .await;
+        }
+        Err(e) => {
+            tracing::warn!("Failed to send whisper: {:?}", e);
+            push_error(state, client_id, send_error_message(&e)).await;
+        }
+    }
+}
+
+/// Apply an edit from `client_id` to one of its messages and broadcast it to the room
+///
+/// Invalid content, unknown messages, edits of other clients' messages and edits whose
+/// `timestamp` predates the original message are answered with an error frame.
+async fn handle_edit(state: &AppState, client_id: &ClientId, edit: EditMessage) {
+    let content_vo =
+        match MessageContent::new_with_policy(edit.content, &state.config.message_content_policy) {
+            Ok(content_vo) => content_vo,
+            Err(e) => {
+                tracing::warn!("Invalid edit content from '{}': {}", client_id, e);
+                state
+                    .send_message_usecase
+                    .report_rejection(
+                        client_id,
+                        MessageRejectionReason::InvalidContent {
+                            detail: e.to_string(),
+                        },
+                    )
+                    .await;
+                push_error(
+                    state,
+                    client_id,
+                    ErrorMessage::new("invalid_content", e.to_string()),
+                )
+                .await;
+                return;
+            }
+        };
+    // A client timestamp in seconds covers the whole second, so compare with its last millisecond
+    // to accept edits made in the same second as the original message
+    let client_timestamp = edit.timestamp.map(|timestamp| {
+        let next = Timestamp::from_unit(timestamp.saturating_add(1), state.config.timestamp_unit);
+        Timestamp::new(next.value() - 1)
+    });
+    let result = match MessageId::parse(edit.message_id.clone()) {
+        Ok(message_id) => {
+            state
+                .send_message_usecase
+                .execute_edit(
+                    client_id.clone(),
+                    message_id,
+                    content_vo,
+                    client_timestamp,
+                    |message| {
+                        serde_json::to_string(&EditMessage {
+                            r#type: MessageType::Edit,
+                            message_id: edit.message_id.clone(),
+                            client_id: client_id.to_string(),
+                            content: message.content.as_str().to_string(),
+                            edited_at: message
+                                .edited_at
+                                .unwrap_or(message.timestamp)
+                                .in_unit(state.config.timestamp_unit),
+                            timestamp: None,
+                        })
+                        .unwrap()
+                    },
+                )
+                .await
+        }
+        Err(_) => Err(SendMessageError::MessageNotFound),
+    };
+    match result {
+        Ok(_) => {}
+        Err(SendMessageError::MessageNotFound) => {
+            tracing::warn!(
+                "Edit from '{}' of unknown message '{}'",
+                client_id,
+                edit.message_id
+            );
+            push_error(
+                state,
+                client_id,
+                ErrorMessage::new(
+                    "message_not_found",
+                    format!("Message '{}' not found", edit.message_id),
+                ),
+            )
+            .await;
+        }
+        Err(SendMessageError::NotMessageAuthor) => {
+            tracing::warn!(
+                "Edit from '{}' of another client's message '{}'",
+                client_id,
+                edit.message_id
+            );
+            push_error(
+                state,
+                client_id,
+                ErrorMessage::new(
+                    "not_message_author",
+                    format!("Only the author can edit message '{}'", edit.message_id),
+                ),
+            )
+            .await;
+        }
+        Err(e) => {
+            tracing::warn!("Failed to edit message: {:?}", e);
+            push_error(state, client_id, send_error_message(&e)).await;
+        }
+    }
+}
+
+/// Delete one of `client_id`'s messages and broadcast the deletion to the room
+///
+/// Unknown messages and deletions of other clients' messages are answered with an error frame.
+async fn handle_delete(state: &AppState, client_id: &ClientId, delete: DeleteMessage) {
+    let result = match MessageId::parse(delete.message_id.clone()) {
+        Ok(message_id) => {
+            let message = serde_json::to_string(&DeleteMessage {
+                r#type: MessageType::Delete,
+                message_id: delete.message_id.clone(),
+                client_id: client_id.to_string(),
+            })
+            .unwrap();
+            state
+                .send_message_usecase
+                .execute_delete(client_id.clone(), message_id, &message)
+                .await
+        }
+        Err(_) => Err(SendMessageError::MessageNotFound),
+    };
+    match result {
+        Ok(()) => {}
+        Err(SendMessageError::MessageNotFound) => {
+            tracing::warn!(
+                "Deletion from '{}' of unknown message '{}'",
+                client_id,
+                delete.message_id
+            );
+            push_error(
+                state,
+                client_id,
+                ErrorMessage::new(
+                    "message_not_found",
+                    format!("Message '{}' not found", delete.message_id),
+                ),
+            )
+            .await;
+        }
+        Err(SendMessageError::NotMessageAuthor) => {
+            tracing::warn!(
+                "Deletion from '{}' of another client's message '{}'",
+                client_id,
+                delete.message_id
+            );
+            push_error(
+                state,
+                client_id,
+                ErrorMessage::new(
+                    "not_message_author",
+                    format!("Only the author can delete message '{}'", delete.message_id),
+                ),
+            )
+            .await;
+        }
+        Err(e) => {
+            tracing::warn!("Failed to delete message: {:?}", e);
+            push_error(state, client_id, send_error_message(&e)).await;
+        }
+    }
+}
+
+/// Build the error frame telling the sender why its message was not sent
+fn send_error_message(error: &SendMessageError) -> ErrorMessage {
+    let message = match error {
+        SendMessageError::MessageCapacityExceeded => "The room message history is full".to_string(),
+        SendMessageError::RoomLocked => "The room is locked".to_string(),
+        SendMessageError::RoomNotFound => "The room no longer exists".to_string(),
+        SendMessageError::RecipientNotFound => "The recipient is not connected".to_string(),
+        SendMessageError::SelfMessage => "You cannot whisper to yourself".to_string(),
+        SendMessageError::NotAParticipant => "You are not a participant of a room".to_string(),
+        SendMessageError::RateLimited { retry_after_ms } => {
+            format!("Too many messages, retry after {} ms", retry_after_ms)
+        }
+        SendMessageError::MessageNotFound => "The message was not found".to_string(),
+        SendMessageError::NotMessageAuthor => "Only the author can change the message".to_string(),
+        SendMessageError::StaleEdit => "The edit is older than the message".to_string(),
+        SendMessageError::BroadcastFailed(_) => {
+            "The message could not be delivered to the room".to_string()
+        }
+        // Storage details are logged but not exposed to clients
+        SendMessageError::RepositoryError(_) => "Internal server error".to_string(),
+    };
+    let error_msg = ErrorMessage::new(error.code(), message);
+    match error {
+        SendMessageError::RateLimited { retry_after_ms } => {
+            error_msg.with_retry_after_ms(*retry_after_ms)
+        }
+        _ => error_msg,
+    }
+}
+
+/// Send an error frame back to the client that caused the error
+async fn push_error(state: &AppState, client_id: &ClientId, error_msg: ErrorMessage) {
+    let error_json = serde_json::to_string(&error_msg).unwrap();
+    if let Err(e) = state
+        .send_message_usecase
+        .push_to_sender(client_id, &error_json)
+        .await
+    {
+        tracing::warn!("Failed to send error frame: {}", e);
+    }
+}
+
+/// Traffic counters of a single connection, reported as a summary when it ends
+#[derive(Debug, Default)]
+struct ConnectionCounters {
+    /// Chat messages sent by the client and accepted by the server
+    messages_sent: AtomicU64,
+    /// Frames written to the client
+    messages_received: AtomicU64,
+    /// Bytes of the frames read from the client
+    bytes_in: AtomicU64,
+    /// Bytes of the frames written to the client
+    bytes_out: AtomicU64,
+}
+
+impl ConnectionCounters {
+    /// Count a frame read from the client
+    fn record_inbound(&self, len: usize) {
+        self.bytes_in.fetch_add(len as u64, Ordering::Relaxed);
+    }
+
+    /// Count a frame written to the client
+    fn record_outbound(&self, len: usize) {
+        self.messages_received.fetch_add(1, Ordering::Relaxed);
+        self.bytes_out.fetch_add(len as u64, Ordering::Relaxed);
+    }
+
+    /// Build the summary of the ended connection
+    fn summary(
+        &self,
+        client_id: ClientId,
+        duration: Duration,
+        reason: DisconnectReason,
+    ) -> ConnectionSummary {
+        ConnectionSummary {
+            client_id,
+            duration_ms: duration.as_millis() as u64,
+            messages_sent: self.messages_sent.load(Ordering::Relaxed),
+            messages_received: self.messages_received.load(Ordering::Relaxed),
+            bytes_in: self.bytes_in.load(Ordering::Relaxed),
+            bytes_out: self.bytes_out.load(Ordering::Relaxed),
+            reason,
+        }
+    }
+}
+
+/// Periodic `room-stats` stream of a client subscribed to room stats
+///
+/// The stream is stopped when the subscription is dropped (explicit unsubscribe or disconnect).
+struct StatsSubscription(tokio::task::JoinHandle<()>);
+
+impl StatsSubscription {
+    /// Start sending `room-stats` frames of the client's room every `stats_interval`
+    fn spawn(state: Arc<AppState>, room_id: RoomId, client_id: ClientId) -> Self {
+        Self(tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(state.config.stats_interval);
+            loop {
+                ticker.tick().await;
+                let stats = match state
+                    .get_room_stats_usecase
+                    .execute(&room_id, state.clock.now())
+                    .await
+                {
+                    Ok(stats) => stats,
+                    Err(e) => {
+                        tracing::warn!("Failed to get room stats: {}", e);
+                        continue;
+                    }
+                };
+                let stats_msg = RoomStatsMessage {
+                    r#type: MessageType::RoomStats,
+                    participant_count: stats.participant_count,
+                    messages_per_minute: stats.messages_per_minute,
+                    timestamp: state.clock.now().in_unit(state.config.timestamp_unit),
+                };
+                let Ok(json) = serde_json::to_string(&stats_msg) else {
+                    continue;
+                };
+                if let Err(e) = state
+                    .get_room_stats_usecase
+                    .push_stats(&client_id, &json)
+                    .await
+                {
+                    tracing::debug!("Stopping room stats for '{}': {}", client_id, e);
+                    break;
+                }
+            }
+        }))
+    }
+}
+
+impl Drop for StatsSubscription {
+    fn drop(&mut self) {
+        self.0.abort();
+    }
+}
+
+/// Remove the participant of a closed connection and notify the remaining participants
+///
+/// # Arguments
+///
+/// * `state` - Shared application state
+/// * `room_id` - Room the client was connected to
+/// * `client_id` - Client id of the closed connection
+/// * `reason` - Why the connection was closed
+/// * `guard` - Disconnect flag of the connection (the cleanup is skipped if already disconnected)
+async fn cleanup_connection(
+    state: &AppState,
+    room_id: &RoomId,
+    client_id: &ClientId,
+    reason: DisconnectReason,
+    guard: &DisconnectGuard,
+) {
+    if !guard.claim() {
+        tracing::debug!(
+            "Client '{}' is already disconnected (reason: {})",
+            client_id,
+            reason
+        );
+        return;
+    }
+
+    // Messages still awaiting an ack from this client time out as undelivered
+    state.send_message_usecase.release_acks(client_id);
+    state.send_message_usecase.release_rate_limit(client_id);
+
     // Use DisconnectParticipantUseCase to handle disconnection
     // (client_id is already a ClientId Domain Model)
     match state
         .disconnect_participant_usecase
-        .execute(client_id.clone())
+        .execute(client_id.clone(), reason)
         .await
     {
         Ok(notify_targets) => {
             tracing::info!(
-                "Client '{}' disconnected and removed from registry",
-                client_id_str
+                "Client '{}' disconnected and removed from registry (reason: {})",
+                client_id,
+                reason
             );
+            announce_departure(state, room_id, client_id, notify_targets).await;
+        }
+        Err(_) => {
+            tracing::warn!("Failed to disconnect participant '{}'", client_id);
+        }
+    }
+}
 
-            // Broadcast participant-left to all remaining clients
-            let disconnected_at = get_jst_timestamp();
-            let left_msg = ParticipantLeftMessage {
-                r#type: MessageType::ParticipantLeft,
-                client_id: client_id_str.clone(),
-                disconnected_at,
-            };
+/// Claim the disconnect of the open connection of `client_id` for a server-side cause
+///
+/// Used by the kick and room removal endpoints. Returns `false` if the connection has already
+/// been disconnected by another cause. Otherwise the connection skips its own cleanup when its
+/// socket closes, so the acks and rate limit of the client are released here.
+pub fn claim_disconnect(state: &AppState, client_id: &ClientId) -> bool {
+    if !state.disconnect_guards.claim(client_id) {
+        return false;
+    }
+    state.send_message_usecase.release_acks(client_id);
+    state.send_message_usecase.release_rate_limit(client_id);
+    true
+}
 
-            let left_json = serde_json::to_string(&left_msg).unwrap();
-            if let Err(e) = state
-                .disconnect_participant_usecase
-                .broadcast_participant_left(notify_targets, &left_json)
-                .await
-            {
-                tracing::warn!("Failed to broadcast participant-left: {}", e);
-            } else {
-                tracing::info!("Broadcasted participant-left for '{}'", client_id_str);
+/// Notify the remaining participants that `client_id` left the room
+///
+/// Broadcasts `participant-left` to `notify_targets`, followed by the remaining participant count
+/// of `room_id`.
+pub async fn announce_departure(
+    state: &AppState,
+    room_id: &RoomId,
+    client_id: &ClientId,
+    notify_targets: Vec<ClientId>,
+) {
+    // Broadcast participant-left to all remaining clients
+    let disconnected_at = state.clock.now();
+    let left_msg = ParticipantLeftMessage {
+        r#type: MessageType::ParticipantLeft,
+        client_id: client_id.as_str().to_string(),
+        disconnected_at: disconnected_at.in_unit(state.config.timestamp_unit),
+        disconnected_at_iso: timestamp_to_rfc3339(
+            disconnected_at.as_millis(),
+            state.config.utc_offset,
+        ),
+    };
+
+    let left_json = serde_json::to_string(&left_msg).unwrap();
+    if let Err(e) = state
+        .disconnect_participant_usecase
+        .broadcast_participant_left(notify_targets, &left_json)
+        .await
+    {
+        tracing::warn!("Failed to broadcast participant-left: {}", e);
+    } else {
+        tracing::info!("Broadcasted participant-left for '{}'", client_id);
+    }
+
+    // Broadcast the remaining participant count to the remaining clients
+    let count_msg = ParticipantCountMessage {
+        r#type: MessageType::ParticipantCount,
+        count: state
+            .disconnect_participant_usecase
+            .count_remaining_participants(room_id)
+            .await,
+    };
+    let count_json = serde_json::to_string(&count_msg).unwrap();
+    if let Err(e) = state
+        .disconnect_participant_usecase
+        .broadcast_participant_count(room_id, &count_json)
+        .await
+    {
+        tracing::warn!("Failed to broadcast participant-count: {}", e);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::{config::DEFAULT_DRAIN_MAX_FRAMES, heartbeat::HeartbeatConfig};
+    use std::{
+        pin::Pin,
+        task::{Context, Poll},
+    };
+
+    /// A sink that never accepts a frame, like a socket whose peer stopped reading
+    struct StalledSink;
+
+    impl Sink<Message> for StalledSink {
+        type Error = axum::Error;
+
+        fn poll_ready(
+            self: Pin<&mut Self>,
+            _cx: &mut Context<'_>,
+        ) -> Poll<Result<(), Self::Error>> {
+            Poll::Pending
+        }
+
+        fn start_send(self: Pin<&mut Self>, _item: Message) -> Result<(), Self::Error> {
+            Ok(())
+        }
+
+        fn poll_flush(
+            self: Pin<&mut Self>,
+            _cx: &mut Context<'_>,
+        ) -> Poll<Result<(), Self::Error>> {
+            Poll::Pending
+        }
+
+        fn poll_close(
+            self: Pin<&mut Self>,
+            _cx: &mut Context<'_>,
+        ) -> Poll<Result<(), Self::Error>> {
+            Poll::Pending
+        }
+    }
+
+    /// A sink that accepts every frame and records its text
+    struct RecordingSink(Arc<std::sync::Mutex<Vec<String>>>);
+
+    impl Sink<Message> for RecordingSink {
+        type Error = axum::Error;
+
+        fn poll_ready(
+            self: Pin<&mut Self>,
+            _cx: &mut Context<'_>,
+        ) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn start_send(self: Pin<&mut Self>, item: Message) -> Result<(), Self::Error> {
+            if let Message::Text(text) = item {
+                self.0.lock().unwrap().push(text.to_string());
             }
+            Ok(())
         }
-        Err(_) => {
-            tracing::warn!("Failed to disconnect participant '{}'", client_id_str);
+
+        fn poll_flush(
+            self: Pin<&mut Self>,
+            _cx: &mut Context<'_>,
+        ) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
         }
+
+        fn poll_close(
+            self: Pin<&mut Self>,
+            _cx: &mut Context<'_>,
+        ) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_pusher_loop_stops_on_send_timeout() {
+        // テスト項目: 送信が詰まったクライアントは、待ち続けずに送信タイムアウトで切断される
+        // given (前提条件):
+        let (tx, rx) = pusher_channel();
+        let (_stop_tx, stop_rx) = oneshot::channel();
+        let handle = pusher_loop(
+            rx,
+            StalledSink,
+            Some(Duration::from_millis(50)),
+            stop_rx,
+            DEFAULT_DRAIN_MAX_FRAMES,
+            false,
+            1,
+            Arc::default(),
+            None,
+        );
+
+        // when (操作):
+        tx.send("hello".to_string()).unwrap();
+        let result = tokio::time::timeout(Duration::from_secs(2), handle).await;
+
+        // then (期待する結果):
+        assert_eq!(result.unwrap().unwrap(), DisconnectReason::Timeout);
+        // 送信ループが終了したため、チャンネルの受信側は閉じている
+        assert!(tx.send("world".to_string()).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_pusher_loop_drains_queued_frames_on_close() {
+        // テスト項目: 接続の終了時に、キューに残っているフレームを上限まで送信してから送信ループが終了する
+        // given (前提条件):
+        let (tx, rx) = pusher_channel();
+        let (stop_tx, stop_rx) = oneshot::channel();
+        let frames = Arc::new(std::sync::Mutex::new(Vec::new()));
+        for content in ["first", "second", "third"] {
+            tx.send(content.to_string()).unwrap();
+        }
+
+        // when (操作): 送信ループの開始前に終了を通知する
+        stop_tx.send(()).unwrap();
+        let handle = pusher_loop(
+            rx,
+            RecordingSink(frames.clone()),
+            None,
+            stop_rx,
+            DEFAULT_DRAIN_MAX_FRAMES,
+            false,
+            1,
+            Arc::default(),
+            None,
+        );
+        let result = tokio::time::timeout(Duration::from_secs(2), handle).await;
+
+        // then (期待する結果):
+        assert_eq!(result.unwrap().unwrap(), DisconnectReason::Closed);
+        assert_eq!(*frames.lock().unwrap(), vec!["first", "second", "third"]);
+    }
+
+    #[tokio::test]
+    async fn test_pusher_loop_suppresses_repeated_frames() {
+        // テスト項目: 直前と同一のフレームは送信されず、同じ内容でもメッセージ ID が異なるフレームは送信される
+        // given (前提条件):
+        let (tx, rx) = pusher_channel();
+        let (_stop_tx, stop_rx) = oneshot::channel();
+        let frames = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let first = r#"{"type":"chat","message_id":"room:1","content":"hi"}"#;
+        let second = r#"{"type":"chat","message_id":"room:2","content":"hi"}"#;
+        for frame in [first, first, second] {
+            tx.send(frame.to_string()).unwrap();
+        }
+        drop(tx);
+
+        // when (操作):
+        let handle = pusher_loop(
+            rx,
+            RecordingSink(frames.clone()),
+            None,
+            stop_rx,
+            DEFAULT_DRAIN_MAX_FRAMES,
+            true,
+            1,
+            Arc::default(),
+            None,
+        );
+        let result = tokio::time::timeout(Duration::from_secs(2), handle).await;
+
+        // then (期待する結果):
+        assert_eq!(result.unwrap().unwrap(), DisconnectReason::Closed);
+        assert_eq!(*frames.lock().unwrap(), vec![first, second]);
+    }
+
+    /// A sink that accepts every frame and counts the flushes
+    #[derive(Default)]
+    struct FlushCountingSink {
+        frames: Arc<std::sync::Mutex<Vec<String>>>,
+        flushes: Arc<AtomicU64>,
+    }
+
+    impl Sink<Message> for FlushCountingSink {
+        type Error = axum::Error;
+
+        fn poll_ready(
+            self: Pin<&mut Self>,
+            _cx: &mut Context<'_>,
+        ) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn start_send(self: Pin<&mut Self>, item: Message) -> Result<(), Self::Error> {
+            if let Message::Text(text) = item {
+                self.frames.lock().unwrap().push(text.to_string());
+            }
+            Ok(())
+        }
+
+        fn poll_flush(
+            self: Pin<&mut Self>,
+            _cx: &mut Context<'_>,
+        ) -> Poll<Result<(), Self::Error>> {
+            self.flushes.fetch_add(1, Ordering::Relaxed);
+            Poll::Ready(Ok(()))
+        }
+
+        fn poll_close(
+            self: Pin<&mut Self>,
+            _cx: &mut Context<'_>,
+        ) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_pusher_loop_batches_burst() {
+        // テスト項目: バースト時にキューに溜まったフレームがまとめて送信され、全てのフレームが順序どおりに少ない flush 回数で届く
+        // given (前提条件):
+        let (tx, rx) = pusher_channel();
+        let (_stop_tx, stop_rx) = oneshot::channel();
+        let sink = FlushCountingSink::default();
+        let frames = sink.frames.clone();
+        let flushes = sink.flushes.clone();
+        let burst: Vec<String> = (0..1000).map(|i| format!("frame-{}", i)).collect();
+        for frame in &burst {
+            tx.send(frame.clone()).unwrap();
+        }
+        drop(tx);
+
+        // when (操作):
+        let handle = pusher_loop(
+            rx,
+            sink,
+            None,
+            stop_rx,
+            DEFAULT_DRAIN_MAX_FRAMES,
+            false,
+            64,
+            Arc::default(),
+            None,
+        );
+        let result = tokio::time::timeout(Duration::from_secs(2), handle).await;
+
+        // then (期待する結果):
+        assert_eq!(result.unwrap().unwrap(), DisconnectReason::Closed);
+        assert_eq!(*frames.lock().unwrap(), burst);
+        // 1000 フレームを 64 件ずつ書き込むため、flush は 16 回（+ close 時の 1 回）
+        assert!(flushes.load(Ordering::Relaxed) <= 17);
+    }
+
+    /// A sink that accepts every frame and counts the pings, like a socket whose peer never answers
+    #[derive(Default)]
+    struct PingCountingSink(Arc<AtomicU64>);
+
+    impl Sink<Message> for PingCountingSink {
+        type Error = axum::Error;
+
+        fn poll_ready(
+            self: Pin<&mut Self>,
+            _cx: &mut Context<'_>,
+        ) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn start_send(self: Pin<&mut Self>, item: Message) -> Result<(), Self::Error> {
+            if let Message::Ping(_) = item {
+                self.0.fetch_add(1, Ordering::Relaxed);
+            }
+            Ok(())
+        }
+
+        fn poll_flush(
+            self: Pin<&mut Self>,
+            _cx: &mut Context<'_>,
+        ) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn poll_close(
+            self: Pin<&mut Self>,
+            _cx: &mut Context<'_>,
+        ) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_pusher_loop_stops_on_heartbeat_timeout() {
+        // テスト項目: ping に応答しない（何も送ってこない）クライアントは、ping を送った後にハートビートのタイムアウトで切断される
+        // given (前提条件):
+        let (_tx, rx) = pusher_channel();
+        let (_stop_tx, stop_rx) = oneshot::channel();
+        let sink = PingCountingSink::default();
+        let pings = sink.0.clone();
+        let heartbeat = Heartbeat::new(
+            HeartbeatConfig {
+                interval: Duration::from_millis(50),
+                timeout: Duration::from_millis(200),
+            },
+            Arc::new(Liveness::new(Instant::now())),
+        );
+
+        // when (操作):
+        let handle = pusher_loop(
+            rx,
+            sink,
+            None,
+            stop_rx,
+            DEFAULT_DRAIN_MAX_FRAMES,
+            false,
+            1,
+            Arc::default(),
+            Some(heartbeat),
+        );
+        let result = tokio::time::timeout(Duration::from_secs(2), handle).await;
+
+        // then (期待する結果):
+        assert_eq!(result.unwrap().unwrap(), DisconnectReason::HeartbeatTimeout);
+        assert!(pings.load(Ordering::Relaxed) >= 2);
     }
 }
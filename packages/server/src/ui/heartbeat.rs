@@ -0,0 +1,104 @@
+//! Per-connection heartbeat.
+//!
+//! A client that silently goes away (e.g. a dropped network) never closes its socket,
+//! so its participant would linger and its client_id could not be used to reconnect.
+//! The server sends a WebSocket `Ping` every interval and treats the client as gone
+//! once nothing (a `Pong` or any other frame) has been read from it within the timeout.
+
+use std::{
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use tokio::time::{Interval, MissedTickBehavior};
+
+/// Heartbeat interval and timeout of a connection
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HeartbeatConfig {
+    /// Time between two `Ping` frames
+    pub interval: Duration,
+    /// Time without any frame from the client after which it is treated as gone
+    pub timeout: Duration,
+}
+
+/// Time of the last frame read from a client, shared by the receive loop and the heartbeat
+#[derive(Debug)]
+pub struct Liveness {
+    last_seen: Mutex<Instant>,
+}
+
+impl Liveness {
+    /// Create a liveness tracker for a client seen at `now`
+    pub fn new(now: Instant) -> Self {
+        Self {
+            last_seen: Mutex::new(now),
+        }
+    }
+
+    /// Record a frame read from the client at `now`
+    pub fn touch(&self, now: Instant) {
+        *self.last_seen.lock().unwrap() = now;
+    }
+
+    /// Whether nothing has been read from the client within `timeout` before `now`
+    pub fn is_expired(&self, now: Instant, timeout: Duration) -> bool {
+        now.saturating_duration_since(*self.last_seen.lock().unwrap()) >= timeout
+    }
+}
+
+/// Heartbeat of a single connection
+#[derive(Debug)]
+pub struct Heartbeat {
+    timeout: Duration,
+    liveness: Arc<Liveness>,
+    ticker: Interval,
+}
+
+impl Heartbeat {
+    /// Start a heartbeat whose first `Ping` is due one interval from now
+    pub fn new(config: HeartbeatConfig, liveness: Arc<Liveness>) -> Self {
+        let mut ticker = tokio::time::interval_at(
+            tokio::time::Instant::now() + config.interval,
+            config.interval,
+        );
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        Self {
+            timeout: config.timeout,
+            liveness,
+            ticker,
+        }
+    }
+
+    /// Wait until the next `Ping` is due
+    ///
+    /// Returns `false` if the client has been silent for longer than the timeout.
+    pub async fn tick(&mut self) -> bool {
+        self.ticker.tick().await;
+        !self.liveness.is_expired(Instant::now(), self.timeout)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_liveness_expires_without_frames() {
+        // テスト項目: 最後にフレームを受信してからタイムアウト以上経過すると期限切れになり、受信すると期限が延びる
+        // given (前提条件):
+        let now = Instant::now();
+        let liveness = Liveness::new(now);
+        let timeout = Duration::from_secs(3);
+
+        // when (操作):
+        let before_timeout = liveness.is_expired(now + Duration::from_secs(2), timeout);
+        let after_timeout = liveness.is_expired(now + Duration::from_secs(3), timeout);
+        liveness.touch(now + Duration::from_secs(2));
+        let after_touch = liveness.is_expired(now + Duration::from_secs(3), timeout);
+
+        // then (期待する結果):
+        assert!(!before_timeout);
+        assert!(after_timeout);
+        assert!(!after_touch);
+    }
+}
@@ -0,0 +1,90 @@
+//! Per-IP connection limiting.
+//!
+//! A single address opening many connections with distinct client_ids is not caught by the
+//! per-client reconnect limit and can exhaust the server. Open connections are counted per
+//! peer IP; a permit is held for the lifetime of each connection and released on drop.
+
+use std::{
+    collections::HashMap,
+    net::IpAddr,
+    sync::{Arc, Mutex},
+};
+
+/// Number of open connections per peer IP
+#[derive(Debug, Default)]
+pub struct IpConnectionLimiter {
+    counts: Arc<Mutex<HashMap<IpAddr, usize>>>,
+}
+
+impl IpConnectionLimiter {
+    /// Count a new connection from `ip` if it has fewer than `max_connections` open
+    ///
+    /// Returns `None` when the limit is reached. The returned permit releases the
+    /// connection when dropped.
+    pub fn try_acquire(&self, ip: IpAddr, max_connections: usize) -> Option<IpConnectionPermit> {
+        let mut counts = self.counts.lock().unwrap();
+        let count = counts.entry(ip).or_default();
+        if *count >= max_connections {
+            return None;
+        }
+        *count += 1;
+        Some(IpConnectionPermit {
+            counts: self.counts.clone(),
+            ip,
+        })
+    }
+
+    /// Number of open connections from `ip`
+    pub fn count(&self, ip: IpAddr) -> usize {
+        self.counts.lock().unwrap().get(&ip).copied().unwrap_or(0)
+    }
+}
+
+/// An open connection counted by [`IpConnectionLimiter`], released on drop
+#[derive(Debug)]
+pub struct IpConnectionPermit {
+    counts: Arc<Mutex<HashMap<IpAddr, usize>>>,
+    ip: IpAddr,
+}
+
+impl Drop for IpConnectionPermit {
+    fn drop(&mut self) {
+        let mut counts = self.counts.lock().unwrap();
+        if let Some(count) = counts.get_mut(&self.ip) {
+            *count -= 1;
+            if *count == 0 {
+                counts.remove(&self.ip);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_connections_beyond_limit_rejected_until_released() {
+        // テスト項目: 同じ IP からの接続は上限まで許可され、接続が閉じる（許可が破棄される）と再び許可される
+        // given (前提条件):
+        let limiter = IpConnectionLimiter::default();
+        let ip: IpAddr = "192.0.2.1".parse().unwrap();
+        let other_ip: IpAddr = "192.0.2.2".parse().unwrap();
+
+        // when (操作):
+        let first = limiter.try_acquire(ip, 2);
+        let second = limiter.try_acquire(ip, 2);
+        let third = limiter.try_acquire(ip, 2);
+        let other = limiter.try_acquire(other_ip, 2);
+
+        // then (期待する結果):
+        assert!(first.is_some());
+        assert!(second.is_some());
+        assert!(third.is_none());
+        assert!(other.is_some());
+
+        drop(first);
+        assert_eq!(limiter.count(ip), 1);
+        assert!(limiter.try_acquire(ip, 2).is_some());
+    }
+}
@@ -0,0 +1,138 @@
+//! Pre-parse limits on inbound JSON frames.
+//!
+//! Even under the frame size limit, a deeply nested or very wide JSON payload is costly to
+//! parse (several `serde_json::from_str` attempts are made per frame). Frames that look like
+//! JSON are scanned once, without building any value, and rejected before parsing when they
+//! exceed the nesting depth or the number of elements in a single array/object.
+
+/// Default maximum nesting depth of an inbound JSON frame
+pub const DEFAULT_JSON_MAX_DEPTH: usize = 32;
+
+/// Default maximum number of elements in a single array/object of an inbound JSON frame
+pub const DEFAULT_JSON_MAX_ELEMENTS: usize = 1024;
+
+/// Limits on the structure of inbound JSON frames
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct JsonLimits {
+    /// Maximum nesting depth of arrays/objects
+    pub max_depth: usize,
+    /// Maximum number of elements (or members) in a single array/object
+    pub max_elements: usize,
+}
+
+impl Default for JsonLimits {
+    fn default() -> Self {
+        Self {
+            max_depth: DEFAULT_JSON_MAX_DEPTH,
+            max_elements: DEFAULT_JSON_MAX_ELEMENTS,
+        }
+    }
+}
+
+/// Reason a frame exceeds the JSON limits
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+pub enum JsonLimitError {
+    /// Arrays/objects are nested deeper than allowed
+    #[error("JSON nesting depth exceeds {max}")]
+    TooDeep { max: usize },
+    /// An array/object has more elements than allowed
+    #[error("JSON array or object has more than {max} elements")]
+    TooManyElements { max: usize },
+}
+
+impl JsonLimits {
+    /// Check the structure of a frame before parsing it
+    ///
+    /// Only frames that look like JSON (starting with `{` or `[`) are checked, so plain-text
+    /// chat frames are unaffected. Brackets and commas inside strings are ignored.
+    pub fn check(&self, text: &str) -> Result<(), JsonLimitError> {
+        if !text.trim_start().starts_with(['{', '[']) {
+            return Ok(());
+        }
+        // Elements seen so far in each open array/object (bounded by `max_depth`)
+        let mut open: Vec<usize> = Vec::new();
+        let mut in_string = false;
+        let mut escaped = false;
+        for byte in text.bytes() {
+            if in_string {
+                match byte {
+                    _ if escaped => escaped = false,
+                    b'\\' => escaped = true,
+                    b'"' => in_string = false,
+                    _ => {}
+                }
+                continue;
+            }
+            match byte {
+                b'"' => in_string = true,
+                b'{' | b'[' => {
+                    if open.len() >= self.max_depth {
+                        return Err(JsonLimitError::TooDeep {
+                            max: self.max_depth,
+                        });
+                    }
+                    open.push(1);
+                }
+                b'}' | b']' => {
+                    open.pop();
+                }
+                b',' => {
+                    if let Some(elements) = open.last_mut() {
+                        *elements += 1;
+                        if *elements > self.max_elements {
+                            return Err(JsonLimitError::TooManyElements {
+                                max: self.max_elements,
+                            });
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normal_chat_frame_passes() {
+        // テスト項目: 通常のチャットフレームと、JSON でないテキストは制限に掛からない
+        // given (前提条件):
+        let limits = JsonLimits::default();
+        let frame =
+            r#"{"type":"chat","client_id":"alice","content":"[[[{{{ , , ,","timestamp":1000}"#;
+
+        // when (操作):
+        let frame_result = limits.check(frame);
+        let plain_result = limits.check("hello [world]");
+
+        // then (期待する結果):
+        assert_eq!(frame_result, Ok(()));
+        assert_eq!(plain_result, Ok(()));
+    }
+
+    #[test]
+    fn test_deep_or_wide_json_rejected() {
+        // テスト項目: 深さの上限を超えてネストした JSON と、要素数の上限を超える配列は拒否される
+        // given (前提条件):
+        let limits = JsonLimits {
+            max_depth: 4,
+            max_elements: 3,
+        };
+        let deep = format!("{}{}", "[".repeat(5), "]".repeat(5));
+        let wide = r#"{"type":"chat","content":"hi","extra":[1,2,3,4]}"#;
+
+        // when (操作):
+        let deep_result = limits.check(&deep);
+        let wide_result = limits.check(wide);
+        let within_result = limits.check(r#"[[[[1,2,3]]]]"#);
+
+        // then (期待する結果):
+        assert_eq!(deep_result, Err(JsonLimitError::TooDeep { max: 4 }));
+        assert_eq!(wide_result, Err(JsonLimitError::TooManyElements { max: 3 }));
+        assert_eq!(within_result, Ok(()));
+    }
+}
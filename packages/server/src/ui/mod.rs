@@ -1,8 +1,28 @@
 //! WebSocket chat server implementation.
 
+mod args;
+mod auth;
+mod builder;
+mod config;
+mod disconnect_guard;
 mod handler;
+mod heartbeat;
+mod ip_connection_limit;
+mod json_limit;
+mod read_rate_limit;
+mod reconnect_limit;
 mod server;
+mod shutdown;
 mod signal;
 pub mod state; // UseCase 層からアクセスするため public に変更
 
+pub use args::ServerArgs;
+pub use builder::ServerBuilder;
+pub use config::{
+    BinaryFramePolicy, DEFAULT_DRAIN_MAX_FRAMES, DEFAULT_DRAIN_TIMEOUT, DEFAULT_STATS_INTERVAL,
+    ServerConfig,
+};
+pub use heartbeat::HeartbeatConfig;
+pub use json_limit::{DEFAULT_JSON_MAX_DEPTH, DEFAULT_JSON_MAX_ELEMENTS, JsonLimits};
+pub use reconnect_limit::ReconnectLimit;
 pub use server::Server;
@@ -0,0 +1,91 @@
+//! Per-connection inbound read rate limiting.
+//!
+//! Unlike the message quota in the domain, this caps how fast frames are read from the socket.
+//! Once a connection has read its budget for the current one-second window, the receive loop
+//! stops reading until the window ends, so a flooding client is throttled by TCP backpressure
+//! instead of having its frames processed (or dropped).
+
+use std::time::{Duration, Instant};
+
+/// Length of the window the read budget applies to
+const READ_RATE_WINDOW: Duration = Duration::from_secs(1);
+
+/// Fixed-window read rate limiter of a single connection
+#[derive(Debug)]
+pub struct ReadRateLimiter {
+    /// Number of frames allowed per window
+    max_frames_per_sec: u32,
+    /// Start of the current window (`None` until the first frame)
+    window_start: Option<Instant>,
+    /// Number of frames read in the current window
+    frames: u32,
+}
+
+impl ReadRateLimiter {
+    /// Create a limiter allowing `max_frames_per_sec` frames per second
+    pub fn new(max_frames_per_sec: u32) -> Self {
+        Self {
+            max_frames_per_sec: max_frames_per_sec.max(1),
+            window_start: None,
+            frames: 0,
+        }
+    }
+
+    /// Record a frame read at `now` and return how long to stop reading before processing it
+    ///
+    /// A frame over the budget starts the next window, which begins once the wait is over.
+    pub fn record(&mut self, now: Instant) -> Option<Duration> {
+        let window_start = match self.window_start {
+            Some(start) if now.duration_since(start) < READ_RATE_WINDOW => start,
+            _ => {
+                self.window_start = Some(now);
+                self.frames = 1;
+                return None;
+            }
+        };
+        if self.frames < self.max_frames_per_sec {
+            self.frames += 1;
+            return None;
+        }
+        let next_window = window_start + READ_RATE_WINDOW;
+        self.window_start = Some(next_window);
+        self.frames = 1;
+        Some(next_window.duration_since(now))
+    }
+
+    /// Record a frame read now, waiting out the rest of the window when over the budget
+    pub async fn throttle(&mut self) {
+        if let Some(wait) = self.record(Instant::now()) {
+            tracing::debug!("Inbound read rate exceeded, pausing reads for {:?}", wait);
+            tokio::time::sleep(wait).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_frames_beyond_budget_wait_for_next_window() {
+        // テスト項目: ウィンドウ内で上限を超えたフレームは次のウィンドウの開始まで待たされ、待った後は再び上限まで読み込める
+        // given (前提条件):
+        let mut limiter = ReadRateLimiter::new(2);
+        let now = Instant::now();
+
+        // when (操作):
+        let first = limiter.record(now);
+        let second = limiter.record(now + Duration::from_millis(100));
+        let third = limiter.record(now + Duration::from_millis(200));
+        let fourth = limiter.record(now + Duration::from_millis(1000));
+        let fifth = limiter.record(now + Duration::from_millis(1100));
+
+        // then (期待する結果):
+        assert_eq!(first, None);
+        assert_eq!(second, None);
+        assert_eq!(third, Some(Duration::from_millis(800)));
+        // 3 件目が次のウィンドウ（1000ms から）の 1 件目として数えられている
+        assert_eq!(fourth, None);
+        assert_eq!(fifth, Some(Duration::from_millis(900)));
+    }
+}
@@ -0,0 +1,107 @@
+//! Per-client reconnect rate limiting.
+//!
+//! A client stuck in a reconnect loop is limited to a number of connection attempts
+//! within a sliding window. Attempts are tracked per normalized client_id.
+
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+/// Maximum number of connection attempts per client within a sliding window
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReconnectLimit {
+    /// Number of attempts allowed within the window
+    pub max_attempts: usize,
+    /// Length of the sliding window
+    pub window: Duration,
+}
+
+/// Sliding-window reconnect rate limiter keyed by client_id
+#[derive(Debug, Default)]
+pub struct ReconnectLimiter {
+    attempts: Mutex<HashMap<String, VecDeque<Instant>>>,
+}
+
+impl ReconnectLimiter {
+    /// Record a connection attempt and check whether it is within the limit
+    ///
+    /// Rejected attempts are not recorded, so a client that backs off is allowed again
+    /// once its earlier attempts leave the window.
+    pub fn try_acquire(&self, client_id: &str, limit: ReconnectLimit, now: Instant) -> bool {
+        let key = normalize(client_id);
+        let mut attempts = self.attempts.lock().unwrap();
+
+        // Forget clients whose attempts have all left the window
+        attempts.retain(|_, timestamps| {
+            while timestamps
+                .front()
+                .is_some_and(|t| now.duration_since(*t) >= limit.window)
+            {
+                timestamps.pop_front();
+            }
+            !timestamps.is_empty()
+        });
+
+        let timestamps = attempts.entry(key).or_default();
+        if timestamps.len() >= limit.max_attempts {
+            return false;
+        }
+        timestamps.push_back(now);
+        true
+    }
+}
+
+/// Normalize a client_id so that case variations share the same limit
+fn normalize(client_id: &str) -> String {
+    client_id.trim().to_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LIMIT: ReconnectLimit = ReconnectLimit {
+        max_attempts: 2,
+        window: Duration::from_secs(60),
+    };
+
+    #[test]
+    fn test_rapid_reconnects_beyond_limit_rejected() {
+        // テスト項目: ウィンドウ内で上限を超える接続試行は拒否され、ID は正規化して数えられる
+        // given (前提条件):
+        let limiter = ReconnectLimiter::default();
+        let now = Instant::now();
+
+        // when (操作):
+        let first = limiter.try_acquire("alice", LIMIT, now);
+        let second = limiter.try_acquire("Alice", LIMIT, now + Duration::from_secs(1));
+        let third = limiter.try_acquire("alice", LIMIT, now + Duration::from_secs(2));
+        let other_client = limiter.try_acquire("bob", LIMIT, now + Duration::from_secs(2));
+
+        // then (期待する結果):
+        assert!(first);
+        assert!(second);
+        assert!(!third);
+        assert!(other_client);
+    }
+
+    #[test]
+    fn test_spaced_reconnects_allowed() {
+        // テスト項目: 以前の試行がウィンドウの外に出れば再び接続できる
+        // given (前提条件):
+        let limiter = ReconnectLimiter::default();
+        let now = Instant::now();
+        assert!(limiter.try_acquire("alice", LIMIT, now));
+        assert!(limiter.try_acquire("alice", LIMIT, now + Duration::from_secs(30)));
+
+        // when (操作):
+        let within_window = limiter.try_acquire("alice", LIMIT, now + Duration::from_secs(59));
+        let after_window = limiter.try_acquire("alice", LIMIT, now + Duration::from_secs(60));
+
+        // then (期待する結果):
+        assert!(!within_window);
+        assert!(after_window);
+    }
+}
@@ -1,16 +1,33 @@
 //! Server execution logic.
 
-use std::sync::Arc;
+use std::{future::Future, net::SocketAddr, sync::Arc};
 
-use axum::{Router, routing::get};
+use axum::{
+    Router, middleware,
+    routing::{delete, get, patch, post},
+};
+use tokio::net::TcpListener;
 
+use crate::domain::{Clock, SystemClock};
 use crate::usecase::{
-    ConnectParticipantUseCase, DisconnectParticipantUseCase, GetRoomDetailUseCase,
-    GetRoomStateUseCase, GetRoomsUseCase, SendMessageUseCase,
+    ConnectParticipantUseCase, CreateRoomUseCase, DisconnectParticipantUseCase,
+    GetMessageHistoryUseCase, GetRoomDetailUseCase, GetRoomStateUseCase, GetRoomStatsUseCase,
+    GetRoomsUseCase, RemoveRoomUseCase, SendMessageUseCase, ShutdownServerUseCase,
+    UpdateRoomUseCase,
 };
 
 use super::{
-    handler::{debug_room_state, get_room_detail, get_rooms, health_check, websocket_handler},
+    auth::require_api_token,
+    config::ServerConfig,
+    disconnect_guard::DisconnectGuards,
+    handler::{
+        create_room, debug_room_state, get_capabilities, get_message_history, get_metrics,
+        get_room_detail, get_rooms, health_check, kick_participant, remove_room, update_room,
+        websocket_handler,
+    },
+    ip_connection_limit::IpConnectionLimiter,
+    reconnect_limit::ReconnectLimiter,
+    shutdown::{ShutdownState, reject_while_shutting_down, shutdown_sequence},
     signal::shutdown_signal,
     state::AppState,
 };
@@ -26,7 +43,17 @@ use super::{
 ///     connect_participant_usecase,
 ///     disconnect_participant_usecase,
 ///     send_message_usecase,
-/// );
+///     get_room_state_usecase,
+///     get_room_stats_usecase,
+///     get_rooms_usecase,
+///     get_room_detail_usecase,
+///     get_message_history_usecase,
+///     create_room_usecase,
+///     update_room_usecase,
+///     remove_room_usecase,
+///     shutdown_server_usecase,
+/// )
+/// .with_config(ServerConfig::default());
 /// server.run("127.0.0.1".to_string(), 8080).await?;
 /// ```
 pub struct Server {
@@ -38,10 +65,26 @@ pub struct Server {
     send_message_usecase: Arc<SendMessageUseCase>,
     /// GetRoomStateUseCase（ルーム状態取得のユースケース）
     get_room_state_usecase: Arc<GetRoomStateUseCase>,
+    /// GetRoomStatsUseCase（ルームの活動状況取得のユースケース）
+    get_room_stats_usecase: Arc<GetRoomStatsUseCase>,
     /// GetRoomsUseCase（ルーム一覧取得のユースケース）
     get_rooms_usecase: Arc<GetRoomsUseCase>,
     /// GetRoomDetailUseCase（ルーム詳細取得のユースケース）
     get_room_detail_usecase: Arc<GetRoomDetailUseCase>,
+    /// GetMessageHistoryUseCase（メッセージの編集・削除の履歴取得のユースケース）
+    get_message_history_usecase: Arc<GetMessageHistoryUseCase>,
+    /// CreateRoomUseCase（ルーム作成のユースケース）
+    create_room_usecase: Arc<CreateRoomUseCase>,
+    /// UpdateRoomUseCase（ルーム設定更新のユースケース）
+    update_room_usecase: Arc<UpdateRoomUseCase>,
+    /// RemoveRoomUseCase（ルーム削除のユースケース）
+    remove_room_usecase: Arc<RemoveRoomUseCase>,
+    /// ShutdownServerUseCase（サーバ停止のユースケース）
+    shutdown_server_usecase: Arc<ShutdownServerUseCase>,
+    /// サーバ設定
+    config: ServerConfig,
+    /// ハンドラーが送信する通知の時刻を取得する Clock
+    clock: Arc<dyn Clock>,
 }
 
 impl Server {
@@ -53,26 +96,60 @@ impl Server {
     /// * `disconnect_participant_usecase` - UseCase for participant disconnection
     /// * `send_message_usecase` - UseCase for message sending
     /// * `get_room_state_usecase` - UseCase for getting room state
+    /// * `get_room_stats_usecase` - UseCase for getting room activity stats
     /// * `get_rooms_usecase` - UseCase for getting rooms list
     /// * `get_room_detail_usecase` - UseCase for getting room detail
+    /// * `get_message_history_usecase` - UseCase for getting the edit and delete history of a message
+    /// * `create_room_usecase` - UseCase for creating a room (with a repository to add it to)
+    /// * `update_room_usecase` - UseCase for updating room settings
+    /// * `remove_room_usecase` - UseCase for removing a room
+    /// * `shutdown_server_usecase` - UseCase for shutting down the server
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         connect_participant_usecase: Arc<ConnectParticipantUseCase>,
         disconnect_participant_usecase: Arc<DisconnectParticipantUseCase>,
         send_message_usecase: Arc<SendMessageUseCase>,
         get_room_state_usecase: Arc<GetRoomStateUseCase>,
+        get_room_stats_usecase: Arc<GetRoomStatsUseCase>,
         get_rooms_usecase: Arc<GetRoomsUseCase>,
         get_room_detail_usecase: Arc<GetRoomDetailUseCase>,
+        get_message_history_usecase: Arc<GetMessageHistoryUseCase>,
+        create_room_usecase: Arc<CreateRoomUseCase>,
+        update_room_usecase: Arc<UpdateRoomUseCase>,
+        remove_room_usecase: Arc<RemoveRoomUseCase>,
+        shutdown_server_usecase: Arc<ShutdownServerUseCase>,
     ) -> Self {
         Self {
             connect_participant_usecase,
             disconnect_participant_usecase,
             send_message_usecase,
             get_room_state_usecase,
+            get_room_stats_usecase,
             get_rooms_usecase,
             get_room_detail_usecase,
+            get_message_history_usecase,
+            create_room_usecase,
+            update_room_usecase,
+            remove_room_usecase,
+            shutdown_server_usecase,
+            config: ServerConfig::default(),
+            clock: Arc::new(SystemClock),
         }
     }
 
+    /// Override the runtime configuration
+    pub fn with_config(mut self, config: ServerConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Override the clock used for the timestamps of notifications sent by the handlers
+    /// (defaults to `SystemClock`)
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
     /// Run the WebSocket chat server
     ///
     /// # Arguments
@@ -85,42 +162,104 @@ impl Server {
     /// Returns an error if the server fails to bind to the specified address or
     /// if there's an error during server execution.
     pub async fn run(self, host: String, port: u16) -> Result<(), Box<dyn std::error::Error>> {
+        // Bind the server to the host and port
+        let bind_addr = format!("{}:{}", host, port);
+        let listener = TcpListener::bind(&bind_addr).await?;
+
+        // Start the server
+        tracing::info!(
+            "WebSocket chat server listening on {}",
+            listener.local_addr()?
+        );
+        tracing::info!("Connect to: ws://{}/ws", bind_addr);
+        tracing::info!("Press Ctrl+C to shutdown gracefully");
+
+        // Set up graceful shutdown signal handler
+        self.serve(listener, shutdown_signal()).await
+    }
+
+    /// Serve on an already bound listener until `signal` completes
+    ///
+    /// Once `signal` completes, the phased shutdown sequence runs
+    /// (see [`shutdown_sequence`]) before the server stops.
+    ///
+    /// # Arguments
+    ///
+    /// * `listener` - The bound TCP listener
+    /// * `signal` - Future that completes when the server should shut down
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there's an error during server execution.
+    pub async fn serve<F>(
+        self,
+        listener: TcpListener,
+        signal: F,
+    ) -> Result<(), Box<dyn std::error::Error>>
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
         let app_state = Arc::new(AppState {
             connect_participant_usecase: self.connect_participant_usecase,
             disconnect_participant_usecase: self.disconnect_participant_usecase,
             send_message_usecase: self.send_message_usecase,
             get_room_state_usecase: self.get_room_state_usecase,
+            get_room_stats_usecase: self.get_room_stats_usecase,
             get_rooms_usecase: self.get_rooms_usecase,
             get_room_detail_usecase: self.get_room_detail_usecase,
+            get_message_history_usecase: self.get_message_history_usecase,
+            create_room_usecase: self.create_room_usecase,
+            update_room_usecase: self.update_room_usecase,
+            remove_room_usecase: self.remove_room_usecase,
+            shutdown_server_usecase: self.shutdown_server_usecase,
+            config: self.config,
+            clock: self.clock,
+            shutdown: ShutdownState::default(),
+            reconnect_limiter: ReconnectLimiter::default(),
+            ip_connection_limiter: IpConnectionLimiter::default(),
+            disconnect_guards: DisconnectGuards::default(),
         });
 
         // Define handlers
         let app = Router::new()
-            // WebSocket エンドポイント
-            .route("/ws", get(websocket_handler))
-            // HTTP エンドポイント
+            // HTTP エンドポイント（API トークンの設定時は認証が必要）
             .route("/debug/room", get(debug_room_state))
-            .route("/api/health", get(health_check))
             .route("/api/rooms", get(get_rooms))
+            .route("/api/rooms", post(create_room))
             .route("/api/rooms/{room_id}", get(get_room_detail))
-            .with_state(app_state);
-
-        // Bind the server to the host and port
-        let bind_addr = format!("{}:{}", host, port);
-        let listener = tokio::net::TcpListener::bind(&bind_addr).await?;
-
-        // Start the server
-        tracing::info!(
-            "WebSocket chat server listening on {}",
-            listener.local_addr()?
-        );
-        tracing::info!("Connect to: ws://{}/ws", bind_addr);
-        tracing::info!("Press Ctrl+C to shutdown gracefully");
+            .route("/api/rooms/{room_id}", patch(update_room))
+            .route("/api/rooms/{room_id}", delete(remove_room))
+            .route(
+                "/api/rooms/{room_id}/kick/{client_id}",
+                post(kick_participant),
+            )
+            .route(
+                "/api/rooms/{room_id}/messages/{message_id}/history",
+                get(get_message_history),
+            )
+            .route("/metrics", get(get_metrics))
+            .route_layer(middleware::from_fn_with_state(
+                app_state.clone(),
+                require_api_token,
+            ))
+            // WebSocket エンドポイント
+            .route("/ws", get(websocket_handler))
+            // HTTP エンドポイント（常に公開）
+            .route("/api/health", get(health_check))
+            .route("/api/capabilities", get(get_capabilities))
+            .layer(middleware::from_fn_with_state(
+                app_state.clone(),
+                reject_while_shutting_down,
+            ))
+            .with_state(app_state.clone());
 
-        // Set up graceful shutdown signal handler
-        axum::serve(listener, app)
-            .with_graceful_shutdown(shutdown_signal())
-            .await?;
+        // The peer address is needed for the per-IP connection limit
+        axum::serve(
+            listener,
+            app.into_make_service_with_connect_info::<SocketAddr>(),
+        )
+        .with_graceful_shutdown(shutdown_sequence(app_state, signal))
+        .await?;
 
         tracing::info!("Server shutdown complete");
 
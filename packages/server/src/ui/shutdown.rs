@@ -0,0 +1,115 @@
+//! Phased shutdown of the server.
+//!
+//! The shutdown proceeds in the following phases:
+//!
+//! 1. Stop accepting new WebSocket/HTTP connections (respond with 503)
+//! 2. Broadcast `server-shutdown` to all connected clients
+//! 3. Wait for the grace period so that in-flight messages can be flushed
+//! 4. Close the remaining connections
+
+use std::{
+    future::Future,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+};
+
+use axum::{
+    extract::{Request, State},
+    http::StatusCode,
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+
+use crate::{
+    infrastructure::dto::websocket::{MessageType, ServerShutdownMessage},
+    ui::state::AppState,
+    usecase::MSG_SERVER_SHUTDOWN,
+};
+
+/// Path of the readiness endpoint, which keeps answering during shutdown
+const READINESS_PATH: &str = "/api/health";
+
+/// Shutdown phase shared between the shutdown sequence and the handlers
+#[derive(Debug, Default)]
+pub struct ShutdownState {
+    shutting_down: AtomicBool,
+}
+
+impl ShutdownState {
+    /// Mark the server as shutting down
+    pub fn begin(&self) {
+        self.shutting_down.store(true, Ordering::SeqCst);
+    }
+
+    /// Whether the shutdown sequence has started
+    pub fn is_shutting_down(&self) -> bool {
+        self.shutting_down.load(Ordering::SeqCst)
+    }
+}
+
+/// Middleware rejecting new requests with 503 once the shutdown sequence has started
+pub async fn reject_while_shutting_down(
+    State(state): State<Arc<AppState>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    if state.shutdown.is_shutting_down() && request.uri().path() != READINESS_PATH {
+        tracing::info!(
+            "Rejecting request to '{}' during shutdown",
+            request.uri().path()
+        );
+        return StatusCode::SERVICE_UNAVAILABLE.into_response();
+    }
+    next.run(request).await
+}
+
+/// Wait for the shutdown signal and run the shutdown phases
+///
+/// The returned future completes once the remaining connections are closed,
+/// so it can be passed to `with_graceful_shutdown` directly.
+pub async fn shutdown_sequence<F>(state: Arc<AppState>, signal: F)
+where
+    F: Future<Output = ()>,
+{
+    signal.await;
+
+    // 1. Stop accepting new connections
+    state.shutdown.begin();
+    tracing::info!("Shutdown phase 1: stopped accepting new connections");
+
+    // 2. Broadcast server-shutdown (a single frame for everyone, so in the default locale)
+    let grace_period = state.config.shutdown_grace_period;
+    let shutdown_msg = ServerShutdownMessage {
+        r#type: MessageType::ServerShutdown,
+        reason: state
+            .config
+            .localizer
+            .localize(None, MSG_SERVER_SHUTDOWN, &[]),
+        grace_period_ms: grace_period.as_millis() as u64,
+    };
+    let shutdown_json = serde_json::to_string(&shutdown_msg).unwrap();
+    if let Err(e) = state
+        .shutdown_server_usecase
+        .broadcast_server_shutdown(&shutdown_json)
+        .await
+    {
+        tracing::warn!("Failed to broadcast server-shutdown: {}", e);
+    }
+    tracing::info!("Shutdown phase 2: broadcasted server-shutdown");
+
+    // 3. Wait for the grace period
+    tracing::info!(
+        "Shutdown phase 3: waiting {}ms for in-flight messages",
+        grace_period.as_millis()
+    );
+    tokio::time::sleep(grace_period).await;
+
+    // 4. Close the remaining connections
+    let closed = state.shutdown_server_usecase.close_all_connections().await;
+    tracing::info!(
+        "Shutdown phase 4: closed {} remaining connection(s)",
+        closed
+    );
+}
@@ -2,14 +2,23 @@
 
 use std::sync::Arc;
 
+use crate::domain::Clock;
 use crate::usecase::{
-    ConnectParticipantUseCase, DisconnectParticipantUseCase, GetRoomDetailUseCase,
-    GetRoomStateUseCase, GetRoomsUseCase, SendMessageUseCase,
+    ConnectParticipantUseCase, CreateRoomUseCase, DisconnectParticipantUseCase,
+    GetMessageHistoryUseCase, GetRoomDetailUseCase, GetRoomStateUseCase, GetRoomStatsUseCase,
+    GetRoomsUseCase, RemoveRoomUseCase, SendMessageUseCase, ShutdownServerUseCase,
+    UpdateRoomUseCase,
+};
+
+use super::{
+    config::ServerConfig, disconnect_guard::DisconnectGuards,
+    ip_connection_limit::IpConnectionLimiter, reconnect_limit::ReconnectLimiter,
+    shutdown::ShutdownState,
 };
 
 /// Shared application state
 ///
-/// AppState は UseCase のみを保持します。
+/// AppState は UseCase と、サーバ自身の設定・時刻・停止状態のみを保持します。
 /// Repository や MessagePusher は UseCase が内部で保持しており、
 /// ハンドラーからは UseCase を通じてのみアクセスします。
 pub struct AppState {
@@ -21,8 +30,32 @@ pub struct AppState {
     pub send_message_usecase: Arc<SendMessageUseCase>,
     /// GetRoomStateUseCase（ルーム状態取得のユースケース）
     pub get_room_state_usecase: Arc<GetRoomStateUseCase>,
+    /// GetRoomStatsUseCase（ルームの活動状況取得のユースケース）
+    pub get_room_stats_usecase: Arc<GetRoomStatsUseCase>,
     /// GetRoomsUseCase（ルーム一覧取得のユースケース）
     pub get_rooms_usecase: Arc<GetRoomsUseCase>,
     /// GetRoomDetailUseCase（ルーム詳細取得のユースケース）
     pub get_room_detail_usecase: Arc<GetRoomDetailUseCase>,
+    /// GetMessageHistoryUseCase（メッセージの編集・削除の履歴取得のユースケース）
+    pub get_message_history_usecase: Arc<GetMessageHistoryUseCase>,
+    /// CreateRoomUseCase（ルーム作成のユースケース）
+    pub create_room_usecase: Arc<CreateRoomUseCase>,
+    /// UpdateRoomUseCase（ルーム設定更新のユースケース）
+    pub update_room_usecase: Arc<UpdateRoomUseCase>,
+    /// RemoveRoomUseCase（ルーム削除のユースケース）
+    pub remove_room_usecase: Arc<RemoveRoomUseCase>,
+    /// ShutdownServerUseCase（サーバ停止のユースケース）
+    pub shutdown_server_usecase: Arc<ShutdownServerUseCase>,
+    /// サーバ設定
+    pub config: ServerConfig,
+    /// ハンドラーが送信する通知の時刻を取得する Clock
+    pub clock: Arc<dyn Clock>,
+    /// 停止状態
+    pub shutdown: ShutdownState,
+    /// クライアントごとの再接続回数の制限
+    pub reconnect_limiter: ReconnectLimiter,
+    /// IP アドレスごとの接続数の制限
+    pub ip_connection_limiter: IpConnectionLimiter,
+    /// 接続中のクライアントごとの切断処理のガード
+    pub disconnect_guards: DisconnectGuards,
 }
@@ -14,15 +14,115 @@
 //! ### どのような状況を想定しているか
 //! - 正常系：新規参加者の接続
 //! - 異常系：重複した client_id での接続試行
+//! - 並行処理：同じ client_id で別々の Room に同時に接続しても、重複した ID で入室しない
+//! - 正常系：サフィックスモードでの重複した client_id の付け替え
 //! - エッジケース：Room の容量超過
+//! - 異常系：Room が存在しない（容量超過と区別される）
+//! - 正常系：ウェルカム bot が参加者の名前を含む挨拶を履歴に追加する（bot は参加者として数えない）
+//! - エッジケース：bot の参加者には挨拶しない
+//! - 正常系：挨拶は参加者のロケールで生成され、未知のロケールはデフォルトロケールになる
+//! - 正常系：管理者が承認すると入室できる（管理者自身は承認不要）
+//! - 異常系：管理者が拒否する・管理者が不在の場合は入室できない（JoinDenied）、承認がタイムアウトした場合も入室できない（JoinApprovalTimeout）
+//! - 正常系：参加者リストのキャッシュが入室後に更新される
+//! - 並行処理：同時に接続したクライアントが一貫した参加者リストを受け取る
+//! - 正常系：入室通知のバッチ化で、同時の入室は 1 つにまとまり、単独の入室は個別に通知される
+//! - 正常系：再接続時の補完は指定した時刻より後のメッセージだけを返し、上限を超えると古い方を切り捨てる
 
-use std::sync::Arc;
+use std::{
+    collections::HashMap,
+    str::FromStr,
+    sync::{Arc, Mutex},
+};
 
 use crate::domain::{
-    ClientId, MessagePusher, Participant, PusherChannel, RoomRepository, Timestamp,
+    ChatMessage, ClientId, Clock, ClockExt, MessageContent, MessagePusher, Participant,
+    PusherChannel, RoomId, RoomRepository, SystemClock, Timestamp, ValueObjectError,
+};
+
+use super::{
+    error::ConnectError,
+    join_approval::{JoinApproval, JoinApprovalGate},
+    join_batch::{JoinBatcher, JoinBatching},
+    localizer::{Localizer, MSG_WELCOME},
 };
 
-use super::error::ConnectError;
+/// デフォルトの Room を指す `room_id` の別名
+const DEFAULT_ROOM_ALIAS: &str = "default";
+
+/// client_id が重複した場合の扱い
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ClientIdCollisionPolicy {
+    /// 接続を拒否する
+    #[default]
+    Reject,
+    /// 数字のサフィックスを付けて一意にする（`alice` → `alice-2` → `alice-3`）
+    Suffix,
+}
+
+impl FromStr for ClientIdCollisionPolicy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "reject" => Ok(Self::Reject),
+            "suffix" => Ok(Self::Suffix),
+            other => Err(format!(
+                "invalid client_id collision policy '{}' (expected 'reject' or 'suffix')",
+                other
+            )),
+        }
+    }
+}
+
+/// 新しい参加者に挨拶するサーバ側の bot
+///
+/// bot は Room の参加者としては登録されないため、Room の容量を消費しない。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WelcomeBot {
+    /// bot の名前（挨拶メッセージの送信者）
+    pub name: ClientId,
+    /// 挨拶メッセージのテンプレート（`{name}` は参加者の名前に置き換えられる）
+    pub template: String,
+}
+
+impl WelcomeBot {
+    /// テンプレート中で参加者の名前に置き換えられるプレースホルダ
+    pub const NAME_PLACEHOLDER: &'static str = "{name}";
+
+    /// 参加者への挨拶メッセージを生成
+    pub fn greeting_for(&self, joiner: &ClientId) -> Result<MessageContent, ValueObjectError> {
+        Self::greeting_from(&self.template, joiner)
+    }
+
+    /// 指定したテンプレート（ロケールに応じたテンプレートなど）から挨拶メッセージを生成
+    pub fn greeting_from(
+        template: &str,
+        joiner: &ClientId,
+    ) -> Result<MessageContent, ValueObjectError> {
+        MessageContent::new(template.replace(Self::NAME_PLACEHOLDER, joiner.as_str()))
+    }
+}
+
+/// 再接続時に送信するメッセージの最大件数（超えた場合は新しい方から残す）
+pub const BACKFILL_MAX_MESSAGES: usize = 200;
+
+/// 再接続したクライアントが取りこぼしたメッセージ
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Backfill {
+    /// 指定した時刻より後のメッセージ（古い順、最大 `BACKFILL_MAX_MESSAGES` 件）
+    pub messages: Vec<ChatMessage>,
+    /// 上限を超えたため古いメッセージを切り捨てたかどうか
+    pub truncated: bool,
+}
+
+/// ソート済みの参加者リストのキャッシュ
+#[derive(Debug)]
+struct ParticipantListCache {
+    /// キャッシュした時点の参加者リストのバージョン
+    version: u64,
+    /// 接続中の参加者リスト（ソート済み）
+    participants: Vec<Participant>,
+}
 
 /// 参加者接続のユースケース
 pub struct ConnectParticipantUseCase {
@@ -30,6 +130,24 @@ pub struct ConnectParticipantUseCase {
     repository: Arc<dyn RoomRepository>,
     /// MessagePusher（メッセージ通知の抽象化）
     message_pusher: Arc<dyn MessagePusher>,
+    /// client_id が重複した場合の扱い
+    collision_policy: ClientIdCollisionPolicy,
+    /// 新しい参加者に挨拶する bot（`None` の場合は挨拶しない）
+    welcome_bot: Option<WelcomeBot>,
+    /// 入室に管理者の承認を必要とする設定（`None` の場合は承認不要）
+    join_approval: Option<JoinApproval>,
+    /// 承認待ちのクライアント
+    approval_gate: JoinApprovalGate,
+    /// ソート済みの参加者リストをキャッシュするかどうか
+    cache_participant_list: bool,
+    /// Room ごとのソート済みの参加者リストのキャッシュ
+    participant_list_cache: Mutex<HashMap<RoomId, ParticipantListCache>>,
+    /// 入室通知のバッチ化（`None` の場合は入室ごとに通知する）
+    join_batcher: Option<JoinBatcher>,
+    /// 挨拶メッセージの多言語化（`None` の場合は bot のテンプレートをそのまま使う）
+    localizer: Option<Localizer>,
+    /// 入室時刻と挨拶メッセージのタイムスタンプを取得する Clock
+    clock: Arc<dyn Clock>,
 }
 
 impl ConnectParticipantUseCase {
@@ -41,58 +159,370 @@ impl ConnectParticipantUseCase {
         Self {
             repository,
             message_pusher,
+            collision_policy: ClientIdCollisionPolicy::default(),
+            welcome_bot: None,
+            join_approval: None,
+            approval_gate: JoinApprovalGate::default(),
+            cache_participant_list: false,
+            participant_list_cache: Mutex::new(HashMap::new()),
+            join_batcher: None,
+            localizer: None,
+            clock: Arc::new(SystemClock),
+        }
+    }
+
+    /// client_id が重複した場合の扱いを設定
+    pub fn with_collision_policy(mut self, collision_policy: ClientIdCollisionPolicy) -> Self {
+        self.collision_policy = collision_policy;
+        self
+    }
+
+    /// 入室時刻と挨拶メッセージのタイムスタンプを取得する Clock を設定（デフォルトは SystemClock）
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// 新しい参加者に挨拶する bot を設定
+    pub fn with_welcome_bot(mut self, welcome_bot: WelcomeBot) -> Self {
+        self.welcome_bot = Some(welcome_bot);
+        self
+    }
+
+    /// 挨拶メッセージを参加者のロケールに合わせて生成する
+    ///
+    /// 参加者のロケールのテンプレートがない場合は、Localizer のフォールバックに従う。
+    pub fn with_localizer(mut self, localizer: Localizer) -> Self {
+        self.localizer = Some(localizer);
+        self
+    }
+
+    /// ソート済みの参加者リストをキャッシュする
+    ///
+    /// 参加者の入室・退室（参加者リストのバージョンの変化）まではキャッシュを再利用するため、
+    /// 参加者の多いルームで接続のたびに参加者リストを複製・ソートせずに済む。
+    pub fn with_participant_list_cache(mut self) -> Self {
+        self.cache_participant_list = true;
+        self
+    }
+
+    /// 短い時間窓の中の入室通知をまとめる
+    pub fn with_join_batching(mut self, join_batching: JoinBatching) -> Self {
+        self.join_batcher = Some(JoinBatcher::new(join_batching));
+        self
+    }
+
+    /// 入室に管理者の承認を必要とする
+    pub fn with_join_approval(mut self, join_approval: JoinApproval) -> Self {
+        self.join_approval = Some(join_approval);
+        self
+    }
+
+    /// 入室の承認を管理者に求め、判断が出るまで待つ
+    ///
+    /// 承認が不要な場合（設定なし、または管理者自身の接続）は直ちに `Ok(())` を返す。
+    /// 管理者は `client_id` だけで識別するため、管理者の ID を名乗る接続は承認を経ずに入室できる
+    /// （API トークンが設定されている場合、UI 層が管理者の ID での接続にトークンを要求する）。
+    /// 判断が出るまで（最大でタイムアウトまで）呼び出し元の接続は待たされる。
+    ///
+    /// # Arguments
+    ///
+    /// * `client_id` - 入室しようとしているクライアントの ID（Domain Model）
+    /// * `request_message` - 管理者に送信する入室リクエスト（JSON）
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - 入室が承認された
+    /// * `Err(ConnectError::JoinDenied)` - 拒否された、または管理者が不在
+    /// * `Err(ConnectError::JoinApprovalTimeout)` - 承認がタイムアウトした
+    pub async fn request_approval(
+        &self,
+        client_id: &ClientId,
+        request_message: &str,
+    ) -> Result<(), ConnectError> {
+        let Some(join_approval) = &self.join_approval else {
+            return Ok(());
+        };
+        if *client_id == join_approval.admin {
+            return Ok(());
+        }
+
+        // 同じ ID での承認待ちが既にある場合は拒否する
+        let decision = self
+            .approval_gate
+            .register(client_id.clone())
+            .ok_or(ConnectError::JoinDenied)?;
+
+        // 管理者が接続していない場合は承認できないため拒否する
+        if let Err(e) = self
+            .message_pusher
+            .push_to(&join_approval.admin, request_message)
+            .await
+        {
+            tracing::warn!("Failed to send join request for '{}': {}", client_id, e);
+            self.approval_gate.cancel(client_id);
+            return Err(ConnectError::JoinDenied);
+        }
+
+        match tokio::time::timeout(join_approval.timeout, decision).await {
+            Ok(Ok(true)) => Ok(()),
+            Ok(_) => Err(ConnectError::JoinDenied),
+            Err(_) => {
+                tracing::info!("Join request for '{}' timed out", client_id);
+                self.approval_gate.cancel(client_id);
+                Err(ConnectError::JoinApprovalTimeout)
+            }
+        }
+    }
+
+    /// 承認待ちのクライアントの入室を承認・拒否
+    ///
+    /// # Arguments
+    ///
+    /// * `decided_by` - 判断したクライアントの ID（管理者以外の判断は無視される）
+    /// * `client_id` - 承認待ちのクライアントの ID
+    /// * `approved` - 承認する場合は `true`
+    ///
+    /// # Returns
+    ///
+    /// 判断が承認待ちのクライアントに届いた場合は `true`
+    pub fn decide_join(&self, decided_by: &ClientId, client_id: &ClientId, approved: bool) -> bool {
+        match &self.join_approval {
+            Some(join_approval) if *decided_by == join_approval.admin => {
+                self.approval_gate.resolve(client_id, approved)
+            }
+            _ => false,
         }
     }
 
+    /// 入室する Room の ID を決定
+    ///
+    /// # Arguments
+    ///
+    /// * `room_id` - クライアントが指定した Room の ID（`None` または `"default"` の場合はデフォルトの Room）
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(RoomId)` - 入室する Room の ID（Domain Model）
+    /// * `Err(ConnectError::RoomNotFound)` - 指定した ID の Room が存在しない
+    pub async fn resolve_room_id(&self, room_id: Option<String>) -> Result<RoomId, ConnectError> {
+        let room_id = match room_id {
+            Some(room_id) if room_id != DEFAULT_ROOM_ALIAS => room_id,
+            _ => return Ok(self.repository.get_room().await?.id),
+        };
+        let room_id = RoomId::new(room_id).map_err(|_| ConnectError::RoomNotFound)?;
+        Ok(self.repository.get_room_by_id(&room_id).await?.id)
+    }
+
     /// 参加者接続を実行
     ///
+    /// client_id は全ての Room を通して一意にする（別の Room に接続中の ID とも重複させない）。
+    ///
     /// # Arguments
     ///
+    /// * `room_id` - 入室する Room の ID（Domain Model）
     /// * `client_id` - 接続するクライアントの ID（Domain Model）
     /// * `sender` - クライアントへのメッセージ送信用チャンネル
+    /// * `is_bot` - 接続するクライアントが bot かどうか
     ///
     /// # Returns
     ///
-    /// * `Ok(Timestamp)` - 接続成功（接続時刻の Domain Model を返す）
+    /// * `Ok(Participant)` - 接続成功（割り当てられた ID と接続時刻を持つ参加者を返す）
     /// * `Err(ConnectError)` - 接続失敗
     pub async fn execute(
         &self,
+        room_id: &RoomId,
         client_id: ClientId,
         sender: PusherChannel,
-    ) -> Result<Timestamp, ConnectError> {
-        use engawa_shared::time::get_jst_timestamp;
+        is_bot: bool,
+    ) -> Result<Participant, ConnectError> {
+        // 1. 重複チェックと Repository への追加を 1 つの操作で行う（サフィックスモードでは一意な ID を割り当てる）
+        // bot かどうかも同時に登録し、bot が人間の参加者として扱われる瞬間を作らない
+        let mut participant =
+            Participant::new(client_id.clone(), self.clock.now()).with_bot(is_bot);
+        participant.id = self
+            .repository
+            .reserve_participant(room_id, participant.clone(), &|connected_ids| {
+                self.resolve_client_id(&client_id, connected_ids)
+            })
+            .await?;
 
-        // 1. 重複チェック
-        let client_ids = self.repository.get_all_connected_client_ids().await;
-        if client_ids
-            .iter()
-            .any(|id| id.as_str() == client_id.as_str())
-        {
-            return Err(ConnectError::DuplicateClientId(
-                client_id.as_str().to_string(),
-            ));
+        // 2. MessagePusher にクライアントを登録（Domain Model を渡す）
+        self.message_pusher
+            .register_client(participant.id.clone(), sender)
+            .await;
+
+        Ok(participant)
+    }
+
+    /// 接続中のクライアント ID と重複しない ID を決定（割り当てられない場合は `None`）
+    fn resolve_client_id(
+        &self,
+        client_id: &ClientId,
+        connected_ids: &[ClientId],
+    ) -> Option<ClientId> {
+        let is_taken = |candidate: &str| connected_ids.iter().any(|id| id.as_str() == candidate);
+
+        if !is_taken(client_id.as_str()) {
+            return Some(client_id.clone());
+        }
+        if self.collision_policy == ClientIdCollisionPolicy::Reject {
+            return None;
         }
 
-        // 2. Repository に参加者を追加
-        let connected_at = Timestamp::new(get_jst_timestamp());
-        self.repository
-            .add_participant(client_id.clone(), connected_at)
-            .await
-            .map_err(|_| ConnectError::RoomCapacityExceeded)?;
+        // 接続中の数 + 1 回試せば必ず空きが見つかる
+        (2..=connected_ids.len() + 1)
+            .map(|n| format!("{}-{}", client_id.as_str(), n))
+            .find(|candidate| !is_taken(candidate))
+            .and_then(|candidate| ClientId::restore(candidate).ok())
+    }
 
-        // 3. MessagePusher にクライアントを登録（Domain Model を渡す）
-        self.message_pusher.register_client(client_id, sender).await;
+    /// ウェルカム bot の挨拶メッセージを Room に追加
+    ///
+    /// # Arguments
+    ///
+    /// * `room_id` - 参加者が入室した Room の ID（Domain Model）
+    /// * `joiner` - 新規接続した参加者（Domain Model）
+    /// * `locale` - 参加者のロケール（`None` の場合はデフォルトロケール）
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(ChatMessage))` - 挨拶メッセージ（メッセージ ID 付き）
+    /// * `Ok(None)` - bot が設定されていない、または参加者が bot の場合
+    /// * `Err(ConnectError)` - 挨拶メッセージの追加に失敗
+    pub async fn greet(
+        &self,
+        room_id: &RoomId,
+        joiner: &Participant,
+        locale: Option<&str>,
+    ) -> Result<Option<ChatMessage>, ConnectError> {
+        let Some(welcome_bot) = &self.welcome_bot else {
+            return Ok(None);
+        };
+        if joiner.is_bot {
+            return Ok(None);
+        }
+        let localized = self
+            .localizer
+            .as_ref()
+            .and_then(|localizer| localizer.template(locale, MSG_WELCOME));
+        let greeting = match localized {
+            Some(template) => WelcomeBot::greeting_from(template, &joiner.id),
+            None => welcome_bot.greeting_for(&joiner.id),
+        };
+        let content = match greeting {
+            Ok(content) => content,
+            Err(e) => {
+                tracing::warn!("Failed to build greeting for '{}': {}", joiner.id, e);
+                return Ok(None);
+            }
+        };
+
+        let timestamp = self.clock.now();
+        let message_id = self
+            .repository
+            .add_message(
+                room_id,
+                welcome_bot.name.clone(),
+                content.clone(),
+                timestamp,
+            )
+            .await?;
+
+        let mut message = ChatMessage::new(welcome_bot.name.clone(), content, timestamp);
+        message.id = Some(message_id);
+        Ok(Some(message))
+    }
 
-        Ok(connected_at)
+    /// ウェルカム bot の挨拶メッセージを新規参加者を含む Room の全ての参加者にブロードキャスト
+    ///
+    /// # Arguments
+    ///
+    /// * `room_id` - 参加者が入室した Room の ID（Domain Model）
+    /// * `message` - ブロードキャストするメッセージ（JSON）
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - ブロードキャスト成功
+    /// * `Err(String)` - ブロードキャスト失敗
+    pub async fn broadcast_greeting(&self, room_id: &RoomId, message: &str) -> Result<(), String> {
+        let target_ids = self.room_client_ids(room_id).await;
+        self.message_pusher
+            .broadcast(target_ids, message)
+            .await
+            .map(|_| ())
+            .map_err(|e| e.to_string())
     }
 
-    /// 参加者リストを構築
+    /// 指定した時刻より後のメッセージを取得（再接続したクライアントの取りこぼしの補完）
+    ///
+    /// # Arguments
+    ///
+    /// * `room_id` - クライアントが入室した Room の ID（Domain Model）
+    /// * `since` - クライアントが最後に受信したメッセージの時刻（Domain Model）
     ///
     /// # Returns
     ///
-    /// 接続中の参加者リスト（Domain Model、ソート済み）
-    pub async fn build_participant_list(&self) -> Vec<Participant> {
-        let mut participants = self.repository.get_participants().await;
+    /// * `Ok(Backfill)` - `since` より後のメッセージ（新しい方から最大 `BACKFILL_MAX_MESSAGES` 件）
+    /// * `Err(ConnectError)` - Room が存在しない
+    pub async fn backfill(
+        &self,
+        room_id: &RoomId,
+        since: Timestamp,
+    ) -> Result<Backfill, ConnectError> {
+        self.repository.get_room_by_id(room_id).await?;
+        let mut messages = self.repository.messages_since(room_id, since).await;
+        let truncated = messages.len() > BACKFILL_MAX_MESSAGES;
+        if truncated {
+            messages.drain(..messages.len() - BACKFILL_MAX_MESSAGES);
+        }
+        Ok(Backfill {
+            messages,
+            truncated,
+        })
+    }
+
+    /// Room の参加者リストを構築
+    ///
+    /// # Returns
+    ///
+    /// Room に接続中の参加者リスト（Domain Model、ソート済み）
+    pub async fn build_participant_list(&self, room_id: &RoomId) -> Vec<Participant> {
+        if !self.cache_participant_list {
+            return self.load_sorted_participants(room_id).await;
+        }
+
+        // バージョンは参加者リストより先に取得する。
+        // 取得の間に参加者が変化した場合、キャッシュは古いバージョンで記録され、
+        // 次回の呼び出しでバージョンの不一致として再構築される（古いリストを返すことはない）。
+        let version = self.repository.get_participants_version(room_id).await;
+        if let Some(cache) = self.participant_list_cache.lock().unwrap().get(room_id)
+            && cache.version == version
+        {
+            return cache.participants.clone();
+        }
+
+        let participants = self.load_sorted_participants(room_id).await;
+        let mut caches = self.participant_list_cache.lock().unwrap();
+        // 並行して新しいバージョンのキャッシュが作られていれば上書きしない
+        if caches
+            .get(room_id)
+            .is_none_or(|cache| cache.version < version)
+        {
+            caches.insert(
+                room_id.clone(),
+                ParticipantListCache {
+                    version,
+                    participants: participants.clone(),
+                },
+            );
+        }
+        participants
+    }
+
+    /// Repository から Room の参加者リストを取得し、client_id でソート
+    async fn load_sorted_participants(&self, room_id: &RoomId) -> Vec<Participant> {
+        let mut participants = self.repository.get_participants(room_id).await;
 
         // Sort by client_id for consistent ordering
         participants.sort_by(|a, b| a.id.as_str().cmp(b.id.as_str()));
@@ -100,10 +530,99 @@ impl ConnectParticipantUseCase {
         participants
     }
 
-    /// 参加者が join したことを既存の参加者にブロードキャスト
+    /// 入室を通知する参加者をまとめて取得
+    ///
+    /// バッチ化が有効な場合は、時間窓を開いた入室の呼び出しが時間窓の終わりまで待ち、
+    /// その間に同じ Room に入室した参加者（まだ接続している参加者のみ）をまとめて返す。
     ///
     /// # Arguments
     ///
+    /// * `room_id` - 参加者が入室した Room の ID（Domain Model）
+    /// * `participant` - 新規接続した参加者（Domain Model）
+    ///
+    /// # Returns
+    ///
+    /// * `(participants, batched)` - 通知する参加者（入室順）と、1 つの通知にまとめるかどうか
+    ///   （時間窓に追加されただけの呼び出しでは参加者は空になる）
+    pub async fn collect_joins(
+        &self,
+        room_id: &RoomId,
+        participant: Participant,
+    ) -> (Vec<Participant>, bool) {
+        let Some(join_batcher) = &self.join_batcher else {
+            return (vec![participant], false);
+        };
+        let Some(mut joined) = join_batcher.collect(room_id, participant).await else {
+            return (Vec::new(), false);
+        };
+
+        // 時間窓の間に退室した参加者は通知しない
+        let connected_ids = self.room_client_ids(room_id).await;
+        joined.retain(|p| connected_ids.contains(&p.id));
+        let batched = join_batcher.batching().should_batch(joined.len());
+        (joined, batched)
+    }
+
+    /// まとめた入室を Room の全ての参加者にブロードキャスト
+    ///
+    /// 入室した参加者自身も、同じ時間窓で入室した他の参加者を知るために受信する。
+    ///
+    /// # Arguments
+    ///
+    /// * `room_id` - 参加者が入室した Room の ID（Domain Model）
+    /// * `message` - ブロードキャストするメッセージ（JSON）
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - ブロードキャスト成功
+    /// * `Err(String)` - ブロードキャスト失敗
+    pub async fn broadcast_participants_joined(
+        &self,
+        room_id: &RoomId,
+        message: &str,
+    ) -> Result<(), String> {
+        let target_ids = self.room_client_ids(room_id).await;
+        self.message_pusher
+            .broadcast(target_ids, message)
+            .await
+            .map(|_| ())
+            .map_err(|e| e.to_string())
+    }
+
+    /// Room に接続中の参加者数を取得
+    pub async fn count_participants(&self, room_id: &RoomId) -> usize {
+        self.repository.get_participants(room_id).await.len()
+    }
+
+    /// 参加者数を Room の全ての参加者にブロードキャスト
+    ///
+    /// # Arguments
+    ///
+    /// * `room_id` - 参加者数を通知する Room の ID（Domain Model）
+    /// * `message` - ブロードキャストするメッセージ（JSON）
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - ブロードキャスト成功
+    /// * `Err(String)` - ブロードキャスト失敗
+    pub async fn broadcast_participant_count(
+        &self,
+        room_id: &RoomId,
+        message: &str,
+    ) -> Result<(), String> {
+        let target_ids = self.room_client_ids(room_id).await;
+        self.message_pusher
+            .broadcast(target_ids, message)
+            .await
+            .map(|_| ())
+            .map_err(|e| e.to_string())
+    }
+
+    /// 参加者が join したことを Room の既存の参加者にブロードキャスト
+    ///
+    /// # Arguments
+    ///
+    /// * `room_id` - 参加者が入室した Room の ID（Domain Model）
     /// * `new_client_id` - 新規接続したクライアントの ID（Domain Model）
     /// * `message` - ブロードキャストするメッセージ（JSON）
     ///
@@ -113,11 +632,12 @@ impl ConnectParticipantUseCase {
     /// * `Err(String)` - ブロードキャスト失敗
     pub async fn broadcast_participant_joined(
         &self,
+        room_id: &RoomId,
         new_client_id: &ClientId,
         message: &str,
     ) -> Result<(), String> {
-        // 新規接続クライアント以外の全てのクライアントを取得
-        let all_client_ids = self.repository.get_all_connected_client_ids().await;
+        // 新規接続クライアント以外の Room の全てのクライアントを取得
+        let all_client_ids = self.room_client_ids(room_id).await;
         let target_ids: Vec<ClientId> = all_client_ids
             .into_iter()
             .filter(|id| id != new_client_id)
@@ -127,15 +647,26 @@ impl ConnectParticipantUseCase {
         self.message_pusher
             .broadcast(target_ids, message)
             .await
+            .map(|_| ())
             .map_err(|e| e.to_string())
     }
+
+    /// Room に接続中のクライアント ID リストを取得
+    async fn room_client_ids(&self, room_id: &RoomId) -> Vec<ClientId> {
+        self.repository
+            .get_participants(room_id)
+            .await
+            .into_iter()
+            .map(|p| p.id)
+            .collect()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::{
-        domain::{Room, RoomIdFactory, Timestamp},
+        domain::{MessageContent, Room, RoomIdFactory, Timestamp, pusher_channel},
         infrastructure::{
             message_pusher::WebSocketMessagePusher, repository::InMemoryRoomRepository,
         },
@@ -174,20 +705,23 @@ mod tests {
         // テスト項目: 新規参加者が正常に接続できる
         // given (前提条件):
         let repository = create_test_repository();
+        let room_id = repository.get_room().await.unwrap().id;
         let message_pusher = create_test_message_pusher();
         let usecase = ConnectParticipantUseCase::new(repository.clone(), message_pusher);
 
         // when (操作):
         let client_id = ClientId::new("alice".to_string()).unwrap();
-        let (tx, _rx) = tokio::sync::mpsc::unbounded_channel();
-        let result = usecase.execute(client_id.clone(), tx).await;
+        let (tx, _rx) = pusher_channel();
+        let result = usecase
+            .execute(&room_id, client_id.clone(), tx, false)
+            .await;
 
         // then (期待する結果):
         assert!(result.is_ok());
 
         // Repository に追加されているか確認
         assert_eq!(repository.count_connected_clients().await, 1);
-        let participants = repository.get_participants().await;
+        let participants = repository.get_participants(&room_id).await;
         assert_eq!(participants.len(), 1);
         assert_eq!(participants[0].id, client_id);
     }
@@ -197,18 +731,22 @@ mod tests {
         // テスト項目: 重複した client_id での接続試行がエラーになる
         // given (前提条件):
         let repository = create_test_repository();
+        let room_id = repository.get_room().await.unwrap().id;
         let message_pusher = create_test_message_pusher();
         let usecase = ConnectParticipantUseCase::new(repository.clone(), message_pusher);
 
         // 最初の接続は成功
         let client_id1 = ClientId::new("alice".to_string()).unwrap();
-        let (tx1, _rx1) = tokio::sync::mpsc::unbounded_channel();
-        usecase.execute(client_id1.clone(), tx1).await.unwrap();
+        let (tx1, _rx1) = pusher_channel();
+        usecase
+            .execute(&room_id, client_id1.clone(), tx1, false)
+            .await
+            .unwrap();
 
         // when (操作): 同じ client_id で再接続を試みる
         let client_id2 = ClientId::new("alice".to_string()).unwrap();
-        let (tx2, _rx2) = tokio::sync::mpsc::unbounded_channel();
-        let result = usecase.execute(client_id2, tx2).await;
+        let (tx2, _rx2) = pusher_channel();
+        let result = usecase.execute(&room_id, client_id2, tx2, false).await;
 
         // then (期待する結果): 重複エラーが返される
         assert_eq!(
@@ -226,21 +764,28 @@ mod tests {
         // given (前提条件):
         let capacity = 2; // Room の人数制限
         let repository = create_test_repository_with_capacity(capacity);
+        let room_id = repository.get_room().await.unwrap().id;
         let message_pusher = create_test_message_pusher();
         let usecase = ConnectParticipantUseCase::new(repository.clone(), message_pusher);
 
         // 2人接続（容量いっぱい）
         let client_id_alice = ClientId::new("alice".to_string()).unwrap();
         let client_id_bob = ClientId::new("bob".to_string()).unwrap();
-        let (tx1, _rx1) = tokio::sync::mpsc::unbounded_channel();
-        let (tx2, _rx2) = tokio::sync::mpsc::unbounded_channel();
-        usecase.execute(client_id_alice.clone(), tx1).await.unwrap();
-        usecase.execute(client_id_bob.clone(), tx2).await.unwrap();
+        let (tx1, _rx1) = pusher_channel();
+        let (tx2, _rx2) = pusher_channel();
+        usecase
+            .execute(&room_id, client_id_alice.clone(), tx1, false)
+            .await
+            .unwrap();
+        usecase
+            .execute(&room_id, client_id_bob.clone(), tx2, false)
+            .await
+            .unwrap();
 
         // when (操作): 3人目の接続を試みる
         let charlie = ClientId::new("charlie".to_string()).unwrap();
-        let (tx3, _rx3) = tokio::sync::mpsc::unbounded_channel();
-        let result = usecase.execute(charlie.clone(), tx3).await;
+        let (tx3, _rx3) = pusher_channel();
+        let result = usecase.execute(&room_id, charlie.clone(), tx3, false).await;
 
         // then (期待する結果): 容量超過エラーが返される
         assert_eq!(result, Err(ConnectError::RoomCapacityExceeded));
@@ -254,6 +799,7 @@ mod tests {
         // テスト項目: 参加者リストが正しく構築される
         // given (前提条件):
         let repository = create_test_repository();
+        let room_id = repository.get_room().await.unwrap().id;
         let message_pusher = create_test_message_pusher();
         let usecase = ConnectParticipantUseCase::new(repository.clone(), message_pusher);
 
@@ -261,18 +807,24 @@ mod tests {
         let client_id_charlie = ClientId::new("charlie".to_string()).unwrap();
         let client_id_alice = ClientId::new("alice".to_string()).unwrap();
         let client_id_bob = ClientId::new("bob".to_string()).unwrap();
-        let (tx1, _rx1) = tokio::sync::mpsc::unbounded_channel();
-        let (tx2, _rx2) = tokio::sync::mpsc::unbounded_channel();
-        let (tx3, _rx3) = tokio::sync::mpsc::unbounded_channel();
+        let (tx1, _rx1) = pusher_channel();
+        let (tx2, _rx2) = pusher_channel();
+        let (tx3, _rx3) = pusher_channel();
+        usecase
+            .execute(&room_id, client_id_charlie.clone(), tx1, false)
+            .await
+            .unwrap();
+        usecase
+            .execute(&room_id, client_id_alice.clone(), tx2, false)
+            .await
+            .unwrap();
         usecase
-            .execute(client_id_charlie.clone(), tx1)
+            .execute(&room_id, client_id_bob.clone(), tx3, false)
             .await
             .unwrap();
-        usecase.execute(client_id_alice.clone(), tx2).await.unwrap();
-        usecase.execute(client_id_bob.clone(), tx3).await.unwrap();
 
         // when (操作):
-        let result = usecase.build_participant_list().await;
+        let result = usecase.build_participant_list(&room_id).await;
 
         // then (期待する結果): client_id でソートされている
         assert_eq!(result.len(), 3);
@@ -280,4 +832,554 @@ mod tests {
         assert_eq!(result[1].id.as_str(), client_id_bob.as_str());
         assert_eq!(result[2].id.as_str(), client_id_charlie.as_str());
     }
+
+    #[tokio::test]
+    async fn test_participant_list_cache_updates_after_join() {
+        // テスト項目: 参加者リストのキャッシュが、入室後の呼び出しで新しい参加者を含むリストに更新される
+        // given (前提条件):
+        let repository = create_test_repository();
+        let room_id = repository.get_room().await.unwrap().id;
+        let message_pusher = create_test_message_pusher();
+        let usecase = ConnectParticipantUseCase::new(repository.clone(), message_pusher)
+            .with_participant_list_cache();
+        let (tx1, _rx1) = pusher_channel();
+        let (tx2, _rx2) = pusher_channel();
+        usecase
+            .execute(
+                &room_id,
+                ClientId::new("bob".to_string()).unwrap(),
+                tx1,
+                false,
+            )
+            .await
+            .unwrap();
+        let before = usecase.build_participant_list(&room_id).await;
+
+        // when (操作):
+        usecase
+            .execute(
+                &room_id,
+                ClientId::new("alice".to_string()).unwrap(),
+                tx2,
+                false,
+            )
+            .await
+            .unwrap();
+        let after = usecase.build_participant_list(&room_id).await;
+
+        // then (期待する結果):
+        let ids = |list: &[Participant]| {
+            list.iter()
+                .map(|p| p.id.as_str().to_string())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(ids(&before), vec!["bob"]);
+        assert_eq!(ids(&after), vec!["alice", "bob"]);
+        assert_eq!(usecase.build_participant_list(&room_id).await, after);
+    }
+
+    #[tokio::test]
+    async fn test_participant_list_cache_concurrent_connects() {
+        // テスト項目: 同時に接続したクライアントが、自身を含むソート済みの参加者リストを受け取り、最終的に全員を含むリストに収束する
+        // given (前提条件):
+        let repository = create_test_repository();
+        let room_id = repository.get_room().await.unwrap().id;
+        let message_pusher = create_test_message_pusher();
+        let usecase = Arc::new(
+            ConnectParticipantUseCase::new(repository.clone(), message_pusher)
+                .with_participant_list_cache(),
+        );
+        let names: Vec<String> = (0..8).map(|i| format!("client-{}", i)).collect();
+
+        // when (操作):
+        let handles: Vec<_> = names
+            .iter()
+            .map(|name| {
+                let usecase = usecase.clone();
+                let room_id = room_id.clone();
+                let client_id = ClientId::new(name.clone()).unwrap();
+                tokio::spawn(async move {
+                    let (tx, _rx) = pusher_channel();
+                    usecase
+                        .execute(&room_id, client_id.clone(), tx, false)
+                        .await
+                        .unwrap();
+                    (client_id, usecase.build_participant_list(&room_id).await)
+                })
+            })
+            .collect();
+
+        // then (期待する結果):
+        for handle in handles {
+            let (client_id, list) = handle.await.unwrap();
+            assert!(list.iter().any(|p| p.id == client_id));
+            assert!(list.windows(2).all(|w| w[0].id.as_str() < w[1].id.as_str()));
+        }
+        let final_list = usecase.build_participant_list(&room_id).await;
+        let final_ids: Vec<&str> = final_list.iter().map(|p| p.id.as_str()).collect();
+        assert_eq!(
+            final_ids,
+            names.iter().map(String::as_str).collect::<Vec<_>>()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_collect_joins_batches_simultaneous_joins() {
+        // テスト項目: 時間窓の中の同時の入室は 1 つの通知にまとまり、単独の入室は個別に通知される
+        // given (前提条件):
+        let repository = create_test_repository();
+        let room_id = repository.get_room().await.unwrap().id;
+        let message_pusher = create_test_message_pusher();
+        let usecase = Arc::new(
+            ConnectParticipantUseCase::new(repository.clone(), message_pusher).with_join_batching(
+                JoinBatching {
+                    window: std::time::Duration::from_millis(50),
+                    threshold: 2,
+                },
+            ),
+        );
+        let mut participants = Vec::new();
+        let mut receivers = Vec::new();
+        for name in ["alice", "bob", "charlie", "dave"] {
+            let (tx, rx) = pusher_channel();
+            let participant = usecase
+                .execute(
+                    &room_id,
+                    ClientId::new(name.to_string()).unwrap(),
+                    tx,
+                    false,
+                )
+                .await
+                .unwrap();
+            participants.push(participant);
+            receivers.push(rx);
+        }
+        let dave = participants.pop().unwrap();
+
+        // when (操作): 3 人が同時に入室し、時間窓が閉じた後に 1 人が入室
+        let handles: Vec<_> = participants
+            .into_iter()
+            .map(|participant| {
+                let usecase = usecase.clone();
+                let room_id = room_id.clone();
+                tokio::spawn(async move { usecase.collect_joins(&room_id, participant).await })
+            })
+            .collect();
+        let mut results = Vec::new();
+        for handle in handles {
+            results.push(handle.await.unwrap());
+        }
+        let lone = usecase.collect_joins(&room_id, dave.clone()).await;
+
+        // then (期待する結果):
+        let (batch, batched) = results
+            .into_iter()
+            .find(|(joined, _)| !joined.is_empty())
+            .unwrap();
+        let mut ids: Vec<&str> = batch.iter().map(|p| p.id.as_str()).collect();
+        ids.sort();
+        assert!(batched);
+        assert_eq!(ids, vec!["alice", "bob", "charlie"]);
+        assert_eq!(lone, (vec![dave], false));
+    }
+
+    #[tokio::test]
+    async fn test_connect_participant_room_not_found() {
+        // テスト項目: 参加者追加時に Room が存在しない場合、容量超過ではなく RoomNotFound になる
+        // given (前提条件):
+        let usecase =
+            ConnectParticipantUseCase::new(create_test_repository(), create_test_message_pusher());
+        let (tx, _rx) = pusher_channel();
+        let room_id = RoomIdFactory::generate().unwrap();
+
+        // when (操作):
+        let result = usecase
+            .execute(
+                &room_id,
+                ClientId::new("alice".to_string()).unwrap(),
+                tx,
+                false,
+            )
+            .await;
+
+        // then (期待する結果):
+        assert_eq!(result, Err(ConnectError::RoomNotFound));
+    }
+
+    #[tokio::test]
+    async fn test_connect_participant_suffix_on_collision() {
+        // テスト項目: サフィックスモードでは重複した client_id に別の ID が割り当てられる
+        // given (前提条件):
+        let repository = create_test_repository();
+        let room_id = repository.get_room().await.unwrap().id;
+        let usecase =
+            ConnectParticipantUseCase::new(repository.clone(), create_test_message_pusher())
+                .with_collision_policy(ClientIdCollisionPolicy::Suffix);
+
+        // when (操作): "alice" で 3 回接続する
+        let mut assigned = Vec::new();
+        for _ in 0..3 {
+            let (tx, _rx) = pusher_channel();
+            let participant = usecase
+                .execute(
+                    &room_id,
+                    ClientId::new("alice".to_string()).unwrap(),
+                    tx,
+                    false,
+                )
+                .await
+                .unwrap();
+            assigned.push(participant.id.into_string());
+        }
+
+        // then (期待する結果):
+        assert_eq!(assigned, vec!["alice", "alice-2", "alice-3"]);
+        assert_eq!(repository.count_connected_clients().await, 3);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_concurrent_connects_to_different_rooms_reserve_unique_ids() {
+        // テスト項目: 同じ client_id で別々の Room に同時に接続すると、拒否モードでは 1 つだけが入室し、サフィックスモードでは別々の ID が割り当てられる
+        // given (前提条件):
+        let mut outcomes = Vec::new();
+        for policy in [
+            ClientIdCollisionPolicy::Reject,
+            ClientIdCollisionPolicy::Suffix,
+        ] {
+            for _ in 0..20 {
+                let repository = create_test_repository();
+                let default_room_id = repository.get_room().await.unwrap().id;
+                let other_room_id = RoomIdFactory::generate().unwrap();
+                repository
+                    .create_room(other_room_id.clone(), Timestamp::new(0), None)
+                    .await
+                    .unwrap();
+                let usecase = Arc::new(
+                    ConnectParticipantUseCase::new(
+                        repository.clone(),
+                        create_test_message_pusher(),
+                    )
+                    .with_collision_policy(policy),
+                );
+
+                // when (操作):
+                let handles: Vec<_> = [default_room_id, other_room_id]
+                    .into_iter()
+                    .map(|room_id| {
+                        let usecase = usecase.clone();
+                        tokio::spawn(async move {
+                            let (tx, _rx) = pusher_channel();
+                            usecase
+                                .execute(
+                                    &room_id,
+                                    ClientId::new("alice".to_string()).unwrap(),
+                                    tx,
+                                    false,
+                                )
+                                .await
+                                .map(|participant| participant.id.into_string())
+                        })
+                    })
+                    .collect();
+                let mut results = Vec::new();
+                for handle in handles {
+                    results.push(handle.await.unwrap());
+                }
+                let mut connected: Vec<String> = repository
+                    .get_all_connected_client_ids()
+                    .await
+                    .into_iter()
+                    .map(ClientId::into_string)
+                    .collect();
+                connected.sort();
+                outcomes.push((policy, results, connected));
+            }
+        }
+
+        // then (期待する結果):
+        for (policy, results, connected) in outcomes {
+            match policy {
+                ClientIdCollisionPolicy::Reject => {
+                    assert_eq!(results.iter().filter(|r| r.is_ok()).count(), 1);
+                    assert!(
+                        results
+                            .contains(&Err(ConnectError::DuplicateClientId("alice".to_string())))
+                    );
+                    assert_eq!(connected, vec!["alice"]);
+                }
+                ClientIdCollisionPolicy::Suffix => {
+                    assert!(results.iter().all(Result::is_ok));
+                    assert_eq!(connected, vec!["alice", "alice-2"]);
+                }
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_connect_participant_suffix_too_long_rejected() {
+        // テスト項目: サフィックスを付けると長さ制限を超える場合は重複エラーになる
+        // given (前提条件):
+        let repository = create_test_repository();
+        let room_id = repository.get_room().await.unwrap().id;
+        let usecase =
+            ConnectParticipantUseCase::new(repository.clone(), create_test_message_pusher())
+                .with_collision_policy(ClientIdCollisionPolicy::Suffix);
+        let long_id = "a".repeat(100);
+        let (tx1, _rx1) = pusher_channel();
+        usecase
+            .execute(
+                &room_id,
+                ClientId::new(long_id.clone()).unwrap(),
+                tx1,
+                false,
+            )
+            .await
+            .unwrap();
+
+        // when (操作):
+        let (tx2, _rx2) = pusher_channel();
+        let result = usecase
+            .execute(
+                &room_id,
+                ClientId::new(long_id.clone()).unwrap(),
+                tx2,
+                false,
+            )
+            .await;
+
+        // then (期待する結果):
+        assert_eq!(result, Err(ConnectError::DuplicateClientId(long_id)));
+    }
+
+    fn create_welcome_bot() -> WelcomeBot {
+        WelcomeBot {
+            name: ClientId::new("welcome-bot".to_string()).unwrap(),
+            template: "Welcome, {name}!".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_greet_adds_greeting_with_joiner_name() {
+        // テスト項目: ウェルカム bot が参加者の名前を含む挨拶を履歴に追加し、bot は参加者として数えない
+        // given (前提条件):
+        let repository = create_test_repository();
+        let room_id = repository.get_room().await.unwrap().id;
+        let usecase =
+            ConnectParticipantUseCase::new(repository.clone(), create_test_message_pusher())
+                .with_welcome_bot(create_welcome_bot());
+        let (tx, _rx) = pusher_channel();
+        let alice = usecase
+            .execute(
+                &room_id,
+                ClientId::new("alice".to_string()).unwrap(),
+                tx,
+                false,
+            )
+            .await
+            .unwrap();
+
+        // when (操作):
+        let greeting = usecase
+            .greet(&room_id, &alice, None)
+            .await
+            .unwrap()
+            .unwrap();
+
+        // then (期待する結果):
+        assert_eq!(greeting.from.as_str(), "welcome-bot");
+        assert_eq!(greeting.content.as_str(), "Welcome, alice!");
+        let room = repository.get_room().await.unwrap();
+        assert_eq!(room.messages.len(), 1);
+        assert_eq!(room.messages[0].id, greeting.id);
+        assert_eq!(repository.count_connected_clients().await, 1);
+    }
+
+    #[tokio::test]
+    async fn test_greet_uses_joiner_locale() {
+        // テスト項目: Localizer を設定すると、参加者のロケールの挨拶が使われ、未知のロケールはデフォルトロケールになる
+        // given (前提条件):
+        let repository = create_test_repository();
+        let room_id = repository.get_room().await.unwrap().id;
+        let usecase =
+            ConnectParticipantUseCase::new(repository.clone(), create_test_message_pusher())
+                .with_welcome_bot(create_welcome_bot())
+                .with_localizer(Localizer::new("en"));
+        let timestamp = Timestamp::new(get_jst_timestamp());
+        let alice = Participant::new(ClientId::new("alice".to_string()).unwrap(), timestamp);
+
+        // when (操作):
+        let french = usecase
+            .greet(&room_id, &alice, Some("fr"))
+            .await
+            .unwrap()
+            .unwrap();
+        let unknown = usecase
+            .greet(&room_id, &alice, Some("xx"))
+            .await
+            .unwrap()
+            .unwrap();
+
+        // then (期待する結果):
+        assert_eq!(french.content.as_str(), "Bienvenue, alice !");
+        assert_eq!(unknown.content.as_str(), "Welcome, alice!");
+    }
+
+    #[tokio::test]
+    async fn test_backfill_since_timestamp_and_capped() {
+        // テスト項目: 指定した時刻より後のメッセージが返り、上限を超えた場合は新しい方から上限件数だけ返る
+        // given (前提条件):
+        let room = Arc::new(Mutex::new(Room::with_capacity(
+            RoomIdFactory::generate().unwrap(),
+            Timestamp::new(0),
+            10,
+            BACKFILL_MAX_MESSAGES + 10,
+        )));
+        let repository = Arc::new(InMemoryRoomRepository::new(room));
+        let room_id = repository.get_room().await.unwrap().id;
+        let usecase =
+            ConnectParticipantUseCase::new(repository.clone(), create_test_message_pusher());
+        let alice = ClientId::new("alice".to_string()).unwrap();
+        let total = BACKFILL_MAX_MESSAGES as i64 + 1;
+        for timestamp in 1..=total {
+            repository
+                .add_message(
+                    &room_id,
+                    alice.clone(),
+                    MessageContent::new(format!("message {}", timestamp)).unwrap(),
+                    Timestamp::new(timestamp),
+                )
+                .await
+                .unwrap();
+        }
+
+        // when (操作):
+        let all = usecase.backfill(&room_id, Timestamp::new(0)).await.unwrap();
+        let recent = usecase
+            .backfill(&room_id, Timestamp::new(total - 2))
+            .await
+            .unwrap();
+
+        // then (期待する結果):
+        assert!(all.truncated);
+        assert_eq!(all.messages.len(), BACKFILL_MAX_MESSAGES);
+        assert_eq!(all.messages[0].timestamp, Timestamp::new(2));
+        assert_eq!(
+            all.messages.last().unwrap().timestamp,
+            Timestamp::new(total)
+        );
+
+        assert!(!recent.truncated);
+        let timestamps: Vec<Timestamp> = recent.messages.iter().map(|m| m.timestamp).collect();
+        assert_eq!(
+            timestamps,
+            vec![Timestamp::new(total - 1), Timestamp::new(total)]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_greet_skips_bots_and_unconfigured() {
+        // テスト項目: bot の参加者や、ウェルカム bot が設定されていない場合は挨拶しない
+        // given (前提条件):
+        let repository = create_test_repository();
+        let room_id = repository.get_room().await.unwrap().id;
+        let with_bot =
+            ConnectParticipantUseCase::new(repository.clone(), create_test_message_pusher())
+                .with_welcome_bot(create_welcome_bot());
+        let without_bot =
+            ConnectParticipantUseCase::new(repository.clone(), create_test_message_pusher());
+        let timestamp = Timestamp::new(get_jst_timestamp());
+        let bot_joiner =
+            Participant::new(ClientId::new("other-bot".to_string()).unwrap(), timestamp)
+                .with_bot(true);
+        let human_joiner = Participant::new(ClientId::new("alice".to_string()).unwrap(), timestamp);
+
+        // when (操作):
+        let bot_result = with_bot.greet(&room_id, &bot_joiner, None).await;
+        let unconfigured_result = without_bot.greet(&room_id, &human_joiner, None).await;
+
+        // then (期待する結果):
+        assert_eq!(bot_result, Ok(None));
+        assert_eq!(unconfigured_result, Ok(None));
+        assert!(repository.get_room().await.unwrap().messages.is_empty());
+    }
+
+    fn create_join_approval(timeout: std::time::Duration) -> JoinApproval {
+        JoinApproval {
+            admin: ClientId::new("admin".to_string()).unwrap(),
+            timeout,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_request_approval_admitted_when_admin_approves() {
+        // テスト項目: 管理者に入室リクエストが届き、承認すると入室でき、管理者自身は承認不要
+        // given (前提条件):
+        let repository = create_test_repository();
+        let room_id = repository.get_room().await.unwrap().id;
+        let usecase = Arc::new(
+            ConnectParticipantUseCase::new(repository, create_test_message_pusher())
+                .with_join_approval(create_join_approval(std::time::Duration::from_secs(5))),
+        );
+        let admin = ClientId::new("admin".to_string()).unwrap();
+        let alice = ClientId::new("alice".to_string()).unwrap();
+        let (admin_tx, mut admin_rx) = pusher_channel();
+        usecase.request_approval(&admin, "unused").await.unwrap();
+        usecase
+            .execute(&room_id, admin.clone(), admin_tx, false)
+            .await
+            .unwrap();
+
+        // when (操作):
+        let pending = tokio::spawn({
+            let usecase = usecase.clone();
+            let alice = alice.clone();
+            async move { usecase.request_approval(&alice, "join-request").await }
+        });
+        let request = admin_rx.recv().await.unwrap();
+        let ignored = usecase.decide_join(&alice, &alice, true);
+        let decided = usecase.decide_join(&admin, &alice, true);
+
+        // then (期待する結果):
+        assert_eq!(request, "join-request");
+        assert!(!ignored);
+        assert!(decided);
+        assert_eq!(pending.await.unwrap(), Ok(()));
+    }
+
+    #[tokio::test]
+    async fn test_request_approval_rejected_on_denial_timeout_or_absent_admin() {
+        // テスト項目: 管理者の拒否・承認のタイムアウト・管理者の不在では入室できない
+        // given (前提条件):
+        let repository = create_test_repository();
+        let room_id = repository.get_room().await.unwrap().id;
+        let usecase = Arc::new(
+            ConnectParticipantUseCase::new(repository, create_test_message_pusher())
+                .with_join_approval(create_join_approval(std::time::Duration::from_millis(50))),
+        );
+        let admin = ClientId::new("admin".to_string()).unwrap();
+        let alice = ClientId::new("alice".to_string()).unwrap();
+        let bob = ClientId::new("bob".to_string()).unwrap();
+
+        // when (操作):
+        let absent_admin = usecase.request_approval(&alice, "join-request").await;
+        let (admin_tx, mut admin_rx) = pusher_channel();
+        usecase
+            .execute(&room_id, admin.clone(), admin_tx, false)
+            .await
+            .unwrap();
+        let denied = tokio::spawn({
+            let usecase = usecase.clone();
+            let alice = alice.clone();
+            async move { usecase.request_approval(&alice, "join-request").await }
+        });
+        admin_rx.recv().await.unwrap();
+        usecase.decide_join(&admin, &alice, false);
+        let timed_out = usecase.request_approval(&bob, "join-request").await;
+
+        // then (期待する結果):
+        assert_eq!(absent_admin, Err(ConnectError::JoinDenied));
+        assert_eq!(denied.await.unwrap(), Err(ConnectError::JoinDenied));
+        assert_eq!(timed_out, Err(ConnectError::JoinApprovalTimeout));
+        assert!(!usecase.decide_join(&admin, &bob, true));
+    }
 }
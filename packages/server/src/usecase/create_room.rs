@@ -0,0 +1,373 @@
+//! UseCase: ルーム作成処理
+//!
+//! ## テスト実装の作業記録
+//!
+//! ### 何をテストしているか
+//! - CreateRoomUseCase::execute() メソッド
+//! - CreateRoomUseCase::add_room() メソッド
+//!
+//! ### なぜこのテストが必要か
+//! - 指定した上限値でルームが作成されることを確認
+//! - ルームの作成時に RoomCreated イベントが発行されることを保証
+//!
+//! ### どのような状況を想定しているか
+//! - 正常系：サーバ起動時のデフォルトルームの作成（作成者なし）
+//! - 正常系：API からのルームの追加（デフォルトルームと同じ上限値で Repository に追加される）
+//! - 正常系：生成した ID が既存のルームと衝突した場合は、衝突しない ID を生成し直す
+//! - 正常系：作成者のテナントがルームに記録される
+//! - 異常系：テナントのルーム数が上限に達している（他のテナントは作成できる）
+//! - 異常系：Repository が設定されていない
+
+use std::{collections::HashSet, sync::Arc};
+
+use tokio::sync::Mutex;
+
+use crate::domain::{
+    ClientId, Clock, ClockExt, DomainEvent, EventBus, Room, RoomId, RoomIdFactory, RoomRepository,
+    SystemClock,
+};
+
+/// RoomId の元になる UUID の生成関数
+type UuidSource = dyn Fn() -> uuid::Uuid + Send + Sync;
+
+/// ルーム作成のユースケース
+pub struct CreateRoomUseCase {
+    /// EventBus（ドメインイベントの発行の抽象化）
+    event_bus: Arc<dyn EventBus>,
+    /// 作成したルームを追加する Repository（`None` の場合は `add_room` を使えない）
+    repository: Option<Arc<dyn RoomRepository>>,
+    /// 作成時刻を取得する Clock
+    clock: Arc<dyn Clock>,
+    /// RoomId の元になる UUID の生成関数（`None` の場合はランダムな UUID v4）
+    uuid_source: Option<Arc<UuidSource>>,
+    /// テナントごとの同時ルーム数の上限（`None` の場合は無制限）
+    tenant_room_limit: Option<usize>,
+    /// ルーム数の確認から追加までを直列化するロック（同時の作成で上限を超えないようにする）
+    add_room_lock: Mutex<()>,
+}
+
+/// ルーム追加エラー
+#[derive(Debug, PartialEq)]
+pub enum CreateRoomError {
+    /// ルームを追加する Repository が設定されていない
+    NoRepository,
+    /// 作成者のテナントのルーム数が上限に達している
+    TenantRoomLimit,
+    /// Repository エラー
+    RepositoryError,
+}
+
+impl CreateRoomUseCase {
+    /// 新しい CreateRoomUseCase を作成
+    pub fn new(event_bus: Arc<dyn EventBus>) -> Self {
+        Self {
+            event_bus,
+            repository: None,
+            clock: Arc::new(SystemClock),
+            uuid_source: None,
+            tenant_room_limit: None,
+            add_room_lock: Mutex::new(()),
+        }
+    }
+
+    /// 作成したルームを追加する Repository を設定
+    pub fn with_repository(mut self, repository: Arc<dyn RoomRepository>) -> Self {
+        self.repository = Some(repository);
+        self
+    }
+
+    /// 作成時刻を取得する Clock を設定（デフォルトは SystemClock）
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// テナントごとの同時ルーム数の上限を設定
+    ///
+    /// テナントは作成者の `client_id` のプレフィックス（`<tenant>:<id>`）。
+    /// テナントのない作成者・管理 API から作成したルームは数えず、制限もしない。
+    pub fn with_tenant_room_limit(mut self, limit: usize) -> Self {
+        self.tenant_room_limit = Some(limit);
+        self
+    }
+
+    /// RoomId の元になる UUID の生成関数を設定（ID の衝突を再現するテスト用）
+    #[cfg(test)]
+    fn with_uuid_source(mut self, uuid_source: Arc<UuidSource>) -> Self {
+        self.uuid_source = Some(uuid_source);
+        self
+    }
+
+    /// ルームを作成し、RoomCreated イベントを発行
+    ///
+    /// # Arguments
+    ///
+    /// * `participant_capacity` - 参加者数の上限
+    /// * `message_capacity` - メッセージ数の上限
+    /// * `creator` - 作成したクライアント（サーバが作成する場合は `None`）
+    ///
+    /// # Returns
+    ///
+    /// 作成したルーム（Domain Model）
+    pub async fn execute(
+        &self,
+        participant_capacity: usize,
+        message_capacity: usize,
+        creator: Option<ClientId>,
+    ) -> Room {
+        let room = Room::with_capacity(
+            self.generate_room_id().await,
+            self.clock.now(),
+            participant_capacity,
+            message_capacity,
+        );
+        self.publish_room_created(&room, creator).await;
+        room
+    }
+
+    /// 空のルームを作成して Repository に追加し、RoomCreated イベントを発行
+    ///
+    /// 上限値はデフォルトのルームと同じになる。
+    /// 作成者のテナントをルームに記録し、テナントのルーム数が上限に達している場合は追加しない。
+    ///
+    /// # Arguments
+    ///
+    /// * `creator` - 作成したクライアント（管理 API から作成する場合は `None`）
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Room)` - 追加したルーム（Domain Model）
+    /// * `Err(CreateRoomError)` - 追加失敗
+    pub async fn add_room(&self, creator: Option<ClientId>) -> Result<Room, CreateRoomError> {
+        let repository = self
+            .repository
+            .as_ref()
+            .ok_or(CreateRoomError::NoRepository)?;
+        let tenant = creator
+            .as_ref()
+            .and_then(|creator| creator.tenant())
+            .map(str::to_string);
+
+        let _guard = self.add_room_lock.lock().await;
+        if let (Some(limit), Some(tenant)) = (self.tenant_room_limit, &tenant) {
+            let rooms = repository.list_rooms().await;
+            let count = rooms
+                .iter()
+                .filter(|room| room.tenant.as_ref() == Some(tenant))
+                .count();
+            if count >= limit {
+                tracing::warn!("Tenant {} reached the room limit ({})", tenant, limit);
+                return Err(CreateRoomError::TenantRoomLimit);
+            }
+        }
+        let room_id = self.generate_room_id().await;
+        repository
+            .create_room(room_id.clone(), self.clock.now(), tenant)
+            .await
+            .map_err(|_| CreateRoomError::RepositoryError)?;
+        let room = repository
+            .get_room_by_id(&room_id)
+            .await
+            .map_err(|_| CreateRoomError::RepositoryError)?;
+        tracing::info!("Room {} added", room.id.as_str());
+        self.publish_room_created(&room, creator).await;
+        Ok(room)
+    }
+
+    /// 既存のルームと衝突しない RoomId を生成
+    ///
+    /// Repository が設定されていない場合（サーバ起動時のデフォルトルーム）は既存のルームはない。
+    async fn generate_room_id(&self) -> RoomId {
+        let existing: HashSet<RoomId> = match &self.repository {
+            Some(repository) => repository
+                .list_rooms()
+                .await
+                .into_iter()
+                .map(|room| room.id)
+                .collect(),
+            None => HashSet::new(),
+        };
+        match &self.uuid_source {
+            Some(uuid_source) => RoomIdFactory::generate_unique_with(&existing, || uuid_source()),
+            None => RoomIdFactory::generate_unique(&existing),
+        }
+    }
+
+    /// RoomCreated イベントを発行
+    async fn publish_room_created(&self, room: &Room, creator: Option<ClientId>) {
+        self.event_bus
+            .publish(DomainEvent::RoomCreated {
+                room_id: room.id.clone(),
+                participant_capacity: room.participant_capacity,
+                message_capacity: room.message_capacity,
+                created_at: room.created_at,
+                creator,
+            })
+            .await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        domain::{FixedClock, TenantPrefixPolicy, Timestamp},
+        infrastructure::{event_bus::InMemoryEventBus, repository::InMemoryRoomRepository},
+    };
+
+    fn create_test_repository() -> Arc<InMemoryRoomRepository> {
+        Arc::new(InMemoryRoomRepository::new(Arc::new(Mutex::new(
+            Room::new(RoomIdFactory::generate_uuid(), Timestamp::new(0)),
+        ))))
+    }
+
+    fn tenant_client_id(tenant: &str, id: &str) -> ClientId {
+        ClientId::new_with_tenant_policy(
+            format!("{}:{}", tenant, id),
+            &TenantPrefixPolicy::Optional(tenant.to_string()),
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_create_room_emits_room_created_event() {
+        // テスト項目: ルームを作成すると、指定した上限値と Clock の時刻を持つ RoomCreated イベントが発行される
+        // given (前提条件):
+        let event_bus = Arc::new(InMemoryEventBus::new());
+        let usecase =
+            CreateRoomUseCase::new(event_bus.clone()).with_clock(Arc::new(FixedClock::new(1000)));
+
+        // when (操作):
+        let room = usecase.execute(5, 50, None).await;
+
+        // then (期待する結果):
+        assert_eq!(room.participant_capacity, 5);
+        assert_eq!(room.message_capacity, 50);
+        assert_eq!(room.created_at, Timestamp::new(1000));
+        assert_eq!(
+            event_bus.events().await,
+            vec![DomainEvent::RoomCreated {
+                room_id: room.id.clone(),
+                participant_capacity: 5,
+                message_capacity: 50,
+                created_at: Timestamp::new(1000),
+                creator: None,
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_add_room_stores_room_in_repository() {
+        // テスト項目: 追加したルームはデフォルトルームと同じ上限値で Repository に追加され、RoomCreated イベントが発行される
+        // given (前提条件):
+        let event_bus = Arc::new(InMemoryEventBus::new());
+        let default_room =
+            Room::with_capacity(RoomIdFactory::generate_uuid(), Timestamp::new(0), 5, 50);
+        let repository = Arc::new(InMemoryRoomRepository::new(Arc::new(Mutex::new(
+            default_room,
+        ))));
+        let usecase = CreateRoomUseCase::new(event_bus.clone()).with_repository(repository.clone());
+
+        // when (操作):
+        let room = usecase.add_room(None).await.unwrap();
+
+        // then (期待する結果):
+        assert_eq!(room.participant_capacity, 5);
+        assert_eq!(room.message_capacity, 50);
+        assert_eq!(repository.list_rooms().await.len(), 2);
+        assert_eq!(
+            repository.get_room_by_id(&room.id).await.unwrap().id,
+            room.id
+        );
+        assert_eq!(event_bus.events().await.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_add_room_regenerates_colliding_room_id() {
+        // テスト項目: 生成した ID が既存のルームと衝突した場合は、衝突しない ID でルームを追加する
+        // given (前提条件):
+        let taken = uuid::Uuid::new_v4();
+        let fresh = uuid::Uuid::new_v4();
+        let repository = Arc::new(InMemoryRoomRepository::new(Arc::new(Mutex::new(
+            Room::new(RoomId::from(taken), Timestamp::new(0)),
+        ))));
+        let uuids = std::sync::Mutex::new(vec![fresh, taken]);
+        let usecase = CreateRoomUseCase::new(Arc::new(InMemoryEventBus::new()))
+            .with_repository(repository.clone())
+            .with_uuid_source(Arc::new(move || uuids.lock().unwrap().pop().unwrap()));
+
+        // when (操作):
+        let room = usecase.add_room(None).await.unwrap();
+
+        // then (期待する結果):
+        assert_eq!(room.id, RoomId::from(fresh));
+        assert_eq!(repository.list_rooms().await.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_add_room_records_creator_tenant() {
+        // テスト項目: 追加したルームには作成者のテナントが記録され、テナントのない作成者の場合は None になる
+        // given (前提条件):
+        let repository = create_test_repository();
+        let usecase = CreateRoomUseCase::new(Arc::new(InMemoryEventBus::new()))
+            .with_repository(repository.clone());
+
+        // when (操作):
+        let tenant_room = usecase
+            .add_room(Some(tenant_client_id("acme", "alice")))
+            .await
+            .unwrap();
+        let plain_room = usecase
+            .add_room(Some(ClientId::new("bob".to_string()).unwrap()))
+            .await
+            .unwrap();
+
+        // then (期待する結果):
+        assert_eq!(tenant_room.tenant.as_deref(), Some("acme"));
+        assert_eq!(plain_room.tenant, None);
+    }
+
+    #[tokio::test]
+    async fn test_add_room_rejects_tenant_over_room_limit() {
+        // テスト項目: テナントのルーム数が上限に達すると TenantRoomLimit を返し、他のテナントや管理 API からは作成できる
+        // given (前提条件):
+        let repository = create_test_repository();
+        let usecase = CreateRoomUseCase::new(Arc::new(InMemoryEventBus::new()))
+            .with_repository(repository.clone())
+            .with_tenant_room_limit(2);
+        for id in ["alice", "bob"] {
+            usecase
+                .add_room(Some(tenant_client_id("acme", id)))
+                .await
+                .unwrap();
+        }
+
+        // when (操作):
+        let over_limit = usecase
+            .add_room(Some(tenant_client_id("acme", "carol")))
+            .await;
+        let other_tenant = usecase
+            .add_room(Some(tenant_client_id("globex", "dave")))
+            .await;
+        let admin = usecase.add_room(None).await;
+
+        // then (期待する結果):
+        assert_eq!(over_limit.unwrap_err(), CreateRoomError::TenantRoomLimit);
+        assert!(other_tenant.is_ok());
+        assert!(admin.is_ok());
+        assert_eq!(repository.list_rooms().await.len(), 5);
+    }
+
+    #[tokio::test]
+    async fn test_add_room_without_repository() {
+        // テスト項目: Repository が設定されていない場合はルームを追加できない
+        // given (前提条件):
+        let usecase = CreateRoomUseCase::new(Arc::new(InMemoryEventBus::new()));
+
+        // when (操作):
+        let result = usecase.add_room(None).await;
+
+        // then (期待する結果):
+        assert!(matches!(result, Err(CreateRoomError::NoRepository)));
+    }
+}
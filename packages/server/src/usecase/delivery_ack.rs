@@ -0,0 +1,122 @@
+//! 受信確認（delivery ack）の追跡
+//!
+//! ack を要求した参加者に配信したメッセージごとに受信確認待ちを記録し、
+//! 受信確認が届いたら配信済み、タイムアウトしたら未配信として扱う。
+//!
+//! ## テスト実装の作業記録
+//!
+//! ### 何をテストしているか
+//! - DeliveryAckTracker の受信確認待ちの記録・受信確認・期限切れ
+//!
+//! ### なぜこのテストが必要か
+//! - 受信確認を要求していない参加者の配信が追跡されないことを保証
+//! - 同じ受信確認が二重に数えられないことを確認
+//!
+//! ### どのような状況を想定しているか
+//! - 正常系：受信確認待ちのメッセージへの受信確認
+//! - 異常系：受信確認待ちでないメッセージへの受信確認・期限切れ
+
+use std::{collections::HashSet, sync::Mutex, time::Duration};
+
+use crate::domain::{ClientId, MessageId};
+
+/// 受信確認を待つ時間のデフォルト値
+pub const DEFAULT_ACK_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// 受信確認待ちのメッセージの追跡
+#[derive(Debug, Default)]
+pub struct DeliveryAckTracker {
+    /// 受信確認を要求した参加者
+    required: Mutex<HashSet<ClientId>>,
+    /// 受信確認待ちのメッセージと送信先
+    pending: Mutex<HashSet<(MessageId, ClientId)>>,
+}
+
+impl DeliveryAckTracker {
+    /// 参加者が受信確認を送ることを登録
+    pub fn require(&self, client_id: ClientId) {
+        self.required.lock().unwrap().insert(client_id);
+    }
+
+    /// 参加者の受信確認の登録を解除
+    ///
+    /// 受信確認待ちのメッセージはそのまま残り、タイムアウト後に未配信として扱われる。
+    pub fn release(&self, client_id: &ClientId) {
+        self.required.lock().unwrap().remove(client_id);
+    }
+
+    /// 送信先のうち受信確認を要求している参加者について、受信確認待ちを記録
+    ///
+    /// # Returns
+    ///
+    /// 受信確認待ちとして記録した送信先
+    pub fn start(&self, message_id: &MessageId, targets: &[ClientId]) -> Vec<ClientId> {
+        let required = self.required.lock().unwrap();
+        let awaiting: Vec<ClientId> = targets
+            .iter()
+            .filter(|client_id| required.contains(*client_id))
+            .cloned()
+            .collect();
+        let mut pending = self.pending.lock().unwrap();
+        for client_id in &awaiting {
+            pending.insert((message_id.clone(), client_id.clone()));
+        }
+        awaiting
+    }
+
+    /// 受信確認を記録（配信済みにする）
+    ///
+    /// # Returns
+    ///
+    /// 受信確認待ちだった場合は `true`
+    pub fn acknowledge(&self, client_id: &ClientId, message_id: &MessageId) -> bool {
+        self.pending
+            .lock()
+            .unwrap()
+            .remove(&(message_id.clone(), client_id.clone()))
+    }
+
+    /// 受信確認待ちを期限切れにする（未配信にする）
+    ///
+    /// # Returns
+    ///
+    /// 受信確認待ちのままだった場合は `true`
+    pub fn expire(&self, client_id: &ClientId, message_id: &MessageId) -> bool {
+        self.acknowledge(client_id, message_id)
+    }
+
+    /// 受信確認待ちの数
+    pub fn pending_count(&self) -> usize {
+        self.pending.lock().unwrap().len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::RoomIdFactory;
+
+    #[test]
+    fn test_start_tracks_only_clients_requiring_acks() {
+        // テスト項目: 受信確認を要求した参加者だけが受信確認待ちになり、受信確認は一度だけ数えられる
+        // given (前提条件):
+        let tracker = DeliveryAckTracker::default();
+        let message_id = MessageId::new(&RoomIdFactory::generate().unwrap(), 1);
+        let alice = ClientId::new("alice".to_string()).unwrap();
+        let bob = ClientId::new("bob".to_string()).unwrap();
+        tracker.require(alice.clone());
+
+        // when (操作):
+        let awaiting = tracker.start(&message_id, &[alice.clone(), bob.clone()]);
+        let first_ack = tracker.acknowledge(&alice, &message_id);
+        let second_ack = tracker.acknowledge(&alice, &message_id);
+
+        // then (期待する結果):
+        assert_eq!(awaiting, vec![alice.clone()]);
+        assert!(first_ack);
+        assert!(!second_ack);
+        assert!(!tracker.expire(&alice, &message_id));
+        assert!(!tracker.acknowledge(&bob, &message_id));
+        assert_eq!(tracker.pending_count(), 0);
+    }
+}
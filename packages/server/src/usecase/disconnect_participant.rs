@@ -13,12 +13,60 @@
 //!
 //! ### どのような状況を想定しているか
 //! - 正常系：参加者の切断と通知
-//! - エッジケース：最後の参加者の切断（通知対象なし）
+//! - エッジケース：最後の参加者の切断（通知対象なし、退室時刻が Room の最終活動時刻になる）
 //! - 異常系：存在しない参加者の切断試行
+//! - 正常系：接続の要約が EventBus に発行される
+//! - 正常系：強制切断された参加者に通知が届いてから送信チャンネルが閉じられる
+
+use std::{fmt, sync::Arc};
+
+use crate::domain::{
+    ClientId, Clock, ClockExt, DomainEvent, EventBus, MessagePusher, RoomId, RoomRepository,
+    SystemClock,
+};
+
+/// 切断の理由
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DisconnectReason {
+    /// クライアントが接続を閉じた（またはサーバが接続を閉じた）
+    Closed,
+    /// クライアントへの送信がタイムアウトした（応答しないクライアント）
+    Timeout,
+    /// ハートビートのタイムアウトまでクライアントから何も受信しなかった（無言で切断されたクライアント）
+    HeartbeatTimeout,
+    /// 管理者によって強制的に切断された
+    Kicked,
+}
 
-use std::sync::Arc;
+impl fmt::Display for DisconnectReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Closed => write!(f, "closed"),
+            Self::Timeout => write!(f, "timeout"),
+            Self::HeartbeatTimeout => write!(f, "heartbeat_timeout"),
+            Self::Kicked => write!(f, "kicked"),
+        }
+    }
+}
 
-use crate::domain::{ClientId, MessagePusher, RoomRepository};
+/// 接続の終了時に記録する接続の要約
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConnectionSummary {
+    /// 切断したクライアントの ID
+    pub client_id: ClientId,
+    /// 接続していた時間（ミリ秒）
+    pub duration_ms: u64,
+    /// クライアントが送信したチャットメッセージ数
+    pub messages_sent: u64,
+    /// クライアントに送信したフレーム数
+    pub messages_received: u64,
+    /// クライアントから受信したバイト数
+    pub bytes_in: u64,
+    /// クライアントに送信したバイト数
+    pub bytes_out: u64,
+    /// 切断の理由
+    pub reason: DisconnectReason,
+}
 
 /// 参加者切断のユースケース
 pub struct DisconnectParticipantUseCase {
@@ -26,6 +74,10 @@ pub struct DisconnectParticipantUseCase {
     repository: Arc<dyn RoomRepository>,
     /// MessagePusher（メッセージ通知の抽象化）
     message_pusher: Arc<dyn MessagePusher>,
+    /// 接続の要約の発行先（`None` の場合はログにのみ出力する）
+    event_bus: Option<Arc<dyn EventBus>>,
+    /// 退室時刻（Room の最終活動時刻）を取得する Clock
+    clock: Arc<dyn Clock>,
 }
 
 impl DisconnectParticipantUseCase {
@@ -37,55 +89,164 @@ impl DisconnectParticipantUseCase {
         Self {
             repository,
             message_pusher,
+            event_bus: None,
+            clock: Arc::new(SystemClock),
         }
     }
 
+    /// 接続の要約を発行する EventBus を設定
+    pub fn with_event_bus(mut self, event_bus: Arc<dyn EventBus>) -> Self {
+        self.event_bus = Some(event_bus);
+        self
+    }
+
+    /// 退室時刻を取得する Clock を設定（デフォルトは SystemClock）
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
     /// 参加者切断を実行
     ///
     /// # Arguments
     ///
     /// * `client_id` - 切断するクライアントの ID（Domain Model）
+    /// * `reason` - 切断の理由
     ///
     /// # Returns
     ///
     /// * `Ok(Vec<ClientId>)` - 通知対象のクライアント ID リスト（Domain Model）
     /// * `Err(())` - 切断失敗（参加者が存在しない場合）
-    pub async fn execute(&self, client_id: ClientId) -> Result<Vec<ClientId>, ()> {
+    pub async fn execute(
+        &self,
+        client_id: ClientId,
+        reason: DisconnectReason,
+    ) -> Result<Vec<ClientId>, ()> {
         // 1. 参加者が存在するかチェック
         let all_client_ids = self.repository.get_all_connected_client_ids().await;
         if !all_client_ids.iter().any(|id| id == &client_id) {
             return Err(());
         }
 
-        // 2. 通知対象を取得（切断するクライアント以外の全てのクライアント）
+        // 2. 通知対象を取得（同じ Room の切断するクライアント以外の全てのクライアント）
         let notify_targets = self.get_notify_targets(&client_id).await;
 
         // 3. Repository 経由で参加者を削除
         self.repository
-            .remove_participant(&client_id)
+            .remove_participant(&client_id, self.clock.now())
             .await
             .map_err(|_| ())?;
 
         // 4. MessagePusher からクライアントを登録解除（Domain Model を渡す）
         self.message_pusher.unregister_client(&client_id).await;
+        tracing::debug!("Disconnected '{}' (reason: {})", client_id, reason);
 
         Ok(notify_targets)
     }
 
+    /// 参加者を強制的に切断（管理者によるキック）
+    ///
+    /// 対象のクライアントに切断の通知を送信してから、参加者を削除して送信チャンネルを閉じる。
+    /// 残りの参加者への participant-left の通知は、通常の切断と同じく呼び出し側で行う。
+    ///
+    /// # Arguments
+    ///
+    /// * `room_id` - 対象のクライアントが入室している Room の ID（Domain Model）
+    /// * `target` - 切断するクライアントの ID（Domain Model）
+    /// * `reason` - 切断の理由（対象のクライアントに通知される）
+    /// * `build_json_message` - 切断の理由から対象に送信する JSON メッセージを生成する関数（DTO 層）
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<ClientId>)` - 通知対象のクライアント ID リスト（Domain Model）
+    /// * `Err(())` - 切断失敗（参加者が Room に存在しない場合）
+    pub async fn force_disconnect<F>(
+        &self,
+        room_id: &RoomId,
+        target: ClientId,
+        reason: String,
+        build_json_message: F,
+    ) -> Result<Vec<ClientId>, ()>
+    where
+        F: FnOnce(&str) -> String,
+    {
+        // 1. 参加者が Room に存在するかチェック
+        if self
+            .repository
+            .find_participant_room(&target)
+            .await
+            .as_ref()
+            != Some(room_id)
+        {
+            return Err(());
+        }
+
+        // 2. 送信チャンネルを閉じる前に、対象のクライアントに切断の通知を送信
+        let json_message = build_json_message(&reason);
+        if let Err(e) = self.message_pusher.push_to(&target, &json_message).await {
+            tracing::warn!("Failed to send kick notice to '{}': {}", target, e);
+        }
+
+        // 3. 参加者を削除し、送信チャンネルを閉じる
+        tracing::info!("Kicking '{}' (reason: {})", target, reason);
+        self.execute(target, DisconnectReason::Kicked).await
+    }
+
     /// 通知対象のクライアント ID リストを取得
     ///
-    /// 切断するクライアント以外の全てのクライアント ID を返す（Domain Model）
+    /// 切断するクライアントと同じ Room の、切断するクライアント以外の全てのクライアント ID を返す（Domain Model）
     async fn get_notify_targets(&self, exclude_client_id: &ClientId) -> Vec<ClientId> {
-        let all_client_ids = self.repository.get_all_connected_client_ids().await;
-        all_client_ids
+        let Some(room_id) = self
+            .repository
+            .find_participant_room(exclude_client_id)
+            .await
+        else {
+            return Vec::new();
+        };
+        self.repository
+            .get_participants(&room_id)
+            .await
             .into_iter()
+            .map(|p| p.id)
             .filter(|id| id != exclude_client_id)
             .collect()
     }
 
-    /// 残りの参加者数を取得
-    pub async fn count_remaining_participants(&self) -> usize {
-        self.repository.count_connected_clients().await
+    /// 接続の要約をログに出力し、EventBus が設定されていれば発行
+    ///
+    /// # Arguments
+    ///
+    /// * `summary` - 終了した接続の要約
+    pub async fn report_connection_summary(&self, summary: ConnectionSummary) {
+        tracing::info!(
+            client_id = summary.client_id.as_str(),
+            duration_ms = summary.duration_ms,
+            messages_sent = summary.messages_sent,
+            messages_received = summary.messages_received,
+            bytes_in = summary.bytes_in,
+            bytes_out = summary.bytes_out,
+            reason = %summary.reason,
+            "Connection summary"
+        );
+        let Some(event_bus) = &self.event_bus else {
+            return;
+        };
+        event_bus
+            .publish(DomainEvent::ConnectionEnded {
+                client_id: summary.client_id,
+                duration_ms: summary.duration_ms,
+                messages_sent: summary.messages_sent,
+                messages_received: summary.messages_received,
+                bytes_in: summary.bytes_in,
+                bytes_out: summary.bytes_out,
+                reason: summary.reason.to_string(),
+            })
+            .await;
+    }
+
+    /// Room の残りの参加者数を取得
+    pub async fn count_remaining_participants(&self, room_id: &RoomId) -> usize {
+        self.repository.get_participants(room_id).await.len()
     }
 
     /// 参加者が left したことを残りの参加者にブロードキャスト
@@ -107,6 +268,37 @@ impl DisconnectParticipantUseCase {
         self.message_pusher
             .broadcast(target_ids, message)
             .await
+            .map(|_| ())
+            .map_err(|e| e.to_string())
+    }
+
+    /// Room の残りの参加者数を Room の残りの全ての参加者にブロードキャスト
+    ///
+    /// # Arguments
+    ///
+    /// * `room_id` - 参加者が退室した Room の ID（Domain Model）
+    /// * `message` - ブロードキャストするメッセージ（JSON）
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - ブロードキャスト成功
+    /// * `Err(String)` - ブロードキャスト失敗
+    pub async fn broadcast_participant_count(
+        &self,
+        room_id: &RoomId,
+        message: &str,
+    ) -> Result<(), String> {
+        let target_ids: Vec<ClientId> = self
+            .repository
+            .get_participants(room_id)
+            .await
+            .into_iter()
+            .map(|p| p.id)
+            .collect();
+        self.message_pusher
+            .broadcast(target_ids, message)
+            .await
+            .map(|_| ())
             .map_err(|e| e.to_string())
     }
 }
@@ -115,7 +307,7 @@ impl DisconnectParticipantUseCase {
 mod tests {
     use super::*;
     use crate::{
-        domain::{Room, RoomIdFactory, Timestamp},
+        domain::{FixedClock, Participant, Room, RoomIdFactory, Timestamp, pusher_channel},
         infrastructure::{
             message_pusher::WebSocketMessagePusher, repository::InMemoryRoomRepository,
         },
@@ -142,6 +334,7 @@ mod tests {
         // テスト項目: 参加者が正常に切断でき、通知対象が返される
         // given (前提条件):
         let repository = create_test_repository();
+        let room_id = repository.get_room().await.unwrap().id;
         let message_pusher = create_test_message_pusher();
         let usecase = DisconnectParticipantUseCase::new(repository.clone(), message_pusher);
 
@@ -151,20 +344,31 @@ mod tests {
         let bob = ClientId::new("bob".to_string()).unwrap();
         let charlie = ClientId::new("charlie".to_string()).unwrap();
         repository
-            .add_participant(alice.clone(), Timestamp::new(timestamp))
+            .add_participant(
+                &room_id,
+                Participant::new(alice.clone(), Timestamp::new(timestamp)),
+            )
             .await
             .unwrap();
         repository
-            .add_participant(bob.clone(), Timestamp::new(timestamp))
+            .add_participant(
+                &room_id,
+                Participant::new(bob.clone(), Timestamp::new(timestamp)),
+            )
             .await
             .unwrap();
         repository
-            .add_participant(charlie.clone(), Timestamp::new(timestamp))
+            .add_participant(
+                &room_id,
+                Participant::new(charlie.clone(), Timestamp::new(timestamp)),
+            )
             .await
             .unwrap();
 
         // when (操作): alice を切断
-        let result = usecase.execute(alice.clone()).await;
+        let result = usecase
+            .execute(alice.clone(), DisconnectReason::Closed)
+            .await;
 
         // then (期待する結果):
         assert!(result.is_ok());
@@ -184,22 +388,29 @@ mod tests {
 
     #[tokio::test]
     async fn test_disconnect_last_participant() {
-        // テスト項目: 最後の参加者が切断した場合、通知対象は空
+        // テスト項目: 最後の参加者が切断した場合、通知対象は空で、Clock の時刻が Room の最終活動時刻になる
         // given (前提条件):
         let repository = create_test_repository();
+        let room_id = repository.get_room().await.unwrap().id;
         let message_pusher = create_test_message_pusher();
-        let usecase = DisconnectParticipantUseCase::new(repository.clone(), message_pusher);
+        let usecase = DisconnectParticipantUseCase::new(repository.clone(), message_pusher)
+            .with_clock(Arc::new(FixedClock::new(9_000_000_000_000)));
 
         // alice のみ接続
         let timestamp = get_jst_timestamp();
         let alice = ClientId::new("alice".to_string()).unwrap();
         repository
-            .add_participant(alice.clone(), Timestamp::new(timestamp))
+            .add_participant(
+                &room_id,
+                Participant::new(alice.clone(), Timestamp::new(timestamp)),
+            )
             .await
             .unwrap();
 
         // when (操作): alice を切断
-        let result = usecase.execute(alice.clone()).await;
+        let result = usecase
+            .execute(alice.clone(), DisconnectReason::Closed)
+            .await;
 
         // then (期待する結果):
         assert!(result.is_ok());
@@ -210,6 +421,10 @@ mod tests {
 
         // Repository から削除されている
         assert_eq!(repository.count_connected_clients().await, 0);
+        assert_eq!(
+            repository.get_room().await.unwrap().last_activity(),
+            Timestamp::new(9_000_000_000_000)
+        );
     }
 
     #[tokio::test]
@@ -222,17 +437,122 @@ mod tests {
 
         // when (操作): 存在しない参加者を切断
         let nonexistent = ClientId::new("nonexistent".to_string()).unwrap();
-        let result = usecase.execute(nonexistent).await;
+        let result = usecase.execute(nonexistent, DisconnectReason::Closed).await;
 
         // then (期待する結果): エラーが返される
         assert!(result.is_err());
     }
 
+    #[tokio::test]
+    async fn test_report_connection_summary_publishes_event() {
+        // テスト項目: 接続の要約が切断の理由とともに EventBus に発行される
+        // given (前提条件):
+        use crate::infrastructure::event_bus::InMemoryEventBus;
+
+        let event_bus = Arc::new(InMemoryEventBus::new());
+        let usecase = DisconnectParticipantUseCase::new(
+            create_test_repository(),
+            create_test_message_pusher(),
+        )
+        .with_event_bus(event_bus.clone());
+        let alice = ClientId::new("alice".to_string()).unwrap();
+
+        // when (操作):
+        usecase
+            .report_connection_summary(ConnectionSummary {
+                client_id: alice.clone(),
+                duration_ms: 1500,
+                messages_sent: 2,
+                messages_received: 3,
+                bytes_in: 120,
+                bytes_out: 300,
+                reason: DisconnectReason::Timeout,
+            })
+            .await;
+
+        // then (期待する結果):
+        assert_eq!(
+            event_bus.events().await,
+            vec![DomainEvent::ConnectionEnded {
+                client_id: alice,
+                duration_ms: 1500,
+                messages_sent: 2,
+                messages_received: 3,
+                bytes_in: 120,
+                bytes_out: 300,
+                reason: "timeout".to_string(),
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_force_disconnect_sends_kick_notice_before_closing() {
+        // テスト項目: 強制切断すると対象のチャンネルに切断の通知が届いてからチャンネルが閉じられ、参加者が削除されて残りの参加者が通知対象として返される
+        // given (前提条件):
+        let repository = create_test_repository();
+        let room_id = repository.get_room().await.unwrap().id;
+        let message_pusher = create_test_message_pusher();
+        let usecase = DisconnectParticipantUseCase::new(repository.clone(), message_pusher.clone());
+
+        let timestamp = get_jst_timestamp();
+        let alice = ClientId::new("alice".to_string()).unwrap();
+        let bob = ClientId::new("bob".to_string()).unwrap();
+        let (alice_tx, mut alice_rx) = pusher_channel();
+        message_pusher
+            .register_client(alice.clone(), alice_tx)
+            .await;
+        for client_id in [alice.clone(), bob.clone()] {
+            repository
+                .add_participant(
+                    &room_id,
+                    Participant::new(client_id, Timestamp::new(timestamp)),
+                )
+                .await
+                .unwrap();
+        }
+
+        // when (操作): alice を強制切断
+        let result = usecase
+            .force_disconnect(&room_id, alice.clone(), "spam".to_string(), |reason| {
+                format!(r#"{{"type":"kicked","reason":"{}"}}"#, reason)
+            })
+            .await;
+
+        // then (期待する結果):
+        assert_eq!(result.unwrap(), vec![bob.clone()]);
+        assert_eq!(
+            alice_rx.recv().await.unwrap(),
+            r#"{"type":"kicked","reason":"spam"}"#
+        );
+        assert!(alice_rx.recv().await.is_none());
+        assert_eq!(repository.get_all_connected_client_ids().await, vec![bob]);
+    }
+
+    #[tokio::test]
+    async fn test_force_disconnect_nonexistent_participant() {
+        // テスト項目: 接続していない参加者の強制切断はエラーになる
+        // given (前提条件):
+        let repository = create_test_repository();
+        let room_id = repository.get_room().await.unwrap().id;
+        let message_pusher = create_test_message_pusher();
+        let usecase = DisconnectParticipantUseCase::new(repository, message_pusher);
+        let alice = ClientId::new("alice".to_string()).unwrap();
+
+        // when (操作):
+        let result = usecase
+            .force_disconnect(&room_id, alice, "spam".to_string(), |_| String::new())
+            .await;
+
+        // then (期待する結果):
+        assert!(result.is_err());
+    }
+
     #[tokio::test]
     async fn test_count_remaining_participants() {
         // テスト項目: 残りの参加者数を正しくカウントできる
         // given (前提条件):
         let repository = create_test_repository();
+        let room_id = repository.get_room().await.unwrap().id;
         let message_pusher = create_test_message_pusher();
         let usecase = DisconnectParticipantUseCase::new(repository.clone(), message_pusher);
 
@@ -242,27 +562,39 @@ mod tests {
         let bob = ClientId::new("bob".to_string()).unwrap();
         let charlie = ClientId::new("charlie".to_string()).unwrap();
         repository
-            .add_participant(alice.clone(), Timestamp::new(timestamp))
+            .add_participant(
+                &room_id,
+                Participant::new(alice.clone(), Timestamp::new(timestamp)),
+            )
             .await
             .unwrap();
         repository
-            .add_participant(bob.clone(), Timestamp::new(timestamp))
+            .add_participant(
+                &room_id,
+                Participant::new(bob.clone(), Timestamp::new(timestamp)),
+            )
             .await
             .unwrap();
         repository
-            .add_participant(charlie.clone(), Timestamp::new(timestamp))
+            .add_participant(
+                &room_id,
+                Participant::new(charlie.clone(), Timestamp::new(timestamp)),
+            )
             .await
             .unwrap();
 
         // when (操作): 参加者数をカウント
-        let count = usecase.count_remaining_participants().await;
+        let count = usecase.count_remaining_participants(&room_id).await;
 
         // then (期待する結果):
         assert_eq!(count, 3);
 
         // 1人切断
-        usecase.execute(alice.clone()).await.unwrap();
-        let count_after = usecase.count_remaining_participants().await;
+        usecase
+            .execute(alice.clone(), DisconnectReason::Closed)
+            .await
+            .unwrap();
+        let count_after = usecase.count_remaining_participants(&room_id).await;
         assert_eq!(count_after, 2);
     }
 }
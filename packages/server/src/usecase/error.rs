@@ -1,5 +1,7 @@
 //! UseCase layer error definitions.
 
+use crate::domain::{RepositoryError, RoomError};
+
 /// Errors related to participant connection
 #[derive(Debug, PartialEq, Eq)]
 pub enum ConnectError {
@@ -7,6 +9,28 @@ pub enum ConnectError {
     DuplicateClientId(String),
     /// Room の容量超過
     RoomCapacityExceeded,
+    /// Room が存在しない
+    RoomNotFound,
+    /// Room が閉じられている（アーカイブ済み）
+    RoomClosed,
+    /// 入室が承認されなかった（管理者の拒否・管理者の不在）
+    JoinDenied,
+    /// 管理者が時間内に入室の承認・拒否を判断しなかった
+    JoinApprovalTimeout,
+    /// その他の Repository エラー
+    RepositoryError(String),
+}
+
+impl From<RepositoryError> for ConnectError {
+    fn from(error: RepositoryError) -> Self {
+        match error {
+            RepositoryError::Room(RoomError::CapacityExceeded { .. }) => Self::RoomCapacityExceeded,
+            RepositoryError::Room(RoomError::RoomClosed) => Self::RoomClosed,
+            RepositoryError::RoomNotFound => Self::RoomNotFound,
+            RepositoryError::DuplicateClientId(client_id) => Self::DuplicateClientId(client_id),
+            other => Self::RepositoryError(other.to_string()),
+        }
+    }
 }
 
 /// Errors related to message sending
@@ -14,6 +38,159 @@ pub enum ConnectError {
 pub enum SendMessageError {
     /// メッセージ容量超過
     MessageCapacityExceeded,
+    /// Room がロックされている
+    RoomLocked,
+    /// Room が存在しない
+    RoomNotFound,
+    /// ウィスパーの送信者または宛先が接続していない
+    RecipientNotFound,
+    /// 自分宛てのウィスパーを拒否する設定で、自分宛てに送信した
+    SelfMessage,
+    /// 送信者が Room に接続している参加者ではない
+    NotAParticipant,
+    /// 送信者のメッセージ送信レートの上限を超えている
+    RateLimited {
+        /// 次のメッセージを送信できるまでの時間（ミリ秒）
+        retry_after_ms: u64,
+    },
+    /// 編集対象のメッセージが存在しない
+    MessageNotFound,
+    /// 編集しようとしたクライアントがメッセージの作成者ではない
+    NotMessageAuthor,
+    /// 編集のクライアントタイムスタンプが元メッセージのサーバタイムスタンプより古い
+    StaleEdit,
     /// ブロードキャスト失敗
     BroadcastFailed(String),
+    /// その他の Repository エラー
+    RepositoryError(String),
+}
+
+impl SendMessageError {
+    /// エラーの種別を表す安定したコード（`message_capacity_exceeded` など）
+    ///
+    /// 送信者に返すエラーフレームの `code` に使う。
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::MessageCapacityExceeded => "message_capacity_exceeded",
+            Self::RoomLocked => "room_locked",
+            Self::RoomNotFound => "room_not_found",
+            Self::RecipientNotFound => "recipient_not_found",
+            Self::SelfMessage => "self_message",
+            Self::NotAParticipant => "not_a_participant",
+            Self::RateLimited { .. } => "rate_limited",
+            Self::MessageNotFound => "message_not_found",
+            Self::NotMessageAuthor => "not_message_author",
+            Self::StaleEdit => "stale_edit",
+            Self::BroadcastFailed(_) => "broadcast_failed",
+            Self::RepositoryError(_) => "internal_error",
+        }
+    }
+}
+
+impl From<RepositoryError> for SendMessageError {
+    fn from(error: RepositoryError) -> Self {
+        match error {
+            RepositoryError::Room(RoomError::MessageCapacityExceeded { .. }) => {
+                Self::MessageCapacityExceeded
+            }
+            RepositoryError::Room(RoomError::MessageNotFound(_)) => Self::MessageNotFound,
+            RepositoryError::Room(RoomError::NotMessageAuthor(_)) => Self::NotMessageAuthor,
+            RepositoryError::RoomNotFound => Self::RoomNotFound,
+            other => Self::RepositoryError(other.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_connect_error_from_repository_error() {
+        // テスト項目: RepositoryError が種類ごとに対応する ConnectError に変換される
+        // given (前提条件):
+        let capacity = RepositoryError::Room(RoomError::CapacityExceeded {
+            capacity: 1,
+            current: 1,
+        });
+        let not_found = RepositoryError::RoomNotFound;
+        let closed = RepositoryError::Room(RoomError::RoomClosed);
+        let duplicate = RepositoryError::DuplicateClientId("alice".to_string());
+        let other = RepositoryError::ParticipantNotFound("alice".to_string());
+
+        // when (操作) / then (期待する結果):
+        assert_eq!(
+            ConnectError::from(capacity),
+            ConnectError::RoomCapacityExceeded
+        );
+        assert_eq!(ConnectError::from(not_found), ConnectError::RoomNotFound);
+        assert_eq!(ConnectError::from(closed), ConnectError::RoomClosed);
+        assert_eq!(
+            ConnectError::from(duplicate),
+            ConnectError::DuplicateClientId("alice".to_string())
+        );
+        assert_eq!(
+            ConnectError::from(other),
+            ConnectError::RepositoryError("Participant not found: alice".to_string())
+        );
+    }
+
+    #[test]
+    fn test_send_message_error_from_repository_error() {
+        // テスト項目: RepositoryError が種類ごとに対応する SendMessageError に変換される
+        // given (前提条件):
+        let capacity = RepositoryError::Room(RoomError::MessageCapacityExceeded {
+            capacity: 1,
+            current: 1,
+        });
+        let not_found = RepositoryError::RoomNotFound;
+        let message_not_found = RepositoryError::Room(RoomError::MessageNotFound("m".to_string()));
+        let not_author = RepositoryError::Room(RoomError::NotMessageAuthor("m".to_string()));
+
+        // when (操作) / then (期待する結果):
+        assert_eq!(
+            SendMessageError::from(capacity),
+            SendMessageError::MessageCapacityExceeded
+        );
+        assert_eq!(
+            SendMessageError::from(not_found),
+            SendMessageError::RoomNotFound
+        );
+        assert_eq!(
+            SendMessageError::from(message_not_found),
+            SendMessageError::MessageNotFound
+        );
+        assert_eq!(
+            SendMessageError::from(not_author),
+            SendMessageError::NotMessageAuthor
+        );
+    }
+
+    #[test]
+    fn test_send_message_error_code() {
+        // テスト項目: SendMessageError が種類ごとに安定したエラーコードを返す
+        // given (前提条件):
+        let errors = [
+            SendMessageError::MessageCapacityExceeded,
+            SendMessageError::RoomLocked,
+            SendMessageError::RateLimited {
+                retry_after_ms: 100,
+            },
+            SendMessageError::RepositoryError("disk full".to_string()),
+        ];
+
+        // when (操作):
+        let codes: Vec<&str> = errors.iter().map(SendMessageError::code).collect();
+
+        // then (期待する結果):
+        assert_eq!(
+            codes,
+            vec![
+                "message_capacity_exceeded",
+                "room_locked",
+                "rate_limited",
+                "internal_error"
+            ]
+        );
+    }
 }
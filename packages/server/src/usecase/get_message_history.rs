@@ -0,0 +1,180 @@
+//! UseCase: メッセージの編集・削除の履歴（監査ログ）取得処理
+//!
+//! ## テスト実装の作業記録
+//!
+//! ### 何をテストしているか
+//! - GetMessageHistoryUseCase::execute() メソッド
+//!
+//! ### なぜこのテストが必要か
+//! - モデレーターが編集前の内容のハッシュを確認できるよう、メッセージの監査エントリが記録した順に返されることを保証
+//! - 存在しないルーム・メッセージや、監査ログを記録していない場合がエラーになることを確認
+//!
+//! ### どのような状況を想定しているか
+//! - 正常系：編集したメッセージの監査エントリの取得
+//! - 異常系：存在しないメッセージ ID の指定、監査ログの無効化
+
+use std::sync::Arc;
+
+use crate::domain::{
+    MessageAuditEntry, MessageAuditLog, MessageId, RepositoryError, RoomId, RoomRepository,
+};
+
+/// メッセージの編集・削除の履歴取得のユースケース
+pub struct GetMessageHistoryUseCase {
+    /// Repository（データアクセス層の抽象化）
+    repository: Arc<dyn RoomRepository>,
+    /// メッセージの編集・削除の監査ログ（`None` の場合は記録していない）
+    audit_log: Option<Arc<dyn MessageAuditLog>>,
+}
+
+/// メッセージの編集・削除の履歴取得エラー
+#[derive(Debug, PartialEq)]
+pub enum GetMessageHistoryError {
+    /// 監査ログを記録していない
+    AuditDisabled,
+    /// ルームが見つからない
+    RoomNotFound,
+    /// ルームにメッセージが見つからない
+    MessageNotFound,
+    /// Repository エラー
+    RepositoryError,
+}
+
+impl GetMessageHistoryUseCase {
+    /// 新しい GetMessageHistoryUseCase を作成
+    pub fn new(repository: Arc<dyn RoomRepository>) -> Self {
+        Self {
+            repository,
+            audit_log: None,
+        }
+    }
+
+    /// 参照する監査ログを設定（SendMessageUseCase に設定したものと同じ監査ログ）
+    pub fn with_audit_log(mut self, audit_log: Arc<dyn MessageAuditLog>) -> Self {
+        self.audit_log = Some(audit_log);
+        self
+    }
+
+    /// メッセージの編集・削除の履歴を取得
+    ///
+    /// # Arguments
+    ///
+    /// * `room_id` - メッセージのルームの ID
+    /// * `message_id` - メッセージの ID
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<MessageAuditEntry>)` - 監査エントリ（記録した順。編集・削除していない場合は空）
+    /// * `Err(GetMessageHistoryError)` - 取得失敗
+    pub async fn execute(
+        &self,
+        room_id: String,
+        message_id: String,
+    ) -> Result<Vec<MessageAuditEntry>, GetMessageHistoryError> {
+        let Some(audit_log) = &self.audit_log else {
+            return Err(GetMessageHistoryError::AuditDisabled);
+        };
+        let room_id = RoomId::new(room_id).map_err(|_| GetMessageHistoryError::RoomNotFound)?;
+        let message_id =
+            MessageId::parse(message_id).map_err(|_| GetMessageHistoryError::MessageNotFound)?;
+        let room = self
+            .repository
+            .get_room_by_id(&room_id)
+            .await
+            .map_err(|e| match e {
+                RepositoryError::RoomNotFound => GetMessageHistoryError::RoomNotFound,
+                _ => GetMessageHistoryError::RepositoryError,
+            })?;
+        if !room
+            .messages
+            .iter()
+            .any(|message| message.id.as_ref() == Some(&message_id))
+        {
+            return Err(GetMessageHistoryError::MessageNotFound);
+        }
+        Ok(audit_log.history(&room_id, &message_id).await)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        domain::{ClientId, MessageAuditAction, MessageContent, Room, RoomIdFactory, Timestamp},
+        infrastructure::{
+            message_audit::InMemoryMessageAuditLog, repository::InMemoryRoomRepository,
+        },
+    };
+    use tokio::sync::Mutex;
+
+    fn create_test_repository() -> Arc<InMemoryRoomRepository> {
+        let room = Arc::new(Mutex::new(Room::new(
+            RoomIdFactory::generate().unwrap(),
+            Timestamp::new(1000),
+        )));
+        Arc::new(InMemoryRoomRepository::new(room))
+    }
+
+    #[tokio::test]
+    async fn test_get_message_history() {
+        // テスト項目: メッセージの監査エントリが返され、存在しないメッセージ ID はエラーになる
+        // given (前提条件):
+        let repository = create_test_repository();
+        let audit_log = Arc::new(InMemoryMessageAuditLog::new());
+        let usecase =
+            GetMessageHistoryUseCase::new(repository.clone()).with_audit_log(audit_log.clone());
+        let room_id = repository.get_room().await.unwrap().id;
+        let alice = ClientId::new("alice".to_string()).unwrap();
+        let message_id = repository
+            .add_message(
+                &room_id,
+                alice.clone(),
+                MessageContent::new("Hello".to_string()).unwrap(),
+                Timestamp::new(1000),
+            )
+            .await
+            .unwrap();
+        let entry = MessageAuditEntry {
+            room_id: room_id.clone(),
+            message_id: message_id.clone(),
+            action: MessageAuditAction::Delete,
+            actor: alice,
+            before_hash: "before".to_string(),
+            after_hash: "after".to_string(),
+            at: Timestamp::new(2000),
+        };
+        audit_log.record(entry.clone()).await;
+
+        // when (操作):
+        let history = usecase
+            .execute(room_id.to_string(), message_id.to_string())
+            .await;
+        let unknown = usecase
+            .execute(
+                room_id.to_string(),
+                MessageId::new(&room_id, 99).to_string(),
+            )
+            .await;
+
+        // then (期待する結果):
+        assert_eq!(history, Ok(vec![entry]));
+        assert_eq!(unknown, Err(GetMessageHistoryError::MessageNotFound));
+    }
+
+    #[tokio::test]
+    async fn test_get_message_history_without_audit_log() {
+        // テスト項目: 監査ログを記録していない場合はエラーになる
+        // given (前提条件):
+        let repository = create_test_repository();
+        let usecase = GetMessageHistoryUseCase::new(repository.clone());
+        let room_id = repository.get_room().await.unwrap().id;
+
+        // when (操作):
+        let result = usecase
+            .execute(room_id.to_string(), MessageId::new(&room_id, 1).to_string())
+            .await;
+
+        // then (期待する結果):
+        assert_eq!(result, Err(GetMessageHistoryError::AuditDisabled));
+    }
+}
@@ -2,7 +2,7 @@
 
 use std::sync::Arc;
 
-use crate::domain::{Room, RoomRepository};
+use crate::domain::{RepositoryError, Room, RoomId, RoomRepository};
 
 /// ルーム詳細取得のユースケース
 pub struct GetRoomDetailUseCase {
@@ -36,17 +36,13 @@ impl GetRoomDetailUseCase {
     /// * `Ok(Room)` - ルームの詳細情報（Domain Model）
     /// * `Err(GetRoomDetailError)` - 取得失敗
     pub async fn execute(&self, room_id: String) -> Result<Room, GetRoomDetailError> {
-        let room = self
-            .repository
-            .get_room()
+        let room_id = RoomId::new(room_id).map_err(|_| GetRoomDetailError::RoomNotFound)?;
+        self.repository
+            .get_room_by_id(&room_id)
             .await
-            .map_err(|_| GetRoomDetailError::RepositoryError)?;
-
-        // Check if the requested room_id matches
-        if room.id.as_str() != room_id {
-            return Err(GetRoomDetailError::RoomNotFound);
-        }
-
-        Ok(room)
+            .map_err(|e| match e {
+                RepositoryError::RoomNotFound => GetRoomDetailError::RoomNotFound,
+                _ => GetRoomDetailError::RepositoryError,
+            })
     }
 }
@@ -0,0 +1,148 @@
+//! UseCase: ルームの活動状況の取得処理
+//!
+//! 購読したクライアントに定期的に送信する、ルームの活動状況（直近 1 分間のメッセージ数と参加者数）を取得します。
+//!
+//! ## テスト実装の作業記録
+//!
+//! ### 何をテストしているか
+//! - GetRoomStatsUseCase::execute() メソッド
+//!
+//! ### なぜこのテストが必要か
+//! - 直近 1 分間のメッセージだけが数えられることを保証
+//!
+//! ### どのような状況を想定しているか
+//! - 正常系：1 分より前のメッセージと直近のメッセージが混在する場合
+
+use std::sync::Arc;
+
+use crate::domain::{ClientId, MessagePusher, RepositoryError, RoomId, RoomRepository, Timestamp};
+
+/// メッセージ数を数える期間（ミリ秒）
+const MESSAGE_RATE_WINDOW_MS: i64 = 60_000;
+
+/// ルームの活動状況
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RoomStats {
+    /// 接続中の参加者数
+    pub participant_count: usize,
+    /// 直近 1 分間のメッセージ数（履歴から削除されたメッセージは数えない）
+    pub messages_per_minute: usize,
+}
+
+/// ルームの活動状況取得のユースケース
+pub struct GetRoomStatsUseCase {
+    /// Repository（データアクセス層の抽象化）
+    repository: Arc<dyn RoomRepository>,
+    /// MessagePusher（メッセージ通知の抽象化）
+    message_pusher: Arc<dyn MessagePusher>,
+}
+
+impl GetRoomStatsUseCase {
+    /// 新しい GetRoomStatsUseCase を作成
+    pub fn new(
+        repository: Arc<dyn RoomRepository>,
+        message_pusher: Arc<dyn MessagePusher>,
+    ) -> Self {
+        Self {
+            repository,
+            message_pusher,
+        }
+    }
+
+    /// ルームの活動状況を取得
+    ///
+    /// # Arguments
+    ///
+    /// * `room_id` - 対象のルームの ID（Domain Model）
+    /// * `now` - 現在時刻（直近 1 分間の起点）
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(RoomStats)` - ルームの活動状況
+    /// * `Err(RepositoryError)` - 取得失敗
+    pub async fn execute(
+        &self,
+        room_id: &RoomId,
+        now: Timestamp,
+    ) -> Result<RoomStats, RepositoryError> {
+        let room = self.repository.get_room_by_id(room_id).await?;
+        let since = now.value() - MESSAGE_RATE_WINDOW_MS;
+        let messages_per_minute = room
+            .messages
+            .iter()
+            .filter(|message| message.timestamp.value() > since)
+            .count();
+        Ok(RoomStats {
+            participant_count: room.participants.len(),
+            messages_per_minute,
+        })
+    }
+
+    /// 購読したクライアントに活動状況を送信
+    ///
+    /// # Arguments
+    ///
+    /// * `client_id` - 購読したクライアントの ID（Domain Model）
+    /// * `message` - 送信するメッセージ（JSON）
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - 送信成功
+    /// * `Err(String)` - 送信失敗（クライアントが切断済みなど）
+    pub async fn push_stats(&self, client_id: &ClientId, message: &str) -> Result<(), String> {
+        self.message_pusher
+            .push_to(client_id, message)
+            .await
+            .map_err(|e| e.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        domain::{ChatMessage, MessageContent, Participant, Room, RoomIdFactory},
+        infrastructure::{
+            message_pusher::WebSocketMessagePusher, repository::InMemoryRoomRepository,
+        },
+    };
+    use std::collections::HashMap;
+    use tokio::sync::Mutex;
+
+    #[tokio::test]
+    async fn test_room_stats_counts_only_recent_messages() {
+        // テスト項目: 直近 1 分間のメッセージ数と接続中の参加者数が取得される
+        // given (前提条件):
+        let now = Timestamp::new(1_000_000);
+        let alice = ClientId::new("alice".to_string()).unwrap();
+        let mut room = Room::new(RoomIdFactory::generate().unwrap(), Timestamp::new(0));
+        room.add_participant(Participant::new(alice.clone(), Timestamp::new(0)))
+            .unwrap();
+        for timestamp in [now.value() - 90_000, now.value() - 30_000, now.value()] {
+            room.add_message(ChatMessage::new(
+                alice.clone(),
+                MessageContent::new("Hello".to_string()).unwrap(),
+                Timestamp::new(timestamp),
+            ))
+            .unwrap();
+        }
+        let repository = Arc::new(InMemoryRoomRepository::new(Arc::new(Mutex::new(room))));
+        let room_id = repository.get_room().await.unwrap().id;
+        let message_pusher = Arc::new(WebSocketMessagePusher::new(Arc::new(Mutex::new(
+            HashMap::new(),
+        ))));
+        let usecase = GetRoomStatsUseCase::new(repository, message_pusher);
+
+        // when (操作):
+        let stats = usecase.execute(&room_id, now).await.unwrap();
+
+        // then (期待する結果):
+        assert_eq!(
+            stats,
+            RoomStats {
+                participant_count: 1,
+                messages_per_minute: 2,
+            }
+        );
+    }
+}
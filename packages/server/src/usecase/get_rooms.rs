@@ -23,7 +23,6 @@ impl GetRoomsUseCase {
     /// * `Ok(Vec<Room>)` - ルーム一覧（Domain Model）
     /// * `Err(())` - 取得失敗
     pub async fn execute(&self) -> Result<Vec<Room>, ()> {
-        let room = self.repository.get_room().await.map_err(|_| ())?;
-        Ok(vec![room])
+        Ok(self.repository.list_rooms().await)
     }
 }
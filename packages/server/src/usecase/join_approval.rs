@@ -0,0 +1,127 @@
+//! 入室承認（join approval）の追跡
+//!
+//! 承認が必要なルームでは、接続しようとしたクライアントを承認待ち（PendingApproval）として記録し、
+//! ルーム管理者の承認・拒否、またはタイムアウトによって入室の可否を決定する。
+//!
+//! ## テスト実装の作業記録
+//!
+//! ### 何をテストしているか
+//! - JoinApprovalGate の承認待ちの記録・承認・拒否・取り消し
+//!
+//! ### なぜこのテストが必要か
+//! - 承認待ちのクライアントに管理者の判断が一度だけ届くことを保証
+//! - 承認待ちでないクライアントへの判断が無視されることを確認
+//!
+//! ### どのような状況を想定しているか
+//! - 正常系：承認待ちのクライアントの承認・拒否
+//! - 異常系：承認待ちでないクライアントへの判断・取り消し後の判断
+
+use std::{collections::HashMap, sync::Mutex, time::Duration};
+
+use tokio::sync::oneshot;
+
+use crate::domain::ClientId;
+
+/// 入室承認を待つ時間のデフォルト値
+pub const DEFAULT_JOIN_APPROVAL_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// 入室に管理者の承認を必要とする設定
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JoinApproval {
+    /// 入室を承認するルーム管理者（管理者自身は承認なしで入室できる）
+    pub admin: ClientId,
+    /// 承認を待つ時間（超過した場合は拒否として扱う）
+    pub timeout: Duration,
+}
+
+/// 承認待ち（PendingApproval）のクライアントの追跡
+#[derive(Debug, Default)]
+pub struct JoinApprovalGate {
+    /// 承認待ちのクライアントと、管理者の判断を通知するチャンネル
+    pending: Mutex<HashMap<ClientId, oneshot::Sender<bool>>>,
+}
+
+impl JoinApprovalGate {
+    /// クライアントを承認待ちとして記録
+    ///
+    /// # Returns
+    ///
+    /// * `Some(Receiver)` - 管理者の判断（承認なら `true`）を受け取るチャンネル
+    /// * `None` - 同じクライアントが既に承認待ちの場合
+    pub fn register(&self, client_id: ClientId) -> Option<oneshot::Receiver<bool>> {
+        let mut pending = self.pending.lock().unwrap();
+        if pending.contains_key(&client_id) {
+            return None;
+        }
+        let (tx, rx) = oneshot::channel();
+        pending.insert(client_id, tx);
+        Some(rx)
+    }
+
+    /// 承認待ちのクライアントに管理者の判断を通知
+    ///
+    /// # Returns
+    ///
+    /// 承認待ちだった場合は `true`
+    pub fn resolve(&self, client_id: &ClientId, approved: bool) -> bool {
+        match self.pending.lock().unwrap().remove(client_id) {
+            Some(tx) => tx.send(approved).is_ok(),
+            None => false,
+        }
+    }
+
+    /// 承認待ちを取り消す（タイムアウトなど）
+    pub fn cancel(&self, client_id: &ClientId) {
+        self.pending.lock().unwrap().remove(client_id);
+    }
+
+    /// 承認待ちの数
+    pub fn pending_count(&self) -> usize {
+        self.pending.lock().unwrap().len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_resolve_notifies_pending_client_once() {
+        // テスト項目: 承認待ちのクライアントに管理者の判断が一度だけ通知される
+        // given (前提条件):
+        let gate = JoinApprovalGate::default();
+        let alice = ClientId::new("alice".to_string()).unwrap();
+        let bob = ClientId::new("bob".to_string()).unwrap();
+        let rx = gate.register(alice.clone()).unwrap();
+
+        // when (操作):
+        let duplicate = gate.register(alice.clone());
+        let first = gate.resolve(&alice, true);
+        let second = gate.resolve(&alice, false);
+
+        // then (期待する結果):
+        assert!(duplicate.is_none());
+        assert!(first);
+        assert!(!second);
+        assert!(!gate.resolve(&bob, true));
+        assert!(rx.await.unwrap());
+        assert_eq!(gate.pending_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_cancel_drops_pending_client() {
+        // テスト項目: 取り消した承認待ちへの判断は無視され、待っている側には通知されない
+        // given (前提条件):
+        let gate = JoinApprovalGate::default();
+        let alice = ClientId::new("alice".to_string()).unwrap();
+        let rx = gate.register(alice.clone()).unwrap();
+
+        // when (操作):
+        gate.cancel(&alice);
+        let resolved = gate.resolve(&alice, true);
+
+        // then (期待する結果):
+        assert!(!resolved);
+        assert!(rx.await.is_err());
+    }
+}
@@ -0,0 +1,172 @@
+//! 入室通知のバッチ化（join batching）
+//!
+//! 多数のクライアントが同時に接続すると、入室ごとに全員へ `participant-joined` を送るため
+//! フレーム数が参加者数の 2 乗に比例して増える。短い時間窓（window）の間に入室した参加者をまとめ、
+//! 閾値（threshold）以上であれば 1 つの通知にまとめて送る。
+//!
+//! ## テスト実装の作業記録
+//!
+//! ### 何をテストしているか
+//! - JoinBatcher の入室のまとめ方
+//!
+//! ### なぜこのテストが必要か
+//! - 時間窓の中の入室が 1 つのバッチにまとまることを保証
+//! - 時間窓を開いた入室だけがバッチを受け取り、同じ入室が二重に通知されないことを確認
+//!
+//! ### どのような状況を想定しているか
+//! - 正常系：同時の入室が 1 つのバッチにまとまる
+//! - 正常系：時間窓が閉じた後の入室は新しいバッチになる
+
+use std::{collections::HashMap, sync::Mutex, time::Duration};
+
+use crate::domain::{Participant, RoomId};
+
+/// 入室通知をまとめる閾値のデフォルト値
+pub const DEFAULT_JOIN_BATCH_THRESHOLD: usize = 3;
+
+/// 入室通知をまとめる設定
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct JoinBatching {
+    /// 入室をまとめる時間窓（最初の入室から数える）
+    pub window: Duration,
+    /// まとめた入室がこの数以上の場合に 1 つの通知にまとめる（未満の場合は個別に通知する）
+    pub threshold: usize,
+}
+
+impl JoinBatching {
+    /// まとめた入室を 1 つの通知で送るかどうか
+    pub fn should_batch(&self, joined_count: usize) -> bool {
+        joined_count >= self.threshold.max(2)
+    }
+}
+
+/// 時間窓の中の入室の収集（Room ごとに別の時間窓を開く）
+#[derive(Debug)]
+pub struct JoinBatcher {
+    /// 入室通知をまとめる設定
+    batching: JoinBatching,
+    /// Room ごとの通知待ちの入室（Room のエントリがない場合は時間窓が開いていない）
+    pending: Mutex<HashMap<RoomId, Vec<Participant>>>,
+}
+
+impl JoinBatcher {
+    /// 新しい JoinBatcher を作成
+    pub fn new(batching: JoinBatching) -> Self {
+        Self {
+            batching,
+            pending: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// 入室通知をまとめる設定
+    pub fn batching(&self) -> JoinBatching {
+        self.batching
+    }
+
+    /// Room への入室を追加し、時間窓が閉じるまでに同じ Room に入室した参加者を受け取る
+    ///
+    /// # Returns
+    ///
+    /// * `Some(Vec<Participant>)` - この入室が時間窓を開いた場合、時間窓の中の全ての入室（入室順）
+    /// * `None` - 既に開いている時間窓に追加された場合（時間窓を開いた側が通知する）
+    pub async fn collect(
+        &self,
+        room_id: &RoomId,
+        participant: Participant,
+    ) -> Option<Vec<Participant>> {
+        let opened = {
+            let mut pending = self.pending.lock().unwrap();
+            let joined = pending.entry(room_id.clone()).or_default();
+            joined.push(participant);
+            joined.len() == 1
+        };
+        if !opened {
+            return None;
+        }
+        tokio::time::sleep(self.batching.window).await;
+        self.pending.lock().unwrap().remove(room_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{ClientId, Timestamp};
+    use std::sync::Arc;
+
+    fn create_test_batcher(window: Duration) -> JoinBatcher {
+        JoinBatcher::new(JoinBatching {
+            window,
+            threshold: DEFAULT_JOIN_BATCH_THRESHOLD,
+        })
+    }
+
+    fn room(id: u128) -> RoomId {
+        RoomId::from(uuid::Uuid::from_u128(id))
+    }
+
+    fn participant(name: &str) -> Participant {
+        Participant::new(ClientId::new(name.to_string()).unwrap(), Timestamp::new(0))
+    }
+
+    #[tokio::test]
+    async fn test_collect_coalesces_joins_within_window() {
+        // テスト項目: 時間窓の中の入室は、時間窓を開いた入室のバッチにまとめられる
+        // given (前提条件):
+        let batcher = Arc::new(create_test_batcher(Duration::from_millis(50)));
+        let opener = {
+            let batcher = batcher.clone();
+            tokio::spawn(async move { batcher.collect(&room(1), participant("alice")).await })
+        };
+        tokio::time::sleep(Duration::from_millis(10)).await;
+
+        // when (操作):
+        let bob = batcher.collect(&room(1), participant("bob")).await;
+        let batch = opener.await.unwrap().unwrap();
+
+        // then (期待する結果):
+        assert!(bob.is_none());
+        let ids: Vec<&str> = batch.iter().map(|p| p.id.as_str()).collect();
+        assert_eq!(ids, vec!["alice", "bob"]);
+    }
+
+    #[tokio::test]
+    async fn test_collect_opens_new_window_after_flush() {
+        // テスト項目: 時間窓が閉じた後の入室は、新しいバッチになる
+        // given (前提条件):
+        let batcher = create_test_batcher(Duration::from_millis(10));
+        let first = batcher
+            .collect(&room(1), participant("alice"))
+            .await
+            .unwrap();
+
+        // when (操作):
+        let second = batcher.collect(&room(1), participant("bob")).await.unwrap();
+
+        // then (期待する結果):
+        assert_eq!(first.len(), 1);
+        assert_eq!(second.len(), 1);
+        assert_eq!(second[0].id.as_str(), "bob");
+    }
+
+    #[tokio::test]
+    async fn test_collect_keeps_rooms_apart() {
+        // テスト項目: 別の Room への入室は同じ時間窓にまとめられず、それぞれの Room で時間窓を開く
+        // given (前提条件):
+        let batcher = Arc::new(create_test_batcher(Duration::from_millis(50)));
+        let opener = {
+            let batcher = batcher.clone();
+            tokio::spawn(async move { batcher.collect(&room(1), participant("alice")).await })
+        };
+        tokio::time::sleep(Duration::from_millis(10)).await;
+
+        // when (操作):
+        let other_room = batcher.collect(&room(2), participant("bob")).await;
+        let batch = opener.await.unwrap().unwrap();
+
+        // then (期待する結果):
+        assert_eq!(other_room.unwrap()[0].id.as_str(), "bob");
+        assert_eq!(batch.len(), 1);
+        assert_eq!(batch[0].id.as_str(), "alice");
+    }
+}
@@ -0,0 +1,106 @@
+//! メッセージ配信の遅延のヒストグラム
+//!
+//! サーバがメッセージを受信してから最後の受信者のチャンネルに渡すまでの時間（ミリ秒）を、
+//! 固定のバケット境界（`LATENCY_BUCKETS_MS`）で集計する。
+//! 時刻は `SendMessageUseCase` に注入された `Clock` で計測するため、テストでは時刻を固定できる。
+//!
+//! ## テスト実装の作業記録
+//!
+//! ### 何をテストしているか
+//! - LatencyHistogram のバケットごとの累積件数、件数、合計
+//!
+//! ### なぜこのテストが必要か
+//! - メトリクスとして公開する累積件数が、境界値を含めて正しく集計されることを保証
+//!
+//! ### どのような状況を想定しているか
+//! - 正常系：境界値ちょうどの遅延、どのバケットにも入らない大きな遅延
+
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// ヒストグラムのバケットの上限（ミリ秒、昇順）
+pub const LATENCY_BUCKETS_MS: [u64; 11] = [1, 5, 10, 25, 50, 100, 250, 500, 1000, 2500, 5000];
+
+/// ヒストグラムのある時点の集計結果
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LatencyHistogramSnapshot {
+    /// バケットの上限（ミリ秒）と、遅延がその上限以下だった件数（累積）
+    pub buckets: Vec<(u64, u64)>,
+    /// 計測した件数
+    pub count: u64,
+    /// 計測した遅延の合計（ミリ秒）
+    pub sum_ms: u64,
+}
+
+/// メッセージ配信の遅延のヒストグラム
+#[derive(Debug, Default)]
+pub struct LatencyHistogram {
+    /// バケットごとの件数（累積ではない。どのバケットにも入らない遅延は数えない）
+    bucket_counts: [AtomicU64; LATENCY_BUCKETS_MS.len()],
+    /// 計測した件数
+    count: AtomicU64,
+    /// 計測した遅延の合計（ミリ秒）
+    sum_ms: AtomicU64,
+}
+
+impl LatencyHistogram {
+    /// 新しい LatencyHistogram を作成
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 遅延を 1 件記録
+    pub fn observe(&self, latency_ms: u64) {
+        if let Some(index) = LATENCY_BUCKETS_MS
+            .iter()
+            .position(|&upper_ms| latency_ms <= upper_ms)
+        {
+            self.bucket_counts[index].fetch_add(1, Ordering::Relaxed);
+        }
+        self.count.fetch_add(1, Ordering::Relaxed);
+        self.sum_ms.fetch_add(latency_ms, Ordering::Relaxed);
+    }
+
+    /// 現在の集計結果を取得
+    pub fn snapshot(&self) -> LatencyHistogramSnapshot {
+        let mut cumulative = 0;
+        let buckets = LATENCY_BUCKETS_MS
+            .iter()
+            .zip(&self.bucket_counts)
+            .map(|(&upper_ms, bucket_count)| {
+                cumulative += bucket_count.load(Ordering::Relaxed);
+                (upper_ms, cumulative)
+            })
+            .collect();
+        LatencyHistogramSnapshot {
+            buckets,
+            count: self.count.load(Ordering::Relaxed),
+            sum_ms: self.sum_ms.load(Ordering::Relaxed),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_snapshot_has_cumulative_bucket_counts() {
+        // テスト項目: 遅延は上限がその値以上の最初のバケットに数えられ、集計結果のバケットは累積件数になる。どのバケットにも入らない遅延は件数と合計にのみ含まれる
+        // given (前提条件):
+        let histogram = LatencyHistogram::new();
+
+        // when (操作):
+        for latency_ms in [0, 1, 7, 60_000] {
+            histogram.observe(latency_ms);
+        }
+        let snapshot = histogram.snapshot();
+
+        // then (期待する結果):
+        assert_eq!(snapshot.buckets[0], (1, 2));
+        assert_eq!(snapshot.buckets[1], (5, 2));
+        assert_eq!(snapshot.buckets[2], (10, 3));
+        assert_eq!(snapshot.buckets.last(), Some(&(5000, 3)));
+        assert_eq!(snapshot.count, 4);
+        assert_eq!(snapshot.sum_ms, 60_008);
+    }
+}
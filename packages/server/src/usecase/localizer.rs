@@ -0,0 +1,198 @@
+//! システムメッセージの多言語化
+//!
+//! ウェルカム bot の挨拶、アナウンス、エラーフレームなどのシステムメッセージを、
+//! メッセージ ID とロケールをキーにしたテンプレートから生成する。
+//! 要求されたロケールのテンプレートがない場合は、言語部分（`fr-CA` → `fr`）、
+//! デフォルトロケール、組み込みのデフォルト（`en`）の順にフォールバックする。
+//!
+//! ## テスト実装の作業記録
+//!
+//! ### 何をテストしているか
+//! - Localizer::localize() メソッド
+//!
+//! ### なぜこのテストが必要か
+//! - 要求されたロケールのテンプレートが使われ、プレースホルダが置き換えられることを保証
+//! - 未知のロケールがデフォルトロケールにフォールバックすることを確認
+//!
+//! ### どのような状況を想定しているか
+//! - 正常系：組み込みのロケール・地域付きのロケール
+//! - 異常系：未知のロケール・未知のメッセージ ID
+
+use std::collections::HashMap;
+
+/// 組み込みのデフォルトロケール（フォールバックの最後）
+pub const DEFAULT_LOCALE: &str = "en";
+
+/// ウェルカム bot の挨拶（`{name}` は参加者の名前に置き換えられる）
+pub const MSG_WELCOME: &str = "welcome";
+/// 想定外のバイナリフレームを拒否したときのエラーメッセージ
+pub const MSG_UNEXPECTED_BINARY: &str = "unexpected_binary";
+/// サーバ停止のアナウンス
+pub const MSG_SERVER_SHUTDOWN: &str = "server_shutdown";
+
+/// 組み込みのテンプレート（ロケール、メッセージ ID、テンプレート）
+const BUILTIN_TEMPLATES: &[(&str, &str, &str)] = &[
+    ("en", MSG_WELCOME, "Welcome, {name}!"),
+    ("fr", MSG_WELCOME, "Bienvenue, {name} !"),
+    ("ja", MSG_WELCOME, "ようこそ、{name} さん！"),
+    (
+        "en",
+        MSG_UNEXPECTED_BINARY,
+        "binary frames are not supported on this connection",
+    ),
+    (
+        "fr",
+        MSG_UNEXPECTED_BINARY,
+        "les trames binaires ne sont pas prises en charge sur cette connexion",
+    ),
+    (
+        "ja",
+        MSG_UNEXPECTED_BINARY,
+        "この接続ではバイナリフレームを利用できません",
+    ),
+    ("en", MSG_SERVER_SHUTDOWN, "server is shutting down"),
+    ("fr", MSG_SERVER_SHUTDOWN, "le serveur est en cours d'arrêt"),
+    ("ja", MSG_SERVER_SHUTDOWN, "サーバを停止しています"),
+];
+
+/// メッセージ ID とロケールをキーにしたシステムメッセージのテンプレート
+#[derive(Debug, Clone)]
+pub struct Localizer {
+    /// ロケールが指定されていない、または未知のロケールの場合に使うロケール
+    default_locale: String,
+    /// ロケールごとのテンプレート（メッセージ ID → テンプレート）
+    templates: HashMap<String, HashMap<String, String>>,
+}
+
+impl Default for Localizer {
+    fn default() -> Self {
+        Self::new(DEFAULT_LOCALE)
+    }
+}
+
+impl Localizer {
+    /// 組み込みのテンプレートを持つ Localizer を作成
+    ///
+    /// # Arguments
+    ///
+    /// * `default_locale` - デフォルトロケール（例: `ja`）
+    pub fn new(default_locale: &str) -> Self {
+        let mut localizer = Self {
+            default_locale: normalize_locale(default_locale),
+            templates: HashMap::new(),
+        };
+        for (locale, id, template) in BUILTIN_TEMPLATES {
+            localizer = localizer.with_template(locale, id, template);
+        }
+        localizer
+    }
+
+    /// テンプレートを追加（同じロケール・メッセージ ID のテンプレートは置き換える）
+    pub fn with_template(mut self, locale: &str, id: &str, template: &str) -> Self {
+        self.templates
+            .entry(normalize_locale(locale))
+            .or_default()
+            .insert(id.to_string(), template.to_string());
+        self
+    }
+
+    /// デフォルトロケール
+    pub fn default_locale(&self) -> &str {
+        &self.default_locale
+    }
+
+    /// フォールバックを適用してテンプレートを取得
+    ///
+    /// # Arguments
+    ///
+    /// * `locale` - 要求されたロケール（`None` の場合はデフォルトロケール）
+    /// * `id` - メッセージ ID
+    ///
+    /// # Returns
+    ///
+    /// 見つかったテンプレート（どのロケールにもない場合は `None`）
+    pub fn template(&self, locale: Option<&str>, id: &str) -> Option<&str> {
+        let requested = locale.map(normalize_locale);
+        let candidates = [
+            requested.clone(),
+            requested.as_deref().map(base_language),
+            Some(self.default_locale.clone()),
+            Some(base_language(&self.default_locale)),
+            Some(DEFAULT_LOCALE.to_string()),
+        ];
+        candidates.into_iter().flatten().find_map(|candidate| {
+            self.templates
+                .get(&candidate)
+                .and_then(|templates| templates.get(id))
+                .map(String::as_str)
+        })
+    }
+
+    /// システムメッセージを生成
+    ///
+    /// テンプレート中の `{key}` は `args` の値に置き換えられる。
+    /// どのロケールにもテンプレートがない場合はメッセージ ID をそのまま返す。
+    ///
+    /// # Arguments
+    ///
+    /// * `locale` - 要求されたロケール（`None` の場合はデフォルトロケール）
+    /// * `id` - メッセージ ID
+    /// * `args` - プレースホルダの名前と値
+    pub fn localize(&self, locale: Option<&str>, id: &str, args: &[(&str, &str)]) -> String {
+        let Some(template) = self.template(locale, id) else {
+            return id.to_string();
+        };
+        args.iter()
+            .fold(template.to_string(), |text, (key, value)| {
+                text.replace(&format!("{{{}}}", key), value)
+            })
+    }
+}
+
+/// ロケールを比較用に正規化（小文字化し、`_` を `-` に揃える）
+fn normalize_locale(locale: &str) -> String {
+    locale.trim().to_ascii_lowercase().replace('_', "-")
+}
+
+/// ロケールの言語部分（`fr-ca` → `fr`）
+fn base_language(locale: &str) -> String {
+    locale.split('-').next().unwrap_or(locale).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_localize_uses_requested_locale() {
+        // テスト項目: 要求されたロケール（地域付きを含む）のテンプレートが使われ、プレースホルダが置き換えられる
+        // given (前提条件):
+        let localizer = Localizer::new("en");
+
+        // when (操作):
+        let french = localizer.localize(Some("fr"), MSG_WELCOME, &[("name", "alice")]);
+        let canadian = localizer.localize(Some("fr_CA"), MSG_WELCOME, &[("name", "alice")]);
+
+        // then (期待する結果):
+        assert_eq!(french, "Bienvenue, alice !");
+        assert_eq!(canadian, "Bienvenue, alice !");
+    }
+
+    #[test]
+    fn test_localize_falls_back_to_default_locale() {
+        // テスト項目: 未知のロケールはデフォルトロケールに、未知のメッセージ ID は ID そのものにフォールバックする
+        // given (前提条件):
+        let localizer =
+            Localizer::new("ja").with_template("ja", MSG_WELCOME, "{name} さん、こんにちは");
+
+        // when (操作):
+        let unknown_locale = localizer.localize(Some("xx"), MSG_WELCOME, &[("name", "alice")]);
+        let no_locale = localizer.localize(None, MSG_SERVER_SHUTDOWN, &[]);
+        let unknown_id = localizer.localize(Some("fr"), "no_such_message", &[]);
+
+        // then (期待する結果):
+        assert_eq!(unknown_locale, "alice さん、こんにちは");
+        assert_eq!(no_locale, "サーバを停止しています");
+        assert_eq!(unknown_id, "no_such_message");
+    }
+}
@@ -4,17 +4,47 @@
 //! UI 層から呼び出され、Domain 層を操作します。
 
 pub mod connect_participant;
+pub mod create_room;
+pub mod delivery_ack;
 pub mod disconnect_participant;
 pub mod error;
+pub mod get_message_history;
 pub mod get_room_detail;
 pub mod get_room_state;
+pub mod get_room_stats;
 pub mod get_rooms;
+pub mod join_approval;
+pub mod join_batch;
+pub mod latency_histogram;
+pub mod localizer;
+pub mod rate_limit;
+pub mod remove_room;
 pub mod send_message;
+pub mod shutdown_server;
+pub mod update_room;
 
-pub use connect_participant::ConnectParticipantUseCase;
-pub use disconnect_participant::DisconnectParticipantUseCase;
+pub use connect_participant::{
+    BACKFILL_MAX_MESSAGES, Backfill, ClientIdCollisionPolicy, ConnectParticipantUseCase, WelcomeBot,
+};
+pub use create_room::{CreateRoomError, CreateRoomUseCase};
+pub use delivery_ack::{DEFAULT_ACK_TIMEOUT, DeliveryAckTracker};
+pub use disconnect_participant::{
+    ConnectionSummary, DisconnectParticipantUseCase, DisconnectReason,
+};
 pub use error::{ConnectError, SendMessageError};
+pub use get_message_history::{GetMessageHistoryError, GetMessageHistoryUseCase};
 pub use get_room_detail::{GetRoomDetailError, GetRoomDetailUseCase};
 pub use get_room_state::GetRoomStateUseCase;
+pub use get_room_stats::{GetRoomStatsUseCase, RoomStats};
 pub use get_rooms::GetRoomsUseCase;
-pub use send_message::SendMessageUseCase;
+pub use join_approval::{DEFAULT_JOIN_APPROVAL_TIMEOUT, JoinApproval, JoinApprovalGate};
+pub use join_batch::{DEFAULT_JOIN_BATCH_THRESHOLD, JoinBatcher, JoinBatching};
+pub use latency_histogram::{LATENCY_BUCKETS_MS, LatencyHistogram, LatencyHistogramSnapshot};
+pub use localizer::{
+    DEFAULT_LOCALE, Localizer, MSG_SERVER_SHUTDOWN, MSG_UNEXPECTED_BINARY, MSG_WELCOME,
+};
+pub use rate_limit::{BurstLimiter, MessageBurstCap, MessageRateLimit, RateLimiter};
+pub use remove_room::{RemoveRoomError, RemoveRoomUseCase, spawn_idle_room_reaper};
+pub use send_message::{BotRecipientPolicy, SelfMessagePolicy, SendMessageUseCase, SentMessage};
+pub use shutdown_server::ShutdownServerUseCase;
+pub use update_room::{UpdateRoomError, UpdateRoomUseCase};
@@ -0,0 +1,338 @@
+//! クライアントごとのメッセージ送信レート制限
+//!
+//! クライアントごとのトークンバケットで、1 秒あたりに送信できるメッセージ数を制限する。
+//! バケットは最大 `burst` 個のトークンを持ち、1 秒あたり `messages_per_sec` 個ずつ補充される。
+//! メッセージを 1 件送信するたびにトークンを 1 個消費し、トークンがなければ送信を拒否する。
+//!
+//! トークンバケットだけでは、貯まったトークンを一度に使い切る連続送信を防げない。
+//! `BurstLimiter` はクライアントごとの直近の送信時刻を保持し、短いウィンドウ
+//! （例: 100ms）あたりの送信数を `max_messages` 件までに制限する。
+//!
+//! ## テスト実装の作業記録
+//!
+//! ### 何をテストしているか
+//! - RateLimiter のトークンの消費・補充・再送信までの待ち時間
+//! - BurstLimiter の短いウィンドウあたりの送信数の制限
+//!
+//! ### なぜこのテストが必要か
+//! - バーストを超えた送信が拒否され、補充後に再び送信できることを保証
+//! - あるクライアントの送信が他のクライアントの制限に影響しないことを確認
+//!
+//! ### どのような状況を想定しているか
+//! - 正常系：バースト以内の連続送信、時間経過によるトークンの補充、ウィンドウ経過後の送信
+//! - 正常系：保存されなかった送信のトークンと送信時刻を戻すと、再び送信できる
+//! - 異常系：バーストを超えた送信、短いウィンドウ内の上限を超えた送信
+
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::Mutex,
+};
+
+use crate::domain::{ClientId, Timestamp};
+
+/// メッセージ送信レートの上限
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MessageRateLimit {
+    /// 1 秒あたりに補充されるトークン数（持続的に送信できるメッセージ数）
+    pub messages_per_sec: u32,
+    /// バケットに貯められるトークン数の上限（連続して送信できるメッセージ数）
+    pub burst: u32,
+}
+
+/// 短いウィンドウあたりの送信数の上限
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MessageBurstCap {
+    /// ウィンドウ内に送信できるメッセージ数
+    pub max_messages: u32,
+    /// ウィンドウの長さ（ミリ秒）
+    pub window_ms: u64,
+}
+
+/// 1 トークンを表す単位量（ミリ秒単位の補充を整数で計算するため）
+const TOKEN: u64 = 1000;
+
+/// クライアントごとのトークンバケット
+#[derive(Debug, Clone, Copy)]
+struct Bucket {
+    /// 残りのトークン量（`TOKEN` で 1 トークン）
+    tokens: u64,
+    /// 最後にトークンを補充した時刻（ミリ秒）
+    refilled_at: i64,
+}
+
+/// クライアントごとのメッセージ送信レート制限
+#[derive(Debug)]
+pub struct RateLimiter {
+    /// 1 ミリ秒あたりに補充されるトークン量（`TOKEN` で 1 トークン）
+    refill_per_ms: u64,
+    /// バケットに貯められるトークン量の上限
+    capacity: u64,
+    /// クライアントごとのバケット（最初の送信時に満杯の状態で作成する）
+    buckets: Mutex<HashMap<ClientId, Bucket>>,
+}
+
+impl RateLimiter {
+    /// 新しい RateLimiter を作成
+    pub fn new(limit: MessageRateLimit) -> Self {
+        Self {
+            refill_per_ms: u64::from(limit.messages_per_sec.max(1)),
+            capacity: u64::from(limit.burst.max(1)) * TOKEN,
+            buckets: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// `now` に送信するメッセージのトークンを消費
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - トークンを消費した（送信してよい）
+    /// * `Err(retry_after_ms)` - トークンがない。次のトークンが補充されるまでの時間（ミリ秒）
+    pub fn try_acquire(&self, client_id: &ClientId, now: Timestamp) -> Result<(), u64> {
+        let now = now.value();
+        let mut buckets = self.buckets.lock().unwrap();
+        let bucket = buckets.entry(client_id.clone()).or_insert(Bucket {
+            tokens: self.capacity,
+            refilled_at: now,
+        });
+        // 時刻が巻き戻った場合は補充しない
+        let elapsed = u64::try_from(now - bucket.refilled_at).unwrap_or(0);
+        bucket.tokens = bucket
+            .tokens
+            .saturating_add(elapsed.saturating_mul(self.refill_per_ms))
+            .min(self.capacity);
+        bucket.refilled_at = bucket.refilled_at.max(now);
+        if bucket.tokens >= TOKEN {
+            bucket.tokens -= TOKEN;
+            Ok(())
+        } else {
+            Err((TOKEN - bucket.tokens).div_ceil(self.refill_per_ms))
+        }
+    }
+
+    /// 消費したトークンを 1 個戻す（送信が保存されずに失敗した場合）
+    pub fn refund(&self, client_id: &ClientId) {
+        if let Some(bucket) = self.buckets.lock().unwrap().get_mut(client_id) {
+            bucket.tokens = bucket.tokens.saturating_add(TOKEN).min(self.capacity);
+        }
+    }
+
+    /// クライアントのバケットを破棄（切断時）
+    pub fn release(&self, client_id: &ClientId) {
+        self.buckets.lock().unwrap().remove(client_id);
+    }
+}
+
+/// クライアントごとの短いウィンドウあたりの送信数の制限
+///
+/// 判定（`check`）と記録（`record`）を分けているのは、`RateLimiter` と組み合わせたときに
+/// どちらかで拒否された送信をウィンドウの送信数に数えないため。
+#[derive(Debug)]
+pub struct BurstLimiter {
+    /// ウィンドウ内に送信できるメッセージ数
+    max_messages: usize,
+    /// ウィンドウの長さ（ミリ秒）
+    window_ms: i64,
+    /// クライアントごとのウィンドウ内の送信時刻（古い順）
+    sent_at: Mutex<HashMap<ClientId, VecDeque<i64>>>,
+}
+
+impl BurstLimiter {
+    /// 新しい BurstLimiter を作成
+    pub fn new(cap: MessageBurstCap) -> Self {
+        Self {
+            max_messages: cap.max_messages.max(1) as usize,
+            window_ms: i64::try_from(cap.window_ms.max(1)).unwrap_or(i64::MAX),
+            sent_at: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// `now` にメッセージを送信できるか判定（送信数には数えない）
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - ウィンドウ内の送信数が上限未満（送信してよい）
+    /// * `Err(retry_after_ms)` - 上限に達している。ウィンドウ内の最も古い送信がウィンドウから外れるまでの時間（ミリ秒）
+    pub fn check(&self, client_id: &ClientId, now: Timestamp) -> Result<(), u64> {
+        let now = now.value();
+        let mut sent_at = self.sent_at.lock().unwrap();
+        let Some(times) = sent_at.get_mut(client_id) else {
+            return Ok(());
+        };
+        while times.front().is_some_and(|&t| t + self.window_ms <= now) {
+            times.pop_front();
+        }
+        match times.front() {
+            Some(&oldest) if times.len() >= self.max_messages => {
+                Err(u64::try_from(oldest + self.window_ms - now).unwrap_or(0))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// `now` に送信したメッセージをウィンドウの送信数に記録
+    pub fn record(&self, client_id: &ClientId, now: Timestamp) {
+        let mut sent_at = self.sent_at.lock().unwrap();
+        let times = sent_at.entry(client_id.clone()).or_default();
+        times.push_back(now.value());
+        // 上限を超えた分は判定に使わないので保持しない
+        while times.len() > self.max_messages {
+            times.pop_front();
+        }
+    }
+
+    /// `record` で記録した `now` の送信を取り消す（送信が保存されずに失敗した場合）
+    pub fn unrecord(&self, client_id: &ClientId, now: Timestamp) {
+        let mut sent_at = self.sent_at.lock().unwrap();
+        if let Some(times) = sent_at.get_mut(client_id)
+            && let Some(position) = times.iter().rposition(|&t| t == now.value())
+        {
+            times.remove(position);
+        }
+    }
+
+    /// クライアントの送信時刻を破棄（切断時）
+    pub fn release(&self, client_id: &ClientId) {
+        self.sent_at.lock().unwrap().remove(client_id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_messages_beyond_burst_are_rejected_until_refilled() {
+        // テスト項目: バーストまでの連続送信は許可され、超えた送信は次のトークンが補充されるまでの時間とともに拒否される
+        // given (前提条件):
+        let limiter = RateLimiter::new(MessageRateLimit {
+            messages_per_sec: 5,
+            burst: 2,
+        });
+        let alice = ClientId::new("alice".to_string()).unwrap();
+
+        // when (操作):
+        let first = limiter.try_acquire(&alice, Timestamp::new(1000));
+        let second = limiter.try_acquire(&alice, Timestamp::new(1000));
+        let third = limiter.try_acquire(&alice, Timestamp::new(1050));
+        let after_refill = limiter.try_acquire(&alice, Timestamp::new(1200));
+
+        // then (期待する結果):
+        assert_eq!(first, Ok(()));
+        assert_eq!(second, Ok(()));
+        assert_eq!(third, Err(150));
+        assert_eq!(after_refill, Ok(()));
+    }
+
+    #[test]
+    fn test_clients_have_separate_buckets() {
+        // テスト項目: あるクライアントがトークンを使い切っても、他のクライアントは送信でき、解放したクライアントのバケットは満杯に戻る
+        // given (前提条件):
+        let limiter = RateLimiter::new(MessageRateLimit {
+            messages_per_sec: 1,
+            burst: 1,
+        });
+        let alice = ClientId::new("alice".to_string()).unwrap();
+        let bob = ClientId::new("bob".to_string()).unwrap();
+        limiter.try_acquire(&alice, Timestamp::new(1000)).unwrap();
+
+        // when (操作):
+        let alice_again = limiter.try_acquire(&alice, Timestamp::new(1000));
+        let bob_first = limiter.try_acquire(&bob, Timestamp::new(1000));
+        limiter.release(&alice);
+        let alice_after_release = limiter.try_acquire(&alice, Timestamp::new(1000));
+
+        // then (期待する結果):
+        assert_eq!(alice_again, Err(1000));
+        assert_eq!(bob_first, Ok(()));
+        assert_eq!(alice_after_release, Ok(()));
+    }
+
+    #[test]
+    fn test_burst_within_short_window_is_rejected() {
+        // テスト項目: トークンバケットの平均レート以内でも、短いウィンドウ内で上限を超えた送信は拒否され、ウィンドウから外れると再び送信できる
+        // given (前提条件):
+        let rate_limiter = RateLimiter::new(MessageRateLimit {
+            messages_per_sec: 10,
+            burst: 10,
+        });
+        let burst_limiter = BurstLimiter::new(MessageBurstCap {
+            max_messages: 2,
+            window_ms: 100,
+        });
+        let alice = ClientId::new("alice".to_string()).unwrap();
+        let send = |now: i64| {
+            let now = Timestamp::new(now);
+            burst_limiter.check(&alice, now)?;
+            rate_limiter.try_acquire(&alice, now)?;
+            burst_limiter.record(&alice, now);
+            Ok::<(), u64>(())
+        };
+
+        // when (操作):
+        let first = send(1000);
+        let second = send(1030);
+        let third = send(1060);
+        let after_window = send(1100);
+
+        // then (期待する結果):
+        assert_eq!(first, Ok(()));
+        assert_eq!(second, Ok(()));
+        assert_eq!(third, Err(40));
+        assert_eq!(after_window, Ok(()));
+    }
+
+    #[test]
+    fn test_burst_limiter_release_clears_window() {
+        // テスト項目: 解放したクライアントのウィンドウは空に戻り、他のクライアントの送信数には影響しない
+        // given (前提条件):
+        let limiter = BurstLimiter::new(MessageBurstCap {
+            max_messages: 1,
+            window_ms: 100,
+        });
+        let alice = ClientId::new("alice".to_string()).unwrap();
+        let bob = ClientId::new("bob".to_string()).unwrap();
+        limiter.record(&alice, Timestamp::new(1000));
+
+        // when (操作):
+        let alice_again = limiter.check(&alice, Timestamp::new(1010));
+        let bob_first = limiter.check(&bob, Timestamp::new(1010));
+        limiter.release(&alice);
+        let alice_after_release = limiter.check(&alice, Timestamp::new(1010));
+
+        // then (期待する結果):
+        assert_eq!(alice_again, Err(90));
+        assert_eq!(bob_first, Ok(()));
+        assert_eq!(alice_after_release, Ok(()));
+    }
+
+    #[test]
+    fn test_refund_and_unrecord_restore_send_slot() {
+        // テスト項目: 消費したトークンを戻し、記録した送信時刻を取り消すと、同じ時刻に再び送信できる（トークンはバケットの上限を超えない）
+        // given (前提条件):
+        let rate_limiter = RateLimiter::new(MessageRateLimit {
+            messages_per_sec: 1,
+            burst: 1,
+        });
+        let burst_limiter = BurstLimiter::new(MessageBurstCap {
+            max_messages: 1,
+            window_ms: 100,
+        });
+        let alice = ClientId::new("alice".to_string()).unwrap();
+        let now = Timestamp::new(1000);
+        rate_limiter.try_acquire(&alice, now).unwrap();
+        burst_limiter.record(&alice, now);
+
+        // when (操作):
+        rate_limiter.refund(&alice);
+        rate_limiter.refund(&alice);
+        burst_limiter.unrecord(&alice, now);
+        let retried = burst_limiter
+            .check(&alice, now)
+            .and_then(|()| rate_limiter.try_acquire(&alice, now));
+        let beyond_burst = rate_limiter.try_acquire(&alice, now);
+
+        // then (期待する結果):
+        assert_eq!(retried, Ok(()));
+        assert_eq!(beyond_burst, Err(1000));
+    }
+}
@@ -0,0 +1,345 @@
+//! UseCase: ルーム削除処理
+//!
+//! ## テスト実装の作業記録
+//!
+//! ### 何をテストしているか
+//! - RemoveRoomUseCase::execute() メソッド
+//! - RemoveRoomUseCase::reap_idle_rooms() メソッド
+//! - 参加していたクライアントの ID の返却と、送信チャンネルの解除
+//!
+//! ### なぜこのテストが必要か
+//! - 削除したルームの参加者の WebSocket タスクが終了する（送信チャンネルが閉じられる）ことを保証
+//! - 存在しないルームの削除がエラーになることを確認
+//! - 無活動のルームだけが削除され、RoomClosed イベントが発行されることを保証
+//!
+//! ### どのような状況を想定しているか
+//! - 正常系：参加者がいるルームの削除
+//! - 異常系：存在しないルーム ID の指定
+//! - 正常系：無活動の空のルームは削除され、最近活動したルーム・参加者がいるルーム・デフォルトのルームは残る
+
+use std::{sync::Arc, time::Duration};
+
+use tokio::task::JoinHandle;
+
+use crate::domain::{
+    ClientId, Clock, ClockExt, DomainEvent, EventBus, MessagePusher, RepositoryError, RoomId,
+    RoomRepository, SystemClock,
+};
+
+/// ルーム削除のユースケース
+pub struct RemoveRoomUseCase {
+    /// Repository（データアクセス層の抽象化）
+    repository: Arc<dyn RoomRepository>,
+    /// MessagePusher（メッセージ通知の抽象化）
+    message_pusher: Arc<dyn MessagePusher>,
+    /// 無活動のルームを閉じたときの RoomClosed の発行先（`None` の場合はログにのみ出力する）
+    event_bus: Option<Arc<dyn EventBus>>,
+    /// 無活動の判定に使う現在時刻を取得する Clock
+    clock: Arc<dyn Clock>,
+}
+
+/// ルーム削除エラー
+#[derive(Debug, PartialEq)]
+pub enum RemoveRoomError {
+    /// ルームが見つからない
+    RoomNotFound,
+    /// Repository エラー
+    RepositoryError,
+}
+
+impl RemoveRoomUseCase {
+    /// 新しい RemoveRoomUseCase を作成
+    pub fn new(
+        repository: Arc<dyn RoomRepository>,
+        message_pusher: Arc<dyn MessagePusher>,
+    ) -> Self {
+        Self {
+            repository,
+            message_pusher,
+            event_bus: None,
+            clock: Arc::new(SystemClock),
+        }
+    }
+
+    /// 無活動のルームを閉じたときに RoomClosed を発行する EventBus を設定
+    pub fn with_event_bus(mut self, event_bus: Arc<dyn EventBus>) -> Self {
+        self.event_bus = Some(event_bus);
+        self
+    }
+
+    /// 無活動の判定に使う Clock を設定（デフォルトは SystemClock）
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// ルームを削除し、参加していたクライアントを切断
+    ///
+    /// 参加していたクライアントの送信チャンネルを閉じることで、各クライアントの WebSocket タスクを終了させる。
+    ///
+    /// # Arguments
+    ///
+    /// * `room_id` - 削除するルームの ID
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<String>)` - ルームに参加していたクライアントの ID
+    /// * `Err(RemoveRoomError)` - 削除失敗
+    pub async fn execute(&self, room_id: String) -> Result<Vec<String>, RemoveRoomError> {
+        let room_id = RoomId::new(room_id).map_err(|_| RemoveRoomError::RoomNotFound)?;
+
+        let evicted = self
+            .repository
+            .remove_room(&room_id)
+            .await
+            .map_err(|e| match e {
+                RepositoryError::RoomNotFound => RemoveRoomError::RoomNotFound,
+                _ => RemoveRoomError::RepositoryError,
+            })?;
+
+        for client_id in &evicted {
+            if let Ok(client_id) = ClientId::restore(client_id.clone()) {
+                self.message_pusher.unregister_client(&client_id).await;
+            }
+        }
+
+        tracing::info!(
+            "Room {} removed ({} client(s) evicted)",
+            room_id.as_str(),
+            evicted.len()
+        );
+        Ok(evicted)
+    }
+
+    /// 無活動のルームを閉じて削除し、RoomClosed イベントを発行
+    ///
+    /// 参加者がおらず、最後の活動（メッセージ、または参加者の増減）から `idle_timeout` 以上経ったルームが対象。
+    /// デフォルトのルームは対象外。判定と削除の間に入室されないよう、先にルームを閉じてから
+    /// 無活動であることを確認し直し、活動があった場合は開き直して残す。
+    ///
+    /// # Arguments
+    ///
+    /// * `idle_timeout` - ルームを削除するまでの無活動の時間
+    ///
+    /// # Returns
+    ///
+    /// 削除したルームの ID
+    pub async fn reap_idle_rooms(&self, idle_timeout: Duration) -> Vec<RoomId> {
+        let idle_timeout_ms = i64::try_from(idle_timeout.as_millis()).unwrap_or(i64::MAX);
+        let default_room_id = self.repository.get_room().await.ok().map(|room| room.id);
+        let mut reaped = Vec::new();
+        for room in self.repository.list_rooms().await {
+            let now = self.clock.now();
+            if Some(&room.id) == default_room_id.as_ref()
+                || room.closed
+                || !room.is_idle(now, idle_timeout_ms)
+            {
+                continue;
+            }
+            if self
+                .repository
+                .set_room_closed(&room.id, true)
+                .await
+                .is_err()
+            {
+                continue;
+            }
+            let room = match self.repository.get_room_by_id(&room.id).await {
+                Ok(room) if room.is_idle(now, idle_timeout_ms) => room,
+                Ok(room) => {
+                    let _ = self.repository.set_room_closed(&room.id, false).await;
+                    continue;
+                }
+                Err(_) => continue,
+            };
+            if let Err(e) = self.execute(room.id.clone().into_string()).await {
+                tracing::warn!("Failed to remove idle room {}: {:?}", room.id.as_str(), e);
+                continue;
+            }
+            tracing::info!(
+                "Room {} closed after {} ms of inactivity",
+                room.id.as_str(),
+                now.value() - room.last_activity().value()
+            );
+            if let Some(event_bus) = &self.event_bus {
+                event_bus
+                    .publish(DomainEvent::RoomClosed {
+                        room_id: room.id.clone(),
+                        last_activity_at: room.last_activity(),
+                        closed_at: now,
+                    })
+                    .await;
+            }
+            reaped.push(room.id);
+        }
+        reaped
+    }
+}
+
+/// 無活動のルームを定期的に削除するタスクを起動
+///
+/// # 引数
+///
+/// - `usecase`: 無活動のルームを削除する RemoveRoomUseCase
+/// - `idle_timeout`: ルームを削除するまでの無活動の時間
+/// - `interval`: 無活動のルームを探す間隔
+pub fn spawn_idle_room_reaper(
+    usecase: Arc<RemoveRoomUseCase>,
+    idle_timeout: Duration,
+    interval: Duration,
+) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        // 最初の tick は即座に完了するため読み飛ばす
+        ticker.tick().await;
+        loop {
+            ticker.tick().await;
+            let reaped = usecase.reap_idle_rooms(idle_timeout).await;
+            if !reaped.is_empty() {
+                tracing::debug!("Reaped {} idle room(s)", reaped.len());
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        domain::{
+            FixedClock, MessageContent, Participant, Room, RoomIdFactory, Timestamp, pusher_channel,
+        },
+        infrastructure::{
+            event_bus::InMemoryEventBus, message_pusher::WebSocketMessagePusher,
+            repository::InMemoryRoomRepository,
+        },
+    };
+    use engawa_shared::time::get_jst_timestamp;
+    use std::collections::HashMap;
+    use tokio::sync::Mutex;
+
+    fn create_test_repository() -> Arc<InMemoryRoomRepository> {
+        let room = Arc::new(Mutex::new(Room::new(
+            RoomIdFactory::generate().unwrap(),
+            Timestamp::new(0),
+        )));
+        Arc::new(InMemoryRoomRepository::new(room))
+    }
+
+    #[tokio::test]
+    async fn test_remove_room_evicts_participants() {
+        // テスト項目: ルームを削除すると参加していたクライアントの ID が返され、送信チャンネルが閉じられる
+        // given (前提条件):
+        let repository = create_test_repository();
+        let message_pusher = Arc::new(WebSocketMessagePusher::new(Arc::new(Mutex::new(
+            HashMap::new(),
+        ))));
+        let usecase = RemoveRoomUseCase::new(repository.clone(), message_pusher.clone());
+        let room_id = repository.get_room().await.unwrap().id;
+        let alice = ClientId::new("alice".to_string()).unwrap();
+        let (tx, mut rx) = pusher_channel();
+        message_pusher.register_client(alice.clone(), tx).await;
+        repository
+            .add_participant(
+                &room_id,
+                Participant::new(alice, Timestamp::new(get_jst_timestamp())),
+            )
+            .await
+            .unwrap();
+
+        // when (操作):
+        let evicted = usecase.execute(room_id.into_string()).await;
+
+        // then (期待する結果):
+        assert_eq!(evicted.unwrap(), vec!["alice".to_string()]);
+        assert!(rx.recv().await.is_none());
+        assert_eq!(repository.count_connected_clients().await, 0);
+    }
+
+    #[tokio::test]
+    async fn test_remove_room_not_found() {
+        // テスト項目: 存在しないルーム ID を指定するとエラーになる
+        // given (前提条件):
+        let repository = create_test_repository();
+        let usecase = RemoveRoomUseCase::new(
+            repository,
+            Arc::new(WebSocketMessagePusher::new(Arc::new(Mutex::new(
+                HashMap::new(),
+            )))),
+        );
+
+        // when (操作):
+        let result = usecase
+            .execute(RoomIdFactory::generate().unwrap().into_string())
+            .await;
+
+        // then (期待する結果):
+        assert_eq!(result.unwrap_err(), RemoveRoomError::RoomNotFound);
+    }
+
+    #[tokio::test]
+    async fn test_reap_idle_rooms_removes_only_inactive_empty_rooms() {
+        // テスト項目: 参加者がおらず無活動の時間が経ったルームだけが削除されて RoomClosed が発行され、最近活動したルーム・参加者がいるルーム・デフォルトのルームは残る
+        // given (前提条件):
+        let repository = create_test_repository();
+        let default_room_id = repository.get_room().await.unwrap().id;
+        let idle_room_id = RoomIdFactory::generate_uuid();
+        let active_room_id = RoomIdFactory::generate_uuid();
+        let occupied_room_id = RoomIdFactory::generate_uuid();
+        for room_id in [&idle_room_id, &active_room_id, &occupied_room_id] {
+            repository
+                .create_room(room_id.clone(), Timestamp::new(0), None)
+                .await
+                .unwrap();
+        }
+        let alice = ClientId::new("alice".to_string()).unwrap();
+        repository
+            .add_message(
+                &active_room_id,
+                alice.clone(),
+                MessageContent::new("Hello".to_string()).unwrap(),
+                Timestamp::new(50_000),
+            )
+            .await
+            .unwrap();
+        repository
+            .add_participant(
+                &occupied_room_id,
+                Participant::new(alice, Timestamp::new(0)),
+            )
+            .await
+            .unwrap();
+        let clock = Arc::new(FixedClock::new(0));
+        let event_bus = Arc::new(InMemoryEventBus::new());
+        let usecase = RemoveRoomUseCase::new(
+            repository.clone(),
+            Arc::new(WebSocketMessagePusher::new(Arc::new(Mutex::new(
+                HashMap::new(),
+            )))),
+        )
+        .with_event_bus(event_bus.clone())
+        .with_clock(clock.clone());
+
+        // when (操作):
+        clock.set(59_999);
+        let reaped_before_timeout = usecase.reap_idle_rooms(Duration::from_secs(60)).await;
+        clock.set(60_000);
+        let reaped = usecase.reap_idle_rooms(Duration::from_secs(60)).await;
+
+        // then (期待する結果):
+        assert!(reaped_before_timeout.is_empty());
+        assert_eq!(reaped, vec![idle_room_id.clone()]);
+        assert!(repository.get_room_by_id(&idle_room_id).await.is_err());
+        for room_id in [&default_room_id, &active_room_id, &occupied_room_id] {
+            assert!(!repository.get_room_by_id(room_id).await.unwrap().closed);
+        }
+        assert_eq!(
+            event_bus.events().await,
+            vec![DomainEvent::RoomClosed {
+                room_id: idle_room_id,
+                last_activity_at: Timestamp::new(0),
+                closed_at: Timestamp::new(60_000),
+            }]
+        );
+    }
+}
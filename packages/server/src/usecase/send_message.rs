@@ -9,18 +9,127 @@
 //! ### なぜこのテストが必要か
 //! - ビジネスロジックの検証：送信者以外にメッセージがブロードキャストされる
 //! - Domain Model（Room）のメッセージ履歴に正しく追加されることを確認
+//! - 割り当てられたメッセージ ID がブロードキャストするメッセージに含まれることを確認
 //! - メッセージ容量超過時のエラーハンドリングを保証
 //!
 //! ### どのような状況を想定しているか
 //! - 正常系：メッセージ送信とブロードキャスト
+//! - 正常系：一部の送信先のチャネルが閉じている場合の配信結果
+//! - 正常系：連続して送信したメッセージの ID が単調増加する
+//! - 正常系：Clock が返す時刻がメッセージのタイムスタンプになる
+//! - 正常系：execute_and_return が保存したメッセージ（ID とサーバのタイムスタンプ）を返し、それを元に送信する JSON メッセージを生成する
+//! - 正常系：ウィスパーが宛先と送信者だけに届き、メッセージ履歴には追加されない
+//! - 異常系：接続していない宛先へのウィスパー
+//! - 正常系：自分宛てのウィスパーは許可する設定では送信者に 1 度だけ届き、履歴には追加されない
+//! - 異常系：自分宛てのウィスパーを拒否する設定での SelfMessage
+//! - 正常系：メッセージの編集が履歴に反映され、送信者以外に通知される
+//! - 異常系：作成者以外によるメッセージの編集
+//! - 異常系：元メッセージより古いクライアントタイムスタンプの編集
+//! - 正常系：メッセージの編集・削除が変更前後の内容のハッシュ付きで監査ログに順に記録される
+//! - 正常系：メッセージの削除が履歴に削除済みとして残り、送信者以外に通知される
+//! - 異常系：送信レートの上限を超えたメッセージ送信
+//! - 異常系：トークンバケットに余裕があっても、短いウィンドウの送信数の上限を超えたメッセージ送信
+//! - 異常系：履歴に保存できなかったメッセージは送信レートと送信数に数えない
+//! - 正常系：受信からブロードキャスト完了までの遅延が注入した Clock で計測され、ヒストグラムに記録される
+//! - 正常系：タイピング通知が送信者以外に届き、メッセージ履歴には追加されない
+//! - 正常系：bot の受信設定（除外・bot のみ）に応じたブロードキャスト対象の選定
+//! - 正常系：メンションされた接続中の参加者だけがメンション通知の対象になる（未接続・不明な名前、送信者自身は除く）
+//! - 異常系：閉じたチャネルへの送信がデッドレターとして記録される
+//! - 正常系：受信確認が届いたメッセージは配信済みになり、届かないメッセージはタイムアウトで未配信になる
 //! - 異常系：メッセージ容量超過
+//! - 異常系：ロック中の Room へのメッセージ送信
+//! - 正常系：ルーム管理者はロック中の Room にもメッセージを送信できる
+//! - 異常系：メッセージの拒否（ロック中の Room・内容の検証違反）で MessageRejected イベントが発行される
 //! - エッジケース：送信者のみが接続している場合（ブロードキャスト対象なし）
 
-use std::sync::Arc;
+use std::{str::FromStr, sync::Arc, time::Duration};
 
-use crate::domain::{ClientId, MessageContent, MessagePusher, RoomRepository, Timestamp};
+use crate::domain::{
+    ChatMessage, ClientId, Clock, ClockExt, DeadLetter, DeadLetterSink, DeliveryReport,
+    DomainEvent, EventBus, MessageAuditAction, MessageAuditEntry, MessageAuditLog, MessageContent,
+    MessageId, MessagePusher, MessageRejectionReason, RoomId, RoomRepository, SystemClock,
+    Timestamp,
+};
 
-use super::error::SendMessageError;
+use super::{
+    delivery_ack::{DEFAULT_ACK_TIMEOUT, DeliveryAckTracker},
+    error::SendMessageError,
+    latency_histogram::{LatencyHistogram, LatencyHistogramSnapshot},
+    rate_limit::{BurstLimiter, RateLimiter},
+};
+
+/// メッセージ送信の結果
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SentMessage {
+    /// 割り当てられたメッセージ ID
+    pub message_id: MessageId,
+    /// ブロードキャストの配信結果
+    pub delivery: DeliveryReport,
+}
+
+/// bot の参加者をブロードキャスト対象に含めるかどうか
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BotRecipientPolicy {
+    /// bot を含む全ての参加者に送信する
+    #[default]
+    Include,
+    /// bot には送信しない（人間向けのブロードキャスト）
+    Exclude,
+    /// bot にのみ送信する（bot 専用チャネル）
+    Only,
+}
+
+impl BotRecipientPolicy {
+    /// 参加者がブロードキャスト対象になるかどうか
+    fn accepts(self, is_bot: bool) -> bool {
+        match self {
+            Self::Include => true,
+            Self::Exclude => !is_bot,
+            Self::Only => is_bot,
+        }
+    }
+}
+
+impl FromStr for BotRecipientPolicy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "include" => Ok(Self::Include),
+            "exclude" => Ok(Self::Exclude),
+            "only" => Ok(Self::Only),
+            other => Err(format!(
+                "invalid bot recipient policy '{}' (expected 'include', 'exclude' or 'only')",
+                other
+            )),
+        }
+    }
+}
+
+/// 自分宛てのウィスパーの扱い
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SelfMessagePolicy {
+    /// 自分用のメモとして許可する（送信者に 1 度だけ届き、他のウィスパーと同じく履歴には追加しない）
+    #[default]
+    Allow,
+    /// `SendMessageError::SelfMessage` で拒否する
+    Reject,
+}
+
+impl FromStr for SelfMessagePolicy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "allow" => Ok(Self::Allow),
+            "reject" => Ok(Self::Reject),
+            other => Err(format!(
+                "invalid self message policy '{}' (expected 'allow' or 'reject')",
+                other
+            )),
+        }
+    }
+}
 
 /// メッセージ送信のユースケース
 pub struct SendMessageUseCase {
@@ -28,6 +137,30 @@ pub struct SendMessageUseCase {
     repository: Arc<dyn RoomRepository>,
     /// MessagePusher（メッセージ通知の抽象化）
     message_pusher: Arc<dyn MessagePusher>,
+    /// bot の参加者をブロードキャスト対象に含めるかどうか
+    bot_recipient_policy: BotRecipientPolicy,
+    /// 自分宛てのウィスパーの扱い
+    self_message_policy: SelfMessagePolicy,
+    /// 配信できなかったメッセージの記録先（`None` の場合は記録しない）
+    dead_letter_sink: Option<Arc<dyn DeadLetterSink>>,
+    /// 受信確認待ちのメッセージの追跡
+    ack_tracker: Arc<DeliveryAckTracker>,
+    /// 受信確認を待つ時間
+    ack_timeout: Duration,
+    /// メッセージの拒否を通知する EventBus（`None` の場合は通知しない）
+    event_bus: Option<Arc<dyn EventBus>>,
+    /// メッセージのタイムスタンプを取得する Clock
+    clock: Arc<dyn Clock>,
+    /// クライアントごとの送信レート制限（`None` の場合は制限しない）
+    rate_limiter: Option<Arc<RateLimiter>>,
+    /// クライアントごとの短いウィンドウあたりの送信数の上限（`None` の場合は制限しない）
+    burst_limiter: Option<Arc<BurstLimiter>>,
+    /// ルーム管理者（ロック中の Room でも送信できる。`None` の場合は全員がロックの対象）
+    admin: Option<ClientId>,
+    /// メッセージの編集・削除の監査ログ（`None` の場合は記録しない）
+    audit_log: Option<Arc<dyn MessageAuditLog>>,
+    /// メッセージ配信の遅延のヒストグラム（`None` の場合は計測しない）
+    latency_histogram: Option<LatencyHistogram>,
 }
 
 impl SendMessageUseCase {
@@ -39,57 +172,752 @@ impl SendMessageUseCase {
         Self {
             repository,
             message_pusher,
+            bot_recipient_policy: BotRecipientPolicy::default(),
+            self_message_policy: SelfMessagePolicy::default(),
+            dead_letter_sink: None,
+            ack_tracker: Arc::new(DeliveryAckTracker::default()),
+            ack_timeout: DEFAULT_ACK_TIMEOUT,
+            event_bus: None,
+            clock: Arc::new(SystemClock),
+            rate_limiter: None,
+            burst_limiter: None,
+            admin: None,
+            audit_log: None,
+            latency_histogram: None,
         }
     }
 
+    /// bot の参加者をブロードキャスト対象に含めるかどうかを設定
+    pub fn with_bot_recipient_policy(mut self, bot_recipient_policy: BotRecipientPolicy) -> Self {
+        self.bot_recipient_policy = bot_recipient_policy;
+        self
+    }
+
+    /// 自分宛てのウィスパーの扱いを設定
+    pub fn with_self_message_policy(mut self, self_message_policy: SelfMessagePolicy) -> Self {
+        self.self_message_policy = self_message_policy;
+        self
+    }
+
+    /// 配信できなかったメッセージの記録先を設定
+    pub fn with_dead_letter_sink(mut self, dead_letter_sink: Arc<dyn DeadLetterSink>) -> Self {
+        self.dead_letter_sink = Some(dead_letter_sink);
+        self
+    }
+
+    /// 受信確認を待つ時間を設定
+    pub fn with_ack_timeout(mut self, ack_timeout: Duration) -> Self {
+        self.ack_timeout = ack_timeout;
+        self
+    }
+
+    /// メッセージの拒否を通知する EventBus を設定
+    pub fn with_event_bus(mut self, event_bus: Arc<dyn EventBus>) -> Self {
+        self.event_bus = Some(event_bus);
+        self
+    }
+
+    /// メッセージのタイムスタンプを取得する Clock を設定（デフォルトは SystemClock）
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// クライアントごとのメッセージ送信レート制限を設定
+    pub fn with_rate_limiter(mut self, rate_limiter: RateLimiter) -> Self {
+        self.rate_limiter = Some(Arc::new(rate_limiter));
+        self
+    }
+
+    /// クライアントごとの短いウィンドウあたりの送信数の上限を設定
+    pub fn with_burst_limiter(mut self, burst_limiter: BurstLimiter) -> Self {
+        self.burst_limiter = Some(Arc::new(burst_limiter));
+        self
+    }
+
+    /// メッセージ配信の遅延の計測を有効にする
+    pub fn with_latency_histogram(mut self) -> Self {
+        self.latency_histogram = Some(LatencyHistogram::new());
+        self
+    }
+
+    /// ロック中の Room でも送信できるルーム管理者を設定
+    pub fn with_admin(mut self, admin: ClientId) -> Self {
+        self.admin = Some(admin);
+        self
+    }
+
+    /// メッセージの編集・削除を記録する監査ログを設定
+    pub fn with_audit_log(mut self, audit_log: Arc<dyn MessageAuditLog>) -> Self {
+        self.audit_log = Some(audit_log);
+        self
+    }
+
+    /// 送信者に対して Room がロックされているかどうか
+    ///
+    /// ルーム管理者はロックの対象外。サーバからのお知らせ（`room-locked` / `room-unlocked` /
+    /// `server-shutdown`、bot の挨拶）はこの UseCase を通らずに配信されるため、ロックの影響を受けない。
+    async fn is_locked_for(&self, room_id: &RoomId, client_id: &ClientId) -> bool {
+        self.admin.as_ref() != Some(client_id) && self.repository.is_room_locked(room_id).await
+    }
+
     /// メッセージ送信を実行
     ///
     /// # Arguments
     ///
     /// * `from_client_id` - メッセージ送信者のクライアント ID（Domain Model）
     /// * `content` - メッセージ内容（Domain Model）
-    /// * `json_message` - 送信する JSON メッセージ（DTO 層で生成されたもの）
+    /// * `build_json_message` - 割り当てられたメッセージ ID から送信する JSON メッセージを生成する関数（DTO 層）
     ///
     /// # Returns
     ///
-    /// * `Ok(Vec<ClientId>)` - ブロードキャスト対象のクライアント ID リスト（Domain Model）
+    /// * `Ok(SentMessage)` - 割り当てられたメッセージ ID と配信結果
     /// * `Err(SendMessageError)` - 送信失敗
-    pub async fn execute(
+    pub async fn execute<F>(
         &self,
         from_client_id: ClientId,
         content: MessageContent,
-        json_message: String,
-    ) -> Result<Vec<ClientId>, SendMessageError> {
-        use engawa_shared::time::get_jst_timestamp;
+        build_json_message: F,
+    ) -> Result<SentMessage, SendMessageError>
+    where
+        F: FnOnce(&MessageId) -> String + Send,
+    {
+        self.execute_and_return(from_client_id, content, |message| {
+            build_json_message(
+                message
+                    .id
+                    .as_ref()
+                    .expect("MessageId is assigned when the message is stored"),
+            )
+        })
+        .await
+        .map(|(_, sent)| sent)
+    }
 
-        let timestamp = Timestamp::new(get_jst_timestamp());
+    /// メッセージ送信を実行し、Room に保存したメッセージを返す
+    ///
+    /// 保存したメッセージのタイムスタンプはサーバの Clock が決めるため、
+    /// 送信する JSON メッセージはクライアントが指定した値ではなく保存したメッセージから生成する。
+    ///
+    /// # Arguments
+    ///
+    /// * `from_client_id` - メッセージ送信者のクライアント ID（Domain Model）
+    /// * `content` - メッセージ内容（Domain Model）
+    /// * `build_json_message` - 保存したメッセージから送信する JSON メッセージを生成する関数（DTO 層）
+    ///
+    /// # Returns
+    ///
+    /// * `Ok((ChatMessage, SentMessage))` - 保存したメッセージ（メッセージ ID とサーバのタイムスタンプを含む）と配信結果
+    /// * `Err(SendMessageError::RateLimited)` - 送信者の送信レートの上限を超えている
+    /// * `Err(SendMessageError)` - 送信失敗
+    pub async fn execute_and_return<F>(
+        &self,
+        from_client_id: ClientId,
+        content: MessageContent,
+        build_json_message: F,
+    ) -> Result<(ChatMessage, SentMessage), SendMessageError>
+    where
+        F: FnOnce(&ChatMessage) -> String + Send,
+    {
+        // 1. 送信者が入室している Room を取得（接続している参加者でなければ拒否）
+        let Some(room_id) = self.repository.find_participant_room(&from_client_id).await else {
+            return Err(SendMessageError::NotAParticipant);
+        };
+
+        // 2. Room がロックされている場合は送信を拒否（ルーム管理者を除く）
+        if self.is_locked_for(&room_id, &from_client_id).await {
+            self.report_rejection(&from_client_id, MessageRejectionReason::RoomLocked)
+                .await;
+            return Err(SendMessageError::RoomLocked);
+        }
 
-        // 1. Repository 経由でメッセージを Room に追加
+        // 3. 送信者の送信レートまたは短いウィンドウの送信数の上限を超えている場合は送信を拒否
+        //    （どちらかで拒否された送信はもう一方の送信数に数えない）
+        let now = self.clock.now();
+        if let Err(retry_after_ms) = self.acquire_send_slot(&from_client_id, now) {
+            self.report_rejection(&from_client_id, MessageRejectionReason::RateLimited)
+                .await;
+            return Err(SendMessageError::RateLimited { retry_after_ms });
+        }
+
+        let mut message = ChatMessage::new(from_client_id.clone(), content, now);
+
+        // 4. Repository 経由でメッセージを Room に追加（メッセージ ID が割り当てられる）
+        //    保存できなかった送信は送信レートと送信数に数えない
+        let message_id = match self
+            .repository
+            .add_message(
+                &room_id,
+                from_client_id.clone(),
+                message.content.clone(),
+                message.timestamp,
+            )
+            .await
+        {
+            Ok(message_id) => message_id,
+            Err(e) => {
+                self.release_send_slot(&from_client_id, now);
+                let error = SendMessageError::from(e);
+                if error == SendMessageError::MessageCapacityExceeded {
+                    self.report_rejection(
+                        &from_client_id,
+                        MessageRejectionReason::CapacityExceeded,
+                    )
+                    .await;
+                }
+                return Err(error);
+            }
+        };
+        message.id = Some(message_id.clone());
+
+        // 5. ブロードキャスト対象を取得（同じ Room の送信者以外の全てのクライアント）
+        let broadcast_targets = self.get_broadcast_targets(&room_id, &from_client_id).await;
+
+        // 6. MessagePusher を使ってブロードキャスト
+        let json_message = build_json_message(&message);
+        let delivery = self
+            .message_pusher
+            .broadcast(broadcast_targets, &json_message)
+            .await
+            .map_err(|e| SendMessageError::BroadcastFailed(e.to_string()))?;
+
+        // 7. 受信してから最後の送信先のチャンネルに渡すまでの時間を記録
+        if let Some(latency_histogram) = &self.latency_histogram {
+            let latency_ms = self.clock.now().value().saturating_sub(now.value());
+            latency_histogram.observe(u64::try_from(latency_ms).unwrap_or(0));
+        }
+
+        // 8. 配信できなかった送信先をデッドレターとして記録
+        if let Some(dead_letter_sink) = &self.dead_letter_sink {
+            for failure in &delivery.failures {
+                dead_letter_sink
+                    .record(DeadLetter {
+                        message_id: message_id.clone(),
+                        target_client_id: failure.client_id.clone(),
+                        reason: failure.reason.clone(),
+                    })
+                    .await;
+            }
+        }
+
+        // 9. 受信確認を要求している送信先について受信確認待ちを開始
+        self.start_awaiting_acks(&message_id, &delivery);
+
+        Ok((
+            message,
+            SentMessage {
+                message_id,
+                delivery,
+            },
+        ))
+    }
+
+    /// メッセージの編集を実行
+    ///
+    /// Room のメッセージ履歴の内容を置き換え（位置と ID はそのまま）、編集を同じ Room の送信者以外に通知する。
+    /// メッセージの作成者以外による編集は拒否する。
+    ///
+    /// # Arguments
+    ///
+    /// * `from_client_id` - 編集するクライアント ID（Domain Model）
+    /// * `message_id` - 編集するメッセージの ID（Domain Model）
+    /// * `content` - 編集後のメッセージ内容（Domain Model）
+    /// * `client_timestamp` - クライアントが編集した時刻（`None` の場合は検査しない）。`edited_at` には使わず、常にサーバの Clock の時刻を設定する
+    /// * `build_json_message` - 編集後のメッセージから送信する JSON メッセージを生成する関数（DTO 層）
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(ChatMessage)` - 編集後のメッセージ（編集日時を含む）
+    /// * `Err(SendMessageError::MessageNotFound)` - 送信者の Room に指定した ID のメッセージが存在しない
+    /// * `Err(SendMessageError::NotMessageAuthor)` - 送信者がメッセージの作成者ではない
+    /// * `Err(SendMessageError::StaleEdit)` - `client_timestamp` が元メッセージのサーバタイムスタンプより古い
+    /// * `Err(SendMessageError)` - その他の送信失敗
+    pub async fn execute_edit<F>(
+        &self,
+        from_client_id: ClientId,
+        message_id: MessageId,
+        content: MessageContent,
+        client_timestamp: Option<Timestamp>,
+        build_json_message: F,
+    ) -> Result<ChatMessage, SendMessageError>
+    where
+        F: FnOnce(&ChatMessage) -> String + Send,
+    {
+        // 1. 送信者が入室している Room を取得（接続している参加者でなければ拒否）
+        let Some(room_id) = self.repository.find_participant_room(&from_client_id).await else {
+            return Err(SendMessageError::NotAParticipant);
+        };
+
+        // 2. Room がロックされている場合は編集を拒否（ルーム管理者を除く）
+        if self.is_locked_for(&room_id, &from_client_id).await {
+            self.report_rejection(&from_client_id, MessageRejectionReason::RoomLocked)
+                .await;
+            return Err(SendMessageError::RoomLocked);
+        }
+
+        // 3. クライアントタイムスタンプが元メッセージより古い編集を拒否
+        let before = if self.audit_log.is_some() || client_timestamp.is_some() {
+            self.find_message(&room_id, &message_id).await
+        } else {
+            None
+        };
+        //    （作成者以外による編集は、時刻にかかわらず Repository で NotMessageAuthor として拒否する）
+        if let (Some(client_timestamp), Some(original)) = (client_timestamp, &before)
+            && original.from == from_client_id
+            && client_timestamp < original.timestamp
+        {
+            return Err(SendMessageError::StaleEdit);
+        }
+
+        // 4. Repository 経由でメッセージを編集（作成者以外による編集は拒否される）
+        let edited_at = self.clock.now();
+        let message = self
+            .repository
+            .edit_message(&room_id, &message_id, &from_client_id, content, edited_at)
+            .await?;
+
+        // 5. 監査ログに編集を記録
+        if let Some(audit_log) = &self.audit_log
+            && let Some(before) = before
+        {
+            audit_log
+                .record(MessageAuditEntry {
+                    room_id: room_id.clone(),
+                    message_id,
+                    action: MessageAuditAction::Edit,
+                    actor: from_client_id.clone(),
+                    before_hash: MessageAuditEntry::hash_content(&before.content),
+                    after_hash: MessageAuditEntry::hash_content(&message.content),
+                    at: edited_at,
+                })
+                .await;
+        }
+
+        // 6. 同じ Room の送信者以外に編集を通知
+        let broadcast_targets = self.get_broadcast_targets(&room_id, &from_client_id).await;
+        self.message_pusher
+            .broadcast(broadcast_targets, &build_json_message(&message))
+            .await
+            .map_err(|e| SendMessageError::BroadcastFailed(e.to_string()))?;
+
+        Ok(message)
+    }
+
+    /// メッセージの削除を実行
+    ///
+    /// Room のメッセージ履歴のメッセージを削除済みにし（内容は空になり、位置と ID はそのまま）、
+    /// 削除を同じ Room の送信者以外に通知する。メッセージの作成者以外による削除は拒否する。
+    ///
+    /// # Arguments
+    ///
+    /// * `from_client_id` - 削除するクライアント ID（Domain Model）
+    /// * `message_id` - 削除するメッセージの ID（Domain Model）
+    /// * `message` - 送信する JSON メッセージ（DTO 層で生成済み）
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - 削除成功
+    /// * `Err(SendMessageError::MessageNotFound)` - 送信者の Room に指定した ID のメッセージが存在しない
+    /// * `Err(SendMessageError::NotMessageAuthor)` - 送信者がメッセージの作成者ではない
+    /// * `Err(SendMessageError)` - その他の送信失敗
+    pub async fn execute_delete(
+        &self,
+        from_client_id: ClientId,
+        message_id: MessageId,
+        message: &str,
+    ) -> Result<(), SendMessageError> {
+        // 1. 送信者が入室している Room を取得（接続している参加者でなければ拒否）
+        let Some(room_id) = self.repository.find_participant_room(&from_client_id).await else {
+            return Err(SendMessageError::NotAParticipant);
+        };
+
+        // 2. Room がロックされている場合は削除を拒否（ルーム管理者を除く）
+        if self.is_locked_for(&room_id, &from_client_id).await {
+            self.report_rejection(&from_client_id, MessageRejectionReason::RoomLocked)
+                .await;
+            return Err(SendMessageError::RoomLocked);
+        }
+
+        // 3. Repository 経由でメッセージを削除（作成者以外による削除は拒否される）
+        let before = if self.audit_log.is_some() {
+            self.find_message(&room_id, &message_id).await
+        } else {
+            None
+        };
         self.repository
-            .add_message(from_client_id.clone(), content, timestamp)
+            .delete_message(&room_id, &message_id, &from_client_id)
+            .await?;
+
+        // 4. 監査ログに削除を記録
+        if let Some(audit_log) = &self.audit_log
+            && let Some(before) = before
+        {
+            audit_log
+                .record(MessageAuditEntry {
+                    room_id: room_id.clone(),
+                    message_id,
+                    action: MessageAuditAction::Delete,
+                    actor: from_client_id.clone(),
+                    before_hash: MessageAuditEntry::hash_content(&before.content),
+                    after_hash: MessageAuditEntry::hash_content(&MessageContent::tombstone()),
+                    at: self.clock.now(),
+                })
+                .await;
+        }
+
+        // 5. 同じ Room の送信者以外に削除を通知
+        let broadcast_targets = self.get_broadcast_targets(&room_id, &from_client_id).await;
+        self.message_pusher
+            .broadcast(broadcast_targets, message)
+            .await
+            .map_err(|e| SendMessageError::BroadcastFailed(e.to_string()))?;
+
+        Ok(())
+    }
+
+    /// ウィスパー（同じ Room の 1 人の参加者だけに宛てたメッセージ）の送信を実行
+    ///
+    /// 宛先のクライアントに送信し、送信者にも同じメッセージを返す（自分宛ての場合は 1 回だけ送信）。
+    /// ウィスパーは Room のメッセージ履歴には追加しない。
+    ///
+    /// # Arguments
+    ///
+    /// * `from_client_id` - 送信者のクライアント ID（Domain Model）
+    /// * `to_client_id` - 宛先のクライアント ID（Domain Model）
+    /// * `content` - メッセージ内容（Domain Model）
+    /// * `build_json_message` - メッセージ内容とタイムスタンプから送信する JSON メッセージを生成する関数（DTO 層）
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - 送信成功
+    /// * `Err(SendMessageError::RecipientNotFound)` - 送信者または宛先が接続していない（宛先が別の Room にいる場合を含む）
+    /// * `Err(SendMessageError)` - その他の送信失敗
+    pub async fn execute_whisper<F>(
+        &self,
+        from_client_id: ClientId,
+        to_client_id: ClientId,
+        content: MessageContent,
+        build_json_message: F,
+    ) -> Result<(), SendMessageError>
+    where
+        F: FnOnce(&MessageContent, Timestamp) -> String + Send,
+    {
+        // 1. 送信者と宛先の両方が同じ Room に接続していることを確認
+        let Some(room_id) = self.repository.find_participant_room(&from_client_id).await else {
+            return Err(SendMessageError::RecipientNotFound);
+        };
+        if self
+            .repository
+            .find_participant_room(&to_client_id)
             .await
-            .map_err(|_| SendMessageError::MessageCapacityExceeded)?;
+            .as_ref()
+            != Some(&room_id)
+        {
+            return Err(SendMessageError::RecipientNotFound);
+        }
+
+        // 2. 自分宛てのウィスパーは設定に応じて拒否
+        if to_client_id == from_client_id && self.self_message_policy == SelfMessagePolicy::Reject {
+            return Err(SendMessageError::SelfMessage);
+        }
 
-        // 2. ブロードキャスト対象を取得（送信者以外の全てのクライアント）
-        let broadcast_targets = self.get_broadcast_targets(&from_client_id).await;
+        // 3. Room がロックされている場合は送信を拒否（ルーム管理者を除く）
+        if self.is_locked_for(&room_id, &from_client_id).await {
+            self.report_rejection(&from_client_id, MessageRejectionReason::RoomLocked)
+                .await;
+            return Err(SendMessageError::RoomLocked);
+        }
 
-        // 3. MessagePusher を使ってブロードキャスト
+        // 4. 宛先に送信し、送信者に同じメッセージを返す（履歴には追加しない。自分宛ての場合は 1 度だけ送信する）
+        let json_message = build_json_message(&content, self.clock.now());
         self.message_pusher
-            .broadcast(broadcast_targets.clone(), &json_message)
+            .push_to(&to_client_id, &json_message)
             .await
             .map_err(|e| SendMessageError::BroadcastFailed(e.to_string()))?;
+        if to_client_id != from_client_id {
+            self.message_pusher
+                .push_to(&from_client_id, &json_message)
+                .await
+                .map_err(|e| SendMessageError::BroadcastFailed(e.to_string()))?;
+        }
+        Ok(())
+    }
+
+    /// タイピング状態（入力中かどうか）を同じ Room の他の参加者に中継
+    ///
+    /// タイピング状態は一時的な通知のため、Room のメッセージ履歴には追加しない。
+    ///
+    /// # Arguments
+    ///
+    /// * `from_client_id` - 入力中の参加者のクライアント ID（Domain Model）
+    /// * `message` - 送信するタイピング通知（JSON）
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<ClientId>)` - タイピング通知を送信したクライアント ID リスト（Domain Model）
+    /// * `Err(SendMessageError::NotAParticipant)` - 送信者が Room に接続していない
+    /// * `Err(SendMessageError)` - その他の送信失敗
+    pub async fn relay_typing(
+        &self,
+        from_client_id: &ClientId,
+        message: &str,
+    ) -> Result<Vec<ClientId>, SendMessageError> {
+        let Some(room_id) = self.repository.find_participant_room(from_client_id).await else {
+            return Err(SendMessageError::NotAParticipant);
+        };
+        let targets = self.get_broadcast_targets(&room_id, from_client_id).await;
+        self.message_pusher
+            .broadcast(targets.clone(), message)
+            .await
+            .map(|_| targets)
+            .map_err(|e| SendMessageError::BroadcastFailed(e.to_string()))
+    }
 
-        Ok(broadcast_targets)
+    /// 参加者が受信確認を送ることを登録
+    pub fn require_acks(&self, client_id: ClientId) {
+        self.ack_tracker.require(client_id);
     }
 
-    /// ブロードキャスト対象のクライアント ID リストを取得
+    /// 参加者の受信確認の登録を解除
+    pub fn release_acks(&self, client_id: &ClientId) {
+        self.ack_tracker.release(client_id);
+    }
+
+    /// メッセージ配信の遅延の集計結果を取得（計測していない場合は `None`）
+    pub fn latency_snapshot(&self) -> Option<LatencyHistogramSnapshot> {
+        self.latency_histogram
+            .as_ref()
+            .map(LatencyHistogram::snapshot)
+    }
+
+    /// 参加者の送信レート制限の状態を破棄
+    pub fn release_rate_limit(&self, client_id: &ClientId) {
+        if let Some(rate_limiter) = &self.rate_limiter {
+            rate_limiter.release(client_id);
+        }
+        if let Some(burst_limiter) = &self.burst_limiter {
+            burst_limiter.release(client_id);
+        }
+    }
+
+    /// 送信レート制限と短いウィンドウの送信数の上限をまとめて判定し、送信できる場合は両方に記録
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - 送信してよい
+    /// * `Err(retry_after_ms)` - 再送信できるまでの時間（ミリ秒）
+    fn acquire_send_slot(&self, client_id: &ClientId, now: Timestamp) -> Result<(), u64> {
+        if let Some(burst_limiter) = &self.burst_limiter {
+            burst_limiter.check(client_id, now)?;
+        }
+        if let Some(rate_limiter) = &self.rate_limiter {
+            rate_limiter.try_acquire(client_id, now)?;
+        }
+        if let Some(burst_limiter) = &self.burst_limiter {
+            burst_limiter.record(client_id, now);
+        }
+        Ok(())
+    }
+
+    /// `acquire_send_slot` で記録した送信を取り消す（メッセージを保存できなかった場合）
+    fn release_send_slot(&self, client_id: &ClientId, now: Timestamp) {
+        if let Some(rate_limiter) = &self.rate_limiter {
+            rate_limiter.refund(client_id);
+        }
+        if let Some(burst_limiter) = &self.burst_limiter {
+            burst_limiter.unrecord(client_id, now);
+        }
+    }
+
+    /// 受信確認を記録
+    ///
+    /// # Returns
+    ///
+    /// 受信確認待ちだったメッセージの場合は `true`（配信済みになる）
+    pub fn acknowledge(&self, client_id: &ClientId, message_id: &MessageId) -> bool {
+        self.ack_tracker.acknowledge(client_id, message_id)
+    }
+
+    /// 受信確認待ちを開始し、タイムアウト後も受信確認がない送信先を未配信として記録
+    fn start_awaiting_acks(&self, message_id: &MessageId, delivery: &DeliveryReport) {
+        let delivered_targets: Vec<ClientId> = delivery
+            .targets
+            .iter()
+            .filter(|id| !delivery.failures.iter().any(|f| &f.client_id == *id))
+            .cloned()
+            .collect();
+        let awaiting = self.ack_tracker.start(message_id, &delivered_targets);
+        if awaiting.is_empty() {
+            return;
+        }
+
+        let ack_tracker = self.ack_tracker.clone();
+        let dead_letter_sink = self.dead_letter_sink.clone();
+        let ack_timeout = self.ack_timeout;
+        let message_id = message_id.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(ack_timeout).await;
+            for client_id in awaiting {
+                if !ack_tracker.expire(&client_id, &message_id) {
+                    continue;
+                }
+                tracing::warn!(
+                    "No delivery ack for message '{}' from '{}' within {:?}",
+                    message_id,
+                    client_id,
+                    ack_timeout
+                );
+                if let Some(dead_letter_sink) = &dead_letter_sink {
+                    dead_letter_sink
+                        .record(DeadLetter {
+                            message_id: message_id.clone(),
+                            target_client_id: client_id,
+                            reason: "delivery ack timed out".to_string(),
+                        })
+                        .await;
+                }
+            }
+        });
+    }
+
+    /// 編集・削除する前のメッセージを取得（監査ログの記録と編集の時刻の検査に使う）
+    ///
+    /// メッセージを編集・削除できるのは作成者の接続だけで、1 つの接続のフレームは順に処理されるため、
+    /// 取得してから編集・削除するまでの間に内容が変わることはない。
+    ///
+    /// # Returns
+    ///
+    /// メッセージが存在しない場合は `None`
+    async fn find_message(&self, room_id: &RoomId, message_id: &MessageId) -> Option<ChatMessage> {
+        let room = self.repository.get_room_by_id(room_id).await.ok()?;
+        room.messages
+            .into_iter()
+            .find(|message| message.id.as_ref() == Some(message_id))
+    }
+
+    /// メッセージの拒否を MessageRejected イベントとして通知
+    ///
+    /// ロック中の Room や容量超過による拒否は `execute()` が通知する。
+    /// UseCase に渡る前に拒否したメッセージ（内容の検証違反など）は、呼び出し側がこのメソッドで通知する。
+    ///
+    /// # Arguments
+    ///
+    /// * `client_id` - メッセージを送信したクライアントの ID（Domain Model）
+    /// * `reason` - 拒否した理由
+    pub async fn report_rejection(&self, client_id: &ClientId, reason: MessageRejectionReason) {
+        let Some(event_bus) = &self.event_bus else {
+            return;
+        };
+        // 送信者が入室している Room（入室していない場合はデフォルトの Room）を対象にする
+        let room_id = match self.repository.find_participant_room(client_id).await {
+            Some(room_id) => room_id,
+            None => match self.repository.get_room().await {
+                Ok(room) => room.id,
+                Err(e) => {
+                    tracing::warn!("Failed to get room for rejection event: {}", e);
+                    return;
+                }
+            },
+        };
+        event_bus
+            .publish(DomainEvent::MessageRejected {
+                room_id,
+                client_id: client_id.clone(),
+                reason,
+            })
+            .await;
+    }
+
+    /// 送信者自身にメッセージを送信
+    ///
+    /// 配信結果（delivery receipt）やエラーフレームなど、送信者だけに返すメッセージに使用する。
+    ///
+    /// # Arguments
+    ///
+    /// * `client_id` - 送信者のクライアント ID（Domain Model）
+    /// * `message` - 送信するメッセージ（JSON）
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - 送信成功
+    /// * `Err(String)` - 送信失敗
+    pub async fn push_to_sender(&self, client_id: &ClientId, message: &str) -> Result<(), String> {
+        self.message_pusher
+            .push_to(client_id, message)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    /// メッセージでメンションされた参加者にメンション通知を送信
+    ///
+    /// 送信者と同じ Room に接続中の参加者のみが対象で、未接続・不明な名前や送信者自身へのメンションは無視する。
+    ///
+    /// # Arguments
+    ///
+    /// * `from_client_id` - メッセージ送信者のクライアント ID（Domain Model）
+    /// * `mentions` - メッセージ内でメンションされた名前
+    /// * `message` - 送信するメンション通知（JSON）
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<ClientId>)` - メンション通知を送信したクライアント ID リスト（Domain Model）
+    /// * `Err(String)` - 送信失敗
+    pub async fn notify_mentions(
+        &self,
+        from_client_id: &ClientId,
+        mentions: &[String],
+        message: &str,
+    ) -> Result<Vec<ClientId>, String> {
+        let targets = self.get_mention_targets(from_client_id, mentions).await;
+        if targets.is_empty() {
+            return Ok(targets);
+        }
+        self.message_pusher
+            .broadcast(targets.clone(), message)
+            .await
+            .map(|_| targets)
+            .map_err(|e| e.to_string())
+    }
+
+    /// メンション通知の対象のクライアント ID リストを取得
     ///
-    /// 送信者以外の全てのクライアント ID を返す（Domain Model）
-    async fn get_broadcast_targets(&self, exclude_client_id: &ClientId) -> Vec<ClientId> {
-        let all_client_ids = self.repository.get_all_connected_client_ids().await;
-        all_client_ids
+    /// 送信者以外で、メンションされた名前と一致する同じ Room に接続中のクライアント ID を返す（Domain Model）
+    async fn get_mention_targets(
+        &self,
+        exclude_client_id: &ClientId,
+        mentions: &[String],
+    ) -> Vec<ClientId> {
+        let Some(room_id) = self
+            .repository
+            .find_participant_room(exclude_client_id)
+            .await
+        else {
+            return Vec::new();
+        };
+        self.repository
+            .get_participants(&room_id)
+            .await
             .into_iter()
+            .map(|p| p.id)
             .filter(|id| id != exclude_client_id)
+            .filter(|id| mentions.iter().any(|name| name == id.as_str()))
+            .collect()
+    }
+
+    /// ブロードキャスト対象のクライアント ID リストを取得
+    ///
+    /// Room の送信者以外で、bot の受信設定に合致するクライアント ID を返す（Domain Model）
+    async fn get_broadcast_targets(
+        &self,
+        room_id: &RoomId,
+        exclude_client_id: &ClientId,
+    ) -> Vec<ClientId> {
+        let participants = self.repository.get_participants(room_id).await;
+        participants
+            .into_iter()
+            .filter(|p| &p.id != exclude_client_id)
+            .filter(|p| self.bot_recipient_policy.accepts(p.is_bot))
+            .map(|p| p.id)
             .collect()
     }
 }
@@ -98,11 +926,19 @@ impl SendMessageUseCase {
 mod tests {
     use super::*;
     use crate::{
-        domain::{MessagePushError, MessagePusher, PusherChannel, Room, RoomIdFactory, Timestamp},
-        infrastructure::repository::InMemoryRoomRepository,
+        domain::{
+            FixedClock, MessagePushError, MessagePusher, Participant, PusherChannel, Room,
+            RoomIdFactory, pusher_channel,
+        },
+        infrastructure::{
+            dead_letter::InMemoryDeadLetterSink, event_bus::InMemoryEventBus,
+            message_audit::InMemoryMessageAuditLog, message_pusher::WebSocketMessagePusher,
+            repository::InMemoryRoomRepository,
+        },
+        usecase::{MessageBurstCap, MessageRateLimit},
     };
     use engawa_shared::time::get_jst_timestamp;
-    use std::sync::Arc;
+    use std::{collections::HashMap, sync::Arc};
     use tokio::sync::Mutex;
 
     // Mock MessagePusher for testing
@@ -128,11 +964,46 @@ mod tests {
 
         async fn broadcast(
             &self,
-            _targets: Vec<ClientId>,
+            targets: Vec<ClientId>,
+            _content: &str,
+        ) -> Result<DeliveryReport, MessagePushError> {
+            let delivered_count = targets.len();
+            Ok(DeliveryReport {
+                targets,
+                delivered_count,
+                failures: Vec::new(),
+            })
+        }
+    }
+
+    /// ブロードキャストに時間がかかる MessagePusher（ブロードキャストのたびに Clock を進める）
+    struct SlowMessagePusher {
+        clock: Arc<FixedClock>,
+        delay_ms: i64,
+    }
+
+    #[async_trait::async_trait]
+    impl MessagePusher for SlowMessagePusher {
+        async fn register_client(&self, _client_id: ClientId, _sender: PusherChannel) {}
+
+        async fn unregister_client(&self, _client_id: &ClientId) {}
+
+        async fn push_to(
+            &self,
+            _client_id: &ClientId,
             _content: &str,
         ) -> Result<(), MessagePushError> {
             Ok(())
         }
+
+        async fn broadcast(
+            &self,
+            targets: Vec<ClientId>,
+            content: &str,
+        ) -> Result<DeliveryReport, MessagePushError> {
+            self.clock.set(self.clock.now_jst_millis() + self.delay_ms);
+            MockMessagePusher.broadcast(targets, content).await
+        }
     }
 
     fn create_test_repository() -> Arc<InMemoryRoomRepository> {
@@ -160,6 +1031,7 @@ mod tests {
         // テスト項目: メッセージ送信が成功し、ブロードキャスト対象が返される
         // given (前提条件):
         let repository = create_test_repository();
+        let room_id = repository.get_room().await.unwrap().id;
         let message_pusher = Arc::new(MockMessagePusher);
         let usecase = SendMessageUseCase::new(repository.clone(), message_pusher);
 
@@ -169,31 +1041,38 @@ mod tests {
         let bob = ClientId::new("bob".to_string()).unwrap();
         let charlie = ClientId::new("charlie".to_string()).unwrap();
         repository
-            .add_participant(alice.clone(), Timestamp::new(timestamp))
+            .add_participant(
+                &room_id,
+                Participant::new(alice.clone(), Timestamp::new(timestamp)),
+            )
             .await
             .unwrap();
         repository
-            .add_participant(bob.clone(), Timestamp::new(timestamp))
+            .add_participant(
+                &room_id,
+                Participant::new(bob.clone(), Timestamp::new(timestamp)),
+            )
             .await
             .unwrap();
         repository
-            .add_participant(charlie.clone(), Timestamp::new(timestamp))
+            .add_participant(
+                &room_id,
+                Participant::new(charlie.clone(), Timestamp::new(timestamp)),
+            )
             .await
             .unwrap();
 
         // when (操作): alice がメッセージを送信
         let content = MessageContent::new("Hello!".to_string()).unwrap();
         let result = usecase
-            .execute(
-                alice.clone(),
-                content,
-                r#"{\"type\":\"chat\",\"client_id\":\"alice\",\"content\":\"Hello!\"}"#.to_string(),
-            )
+            .execute(alice.clone(), content, |_| {
+                r#"{\"type\":\"chat\",\"client_id\":\"alice\",\"content\":\"Hello!\"}"#.to_string()
+            })
             .await;
 
         // then (期待する結果):
         assert!(result.is_ok());
-        let broadcast_targets = result.unwrap();
+        let broadcast_targets = result.unwrap().delivery.targets;
 
         // alice 以外の2人がブロードキャスト対象
         assert_eq!(broadcast_targets.len(), 2);
@@ -213,29 +1092,31 @@ mod tests {
         // テスト項目: 送信者のみが接続している場合、ブロードキャスト対象は空
         // given (前提条件):
         let repository = create_test_repository();
+        let room_id = repository.get_room().await.unwrap().id;
         let usecase = SendMessageUseCase::new(repository.clone(), Arc::new(MockMessagePusher));
 
         // alice のみ接続
         let timestamp = get_jst_timestamp();
         let alice = ClientId::new("alice".to_string()).unwrap();
         repository
-            .add_participant(alice.clone(), Timestamp::new(timestamp))
+            .add_participant(
+                &room_id,
+                Participant::new(alice.clone(), Timestamp::new(timestamp)),
+            )
             .await
             .unwrap();
 
         // when (操作): alice がメッセージを送信
         let content = MessageContent::new("Hello!".to_string()).unwrap();
         let result = usecase
-            .execute(
-                alice.clone(),
-                content,
-                r#"{\"type\":\"chat\",\"client_id\":\"alice\",\"content\":\"Hello!\"}"#.to_string(),
-            )
+            .execute(alice.clone(), content, |_| {
+                r#"{\"type\":\"chat\",\"client_id\":\"alice\",\"content\":\"Hello!\"}"#.to_string()
+            })
             .await;
 
         // then (期待する結果):
         assert!(result.is_ok());
-        let broadcast_targets = result.unwrap();
+        let broadcast_targets = result.unwrap().delivery.targets;
 
         // ブロードキャスト対象は空
         assert_eq!(broadcast_targets.len(), 0);
@@ -245,38 +1126,77 @@ mod tests {
         assert_eq!(room.messages.len(), 1);
     }
 
+    #[tokio::test]
+    async fn test_send_message_from_disconnected_client_rejected() {
+        // テスト項目: 切断済みのクライアント ID からのメッセージは NotAParticipant で拒否され、履歴に追加されない
+        // given (前提条件):
+        let repository = create_test_repository();
+        let room_id = repository.get_room().await.unwrap().id;
+        let usecase = SendMessageUseCase::new(repository.clone(), Arc::new(MockMessagePusher));
+
+        // alice は接続後に切断済み
+        let timestamp = get_jst_timestamp();
+        let alice = ClientId::new("alice".to_string()).unwrap();
+        repository
+            .add_participant(
+                &room_id,
+                Participant::new(alice.clone(), Timestamp::new(timestamp)),
+            )
+            .await
+            .unwrap();
+        repository
+            .remove_participant(&alice, Timestamp::new(timestamp))
+            .await
+            .unwrap();
+
+        // when (操作): 切断済みの alice がメッセージを送信
+        let content = MessageContent::new("Hello!".to_string()).unwrap();
+        let result = usecase
+            .execute(alice.clone(), content, |_| r#"{"type":"chat"}"#.to_string())
+            .await;
+
+        // then (期待する結果):
+        assert_eq!(result.unwrap_err(), SendMessageError::NotAParticipant);
+        let room = repository.get_room().await.unwrap();
+        assert!(room.messages.is_empty());
+    }
+
     #[tokio::test]
     async fn test_send_message_capacity_exceeded() {
         // テスト項目: メッセージ容量超過時にエラーが返される
         // given (前提条件):
         let repository = create_test_repository_with_capacity(2); // 2件まで
+        let room_id = repository.get_room().await.unwrap().id;
         let usecase = SendMessageUseCase::new(repository.clone(), Arc::new(MockMessagePusher));
 
         // alice を接続
         let timestamp = get_jst_timestamp();
         let alice = ClientId::new("alice".to_string()).unwrap();
         repository
-            .add_participant(alice.clone(), Timestamp::new(timestamp))
+            .add_participant(
+                &room_id,
+                Participant::new(alice.clone(), Timestamp::new(timestamp)),
+            )
             .await
             .unwrap();
 
         // 2件のメッセージを送信（容量いっぱい）
         let msg1 = MessageContent::new("Message 1".to_string()).unwrap();
         usecase
-            .execute(alice.clone(), msg1, r#"{"type":"chat"}"#.to_string())
+            .execute(alice.clone(), msg1, |_| r#"{"type":"chat"}"#.to_string())
             .await
             .unwrap();
 
         let msg2 = MessageContent::new("Message 2".to_string()).unwrap();
         usecase
-            .execute(alice.clone(), msg2, r#"{"type":"chat"}"#.to_string())
+            .execute(alice.clone(), msg2, |_| r#"{"type":"chat"}"#.to_string())
             .await
             .unwrap();
 
         // when (操作): 3件目のメッセージを送信
         let msg3 = MessageContent::new("Message 3".to_string()).unwrap();
         let result = usecase
-            .execute(alice.clone(), msg3, r#"{"type":"chat"}"#.to_string())
+            .execute(alice.clone(), msg3, |_| r#"{"type":"chat"}"#.to_string())
             .await;
 
         // then (期待する結果): 容量超過エラーが返される
@@ -292,6 +1212,7 @@ mod tests {
         // テスト項目: 複数クライアント接続時に正しいブロードキャスト対象が取得できる
         // given (前提条件):
         let repository = create_test_repository();
+        let room_id = repository.get_room().await.unwrap().id;
         let usecase = SendMessageUseCase::new(repository.clone(), Arc::new(MockMessagePusher));
 
         // 3人のクライアントを接続
@@ -300,20 +1221,29 @@ mod tests {
         let bob = ClientId::new("bob".to_string()).unwrap();
         let charlie = ClientId::new("charlie".to_string()).unwrap();
         repository
-            .add_participant(alice.clone(), Timestamp::new(timestamp))
+            .add_participant(
+                &room_id,
+                Participant::new(alice.clone(), Timestamp::new(timestamp)),
+            )
             .await
             .unwrap();
         repository
-            .add_participant(bob.clone(), Timestamp::new(timestamp))
+            .add_participant(
+                &room_id,
+                Participant::new(bob.clone(), Timestamp::new(timestamp)),
+            )
             .await
             .unwrap();
         repository
-            .add_participant(charlie.clone(), Timestamp::new(timestamp))
+            .add_participant(
+                &room_id,
+                Participant::new(charlie.clone(), Timestamp::new(timestamp)),
+            )
             .await
             .unwrap();
 
         // when (操作): bob を除いたブロードキャスト対象を取得
-        let result = usecase.get_broadcast_targets(&bob).await;
+        let result = usecase.get_broadcast_targets(&room_id, &bob).await;
 
         // then (期待する結果):
         assert_eq!(result.len(), 2);
@@ -321,4 +1251,1150 @@ mod tests {
         assert!(result.contains(&charlie));
         assert!(!result.contains(&bob));
     }
+
+    #[tokio::test]
+    async fn test_get_mention_targets_only_connected_mentioned_participants() {
+        // テスト項目: メンションされた接続中の参加者だけが対象になり、未接続・不明な名前や送信者自身は対象にならない
+        // given (前提条件):
+        let repository = create_test_repository();
+        let room_id = repository.get_room().await.unwrap().id;
+        let usecase = SendMessageUseCase::new(repository.clone(), Arc::new(MockMessagePusher));
+        let timestamp = get_jst_timestamp();
+        let alice = ClientId::new("alice".to_string()).unwrap();
+        let bob = ClientId::new("bob".to_string()).unwrap();
+        let charlie = ClientId::new("charlie".to_string()).unwrap();
+        for client_id in [&alice, &bob, &charlie] {
+            repository
+                .add_participant(
+                    &room_id,
+                    Participant::new(client_id.clone(), Timestamp::new(timestamp)),
+                )
+                .await
+                .unwrap();
+        }
+        let mentions = vec!["alice".to_string(), "bob".to_string(), "dave".to_string()];
+
+        // when (操作): alice が bob と dave（未接続）と自分自身をメンション
+        let result = usecase.get_mention_targets(&alice, &mentions).await;
+
+        // then (期待する結果):
+        assert_eq!(result, vec![bob]);
+    }
+
+    #[tokio::test]
+    async fn test_send_message_rejected_while_room_locked() {
+        // テスト項目: ロック中の Room ではメッセージ送信が拒否され、ロック解除後は成功する
+        // given (前提条件):
+        let repository = create_test_repository();
+        let room_id = repository.get_room().await.unwrap().id;
+        let usecase = SendMessageUseCase::new(repository.clone(), Arc::new(MockMessagePusher));
+
+        let alice = ClientId::new("alice".to_string()).unwrap();
+        repository
+            .add_participant(
+                &room_id,
+                Participant::new(alice.clone(), Timestamp::new(get_jst_timestamp())),
+            )
+            .await
+            .unwrap();
+        repository.set_room_locked(&room_id, true).await.unwrap();
+
+        // when (操作): ロック中に送信
+        let locked_result = usecase
+            .execute(
+                alice.clone(),
+                MessageContent::new("Hello!".to_string()).unwrap(),
+                |_| r#"{"type":"chat"}"#.to_string(),
+            )
+            .await;
+
+        // then (期待する結果): RoomLocked エラーが返され、履歴に追加されない
+        assert_eq!(locked_result, Err(SendMessageError::RoomLocked));
+        assert_eq!(repository.get_room().await.unwrap().messages.len(), 0);
+
+        // when (操作): ロック解除後に送信
+        repository.set_room_locked(&room_id, false).await.unwrap();
+        let unlocked_result = usecase
+            .execute(
+                alice.clone(),
+                MessageContent::new("Hello again!".to_string()).unwrap(),
+                |_| r#"{"type":"chat"}"#.to_string(),
+            )
+            .await;
+
+        // then (期待する結果): 送信が成功し、履歴に追加される
+        assert!(unlocked_result.is_ok());
+        assert_eq!(repository.get_room().await.unwrap().messages.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_admin_can_send_while_room_locked() {
+        // テスト項目: ロック中の Room でもルーム管理者のメッセージ送信は成功し、他の参加者の送信は拒否される
+        // given (前提条件):
+        let repository = create_test_repository();
+        let room_id = repository.get_room().await.unwrap().id;
+        let admin = ClientId::new("admin".to_string()).unwrap();
+        let alice = ClientId::new("alice".to_string()).unwrap();
+        let usecase = SendMessageUseCase::new(repository.clone(), Arc::new(MockMessagePusher))
+            .with_admin(admin.clone());
+        for client_id in [&admin, &alice] {
+            repository
+                .add_participant(
+                    &room_id,
+                    Participant::new(client_id.clone(), Timestamp::new(get_jst_timestamp())),
+                )
+                .await
+                .unwrap();
+        }
+        repository.set_room_locked(&room_id, true).await.unwrap();
+
+        // when (操作): ロック中に管理者と alice が送信
+        let admin_result = usecase
+            .execute(
+                admin,
+                MessageContent::new("Announcement".to_string()).unwrap(),
+                |_| r#"{"type":"chat"}"#.to_string(),
+            )
+            .await;
+        let alice_result = usecase
+            .execute(
+                alice,
+                MessageContent::new("Hello!".to_string()).unwrap(),
+                |_| r#"{"type":"chat"}"#.to_string(),
+            )
+            .await;
+
+        // then (期待する結果): 管理者のメッセージだけが履歴に追加される
+        assert!(admin_result.is_ok());
+        assert_eq!(alice_result, Err(SendMessageError::RoomLocked));
+        let messages = repository.get_room().await.unwrap().messages;
+        assert_eq!(messages.len(), 1);
+        assert_eq!(messages[0].content.as_str(), "Announcement");
+    }
+
+    #[tokio::test]
+    async fn test_rejections_emit_message_rejected_event() {
+        // テスト項目: 内容の検証違反とロック中の Room による拒否で、理由付きの MessageRejected イベントが発行される
+        // given (前提条件):
+        let repository = create_test_repository();
+        let event_bus = Arc::new(InMemoryEventBus::new());
+        let usecase = SendMessageUseCase::new(repository.clone(), Arc::new(MockMessagePusher))
+            .with_event_bus(event_bus.clone());
+        let room_id = repository.get_room().await.unwrap().id;
+        let alice = ClientId::new("alice".to_string()).unwrap();
+        repository
+            .add_participant(
+                &room_id,
+                Participant::new(alice.clone(), Timestamp::new(get_jst_timestamp())),
+            )
+            .await
+            .unwrap();
+        let content_error = MessageContent::new(String::new()).unwrap_err();
+
+        // when (操作): 内容の検証違反を通知し、ロック中の Room に送信
+        usecase
+            .report_rejection(
+                &alice,
+                MessageRejectionReason::InvalidContent {
+                    detail: content_error.to_string(),
+                },
+            )
+            .await;
+        repository.set_room_locked(&room_id, true).await.unwrap();
+        let result = usecase
+            .execute(
+                alice.clone(),
+                MessageContent::new("Hello!".to_string()).unwrap(),
+                |_| r#"{"type":"chat"}"#.to_string(),
+            )
+            .await;
+
+        // then (期待する結果):
+        assert_eq!(result, Err(SendMessageError::RoomLocked));
+        assert_eq!(
+            event_bus.events().await,
+            vec![
+                DomainEvent::MessageRejected {
+                    room_id: room_id.clone(),
+                    client_id: alice.clone(),
+                    reason: MessageRejectionReason::InvalidContent {
+                        detail: content_error.to_string(),
+                    },
+                },
+                DomainEvent::MessageRejected {
+                    room_id,
+                    client_id: alice,
+                    reason: MessageRejectionReason::RoomLocked,
+                },
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_execute_beyond_rate_limit_fails() {
+        // テスト項目: 送信レートの上限を超えたメッセージは RateLimited で拒否されて履歴に追加されず、MessageRejected イベントが発行される。時間が経てば再び送信できる
+        // given (前提条件):
+        let repository = create_test_repository();
+        let event_bus = Arc::new(InMemoryEventBus::new());
+        let clock = Arc::new(FixedClock::new(1000));
+        let usecase = SendMessageUseCase::new(repository.clone(), Arc::new(MockMessagePusher))
+            .with_event_bus(event_bus.clone())
+            .with_clock(clock.clone())
+            .with_rate_limiter(RateLimiter::new(MessageRateLimit {
+                messages_per_sec: 5,
+                burst: 1,
+            }));
+        let room_id = repository.get_room().await.unwrap().id;
+        let alice = ClientId::new("alice".to_string()).unwrap();
+        repository
+            .add_participant(
+                &room_id,
+                Participant::new(alice.clone(), Timestamp::new(500)),
+            )
+            .await
+            .unwrap();
+        let send = |content: &str| {
+            usecase.execute(
+                alice.clone(),
+                MessageContent::new(content.to_string()).unwrap(),
+                |_| "chat".to_string(),
+            )
+        };
+        send("first").await.unwrap();
+
+        // when (操作):
+        let limited = send("second").await;
+        clock.set(1200);
+        let after_refill = send("third").await;
+
+        // then (期待する結果):
+        assert_eq!(
+            limited,
+            Err(SendMessageError::RateLimited {
+                retry_after_ms: 200
+            })
+        );
+        assert!(after_refill.is_ok());
+        let room = repository.get_room().await.unwrap();
+        let contents: Vec<&str> = room.messages.iter().map(|m| m.content.as_str()).collect();
+        assert_eq!(contents, vec!["first", "third"]);
+        assert_eq!(
+            event_bus.events().await,
+            vec![DomainEvent::MessageRejected {
+                room_id,
+                client_id: alice.clone(),
+                reason: MessageRejectionReason::RateLimited,
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_execute_failing_to_store_does_not_consume_send_slot() {
+        // テスト項目: 履歴の容量超過で保存できなかったメッセージは送信レートと送信数に数えられず、続けて送信しても RateLimited にならない
+        // given (前提条件):
+        let repository = create_test_repository_with_capacity(0);
+        let clock = Arc::new(FixedClock::new(1000));
+        let usecase = SendMessageUseCase::new(repository.clone(), Arc::new(MockMessagePusher))
+            .with_clock(clock.clone())
+            .with_rate_limiter(RateLimiter::new(MessageRateLimit {
+                messages_per_sec: 1,
+                burst: 1,
+            }))
+            .with_burst_limiter(BurstLimiter::new(MessageBurstCap {
+                max_messages: 1,
+                window_ms: 1000,
+            }));
+        let room_id = repository.get_room().await.unwrap().id;
+        let alice = ClientId::new("alice".to_string()).unwrap();
+        repository
+            .add_participant(
+                &room_id,
+                Participant::new(alice.clone(), Timestamp::new(500)),
+            )
+            .await
+            .unwrap();
+
+        // when (操作):
+        let mut results = Vec::new();
+        for content in ["first", "second"] {
+            results.push(
+                usecase
+                    .execute(
+                        alice.clone(),
+                        MessageContent::new(content.to_string()).unwrap(),
+                        |_| "chat".to_string(),
+                    )
+                    .await,
+            );
+        }
+
+        // then (期待する結果):
+        assert_eq!(
+            results,
+            vec![
+                Err(SendMessageError::MessageCapacityExceeded),
+                Err(SendMessageError::MessageCapacityExceeded),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_execute_records_delivery_latency() {
+        // テスト項目: 遅延の計測を有効にすると、注入した Clock で計測した受信からブロードキャスト完了までの時間がヒストグラムに記録される
+        // given (前提条件):
+        let repository = create_test_repository();
+        let clock = Arc::new(FixedClock::new(1000));
+        let message_pusher = Arc::new(SlowMessagePusher {
+            clock: clock.clone(),
+            delay_ms: 30,
+        });
+        let usecase = SendMessageUseCase::new(repository.clone(), message_pusher)
+            .with_clock(clock.clone())
+            .with_latency_histogram();
+        let room_id = repository.get_room().await.unwrap().id;
+        let alice = ClientId::new("alice".to_string()).unwrap();
+        repository
+            .add_participant(
+                &room_id,
+                Participant::new(alice.clone(), Timestamp::new(500)),
+            )
+            .await
+            .unwrap();
+
+        // when (操作):
+        usecase
+            .execute(
+                alice,
+                MessageContent::new("Hello".to_string()).unwrap(),
+                |_| "chat".to_string(),
+            )
+            .await
+            .unwrap();
+
+        // then (期待する結果):
+        let snapshot = usecase.latency_snapshot().unwrap();
+        assert_eq!(snapshot.count, 1);
+        assert_eq!(snapshot.sum_ms, 30);
+        assert_eq!(snapshot.buckets[3], (25, 0));
+        assert_eq!(snapshot.buckets[4], (50, 1));
+    }
+
+    #[tokio::test]
+    async fn test_execute_beyond_burst_cap_fails() {
+        // テスト項目: トークンバケットに余裕があっても、短いウィンドウ内の送信数の上限を超えたメッセージは RateLimited で拒否され、ウィンドウから外れると再び送信できる
+        // given (前提条件):
+        let repository = create_test_repository();
+        let clock = Arc::new(FixedClock::new(1000));
+        let usecase = SendMessageUseCase::new(repository.clone(), Arc::new(MockMessagePusher))
+            .with_clock(clock.clone())
+            .with_rate_limiter(RateLimiter::new(MessageRateLimit {
+                messages_per_sec: 10,
+                burst: 10,
+            }))
+            .with_burst_limiter(BurstLimiter::new(MessageBurstCap {
+                max_messages: 2,
+                window_ms: 100,
+            }));
+        let room_id = repository.get_room().await.unwrap().id;
+        let alice = ClientId::new("alice".to_string()).unwrap();
+        repository
+            .add_participant(
+                &room_id,
+                Participant::new(alice.clone(), Timestamp::new(500)),
+            )
+            .await
+            .unwrap();
+        let send = |content: &str| {
+            usecase.execute(
+                alice.clone(),
+                MessageContent::new(content.to_string()).unwrap(),
+                |_| "chat".to_string(),
+            )
+        };
+        send("first").await.unwrap();
+        clock.set(1020);
+        send("second").await.unwrap();
+
+        // when (操作):
+        clock.set(1040);
+        let limited = send("third").await;
+        clock.set(1100);
+        let after_window = send("fourth").await;
+
+        // then (期待する結果):
+        assert_eq!(
+            limited,
+            Err(SendMessageError::RateLimited { retry_after_ms: 60 })
+        );
+        assert!(after_window.is_ok());
+        let room = repository.get_room().await.unwrap();
+        let contents: Vec<&str> = room.messages.iter().map(|m| m.content.as_str()).collect();
+        assert_eq!(contents, vec!["first", "second", "fourth"]);
+    }
+
+    #[tokio::test]
+    async fn test_send_message_delivery_report_with_closed_channel() {
+        // テスト項目: 送信先のチャネルの一部が閉じている場合、配信に成功した数だけが報告される
+        // given (前提条件):
+        let repository = create_test_repository();
+        let room_id = repository.get_room().await.unwrap().id;
+        let message_pusher = Arc::new(WebSocketMessagePusher::new(Arc::new(Mutex::new(
+            HashMap::new(),
+        ))));
+        let usecase = SendMessageUseCase::new(repository.clone(), message_pusher.clone());
+
+        let timestamp = Timestamp::new(get_jst_timestamp());
+        let alice = ClientId::new("alice".to_string()).unwrap();
+        let bob = ClientId::new("bob".to_string()).unwrap();
+        let charlie = ClientId::new("charlie".to_string()).unwrap();
+        let (bob_tx, mut bob_rx) = pusher_channel();
+        let (charlie_tx, charlie_rx) = pusher_channel();
+        for (client_id, tx) in [(bob.clone(), bob_tx), (charlie.clone(), charlie_tx)] {
+            repository
+                .add_participant(&room_id, Participant::new(client_id.clone(), timestamp))
+                .await
+                .unwrap();
+            message_pusher.register_client(client_id, tx).await;
+        }
+        repository
+            .add_participant(&room_id, Participant::new(alice.clone(), timestamp))
+            .await
+            .unwrap();
+
+        // charlie のチャネルを閉じる
+        drop(charlie_rx);
+
+        // when (操作):
+        let report = usecase
+            .execute(
+                alice,
+                MessageContent::new("Hello!".to_string()).unwrap(),
+                |_| r#"{"type":"chat"}"#.to_string(),
+            )
+            .await
+            .unwrap();
+
+        // then (期待する結果):
+        assert_eq!(report.delivery.delivered_count, 1);
+        assert_eq!(report.delivery.total_targets(), 2);
+        assert_eq!(bob_rx.recv().await, Some(r#"{"type":"chat"}"#.to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_send_message_assigns_increasing_message_ids() {
+        // テスト項目: 連続して送信したメッセージに単調増加するメッセージ ID が割り当てられ、JSON に含まれる
+        // given (前提条件):
+        let repository = create_test_repository();
+        let room_id = repository.get_room().await.unwrap().id;
+        let message_pusher = Arc::new(WebSocketMessagePusher::new(Arc::new(Mutex::new(
+            HashMap::new(),
+        ))));
+        let usecase = SendMessageUseCase::new(repository.clone(), message_pusher.clone());
+
+        let timestamp = Timestamp::new(get_jst_timestamp());
+        let alice = ClientId::new("alice".to_string()).unwrap();
+        let bob = ClientId::new("bob".to_string()).unwrap();
+        let (bob_tx, mut bob_rx) = pusher_channel();
+        for client_id in [alice.clone(), bob.clone()] {
+            repository
+                .add_participant(&room_id, Participant::new(client_id, timestamp))
+                .await
+                .unwrap();
+        }
+        message_pusher.register_client(bob, bob_tx).await;
+
+        // when (操作):
+        let mut sent = Vec::new();
+        for content in ["first", "second"] {
+            let result = usecase
+                .execute(
+                    alice.clone(),
+                    MessageContent::new(content.to_string()).unwrap(),
+                    |message_id| message_id.to_string(),
+                )
+                .await
+                .unwrap();
+            sent.push(result);
+        }
+
+        // then (期待する結果):
+        assert!(sent[0].message_id < sent[1].message_id);
+        assert_eq!(bob_rx.recv().await, Some(sent[0].message_id.to_string()));
+        assert_eq!(bob_rx.recv().await, Some(sent[1].message_id.to_string()));
+        let room = repository.get_room().await.unwrap();
+        assert_eq!(room.messages[1].id.as_ref(), Some(&sent[1].message_id));
+    }
+
+    #[tokio::test]
+    async fn test_send_message_uses_clock_timestamps() {
+        // テスト項目: FixedClock で指定した時刻が、そのまま各メッセージのタイムスタンプになる
+        // given (前提条件):
+        let repository = create_test_repository();
+        let room_id = repository.get_room().await.unwrap().id;
+        let clock = Arc::new(FixedClock::new(1000));
+        let usecase = SendMessageUseCase::new(repository.clone(), Arc::new(MockMessagePusher))
+            .with_clock(clock.clone());
+        let alice = ClientId::new("alice".to_string()).unwrap();
+        repository
+            .add_participant(
+                &room_id,
+                Participant::new(alice.clone(), Timestamp::new(500)),
+            )
+            .await
+            .unwrap();
+
+        // when (操作):
+        for (content, now) in [("first", 2000), ("second", 1500)] {
+            clock.set(now);
+            usecase
+                .execute(
+                    alice.clone(),
+                    MessageContent::new(content.to_string()).unwrap(),
+                    |message_id| message_id.to_string(),
+                )
+                .await
+                .unwrap();
+        }
+
+        // then (期待する結果):
+        let room = repository.get_room().await.unwrap();
+        assert_eq!(room.messages[0].timestamp, Timestamp::new(2000));
+        assert_eq!(room.messages[1].timestamp, Timestamp::new(1500));
+    }
+
+    #[tokio::test]
+    async fn test_execute_and_return_returns_stored_message() {
+        // テスト項目: execute_and_return は保存したメッセージ（ID と Clock の時刻）を返し、それを JSON の生成に渡す
+        // given (前提条件):
+        let repository = create_test_repository();
+        let room_id = repository.get_room().await.unwrap().id;
+        let usecase = SendMessageUseCase::new(repository.clone(), Arc::new(MockMessagePusher))
+            .with_clock(Arc::new(FixedClock::new(2000)));
+        let alice = ClientId::new("alice".to_string()).unwrap();
+        repository
+            .add_participant(
+                &room_id,
+                Participant::new(alice.clone(), Timestamp::new(500)),
+            )
+            .await
+            .unwrap();
+
+        // when (操作):
+        let mut built_timestamp = None;
+        let (message, sent) = usecase
+            .execute_and_return(
+                alice.clone(),
+                MessageContent::new("Hello!".to_string()).unwrap(),
+                |message| {
+                    built_timestamp = Some(message.timestamp);
+                    message.content.as_str().to_string()
+                },
+            )
+            .await
+            .unwrap();
+
+        // then (期待する結果):
+        assert_eq!(message.timestamp, Timestamp::new(2000));
+        assert_eq!(built_timestamp, Some(Timestamp::new(2000)));
+        assert_eq!(message.id.as_ref(), Some(&sent.message_id));
+        let room = repository.get_room().await.unwrap();
+        assert_eq!(room.messages[0], message);
+    }
+
+    #[tokio::test]
+    async fn test_execute_whisper_reaches_recipient_and_sender_only() {
+        // テスト項目: ウィスパーは宛先と送信者（エコー）だけに届き、メッセージ履歴には追加されない
+        // given (前提条件):
+        let repository = create_test_repository();
+        let room_id = repository.get_room().await.unwrap().id;
+        let message_pusher = Arc::new(WebSocketMessagePusher::new(Arc::new(Mutex::new(
+            HashMap::new(),
+        ))));
+        let usecase = SendMessageUseCase::new(repository.clone(), message_pusher.clone())
+            .with_clock(Arc::new(FixedClock::new(1000)));
+        let alice = ClientId::new("alice".to_string()).unwrap();
+        let bob = ClientId::new("bob".to_string()).unwrap();
+        let charlie = ClientId::new("charlie".to_string()).unwrap();
+        let mut receivers = Vec::new();
+        for client_id in [alice.clone(), bob.clone(), charlie.clone()] {
+            let (tx, rx) = pusher_channel();
+            repository
+                .add_participant(
+                    &room_id,
+                    Participant::new(client_id.clone(), Timestamp::new(500)),
+                )
+                .await
+                .unwrap();
+            message_pusher.register_client(client_id, tx).await;
+            receivers.push(rx);
+        }
+
+        // when (操作):
+        let result = usecase
+            .execute_whisper(
+                alice,
+                bob,
+                MessageContent::new("psst".to_string()).unwrap(),
+                |content, timestamp| format!("{}@{}", content.as_str(), timestamp.value()),
+            )
+            .await;
+
+        // then (期待する結果):
+        assert_eq!(result, Ok(()));
+        let [alice_rx, bob_rx, charlie_rx] = receivers.as_mut_slice() else {
+            unreachable!();
+        };
+        assert_eq!(bob_rx.try_recv().ok(), Some("psst@1000".to_string()));
+        assert_eq!(alice_rx.try_recv().ok(), Some("psst@1000".to_string()));
+        assert!(charlie_rx.try_recv().is_err());
+        assert!(repository.get_room().await.unwrap().messages.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_execute_edit_updates_history_and_notifies_others() {
+        // テスト項目: メッセージの編集は履歴の内容と編集日時を更新し、送信者以外に通知される
+        // given (前提条件):
+        let repository = create_test_repository();
+        let room_id = repository.get_room().await.unwrap().id;
+        let message_pusher = Arc::new(WebSocketMessagePusher::new(Arc::new(Mutex::new(
+            HashMap::new(),
+        ))));
+        let clock = Arc::new(FixedClock::new(1000));
+        let usecase = SendMessageUseCase::new(repository.clone(), message_pusher.clone())
+            .with_clock(clock.clone());
+        let alice = ClientId::new("alice".to_string()).unwrap();
+        let bob = ClientId::new("bob".to_string()).unwrap();
+        let mut receivers = Vec::new();
+        for client_id in [alice.clone(), bob.clone()] {
+            let (tx, rx) = pusher_channel();
+            repository
+                .add_participant(
+                    &room_id,
+                    Participant::new(client_id.clone(), Timestamp::new(500)),
+                )
+                .await
+                .unwrap();
+            message_pusher.register_client(client_id, tx).await;
+            receivers.push(rx);
+        }
+        let sent = usecase
+            .execute(
+                alice.clone(),
+                MessageContent::new("Helo".to_string()).unwrap(),
+                |_| "chat".to_string(),
+            )
+            .await
+            .unwrap();
+        let [alice_rx, bob_rx] = receivers.as_mut_slice() else {
+            unreachable!();
+        };
+        assert_eq!(bob_rx.try_recv().ok(), Some("chat".to_string()));
+        clock.set(2000);
+
+        // when (操作):
+        let edited = usecase
+            .execute_edit(
+                alice,
+                sent.message_id.clone(),
+                MessageContent::new("Hello".to_string()).unwrap(),
+                None,
+                |message| format!("edit@{}", message.edited_at.unwrap().value()),
+            )
+            .await
+            .unwrap();
+
+        // then (期待する結果):
+        assert_eq!(edited.content.as_str(), "Hello");
+        assert_eq!(edited.edited_at, Some(Timestamp::new(2000)));
+        assert_eq!(bob_rx.try_recv().ok(), Some("edit@2000".to_string()));
+        assert!(alice_rx.try_recv().is_err());
+        let room = repository.get_room().await.unwrap();
+        assert_eq!(room.messages, vec![edited]);
+    }
+
+    #[tokio::test]
+    async fn test_execute_edit_by_other_client_fails() {
+        // テスト項目: 作成者以外によるメッセージの編集は NotMessageAuthor で拒否され、履歴は変わらない
+        // given (前提条件):
+        let repository = create_test_repository();
+        let room_id = repository.get_room().await.unwrap().id;
+        let usecase = SendMessageUseCase::new(repository.clone(), Arc::new(MockMessagePusher));
+        let alice = ClientId::new("alice".to_string()).unwrap();
+        let bob = ClientId::new("bob".to_string()).unwrap();
+        for client_id in [alice.clone(), bob.clone()] {
+            repository
+                .add_participant(&room_id, Participant::new(client_id, Timestamp::new(500)))
+                .await
+                .unwrap();
+        }
+        let sent = usecase
+            .execute(
+                alice,
+                MessageContent::new("Hello".to_string()).unwrap(),
+                |_| "chat".to_string(),
+            )
+            .await
+            .unwrap();
+
+        // when (操作):
+        let result = usecase
+            .execute_edit(
+                bob,
+                sent.message_id,
+                MessageContent::new("Hacked".to_string()).unwrap(),
+                None,
+                |_| "edit".to_string(),
+            )
+            .await;
+
+        // then (期待する結果):
+        assert_eq!(result, Err(SendMessageError::NotMessageAuthor));
+        let room = repository.get_room().await.unwrap();
+        assert_eq!(room.messages[0].content.as_str(), "Hello");
+        assert_eq!(room.messages[0].edited_at, None);
+    }
+
+    #[tokio::test]
+    async fn test_execute_edit_with_stale_client_timestamp_fails() {
+        // テスト項目: 元メッセージのサーバタイムスタンプより古いクライアントタイムスタンプの編集は StaleEdit で拒否され、同時刻以降なら受け付けて edited_at はサーバ時刻になる
+        // given (前提条件):
+        let repository = create_test_repository();
+        let room_id = repository.get_room().await.unwrap().id;
+        let clock = Arc::new(FixedClock::new(1000));
+        let usecase = SendMessageUseCase::new(repository.clone(), Arc::new(MockMessagePusher))
+            .with_clock(clock.clone());
+        let alice = ClientId::new("alice".to_string()).unwrap();
+        repository
+            .add_participant(
+                &room_id,
+                Participant::new(alice.clone(), Timestamp::new(500)),
+            )
+            .await
+            .unwrap();
+        let content = |text: &str| MessageContent::new(text.to_string()).unwrap();
+        let sent = usecase
+            .execute(alice.clone(), content("v1"), |_| "chat".to_string())
+            .await
+            .unwrap();
+        clock.set(2000);
+
+        // when (操作):
+        let stale = usecase
+            .execute_edit(
+                alice.clone(),
+                sent.message_id.clone(),
+                content("stale"),
+                Some(Timestamp::new(999)),
+                |_| "edit".to_string(),
+            )
+            .await;
+        let accepted = usecase
+            .execute_edit(
+                alice.clone(),
+                sent.message_id.clone(),
+                content("v2"),
+                Some(Timestamp::new(1000)),
+                |_| "edit".to_string(),
+            )
+            .await;
+
+        // then (期待する結果):
+        assert_eq!(stale, Err(SendMessageError::StaleEdit));
+        let edited = accepted.unwrap();
+        assert_eq!(edited.content.as_str(), "v2");
+        assert_eq!(edited.edited_at, Some(Timestamp::new(2000)));
+    }
+
+    #[tokio::test]
+    async fn test_edits_and_delete_are_audited_in_order() {
+        // テスト項目: メッセージを 2 回編集して削除すると、変更前後の内容のハッシュを持つ監査エントリが順に 3 件記録される
+        // given (前提条件):
+        let repository = create_test_repository();
+        let room_id = repository.get_room().await.unwrap().id;
+        let clock = Arc::new(FixedClock::new(1000));
+        let audit_log = Arc::new(InMemoryMessageAuditLog::new());
+        let usecase = SendMessageUseCase::new(repository.clone(), Arc::new(MockMessagePusher))
+            .with_clock(clock.clone())
+            .with_audit_log(audit_log.clone());
+        let alice = ClientId::new("alice".to_string()).unwrap();
+        repository
+            .add_participant(
+                &room_id,
+                Participant::new(alice.clone(), Timestamp::new(500)),
+            )
+            .await
+            .unwrap();
+        let content = |text: &str| MessageContent::new(text.to_string()).unwrap();
+        let sent = usecase
+            .execute(alice.clone(), content("v1"), |_| "chat".to_string())
+            .await
+            .unwrap();
+
+        // when (操作):
+        for (at, text) in [(2000, "v2"), (3000, "v3")] {
+            clock.set(at);
+            usecase
+                .execute_edit(
+                    alice.clone(),
+                    sent.message_id.clone(),
+                    content(text),
+                    None,
+                    |_| "edit".to_string(),
+                )
+                .await
+                .unwrap();
+        }
+        clock.set(4000);
+        usecase
+            .execute_delete(alice.clone(), sent.message_id.clone(), "delete")
+            .await
+            .unwrap();
+
+        // then (期待する結果):
+        let hash = |text: &str| MessageAuditEntry::hash_content(&content(text));
+        let entry = |action, before_hash, after_hash, at| MessageAuditEntry {
+            room_id: room_id.clone(),
+            message_id: sent.message_id.clone(),
+            action,
+            actor: alice.clone(),
+            before_hash,
+            after_hash,
+            at: Timestamp::new(at),
+        };
+        assert_eq!(
+            audit_log.history(&room_id, &sent.message_id).await,
+            vec![
+                entry(MessageAuditAction::Edit, hash("v1"), hash("v2"), 2000),
+                entry(MessageAuditAction::Edit, hash("v2"), hash("v3"), 3000),
+                entry(
+                    MessageAuditAction::Delete,
+                    hash("v3"),
+                    MessageAuditEntry::hash_content(&MessageContent::tombstone()),
+                    4000
+                ),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_execute_delete_tombstones_message_and_notifies_others() {
+        // テスト項目: メッセージの削除は履歴に空の内容の削除済みメッセージを残し、送信者以外に通知される。作成者以外の削除は拒否される
+        // given (前提条件):
+        let repository = create_test_repository();
+        let room_id = repository.get_room().await.unwrap().id;
+        let message_pusher = Arc::new(WebSocketMessagePusher::new(Arc::new(Mutex::new(
+            HashMap::new(),
+        ))));
+        let usecase = SendMessageUseCase::new(repository.clone(), message_pusher.clone());
+        let alice = ClientId::new("alice".to_string()).unwrap();
+        let bob = ClientId::new("bob".to_string()).unwrap();
+        let mut receivers = Vec::new();
+        for client_id in [alice.clone(), bob.clone()] {
+            let (tx, rx) = pusher_channel();
+            repository
+                .add_participant(
+                    &room_id,
+                    Participant::new(client_id.clone(), Timestamp::new(500)),
+                )
+                .await
+                .unwrap();
+            message_pusher.register_client(client_id, tx).await;
+            receivers.push(rx);
+        }
+        let sent = usecase
+            .execute(
+                alice.clone(),
+                MessageContent::new("Hello".to_string()).unwrap(),
+                |_| "chat".to_string(),
+            )
+            .await
+            .unwrap();
+        let [alice_rx, bob_rx] = receivers.as_mut_slice() else {
+            unreachable!();
+        };
+        assert_eq!(bob_rx.try_recv().ok(), Some("chat".to_string()));
+
+        // when (操作):
+        let by_bob = usecase
+            .execute_delete(bob, sent.message_id.clone(), "delete")
+            .await;
+        let by_alice = usecase
+            .execute_delete(alice, sent.message_id.clone(), "delete")
+            .await;
+
+        // then (期待する結果):
+        assert_eq!(by_bob, Err(SendMessageError::NotMessageAuthor));
+        assert_eq!(by_alice, Ok(()));
+        assert_eq!(bob_rx.try_recv().ok(), Some("delete".to_string()));
+        assert!(bob_rx.try_recv().is_err());
+        assert!(alice_rx.try_recv().is_err());
+        let room = repository.get_room().await.unwrap();
+        assert_eq!(room.messages.len(), 1);
+        assert!(room.messages[0].deleted);
+        assert_eq!(room.messages[0].content.as_str(), "");
+        assert_eq!(room.messages[0].id, Some(sent.message_id));
+    }
+
+    #[tokio::test]
+    async fn test_relay_typing_reaches_others_without_history() {
+        // テスト項目: タイピング通知は送信者以外の参加者に届き、メッセージ履歴には追加されない
+        // given (前提条件):
+        let repository = create_test_repository();
+        let room_id = repository.get_room().await.unwrap().id;
+        let message_pusher = Arc::new(WebSocketMessagePusher::new(Arc::new(Mutex::new(
+            HashMap::new(),
+        ))));
+        let usecase = SendMessageUseCase::new(repository.clone(), message_pusher.clone());
+        let alice = ClientId::new("alice".to_string()).unwrap();
+        let bob = ClientId::new("bob".to_string()).unwrap();
+        let mut receivers = Vec::new();
+        for client_id in [alice.clone(), bob.clone()] {
+            let (tx, rx) = pusher_channel();
+            repository
+                .add_participant(
+                    &room_id,
+                    Participant::new(client_id.clone(), Timestamp::new(500)),
+                )
+                .await
+                .unwrap();
+            message_pusher.register_client(client_id, tx).await;
+            receivers.push(rx);
+        }
+
+        // when (操作):
+        let result = usecase.relay_typing(&alice, "typing").await;
+
+        // then (期待する結果):
+        assert_eq!(result, Ok(vec![bob]));
+        let [alice_rx, bob_rx] = receivers.as_mut_slice() else {
+            unreachable!();
+        };
+        assert_eq!(bob_rx.try_recv().ok(), Some("typing".to_string()));
+        assert!(alice_rx.try_recv().is_err());
+        assert!(repository.get_room().await.unwrap().messages.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_execute_whisper_to_unknown_recipient_fails() {
+        // テスト項目: 接続していない宛先へのウィスパーは RecipientNotFound で拒否される
+        // given (前提条件):
+        let repository = create_test_repository();
+        let room_id = repository.get_room().await.unwrap().id;
+        let usecase = SendMessageUseCase::new(repository.clone(), Arc::new(MockMessagePusher));
+        let alice = ClientId::new("alice".to_string()).unwrap();
+        repository
+            .add_participant(
+                &room_id,
+                Participant::new(alice.clone(), Timestamp::new(500)),
+            )
+            .await
+            .unwrap();
+
+        // when (操作):
+        let result = usecase
+            .execute_whisper(
+                alice,
+                ClientId::new("dave".to_string()).unwrap(),
+                MessageContent::new("psst".to_string()).unwrap(),
+                |content, _| content.as_str().to_string(),
+            )
+            .await;
+
+        // then (期待する結果):
+        assert_eq!(result, Err(SendMessageError::RecipientNotFound));
+    }
+
+    #[tokio::test]
+    async fn test_execute_whisper_to_self_by_policy() {
+        // テスト項目: 自分宛てのウィスパーは、許可する設定では自分用のメモとして送信者に 1 度だけ届いて履歴には追加されず、拒否する設定では SelfMessage で拒否されて何も届かない
+        // given (前提条件):
+        let repository = create_test_repository();
+        let room_id = repository.get_room().await.unwrap().id;
+        let message_pusher = Arc::new(WebSocketMessagePusher::new(Arc::new(Mutex::new(
+            HashMap::new(),
+        ))));
+        let alice = ClientId::new("alice".to_string()).unwrap();
+        let (tx, mut alice_rx) = pusher_channel();
+        repository
+            .add_participant(
+                &room_id,
+                Participant::new(alice.clone(), Timestamp::new(500)),
+            )
+            .await
+            .unwrap();
+        message_pusher.register_client(alice.clone(), tx).await;
+        let allowing = SendMessageUseCase::new(repository.clone(), message_pusher.clone());
+        let rejecting = SendMessageUseCase::new(repository.clone(), message_pusher.clone())
+            .with_self_message_policy(SelfMessagePolicy::Reject);
+
+        // when (操作):
+        let mut results = Vec::new();
+        let mut received = Vec::new();
+        for usecase in [&allowing, &rejecting] {
+            results.push(
+                usecase
+                    .execute_whisper(
+                        alice.clone(),
+                        alice.clone(),
+                        MessageContent::new("note".to_string()).unwrap(),
+                        |content, _| content.as_str().to_string(),
+                    )
+                    .await,
+            );
+            let mut frames = Vec::new();
+            while let Ok(frame) = alice_rx.try_recv() {
+                frames.push(frame);
+            }
+            received.push(frames);
+        }
+
+        // then (期待する結果):
+        assert_eq!(results, vec![Ok(()), Err(SendMessageError::SelfMessage)]);
+        assert_eq!(received[0], vec!["note".to_string()]);
+        assert!(received[1].is_empty());
+        assert!(repository.get_room().await.unwrap().messages.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_get_broadcast_targets_with_bot_recipient_policy() {
+        // テスト項目: bot を除外する設定では人間にのみ、bot のみの設定では bot にのみ送信される
+        // given (前提条件):
+        let repository = create_test_repository();
+        let room_id = repository.get_room().await.unwrap().id;
+        let timestamp = Timestamp::new(get_jst_timestamp());
+        let alice = ClientId::new("alice".to_string()).unwrap();
+        let bob = ClientId::new("bob".to_string()).unwrap();
+        let bot = ClientId::new("helper-bot".to_string()).unwrap();
+        for (client_id, is_bot) in [
+            (alice.clone(), false),
+            (bob.clone(), false),
+            (bot.clone(), true),
+        ] {
+            repository
+                .add_participant(
+                    &room_id,
+                    Participant::new(client_id, timestamp).with_bot(is_bot),
+                )
+                .await
+                .unwrap();
+        }
+
+        let exclude_usecase =
+            SendMessageUseCase::new(repository.clone(), Arc::new(MockMessagePusher))
+                .with_bot_recipient_policy(BotRecipientPolicy::Exclude);
+        let only_usecase = SendMessageUseCase::new(repository.clone(), Arc::new(MockMessagePusher))
+            .with_bot_recipient_policy(BotRecipientPolicy::Only);
+
+        // when (操作):
+        let excluded = exclude_usecase
+            .execute(
+                alice.clone(),
+                MessageContent::new("Hello humans!".to_string()).unwrap(),
+                |_| r#"{"type":"chat"}"#.to_string(),
+            )
+            .await
+            .unwrap();
+        let bot_only_targets = only_usecase.get_broadcast_targets(&room_id, &alice).await;
+
+        // then (期待する結果):
+        assert_eq!(excluded.delivery.targets, vec![bob]);
+        assert_eq!(bot_only_targets, vec![bot]);
+    }
+
+    #[tokio::test]
+    async fn test_send_message_records_dead_letter_for_closed_channel() {
+        // テスト項目: 閉じたチャネルへの送信がデッドレターとして記録される
+        // given (前提条件):
+        let repository = create_test_repository();
+        let room_id = repository.get_room().await.unwrap().id;
+        let message_pusher = Arc::new(WebSocketMessagePusher::new(Arc::new(Mutex::new(
+            HashMap::new(),
+        ))));
+        let dead_letter_sink = Arc::new(InMemoryDeadLetterSink::new(10));
+        let usecase = SendMessageUseCase::new(repository.clone(), message_pusher.clone())
+            .with_dead_letter_sink(dead_letter_sink.clone());
+
+        let timestamp = Timestamp::new(get_jst_timestamp());
+        let alice = ClientId::new("alice".to_string()).unwrap();
+        let bob = ClientId::new("bob".to_string()).unwrap();
+        let (bob_tx, bob_rx) = pusher_channel();
+        for client_id in [alice.clone(), bob.clone()] {
+            repository
+                .add_participant(&room_id, Participant::new(client_id, timestamp))
+                .await
+                .unwrap();
+        }
+        message_pusher.register_client(bob.clone(), bob_tx).await;
+
+        // bob のチャネルを閉じる
+        drop(bob_rx);
+
+        // when (操作):
+        let sent = usecase
+            .execute(
+                alice,
+                MessageContent::new("Hello!".to_string()).unwrap(),
+                |_| r#"{"type":"chat"}"#.to_string(),
+            )
+            .await
+            .unwrap();
+
+        // then (期待する結果):
+        let entries = dead_letter_sink.entries().await;
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].message_id, sent.message_id);
+        assert_eq!(entries[0].target_client_id, bob);
+        assert!(!entries[0].reason.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_send_message_ack_marks_delivered_and_timeout_marks_undelivered() {
+        // テスト項目: 受信確認が届いたメッセージは配信済みになり、届かないメッセージはタイムアウトで未配信になる
+        // given (前提条件):
+        let repository = create_test_repository();
+        let room_id = repository.get_room().await.unwrap().id;
+        let message_pusher = Arc::new(WebSocketMessagePusher::new(Arc::new(Mutex::new(
+            HashMap::new(),
+        ))));
+        let dead_letter_sink = Arc::new(InMemoryDeadLetterSink::new(10));
+        let usecase = SendMessageUseCase::new(repository.clone(), message_pusher.clone())
+            .with_dead_letter_sink(dead_letter_sink.clone())
+            .with_ack_timeout(Duration::from_millis(50));
+
+        let timestamp = Timestamp::new(get_jst_timestamp());
+        let alice = ClientId::new("alice".to_string()).unwrap();
+        let bob = ClientId::new("bob".to_string()).unwrap();
+        let charlie = ClientId::new("charlie".to_string()).unwrap();
+        let mut receivers = Vec::new();
+        for client_id in [alice.clone(), bob.clone(), charlie.clone()] {
+            let (tx, rx) = pusher_channel();
+            repository
+                .add_participant(&room_id, Participant::new(client_id.clone(), timestamp))
+                .await
+                .unwrap();
+            message_pusher.register_client(client_id.clone(), tx).await;
+            usecase.require_acks(client_id);
+            receivers.push(rx);
+        }
+
+        // when (操作): bob だけが受信確認を送る
+        let sent = usecase
+            .execute(
+                alice,
+                MessageContent::new("Hello!".to_string()).unwrap(),
+                |_| r#"{"type":"chat"}"#.to_string(),
+            )
+            .await
+            .unwrap();
+        let bob_acked = usecase.acknowledge(&bob, &sent.message_id);
+        tokio::time::sleep(Duration::from_millis(150)).await;
+
+        // then (期待する結果):
+        assert!(bob_acked);
+        let entries = dead_letter_sink.entries().await;
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].target_client_id, charlie);
+        assert_eq!(entries[0].message_id, sent.message_id);
+        assert!(!usecase.acknowledge(&charlie, &sent.message_id));
+    }
 }
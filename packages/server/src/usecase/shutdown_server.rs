@@ -0,0 +1,154 @@
+//! UseCase: サーバ停止処理
+//!
+//! ## テスト実装の作業記録
+//!
+//! ### 何をテストしているか
+//! - ShutdownServerUseCase::close_all_connections() メソッド
+//!
+//! ### なぜこのテストが必要か
+//! - 猶予期間後に残っている全ての接続が MessagePusher から登録解除されることを確認
+//!
+//! ### どのような状況を想定しているか
+//! - 正常系：接続中のクライアントが存在する場合
+//! - エッジケース：接続中のクライアントが存在しない場合
+
+use std::sync::Arc;
+
+use crate::domain::{ClientId, MessagePriority, MessagePusher, RoomRepository};
+
+/// サーバ停止のユースケース
+pub struct ShutdownServerUseCase {
+    /// Repository（データアクセス層の抽象化）
+    repository: Arc<dyn RoomRepository>,
+    /// MessagePusher（メッセージ通知の抽象化）
+    message_pusher: Arc<dyn MessagePusher>,
+    /// お知らせの配信優先度（デフォルトは未送信のチャットより先に送信する `High`）
+    announcement_priority: MessagePriority,
+}
+
+impl ShutdownServerUseCase {
+    /// 新しい ShutdownServerUseCase を作成
+    pub fn new(
+        repository: Arc<dyn RoomRepository>,
+        message_pusher: Arc<dyn MessagePusher>,
+    ) -> Self {
+        Self {
+            repository,
+            message_pusher,
+            announcement_priority: MessagePriority::High,
+        }
+    }
+
+    /// お知らせの配信優先度を設定
+    pub fn with_announcement_priority(mut self, announcement_priority: MessagePriority) -> Self {
+        self.announcement_priority = announcement_priority;
+        self
+    }
+
+    /// サーバ停止の通知を全ての参加者にブロードキャスト
+    ///
+    /// # Arguments
+    ///
+    /// * `message` - ブロードキャストするメッセージ（JSON）
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - ブロードキャスト成功
+    /// * `Err(String)` - ブロードキャスト失敗
+    pub async fn broadcast_server_shutdown(&self, message: &str) -> Result<(), String> {
+        let target_ids: Vec<ClientId> = self.repository.get_all_connected_client_ids().await;
+        self.message_pusher
+            .broadcast_with_priority(target_ids, message, self.announcement_priority)
+            .await
+            .map(|_| ())
+            .map_err(|e| e.to_string())
+    }
+
+    /// 残っている全ての接続を閉じる
+    ///
+    /// MessagePusher から全てのクライアントを登録解除することで、
+    /// 各接続の送信ループを終了させる（参加者の削除は各接続の切断処理で行う）。
+    ///
+    /// # Returns
+    ///
+    /// 登録解除したクライアントの数
+    pub async fn close_all_connections(&self) -> usize {
+        let client_ids = self.repository.get_all_connected_client_ids().await;
+        for client_id in &client_ids {
+            self.message_pusher.unregister_client(client_id).await;
+        }
+        client_ids.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        domain::{ClockExt, FixedClock, Participant, Room, RoomIdFactory, pusher_channel},
+        infrastructure::{
+            message_pusher::WebSocketMessagePusher, repository::InMemoryRoomRepository,
+        },
+    };
+    use std::collections::HashMap;
+    use tokio::sync::Mutex;
+
+    fn create_test_repository(clock: &FixedClock) -> Arc<InMemoryRoomRepository> {
+        let room = Arc::new(Mutex::new(Room::new(
+            RoomIdFactory::generate().unwrap(),
+            clock.now(),
+        )));
+        Arc::new(InMemoryRoomRepository::new(room))
+    }
+
+    #[tokio::test]
+    async fn test_close_all_connections_unregisters_clients() {
+        // テスト項目: 接続中の全てのクライアントが MessagePusher から登録解除される
+        // given (前提条件):
+        let clock = FixedClock::new(1000);
+        let repository = create_test_repository(&clock);
+        let room_id = repository.get_room().await.unwrap().id;
+        let clients = Arc::new(Mutex::new(HashMap::new()));
+        let message_pusher = Arc::new(WebSocketMessagePusher::new(clients.clone()));
+        let usecase = ShutdownServerUseCase::new(repository.clone(), message_pusher.clone());
+
+        let mut receivers = Vec::new();
+        for name in ["alice", "bob"] {
+            let client_id = ClientId::new(name.to_string()).unwrap();
+            let (tx, rx) = pusher_channel();
+            repository
+                .add_participant(&room_id, Participant::new(client_id.clone(), clock.now()))
+                .await
+                .unwrap();
+            message_pusher.register_client(client_id, tx).await;
+            receivers.push(rx);
+        }
+
+        // when (操作):
+        let closed = usecase.close_all_connections().await;
+
+        // then (期待する結果):
+        assert_eq!(closed, 2);
+        assert!(clients.lock().await.is_empty());
+        for mut rx in receivers {
+            assert!(rx.recv().await.is_none());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_close_all_connections_with_no_clients() {
+        // テスト項目: 接続中のクライアントが存在しない場合は何もしない
+        // given (前提条件):
+        let clock = FixedClock::new(1000);
+        let repository = create_test_repository(&clock);
+        let clients = Arc::new(Mutex::new(HashMap::new()));
+        let message_pusher = Arc::new(WebSocketMessagePusher::new(clients));
+        let usecase = ShutdownServerUseCase::new(repository, message_pusher);
+
+        // when (操作):
+        let closed = usecase.close_all_connections().await;
+
+        // then (期待する結果):
+        assert_eq!(closed, 0);
+    }
+}
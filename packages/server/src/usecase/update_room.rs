@@ -0,0 +1,233 @@
+//! UseCase: ルーム設定更新処理
+//!
+//! ## テスト実装の作業記録
+//!
+//! ### 何をテストしているか
+//! - UpdateRoomUseCase::execute() メソッド
+//! - ルームのロック状態の更新
+//! - ルームを閉じる・再開する
+//!
+//! ### なぜこのテストが必要か
+//! - ロック状態が Domain Model（Room）に正しく反映されることを確認
+//! - 存在しないルームへの更新がエラーになることを保証
+//!
+//! ### どのような状況を想定しているか
+//! - 正常系：ルームのロック・ロック解除
+//! - 正常系：ルームを閉じる・再開する
+//! - 異常系：存在しないルーム ID の指定
+
+use std::sync::Arc;
+
+use crate::domain::{
+    ClientId, MessagePriority, MessagePusher, RepositoryError, Room, RoomId, RoomRepository,
+};
+
+/// ルーム設定更新のユースケース
+pub struct UpdateRoomUseCase {
+    /// Repository（データアクセス層の抽象化）
+    repository: Arc<dyn RoomRepository>,
+    /// MessagePusher（メッセージ通知の抽象化）
+    message_pusher: Arc<dyn MessagePusher>,
+    /// お知らせの配信優先度（デフォルトは未送信のチャットより先に送信する `High`）
+    announcement_priority: MessagePriority,
+}
+
+/// ルーム設定更新エラー
+#[derive(Debug, PartialEq)]
+pub enum UpdateRoomError {
+    /// ルームが見つからない
+    RoomNotFound,
+    /// Repository エラー
+    RepositoryError,
+}
+
+impl UpdateRoomUseCase {
+    /// 新しい UpdateRoomUseCase を作成
+    pub fn new(
+        repository: Arc<dyn RoomRepository>,
+        message_pusher: Arc<dyn MessagePusher>,
+    ) -> Self {
+        Self {
+            repository,
+            message_pusher,
+            announcement_priority: MessagePriority::High,
+        }
+    }
+
+    /// お知らせの配信優先度を設定
+    pub fn with_announcement_priority(mut self, announcement_priority: MessagePriority) -> Self {
+        self.announcement_priority = announcement_priority;
+        self
+    }
+
+    /// ルーム設定を更新
+    ///
+    /// # Arguments
+    ///
+    /// * `room_id` - 更新するルームの ID
+    /// * `locked` - ロック状態（`None` の場合は変更しない）
+    /// * `closed` - 閉じた状態（`None` の場合は変更しない）
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Room)` - 更新後のルーム（Domain Model）
+    /// * `Err(UpdateRoomError)` - 更新失敗
+    pub async fn execute(
+        &self,
+        room_id: String,
+        locked: Option<bool>,
+        closed: Option<bool>,
+    ) -> Result<Room, UpdateRoomError> {
+        let room_id = RoomId::new(room_id).map_err(|_| UpdateRoomError::RoomNotFound)?;
+        self.repository
+            .get_room_by_id(&room_id)
+            .await
+            .map_err(|e| match e {
+                RepositoryError::RoomNotFound => UpdateRoomError::RoomNotFound,
+                _ => UpdateRoomError::RepositoryError,
+            })?;
+
+        if let Some(locked) = locked {
+            self.repository
+                .set_room_locked(&room_id, locked)
+                .await
+                .map_err(|_| UpdateRoomError::RepositoryError)?;
+        }
+
+        if let Some(closed) = closed {
+            self.repository
+                .set_room_closed(&room_id, closed)
+                .await
+                .map_err(|_| UpdateRoomError::RepositoryError)?;
+        }
+
+        self.repository
+            .get_room_by_id(&room_id)
+            .await
+            .map_err(|_| UpdateRoomError::RepositoryError)
+    }
+
+    /// ルーム設定の変更をルームの全ての参加者にブロードキャスト
+    ///
+    /// # Arguments
+    ///
+    /// * `room_id` - 更新したルームの ID（Domain Model）
+    /// * `message` - ブロードキャストするメッセージ（JSON）
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - ブロードキャスト成功
+    /// * `Err(String)` - ブロードキャスト失敗
+    pub async fn broadcast_room_updated(
+        &self,
+        room_id: &RoomId,
+        message: &str,
+    ) -> Result<(), String> {
+        let target_ids: Vec<ClientId> = self
+            .repository
+            .get_participants(room_id)
+            .await
+            .into_iter()
+            .map(|p| p.id)
+            .collect();
+        self.message_pusher
+            .broadcast_with_priority(target_ids, message, self.announcement_priority)
+            .await
+            .map(|_| ())
+            .map_err(|e| e.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        domain::{RoomIdFactory, Timestamp},
+        infrastructure::{
+            message_pusher::WebSocketMessagePusher, repository::InMemoryRoomRepository,
+        },
+    };
+    use engawa_shared::time::get_jst_timestamp;
+    use std::collections::HashMap;
+    use tokio::sync::Mutex;
+
+    fn create_test_repository() -> Arc<InMemoryRoomRepository> {
+        let room = Arc::new(Mutex::new(Room::new(
+            RoomIdFactory::generate().unwrap(),
+            Timestamp::new(get_jst_timestamp()),
+        )));
+        Arc::new(InMemoryRoomRepository::new(room))
+    }
+
+    fn create_test_message_pusher() -> Arc<WebSocketMessagePusher> {
+        let clients = Arc::new(Mutex::new(HashMap::new()));
+        Arc::new(WebSocketMessagePusher::new(clients))
+    }
+
+    #[tokio::test]
+    async fn test_update_room_lock_and_unlock() {
+        // テスト項目: ルームをロック・ロック解除できる
+        // given (前提条件):
+        let repository = create_test_repository();
+        let usecase = UpdateRoomUseCase::new(repository.clone(), create_test_message_pusher());
+        let room_id = repository.get_room().await.unwrap().id;
+
+        // when (操作):
+        let locked_room = usecase
+            .execute(room_id.as_str().to_string(), Some(true), None)
+            .await
+            .unwrap();
+        let unlocked_room = usecase
+            .execute(room_id.as_str().to_string(), Some(false), None)
+            .await
+            .unwrap();
+
+        // then (期待する結果):
+        assert!(locked_room.locked);
+        assert!(!unlocked_room.locked);
+        assert!(!repository.is_room_locked(&room_id).await);
+    }
+
+    #[tokio::test]
+    async fn test_update_room_close_and_reopen() {
+        // テスト項目: ルームを閉じる・再開でき、ロック状態は変更されない
+        // given (前提条件):
+        let repository = create_test_repository();
+        let usecase = UpdateRoomUseCase::new(repository.clone(), create_test_message_pusher());
+        let room_id = repository.get_room().await.unwrap().id.into_string();
+
+        // when (操作):
+        let closed_room = usecase
+            .execute(room_id.clone(), None, Some(true))
+            .await
+            .unwrap();
+        let reopened_room = usecase.execute(room_id, None, Some(false)).await.unwrap();
+
+        // then (期待する結果):
+        assert!(closed_room.closed);
+        assert!(!reopened_room.closed);
+        assert!(!reopened_room.locked);
+    }
+
+    #[tokio::test]
+    async fn test_update_room_not_found() {
+        // テスト項目: 存在しないルーム ID を指定するとエラーになる
+        // given (前提条件):
+        let repository = create_test_repository();
+        let room_id = repository.get_room().await.unwrap().id;
+        let usecase = UpdateRoomUseCase::new(repository.clone(), create_test_message_pusher());
+
+        // when (操作):
+        let result = usecase
+            .execute(
+                RoomIdFactory::generate().unwrap().into_string(),
+                Some(true),
+                None,
+            )
+            .await;
+
+        // then (期待する結果):
+        assert_eq!(result.unwrap_err(), UpdateRoomError::RoomNotFound);
+        assert!(!repository.is_room_locked(&room_id).await);
+    }
+}
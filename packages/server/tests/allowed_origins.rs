@@ -0,0 +1,54 @@
+//! Allowed origins integration tests.
+
+mod fixtures;
+
+use fixtures::TestServer;
+use tokio_tungstenite::tungstenite::{
+    Error as WsError, client::IntoClientRequest, http::HeaderValue,
+};
+
+async fn connect_with_origin(
+    server: &TestServer,
+    client_id: &str,
+    origin: &str,
+) -> Result<(), WsError> {
+    let mut request = server.url(client_id).into_client_request().unwrap();
+    request
+        .headers_mut()
+        .insert("Origin", HeaderValue::from_str(origin).unwrap());
+    tokio_tungstenite::connect_async(request).await.map(|_| ())
+}
+
+#[tokio::test]
+async fn test_disallowed_origin_rejected_with_403() {
+    // テスト項目: 許可リストにない Origin からの接続は HTTP 403 で拒否され、許可された Origin と Origin なしの接続は成功する
+    // given (前提条件):
+    let server =
+        TestServer::start_with_args(&["--allowed-origin", "https://chat.example.com"]).await;
+
+    // when (操作):
+    let disallowed = connect_with_origin(&server, "alice", "https://evil.example.net").await;
+    let allowed = connect_with_origin(&server, "bob", "https://chat.example.com").await;
+    let without_origin = tokio_tungstenite::connect_async(server.url("carol")).await;
+
+    // then (期待する結果):
+    match disallowed {
+        Err(WsError::Http(response)) => assert_eq!(response.status(), 403),
+        other => panic!("Expected HTTP 403, got {:?}", other),
+    }
+    assert!(allowed.is_ok());
+    assert!(without_origin.is_ok());
+}
+
+#[tokio::test]
+async fn test_empty_allowlist_allows_any_origin() {
+    // テスト項目: 許可リストが空の場合は任意の Origin からの接続を許可する
+    // given (前提条件):
+    let server = TestServer::start().await;
+
+    // when (操作):
+    let result = connect_with_origin(&server, "alice", "http://localhost:5173").await;
+
+    // then (期待する結果):
+    assert!(result.is_ok());
+}
@@ -0,0 +1,53 @@
+//! HTTP API bearer token integration tests.
+
+mod fixtures;
+
+use fixtures::TestServer;
+
+const API_TOKEN: &str = "test-token";
+
+async fn get(server: &TestServer, path: &str, token: Option<&str>) -> reqwest::Response {
+    let mut request = reqwest::Client::new().get(format!("{}{}", server.base_url(), path));
+    if let Some(token) = token {
+        request = request.bearer_auth(token);
+    }
+    request.send().await.expect("Failed to send request")
+}
+
+async fn start_protected_server() -> TestServer {
+    TestServer::start_with_args(&["--api-token", API_TOKEN]).await
+}
+
+#[tokio::test]
+async fn test_protected_endpoints_require_token() {
+    // テスト項目: API トークンを設定すると、ルームの API はトークンなし・誤ったトークンでは 401 になり、正しいトークンでは成功する
+    // given (前提条件):
+    let server = start_protected_server().await;
+
+    for path in ["/api/rooms", "/debug/room"] {
+        // when (操作):
+        let without_token = get(&server, path, None).await;
+        let wrong_token = get(&server, path, Some("wrong")).await;
+        let with_token = get(&server, path, Some(API_TOKEN)).await;
+
+        // then (期待する結果):
+        assert_eq!(without_token.status(), 401, "{}", path);
+        assert_eq!(wrong_token.status(), 401, "{}", path);
+        assert_eq!(with_token.status(), 200, "{}", path);
+    }
+}
+
+#[tokio::test]
+async fn test_health_stays_public() {
+    // テスト項目: API トークンを設定しても、/api/health と /api/capabilities はトークンなしで利用できる
+    // given (前提条件):
+    let server = start_protected_server().await;
+
+    // when (操作):
+    let health = get(&server, "/api/health", None).await;
+    let capabilities = get(&server, "/api/capabilities", None).await;
+
+    // then (期待する結果):
+    assert_eq!(health.status(), 200);
+    assert_eq!(capabilities.status(), 200);
+}
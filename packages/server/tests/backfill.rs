@@ -0,0 +1,130 @@
+//! Reconnection backfill integration tests.
+
+mod fixtures;
+
+use std::time::{Duration, Instant};
+
+use fixtures::{TestServer, connect, next_json, send_chat, wait_for_type};
+
+#[tokio::test]
+async fn test_reconnect_with_since_receives_missed_messages() {
+    // テスト項目: since を指定して再接続すると、room-connected の直後に since より後のメッセージが backfill で届く
+    // given (前提条件):
+    let server = TestServer::start().await;
+    let mut alice = connect(&server, "alice").await;
+    let mut bob = connect(&server, "bob").await;
+    wait_for_type(&mut alice, "participant-joined", Duration::from_secs(2)).await;
+
+    send_chat(&mut alice, "alice", "one", 1000).await;
+    wait_for_type(&mut bob, "chat", Duration::from_secs(2))
+        .await
+        .expect("Expected chat for bob");
+    drop(bob);
+    wait_for_type(&mut alice, "participant-left", Duration::from_secs(2)).await;
+
+    // 2 つのメッセージのタイムスタンプが同じミリ秒にならないようにする
+    tokio::time::sleep(Duration::from_millis(20)).await;
+    send_chat(&mut alice, "alice", "two", 2000).await;
+
+    let room: serde_json::Value = reqwest::get(format!("{}/debug/room", server.base_url()))
+        .await
+        .expect("Failed to request room")
+        .json()
+        .await
+        .expect("Failed to parse room");
+    let since = room["messages"][0]["timestamp"].as_i64().unwrap();
+
+    // when (操作):
+    let (mut bob, _) =
+        tokio_tungstenite::connect_async(format!("{}&since={}", server.url("bob"), since))
+            .await
+            .expect("Failed to connect");
+
+    // then (期待する結果):
+    let first = next_json(&mut bob, Duration::from_secs(2))
+        .await
+        .expect("Expected room-connected");
+    assert_eq!(first["type"], "room-connected");
+    let backfill = next_json(&mut bob, Duration::from_secs(2))
+        .await
+        .expect("Expected backfill");
+    assert_eq!(backfill["type"], "backfill");
+    assert_eq!(backfill["truncated"], false);
+    let messages = backfill["messages"].as_array().unwrap();
+    assert_eq!(messages.len(), 1);
+    assert_eq!(messages[0]["client_id"], "alice");
+    assert_eq!(messages[0]["content"], "two");
+}
+
+#[tokio::test]
+async fn test_connect_without_since_sends_no_backfill() {
+    // テスト項目: since を指定しない接続には backfill が送られない
+    // given (前提条件):
+    let server = TestServer::start().await;
+    let mut alice = connect(&server, "alice").await;
+    send_chat(&mut alice, "alice", "one", 1000).await;
+
+    // when (操作):
+    let mut bob = connect(&server, "bob").await;
+
+    // then (期待する結果):
+    let frame = next_json(&mut bob, Duration::from_secs(2))
+        .await
+        .expect("Expected participant-count");
+    assert_eq!(frame["type"], "participant-count");
+}
+
+#[tokio::test]
+async fn test_reconnect_after_closing_during_backfill() {
+    // テスト項目: backfill を受信する前に接続を閉じたクライアントも切断処理され、同じ client_id で再接続できる
+    // given (前提条件):
+    // backfill がソケットのバッファに収まらず、送信中に接続が閉じられるように大きな履歴を用意する
+    let server = TestServer::start_with_args(&["--message-capacity", "200"]).await;
+    let mut alice = connect(&server, "alice").await;
+    let content = "あ".repeat(10_000);
+    for _ in 0..200 {
+        send_chat(&mut alice, "alice", &content, 0).await;
+    }
+    let deadline = Instant::now() + Duration::from_secs(10);
+    loop {
+        let room: serde_json::Value = reqwest::get(format!("{}/debug/room", server.base_url()))
+            .await
+            .expect("Failed to request room")
+            .json()
+            .await
+            .expect("Failed to parse room");
+        if room["messages"].as_array().unwrap().len() == 200 {
+            break;
+        }
+        assert!(
+            Instant::now() < deadline,
+            "Messages were not stored in time"
+        );
+        tokio::time::sleep(Duration::from_millis(20)).await;
+    }
+    let url = format!("{}&since=0", server.url("bob"));
+    let (bob, _) = tokio_tungstenite::connect_async(&url)
+        .await
+        .expect("Failed to connect");
+
+    // when (操作):
+    // 何も読まずに閉じる（未読のデータがあるため、サーバ側の送信は失敗する）
+    drop(bob);
+
+    // then (期待する結果):
+    let deadline = Instant::now() + Duration::from_secs(5);
+    let mut bob = loop {
+        if let Ok((bob, _)) = tokio_tungstenite::connect_async(server.url("bob")).await {
+            break bob;
+        }
+        assert!(
+            Instant::now() < deadline,
+            "Reconnect with the same client_id was rejected"
+        );
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    };
+    let first = next_json(&mut bob, Duration::from_secs(2))
+        .await
+        .expect("Expected room-connected");
+    assert_eq!(first["type"], "room-connected");
+}
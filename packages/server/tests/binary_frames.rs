@@ -0,0 +1,59 @@
+//! Binary frame policy integration tests.
+
+mod fixtures;
+
+use std::time::Duration;
+
+use fixtures::{TestServer, connect, next_json, wait_for_type};
+use futures_util::SinkExt;
+use tokio_tungstenite::tungstenite::protocol::Message;
+
+#[tokio::test]
+async fn test_binary_frame_rejected_with_error_frame() {
+    // テスト項目: reject ポリシーでは、バイナリフレームにエラーフレームが返され接続は維持される
+    // given (前提条件):
+    let server = TestServer::start_with_args(&["--binary-frame-policy", "reject"]).await;
+    let mut alice = connect(&server, "alice").await;
+
+    // when (操作):
+    alice
+        .send(Message::Binary(vec![0x01, 0x02].into()))
+        .await
+        .expect("Failed to send binary frame");
+
+    // then (期待する結果):
+    let error = wait_for_type(&mut alice, "error", Duration::from_secs(2))
+        .await
+        .expect("Expected error frame");
+    assert_eq!(error["type"], "error");
+    assert_eq!(error["code"], "unexpected_binary");
+
+    // 接続は維持されている
+    let response = reqwest::get(format!("{}/api/rooms", server.base_url()))
+        .await
+        .expect("Failed to send request");
+    let rooms: serde_json::Value = response.json().await.expect("Failed to parse JSON");
+    assert_eq!(rooms[0]["participants"], serde_json::json!(["alice"]));
+}
+
+#[tokio::test]
+async fn test_binary_frame_closes_connection() {
+    // テスト項目: close ポリシーでは、バイナリフレームを受信すると接続が閉じられる
+    // given (前提条件):
+    let server = TestServer::start_with_args(&["--binary-frame-policy", "close"]).await;
+    let mut alice = connect(&server, "alice").await;
+    wait_for_type(&mut alice, "participant-count", Duration::from_secs(2)).await;
+
+    // when (操作):
+    alice
+        .send(Message::Binary(vec![0x01, 0x02].into()))
+        .await
+        .expect("Failed to send binary frame");
+
+    // then (期待する結果):
+    assert!(
+        next_json(&mut alice, Duration::from_secs(2))
+            .await
+            .is_none()
+    );
+}
@@ -0,0 +1,60 @@
+//! Capability endpoint integration tests.
+
+mod fixtures;
+
+use fixtures::TestServer;
+
+async fn get_capabilities(server: &TestServer) -> serde_json::Value {
+    reqwest::get(format!("{}/api/capabilities", server.base_url()))
+        .await
+        .expect("Failed to request capabilities")
+        .json()
+        .await
+        .expect("Failed to parse capabilities")
+}
+
+#[tokio::test]
+async fn test_capabilities_reflect_configured_features() {
+    // テスト項目: /api/capabilities が設定された機能フラグを反映する
+    // given (前提条件):
+    let default_server = TestServer::start().await;
+    let configured_server = TestServer::start_with_args(&[
+        "--detect-language",
+        "--strict-inbound-schema",
+        "--timestamp-unit",
+        "s",
+    ])
+    .await;
+
+    // when (操作):
+    let default_caps = get_capabilities(&default_server).await;
+    let configured_caps = get_capabilities(&configured_server).await;
+
+    // then (期待する結果):
+    assert_eq!(default_caps["protocol_version"], "1");
+    assert_eq!(default_caps["codecs"], serde_json::json!(["json"]));
+    assert_eq!(default_caps["max_message_size"], 10000);
+    assert_eq!(default_caps["auth_required"], false);
+    assert_eq!(default_caps["timestamp_unit"], "ms");
+    assert_eq!(default_caps["features"]["language_detection"], false);
+    assert_eq!(default_caps["features"]["strict_inbound_schema"], false);
+    assert_eq!(default_caps["features"]["direct_messages"], true);
+
+    assert_eq!(configured_caps["features"]["language_detection"], true);
+    assert_eq!(configured_caps["features"]["strict_inbound_schema"], true);
+    assert_eq!(configured_caps["features"]["edits"], true);
+    assert_eq!(configured_caps["timestamp_unit"], "s");
+}
+
+#[tokio::test]
+async fn test_capabilities_report_auth_required_with_api_token() {
+    // テスト項目: API トークンを設定したサーバでは、/api/capabilities は認証なしで取得でき、auth_required が true になる
+    // given (前提条件):
+    let server = TestServer::start_with_args(&["--api-token", "test-token"]).await;
+
+    // when (操作):
+    let caps = get_capabilities(&server).await;
+
+    // then (期待する結果):
+    assert_eq!(caps["auth_required"], true);
+}
@@ -0,0 +1,59 @@
+//! client_id collision resolution integration tests.
+
+mod fixtures;
+
+use std::time::Duration;
+
+use fixtures::{TestServer, next_json};
+use tokio_tungstenite::tungstenite::Error as WsError;
+
+#[tokio::test]
+async fn test_duplicate_client_id_suffixed_in_suffix_mode() {
+    // テスト項目: サフィックスモードでは同じ client_id の 2 つの接続に別の ID が割り当てられ、通知される
+    // given (前提条件):
+    let server = TestServer::start_with_args(&["--client-id-collision", "suffix"]).await;
+
+    // when (操作):
+    let (mut first, _) = tokio_tungstenite::connect_async(server.url("alice"))
+        .await
+        .expect("Failed to connect");
+    let first_connected = next_json(&mut first, Duration::from_secs(2))
+        .await
+        .expect("Expected room-connected message");
+    let (mut second, _) = tokio_tungstenite::connect_async(server.url("alice"))
+        .await
+        .expect("Failed to connect");
+    let second_connected = next_json(&mut second, Duration::from_secs(2))
+        .await
+        .expect("Expected room-connected message");
+
+    // then (期待する結果):
+    assert_eq!(first_connected["assigned_client_id"], "alice");
+    assert_eq!(second_connected["assigned_client_id"], "alice-2");
+    let participants: Vec<&str> = second_connected["participants"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|p| p["client_id"].as_str().unwrap())
+        .collect();
+    assert_eq!(participants, vec!["alice", "alice-2"]);
+}
+
+#[tokio::test]
+async fn test_duplicate_client_id_rejected_by_default() {
+    // テスト項目: デフォルトでは重複した client_id の接続は 409 で拒否される
+    // given (前提条件):
+    let server = TestServer::start().await;
+    let (_first, _) = tokio_tungstenite::connect_async(server.url("alice"))
+        .await
+        .expect("Failed to connect");
+
+    // when (操作):
+    let result = tokio_tungstenite::connect_async(server.url("alice")).await;
+
+    // then (期待する結果):
+    match result {
+        Err(WsError::Http(response)) => assert_eq!(response.status(), 409),
+        other => panic!("Expected HTTP 409, got {:?}", other.map(|_| ())),
+    }
+}
@@ -0,0 +1,74 @@
+//! Connection summary integration tests.
+
+mod fixtures;
+
+use std::{sync::Arc, time::Duration};
+
+use engawa_server::{domain::DomainEvent, infrastructure::event_bus::InMemoryEventBus};
+use fixtures::{TestServer, builder, connect, send_chat, wait_for_type};
+
+#[tokio::test]
+async fn test_connection_summary_reports_counts_and_reason() {
+    // テスト項目: 接続の終了時に、送信したメッセージ数・受信したフレーム数・バイト数と切断の理由が要約として発行される
+    // given (前提条件):
+    let event_bus = Arc::new(InMemoryEventBus::new());
+    let server = TestServer::start_with_builder(
+        builder(&["--connection-summary"]).with_event_bus(event_bus.clone()),
+    )
+    .await;
+    let mut alice = connect(&server, "alice").await;
+    let mut bob = connect(&server, "bob").await;
+    wait_for_type(&mut alice, "participant-joined", Duration::from_secs(2))
+        .await
+        .expect("Expected participant-joined for alice");
+
+    // when (操作):
+    let mut bytes_in = 0;
+    for content in ["Hello", "World"] {
+        send_chat(&mut alice, "alice", content, 1).await;
+        wait_for_type(&mut bob, "chat", Duration::from_secs(2))
+            .await
+            .expect("Expected chat for bob");
+        bytes_in += serde_json::json!({
+            "type": "chat",
+            "client_id": "alice",
+            "content": content,
+            "timestamp": 1,
+        })
+        .to_string()
+        .len() as u64;
+    }
+    alice.close(None).await.expect("Failed to close");
+
+    // then (期待する結果):
+    let summary = tokio::time::timeout(Duration::from_secs(2), async {
+        loop {
+            let summary = event_bus.events().await.into_iter().find(
+                |event| matches!(event, DomainEvent::ConnectionEnded { client_id, .. } if client_id.as_str() == "alice"),
+            );
+            if let Some(summary) = summary {
+                return summary;
+            }
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+    })
+    .await
+    .expect("Expected connection summary for alice");
+    let DomainEvent::ConnectionEnded {
+        messages_sent,
+        messages_received,
+        bytes_in: actual_bytes_in,
+        bytes_out,
+        reason,
+        ..
+    } = summary
+    else {
+        unreachable!();
+    };
+    assert_eq!(messages_sent, 2);
+    // room-connected、participant-joined（bob）と participant-count（alice と bob の入室）
+    assert_eq!(messages_received, 4);
+    assert_eq!(actual_bytes_in, bytes_in);
+    assert!(bytes_out > 0);
+    assert_eq!(reason, "closed");
+}
@@ -0,0 +1,34 @@
+//! Per-IP connection limit integration tests.
+
+mod fixtures;
+
+use std::time::Duration;
+
+use fixtures::{TestServer, connect};
+use tokio_tungstenite::tungstenite::Error as WsError;
+
+#[tokio::test]
+async fn test_connections_beyond_per_ip_limit_rejected_with_429() {
+    // テスト項目: 同じ IP から上限を超える接続は（client_id が異なっても）HTTP 429 で拒否され、接続が閉じると再び接続できる
+    // given (前提条件):
+    let server = TestServer::start_with_args(&["--max-connections-per-ip", "2"]).await;
+    let mut alice = connect(&server, "alice").await;
+    let _bob = connect(&server, "bob").await;
+
+    // when (操作):
+    let over_limit_result = tokio_tungstenite::connect_async(server.url("charlie")).await;
+
+    // then (期待する結果):
+    match over_limit_result {
+        Err(WsError::Http(response)) => assert_eq!(response.status(), 429),
+        other => panic!("Expected HTTP 429, got {:?}", other.map(|_| ())),
+    }
+
+    // when (操作):
+    alice.close(None).await.expect("Failed to close");
+    tokio::time::sleep(Duration::from_millis(200)).await;
+    let after_close_result = tokio_tungstenite::connect_async(server.url("charlie")).await;
+
+    // then (期待する結果):
+    assert!(after_close_result.is_ok());
+}
@@ -0,0 +1,102 @@
+//! Message deletion integration tests.
+
+mod fixtures;
+
+use std::time::Duration;
+
+use fixtures::{TestServer, TestWebSocket, connect, send_chat, wait_for_type};
+use futures_util::SinkExt;
+use tokio_tungstenite::tungstenite::protocol::Message;
+
+/// Send a deletion of the given message
+async fn send_delete(ws: &mut TestWebSocket, message_id: &str) {
+    let message = serde_json::json!({
+        "type": "delete",
+        "message_id": message_id,
+    });
+    ws.send(Message::Text(message.to_string().into()))
+        .await
+        .expect("Failed to send delete");
+}
+
+#[tokio::test]
+async fn test_delete_is_broadcast_and_leaves_tombstone() {
+    // テスト項目: 作成者による削除は他の参加者に delete フレームで通知され、ルーム詳細では内容が空の削除済みメッセージとして位置を保つ
+    // given (前提条件):
+    let server = TestServer::start().await;
+    let mut alice = connect(&server, "alice").await;
+    let mut bob = connect(&server, "bob").await;
+    let mut message_ids = Vec::new();
+    for content in ["first", "second"] {
+        send_chat(&mut alice, "alice", content, 0).await;
+        let chat = wait_for_type(&mut bob, "chat", Duration::from_secs(2))
+            .await
+            .expect("Expected chat message");
+        message_ids.push(chat["message_id"].as_str().unwrap().to_string());
+    }
+
+    // when (操作):
+    send_delete(&mut alice, &message_ids[0]).await;
+
+    // then (期待する結果):
+    let delete = wait_for_type(&mut bob, "delete", Duration::from_secs(2))
+        .await
+        .expect("Expected delete message");
+    assert_eq!(delete["message_id"], message_ids[0].as_str());
+    assert_eq!(delete["client_id"], "alice");
+
+    let client = reqwest::Client::new();
+    let rooms: serde_json::Value = client
+        .get(format!("{}/api/rooms", server.base_url()))
+        .send()
+        .await
+        .expect("Failed to send request")
+        .json()
+        .await
+        .expect("Failed to parse JSON");
+    let room_id = rooms[0]["id"].as_str().unwrap();
+    let detail: serde_json::Value = client
+        .get(format!("{}/api/rooms/{}", server.base_url(), room_id))
+        .send()
+        .await
+        .expect("Failed to send request")
+        .json()
+        .await
+        .expect("Failed to parse JSON");
+    assert_eq!(detail["total"], 2);
+    assert_eq!(detail["messages"][0]["message_id"], message_ids[0].as_str());
+    assert_eq!(detail["messages"][0]["content"], "");
+    assert_eq!(detail["messages"][0]["deleted"], true);
+    assert_eq!(detail["messages"][1]["content"], "second");
+    assert_eq!(detail["messages"][1]["deleted"], false);
+}
+
+#[tokio::test]
+async fn test_delete_of_another_clients_message_is_rejected() {
+    // テスト項目: 作成者以外による削除と、存在しないメッセージの削除はエラーフレームで拒否される
+    // given (前提条件):
+    let server = TestServer::start().await;
+    let mut alice = connect(&server, "alice").await;
+    let mut bob = connect(&server, "bob").await;
+    send_chat(&mut alice, "alice", "Hello", 0).await;
+    let chat = wait_for_type(&mut bob, "chat", Duration::from_secs(2))
+        .await
+        .expect("Expected chat message");
+    let message_id = chat["message_id"].as_str().unwrap();
+
+    // when (操作):
+    send_delete(&mut bob, message_id).await;
+    let not_author = wait_for_type(&mut bob, "error", Duration::from_secs(2)).await;
+    send_delete(&mut bob, "unknown").await;
+    let not_found = wait_for_type(&mut bob, "error", Duration::from_secs(2)).await;
+
+    // then (期待する結果):
+    assert_eq!(
+        not_author.expect("Expected error frame")["code"],
+        "not_message_author"
+    );
+    assert_eq!(
+        not_found.expect("Expected error frame")["code"],
+        "message_not_found"
+    );
+}
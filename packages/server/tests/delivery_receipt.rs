@@ -0,0 +1,113 @@
+//! Delivery receipt integration tests.
+
+mod fixtures;
+
+use std::time::Duration;
+
+use engawa_shared::time::get_jst_timestamp;
+use fixtures::{TestServer, connect, connect_url, send_chat, wait_for_type};
+
+#[tokio::test]
+async fn test_delivery_receipt_sent_to_opted_in_sender() {
+    // テスト項目: delivery_receipts を有効にした送信者には配信結果が返される
+    // given (前提条件):
+    let server = TestServer::start().await;
+    let mut alice = connect_url(&format!("{}&delivery_receipts=true", server.url("alice"))).await;
+    let mut bob = connect(&server, "bob").await;
+    wait_for_type(&mut alice, "participant-joined", Duration::from_secs(2))
+        .await
+        .expect("Expected participant-joined message");
+
+    // when (操作):
+    send_chat(&mut alice, "alice", "Hello!", 1000).await;
+
+    // then (期待する結果):
+    let receipt = wait_for_type(&mut alice, "delivery-receipt", Duration::from_secs(2))
+        .await
+        .expect("Expected delivery-receipt message");
+    assert_eq!(receipt["delivered_count"], 1);
+    assert_eq!(receipt["total_targets"], 1);
+
+    let chat = wait_for_type(&mut bob, "chat", Duration::from_secs(2))
+        .await
+        .expect("Expected chat message");
+    assert_eq!(chat["content"], "Hello!");
+    assert_eq!(chat["message_id"], receipt["message_id"]);
+    assert_eq!(chat["timestamp"], receipt["timestamp"]);
+}
+
+#[tokio::test]
+async fn test_delivery_receipt_not_sent_by_default() {
+    // テスト項目: delivery_receipts を指定しない送信者には配信結果が返されない
+    // given (前提条件):
+    let server = TestServer::start().await;
+    let mut alice = connect(&server, "alice").await;
+
+    // when (操作):
+    send_chat(&mut alice, "alice", "Hello!", 1000).await;
+
+    // then (期待する結果):
+    assert!(
+        wait_for_type(&mut alice, "delivery-receipt", Duration::from_millis(300))
+            .await
+            .is_none()
+    );
+}
+
+#[tokio::test]
+async fn test_delivery_receipt_reports_clock_skew() {
+    // テスト項目: クロックスキューの許容値を設定すると、配信結果に clock_skew_ms が含まれ、許容値を超えたスキューには clock_skew_exceeded が付く
+    // given (前提条件):
+    let server = TestServer::start_with_args(&["--clock-skew-tolerance-ms", "60000"]).await;
+    let mut alice = connect_url(&format!("{}&delivery_receipts=true", server.url("alice"))).await;
+    let _bob = connect(&server, "bob").await;
+    wait_for_type(&mut alice, "participant-joined", Duration::from_secs(2))
+        .await
+        .expect("Expected participant-joined message");
+
+    // when (操作):
+    send_chat(&mut alice, "alice", "in sync", get_jst_timestamp()).await;
+    let in_sync = wait_for_type(&mut alice, "delivery-receipt", Duration::from_secs(2))
+        .await
+        .expect("Expected delivery-receipt message");
+    send_chat(
+        &mut alice,
+        "alice",
+        "behind",
+        get_jst_timestamp() - 3_600_000,
+    )
+    .await;
+    let behind = wait_for_type(&mut alice, "delivery-receipt", Duration::from_secs(2))
+        .await
+        .expect("Expected delivery-receipt message");
+
+    // then (期待する結果):
+    assert!(in_sync["clock_skew_ms"].as_i64().unwrap().abs() < 60_000);
+    assert!(in_sync.get("clock_skew_exceeded").is_none());
+    assert!(behind["clock_skew_ms"].as_i64().unwrap() <= -3_600_000);
+    assert_eq!(behind["clock_skew_exceeded"], true);
+}
+
+#[tokio::test]
+async fn test_delivery_receipt_omits_clock_skew_by_default() {
+    // テスト項目: クロックスキューの許容値を設定しない場合、配信結果に clock_skew_ms は含まれない
+    // given (前提条件):
+    let server = TestServer::start().await;
+    let mut alice = connect_url(&format!("{}&delivery_receipts=true", server.url("alice"))).await;
+
+    // when (操作):
+    send_chat(
+        &mut alice,
+        "alice",
+        "Hello!",
+        get_jst_timestamp() - 3_600_000,
+    )
+    .await;
+
+    // then (期待する結果):
+    let receipt = wait_for_type(&mut alice, "delivery-receipt", Duration::from_secs(2))
+        .await
+        .expect("Expected delivery-receipt message");
+    assert!(receipt.get("clock_skew_ms").is_none());
+    assert!(receipt.get("clock_skew_exceeded").is_none());
+}
@@ -0,0 +1,111 @@
+//! Integration tests for connections ended by several causes at once.
+
+mod fixtures;
+
+use std::time::Duration;
+
+use engawa_server::ui::{HeartbeatConfig, ServerConfig};
+use fixtures::{TestServer, TestWebSocket, connect, next_json};
+use futures_util::SinkExt;
+use tokio_tungstenite::tungstenite::protocol::Message;
+
+/// Get the id of the room of the server
+async fn room_id(server: &TestServer) -> String {
+    let rooms: serde_json::Value = reqwest::get(format!("{}/api/rooms", server.base_url()))
+        .await
+        .expect("Failed to send request")
+        .json()
+        .await
+        .expect("Failed to parse JSON");
+    rooms[0]["id"].as_str().unwrap().to_string()
+}
+
+/// Get the client ids of the participants of the room, sorted
+async fn participants(server: &TestServer, room_id: &str) -> Vec<String> {
+    let room: serde_json::Value =
+        reqwest::get(format!("{}/api/rooms/{}", server.base_url(), room_id))
+            .await
+            .expect("Failed to send request")
+            .json()
+            .await
+            .expect("Failed to parse JSON");
+    let mut client_ids: Vec<String> = room["participants"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|p| p["client_id"].as_str().unwrap().to_string())
+        .collect();
+    client_ids.sort();
+    client_ids
+}
+
+/// Count the `participant-left` frames for `client_id` until no frame arrives for a while
+async fn count_left(ws: &mut TestWebSocket, client_id: &str) -> usize {
+    let mut count = 0;
+    while let Some(frame) = next_json(ws, Duration::from_secs(1)).await {
+        if frame["type"] == "participant-left" && frame["client_id"] == client_id {
+            count += 1;
+        }
+    }
+    count
+}
+
+#[tokio::test]
+async fn test_close_and_heartbeat_timeout_leave_once() {
+    // テスト項目: クライアントの close とハートビートのタイムアウトが同時に発生しても、参加者の削除と participant-left の通知は 1 回だけ行われる
+    // given (前提条件):
+    let config = ServerConfig {
+        heartbeat: Some(HeartbeatConfig {
+            interval: Duration::from_millis(50),
+            timeout: Duration::from_millis(400),
+        }),
+        ..ServerConfig::default()
+    };
+    let server = TestServer::start_with_config(config).await;
+    let room_id = room_id(&server).await;
+    let mut bob = connect(&server, "bob").await;
+    // alice は Pong を返さない（フレームを読まない）
+    let mut alice = connect(&server, "alice").await;
+
+    // when (操作): タイムアウトの直前に alice が close を送信（bob はフレームを読み続けて Pong を返す）
+    let close_before_timeout = async {
+        tokio::time::sleep(Duration::from_millis(390)).await;
+        let _ = alice.send(Message::Close(None)).await;
+    };
+    let (_, left_count) = tokio::join!(close_before_timeout, count_left(&mut bob, "alice"));
+
+    // then (期待する結果):
+    assert_eq!(left_count, 1);
+    assert_eq!(participants(&server, &room_id).await, vec!["bob"]);
+}
+
+#[tokio::test]
+async fn test_cleanup_connection_runs_once_for_two_causes() {
+    // テスト項目: 切断の原因が 2 つ同時に発生しても（close とキック）、参加者の削除と participant-left の通知は 1 回だけ行われ、
+    //            再接続した参加者が古い接続の切断処理で削除されることもない
+    // given (前提条件):
+    let server = TestServer::start().await;
+    let room_id = room_id(&server).await;
+    let mut bob = connect(&server, "bob").await;
+    let mut alice = connect(&server, "alice").await;
+    let kick_url = format!("{}/api/rooms/{}/kick/alice", server.base_url(), room_id);
+
+    // when (操作): alice の close と管理者によるキックが同時に発生
+    let (_, kick) = tokio::join!(
+        alice.send(Message::Close(None)),
+        reqwest::Client::new().post(&kick_url).send(),
+    );
+
+    // then (期待する結果):
+    let status = kick.expect("Failed to send request").status();
+    assert!(status == 204 || status == 404, "unexpected status {status}");
+    assert_eq!(count_left(&mut bob, "alice").await, 1);
+    assert_eq!(participants(&server, &room_id).await, vec!["bob"]);
+
+    // when (操作): 同じ ID で再接続
+    let _alice = connect(&server, "alice").await;
+    tokio::time::sleep(Duration::from_millis(200)).await;
+
+    // then (期待する結果): 再接続した参加者は削除されない
+    assert_eq!(participants(&server, &room_id).await, vec!["alice", "bob"]);
+}
@@ -0,0 +1,131 @@
+//! Message editing integration tests.
+
+mod fixtures;
+
+use std::time::Duration;
+
+use fixtures::{TestServer, TestWebSocket, connect, send_chat, wait_for_type};
+use futures_util::SinkExt;
+use tokio_tungstenite::tungstenite::protocol::Message;
+
+/// Send an edit of the given message
+async fn send_edit(ws: &mut TestWebSocket, message_id: &str, content: &str) {
+    let message = serde_json::json!({
+        "type": "edit",
+        "message_id": message_id,
+        "content": content,
+    });
+    ws.send(Message::Text(message.to_string().into()))
+        .await
+        .expect("Failed to send edit");
+}
+
+#[tokio::test]
+async fn test_edit_is_broadcast_and_stored() {
+    // テスト項目: 作成者による編集は他の参加者に edit フレームで通知され、ルームの履歴の内容が置き換わる
+    // given (前提条件):
+    let server = TestServer::start().await;
+    let mut alice = connect(&server, "alice").await;
+    let mut bob = connect(&server, "bob").await;
+    send_chat(&mut alice, "alice", "Helo", 0).await;
+    let chat = wait_for_type(&mut bob, "chat", Duration::from_secs(2))
+        .await
+        .expect("Expected chat message");
+    let message_id = chat["message_id"].as_str().unwrap();
+
+    // when (操作):
+    send_edit(&mut alice, message_id, "Hello").await;
+
+    // then (期待する結果):
+    let edit = wait_for_type(&mut bob, "edit", Duration::from_secs(2))
+        .await
+        .expect("Expected edit message");
+    assert_eq!(edit["message_id"], message_id);
+    assert_eq!(edit["client_id"], "alice");
+    assert_eq!(edit["content"], "Hello");
+    assert!(edit["edited_at"].as_i64().unwrap() >= chat["timestamp"].as_i64().unwrap());
+
+    let room: serde_json::Value = reqwest::get(format!("{}/debug/room", server.base_url()))
+        .await
+        .expect("Failed to request room")
+        .json()
+        .await
+        .expect("Failed to parse room");
+    assert_eq!(room["messages"].as_array().unwrap().len(), 1);
+    assert_eq!(room["messages"][0]["content"], "Hello");
+    assert_eq!(room["messages"][0]["edited_at"], edit["edited_at"]);
+}
+
+#[tokio::test]
+async fn test_edit_of_another_clients_message_is_rejected() {
+    // テスト項目: 作成者以外による編集と、存在しないメッセージの編集はエラーフレームで拒否される
+    // given (前提条件):
+    let server = TestServer::start().await;
+    let mut alice = connect(&server, "alice").await;
+    let mut bob = connect(&server, "bob").await;
+    send_chat(&mut alice, "alice", "Hello", 0).await;
+    let chat = wait_for_type(&mut bob, "chat", Duration::from_secs(2))
+        .await
+        .expect("Expected chat message");
+    let message_id = chat["message_id"].as_str().unwrap();
+
+    // when (操作):
+    send_edit(&mut bob, message_id, "Hacked").await;
+    let not_author = wait_for_type(&mut bob, "error", Duration::from_secs(2)).await;
+    send_edit(&mut bob, "unknown", "Hacked").await;
+    let not_found = wait_for_type(&mut bob, "error", Duration::from_secs(2)).await;
+
+    // then (期待する結果):
+    assert_eq!(
+        not_author.expect("Expected error frame")["code"],
+        "not_message_author"
+    );
+    assert_eq!(
+        not_found.expect("Expected error frame")["code"],
+        "message_not_found"
+    );
+}
+
+#[tokio::test]
+async fn test_edit_with_timestamp_older_than_message_is_rejected() {
+    // テスト項目: 元メッセージのサーバタイムスタンプより古い timestamp 付きの編集は stale_edit エラーで拒否され、新しい timestamp の編集は受け付けられて edited_at はサーバ時刻になる
+    // given (前提条件):
+    let server = TestServer::start().await;
+    let mut alice = connect(&server, "alice").await;
+    let mut bob = connect(&server, "bob").await;
+    send_chat(&mut alice, "alice", "Helo", 0).await;
+    let chat = wait_for_type(&mut bob, "chat", Duration::from_secs(2))
+        .await
+        .expect("Expected chat message");
+    let message_id = chat["message_id"].as_str().unwrap();
+    let sent_at = chat["timestamp"].as_i64().unwrap();
+    let send_edit_at = |content: &str, timestamp: i64| {
+        serde_json::json!({
+            "type": "edit",
+            "message_id": message_id,
+            "content": content,
+            "timestamp": timestamp,
+        })
+        .to_string()
+    };
+
+    // when (操作):
+    alice
+        .send(Message::Text(send_edit_at("Stale", sent_at - 1).into()))
+        .await
+        .expect("Failed to send edit");
+    let stale = wait_for_type(&mut alice, "error", Duration::from_secs(2)).await;
+    alice
+        .send(Message::Text(send_edit_at("Hello", sent_at).into()))
+        .await
+        .expect("Failed to send edit");
+
+    // then (期待する結果):
+    assert_eq!(stale.expect("Expected error frame")["code"], "stale_edit");
+    let edit = wait_for_type(&mut bob, "edit", Duration::from_secs(2))
+        .await
+        .expect("Expected edit message");
+    assert_eq!(edit["content"], "Hello");
+    assert!(edit["edited_at"].as_i64().unwrap() >= sent_at);
+    assert!(edit.get("timestamp").is_none());
+}
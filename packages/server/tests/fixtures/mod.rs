@@ -0,0 +1,186 @@
+//! Test fixtures for in-process integration tests.
+//!
+//! The server is built by [`ServerBuilder`] from command line flags, exactly as the
+//! server binary does, and started inside the test process on an ephemeral port,
+//! so that tests can control its shutdown signal directly.
+
+#![allow(dead_code)]
+
+use std::{net::SocketAddr, time::Duration};
+
+use clap::Parser;
+use engawa_server::ui::{Server, ServerArgs, ServerBuilder, ServerConfig};
+use futures_util::{SinkExt, StreamExt};
+use tokio::{
+    net::{TcpListener, TcpStream},
+    sync::oneshot,
+    task::JoinHandle,
+};
+use tokio_tungstenite::{MaybeTlsStream, WebSocketStream, tungstenite::protocol::Message};
+
+pub type TestWebSocket = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
+/// Create a server builder from command line flags (e.g. `&["--max-emoji", "3"]`)
+pub fn builder(flags: &[&str]) -> ServerBuilder {
+    let args = ServerArgs::try_parse_from(std::iter::once("server").chain(flags.iter().copied()))
+        .expect("Invalid server flags");
+    ServerBuilder::new(args)
+}
+
+/// Helper struct to manage an in-process server
+pub struct TestServer {
+    addr: SocketAddr,
+    shutdown_tx: Option<oneshot::Sender<()>>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl TestServer {
+    /// Start a test server with the default flags
+    pub async fn start() -> Self {
+        Self::start_with_args(&[]).await
+    }
+
+    /// Start a test server with the given command line flags
+    pub async fn start_with_args(flags: &[&str]) -> Self {
+        Self::start_with_builder(builder(flags)).await
+    }
+
+    /// Start a test server built by the given builder (to inject a clock or an EventBus)
+    pub async fn start_with_builder(builder: ServerBuilder) -> Self {
+        Self::serve(builder.build().await).await
+    }
+
+    /// Start a test server with the default flags and the given runtime configuration
+    ///
+    /// Only for timings finer than the command line flags allow (e.g. sub-second heartbeats).
+    pub async fn start_with_config(config: ServerConfig) -> Self {
+        Self::serve(builder(&[]).build().await.with_config(config)).await
+    }
+
+    async fn serve(server: Server) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("Failed to bind listener");
+        let addr = listener.local_addr().expect("Failed to get local address");
+
+        let (shutdown_tx, shutdown_rx) = oneshot::channel::<()>();
+        let handle = tokio::spawn(async move {
+            server
+                .serve(listener, async {
+                    let _ = shutdown_rx.await;
+                })
+                .await
+                .expect("Server error");
+        });
+
+        TestServer {
+            addr,
+            shutdown_tx: Some(shutdown_tx),
+            handle: Some(handle),
+        }
+    }
+
+    /// Get the WebSocket URL for the given client_id
+    pub fn url(&self, client_id: &str) -> String {
+        format!("ws://{}/ws?client_id={}", self.addr, client_id)
+    }
+
+    /// Get the base HTTP URL for this server
+    pub fn base_url(&self) -> String {
+        format!("http://{}", self.addr)
+    }
+
+    /// Trigger the shutdown signal
+    pub fn trigger_shutdown(&mut self) {
+        if let Some(tx) = self.shutdown_tx.take() {
+            let _ = tx.send(());
+        }
+    }
+
+    /// Wait for the server to stop
+    pub async fn wait_for_stop(&mut self, timeout: Duration) {
+        if let Some(handle) = self.handle.take() {
+            tokio::time::timeout(timeout, handle)
+                .await
+                .expect("Server did not stop within timeout")
+                .expect("Server task panicked");
+        }
+    }
+}
+
+impl Drop for TestServer {
+    fn drop(&mut self) {
+        if let Some(handle) = self.handle.take() {
+            handle.abort();
+        }
+    }
+}
+
+/// Connect a WebSocket client and consume the initial `room-connected` message
+pub async fn connect(server: &TestServer, client_id: &str) -> TestWebSocket {
+    connect_url(&server.url(client_id)).await
+}
+
+/// Connect a WebSocket client to the given URL and consume the initial `room-connected` message
+pub async fn connect_url(url: &str) -> TestWebSocket {
+    let (mut ws, _) = tokio_tungstenite::connect_async(url)
+        .await
+        .expect("Failed to connect");
+    let first = next_json(&mut ws, Duration::from_secs(2))
+        .await
+        .expect("Expected room-connected message");
+    assert_eq!(first["type"], "room-connected");
+    ws
+}
+
+/// Send a chat message as the given client
+pub async fn send_chat(ws: &mut TestWebSocket, client_id: &str, content: &str, timestamp: i64) {
+    let message = serde_json::json!({
+        "type": "chat",
+        "client_id": client_id,
+        "content": content,
+        "timestamp": timestamp,
+    });
+    ws.send(Message::Text(message.to_string().into()))
+        .await
+        .expect("Failed to send message");
+}
+
+/// Read the next text frame as JSON, skipping other frames
+///
+/// Returns `None` if the connection is closed or no message arrives within `timeout`.
+pub async fn next_json(ws: &mut TestWebSocket, timeout: Duration) -> Option<serde_json::Value> {
+    tokio::time::timeout(timeout, async {
+        while let Some(Ok(message)) = ws.next().await {
+            if let Message::Text(text) = message {
+                return serde_json::from_str(&text).ok();
+            }
+            if let Message::Close(_) = message {
+                return None;
+            }
+        }
+        None
+    })
+    .await
+    .ok()
+    .flatten()
+}
+
+/// Read messages until a message of the given type arrives
+pub async fn wait_for_type(
+    ws: &mut TestWebSocket,
+    message_type: &str,
+    timeout: Duration,
+) -> Option<serde_json::Value> {
+    tokio::time::timeout(timeout, async {
+        while let Some(message) = next_json(ws, timeout).await {
+            if message["type"] == message_type {
+                return Some(message);
+            }
+        }
+        None
+    })
+    .await
+    .ok()
+    .flatten()
+}
@@ -0,0 +1,73 @@
+//! Strict inbound schema validation integration tests.
+
+mod fixtures;
+
+use std::time::Duration;
+
+use fixtures::{TestServer, connect, send_chat, wait_for_type};
+use futures_util::SinkExt;
+use tokio_tungstenite::tungstenite::protocol::Message;
+
+#[tokio::test]
+async fn test_strict_schema_rejects_unknown_field_and_accepts_valid_frame() {
+    // テスト項目: 厳格モードでは未知のフィールドを含むフレームがエラーで拒否され、正しいフレームは配信される
+    // given (前提条件):
+    let server = TestServer::start_with_args(&["--strict-inbound-schema"]).await;
+    let mut alice = connect(&server, "alice").await;
+    let mut bob = connect(&server, "bob").await;
+
+    // when (操作): 未知のフィールドを含むフレームを送信
+    alice
+        .send(Message::Text(
+            r#"{"type":"chat","content":"Hello!","colour":"red"}"#.into(),
+        ))
+        .await
+        .expect("Failed to send message");
+
+    // then (期待する結果): エラーフレームが返される
+    let error = wait_for_type(&mut alice, "error", Duration::from_secs(2))
+        .await
+        .expect("Expected error frame");
+    assert_eq!(error["code"], "unknown_field");
+    assert!(error["message"].as_str().unwrap().contains("colour"));
+
+    // when (操作): 正しいフレームを送信
+    send_chat(&mut alice, "alice", "Valid!", 1000).await;
+
+    // then (期待する結果): 他の参加者に配信される（拒否されたフレームは配信されない）
+    let chat = wait_for_type(&mut bob, "chat", Duration::from_secs(2))
+        .await
+        .expect("Expected chat message");
+    assert_eq!(chat["content"], "Valid!");
+}
+
+#[tokio::test]
+async fn test_deeply_nested_json_rejected_before_parsing() {
+    // テスト項目: 深くネストした JSON は解析前に json_too_complex の error フレームで拒否され、通常のチャットフレームは配信される
+    // given (前提条件):
+    let server = TestServer::start().await;
+    let mut alice = connect(&server, "alice").await;
+    let mut bob = connect(&server, "bob").await;
+
+    // when (操作):
+    let nested = format!(
+        r#"{{"type":"chat","content":"hi","extra":{}{}}}"#,
+        "[".repeat(10_000),
+        "]".repeat(10_000)
+    );
+    alice
+        .send(Message::Text(nested.into()))
+        .await
+        .expect("Failed to send message");
+    send_chat(&mut alice, "alice", "normal", 1000).await;
+
+    // then (期待する結果):
+    let error = wait_for_type(&mut alice, "error", Duration::from_secs(2))
+        .await
+        .expect("Expected error frame");
+    assert_eq!(error["code"], "json_too_complex");
+    let chat = wait_for_type(&mut bob, "chat", Duration::from_secs(2))
+        .await
+        .expect("Expected chat message");
+    assert_eq!(chat["content"], "normal");
+}
@@ -0,0 +1,120 @@
+//! Join approval integration tests.
+
+mod fixtures;
+
+use std::time::{Duration, Instant};
+
+use fixtures::{TestServer, TestWebSocket, connect, wait_for_type};
+use futures_util::SinkExt;
+use tokio_tungstenite::tungstenite::{
+    Error as WsError, client::IntoClientRequest, http::HeaderValue, protocol::Message,
+};
+
+const API_TOKEN: &str = "test-token";
+
+async fn start_server(timeout: Duration) -> TestServer {
+    TestServer::start_with_args(&[
+        "--join-approval-admin",
+        "admin",
+        "--join-approval-timeout-ms",
+        &timeout.as_millis().to_string(),
+    ])
+    .await
+}
+
+async fn send_decision(admin: &mut TestWebSocket, client_id: &str, approved: bool) {
+    let decision = serde_json::json!({
+        "type": "join-decision",
+        "client_id": client_id,
+        "approved": approved,
+    });
+    admin
+        .send(Message::Text(decision.to_string().into()))
+        .await
+        .expect("Failed to send join decision");
+}
+
+#[tokio::test]
+async fn test_join_admitted_after_admin_approval() {
+    // テスト項目: 管理者に join-request が届き、承認すると参加者が入室できる
+    // given (前提条件):
+    let server = start_server(Duration::from_secs(5)).await;
+    let mut admin = connect(&server, "admin").await;
+    let url = server.url("alice");
+    let pending = tokio::spawn(async move { tokio_tungstenite::connect_async(url).await });
+
+    // when (操作):
+    let request = wait_for_type(&mut admin, "join-request", Duration::from_secs(2))
+        .await
+        .expect("Expected join-request for admin");
+    send_decision(&mut admin, "alice", true).await;
+
+    // then (期待する結果):
+    assert_eq!(request["client_id"], "alice");
+    assert!(pending.await.unwrap().is_ok());
+    let joined = wait_for_type(&mut admin, "participant-joined", Duration::from_secs(2))
+        .await
+        .expect("Expected participant-joined for alice");
+    assert_eq!(joined["client_id"], "alice");
+}
+
+#[tokio::test]
+async fn test_join_rejected_on_denial_or_timeout() {
+    // テスト項目: 管理者が拒否した場合や承認がタイムアウトした場合は、理由の異なる HTTP 403 で入室を拒否される。
+    //            承認を待つ間は HTTP のアップグレードへの応答が保留される
+    // given (前提条件):
+    let server = start_server(Duration::from_millis(300)).await;
+    let mut admin = connect(&server, "admin").await;
+    let url = server.url("alice");
+    let denied = tokio::spawn(async move { tokio_tungstenite::connect_async(url).await });
+
+    // when (操作):
+    wait_for_type(&mut admin, "join-request", Duration::from_secs(2))
+        .await
+        .expect("Expected join-request for admin");
+    send_decision(&mut admin, "alice", false).await;
+    let started_at = Instant::now();
+    let timed_out = tokio_tungstenite::connect_async(server.url("bob")).await;
+    let waited = started_at.elapsed();
+
+    // then (期待する結果):
+    for (result, reason) in [
+        (denied.await.unwrap(), "join denied"),
+        (timed_out, "join approval timed out"),
+    ] {
+        match result {
+            Err(WsError::Http(response)) => {
+                assert_eq!(response.status(), 403);
+                let body = response.body().clone().unwrap_or_default();
+                assert_eq!(String::from_utf8(body).unwrap(), reason);
+            }
+            other => panic!("Expected HTTP 403, got {:?}", other.map(|_| ())),
+        }
+    }
+    assert!(waited >= Duration::from_millis(300));
+}
+
+#[tokio::test]
+async fn test_admin_id_requires_api_token() {
+    // テスト項目: API トークンが設定されている場合、トークンなしで管理者の ID を名乗って承認を回避することはできない
+    // given (前提条件):
+    let server =
+        TestServer::start_with_args(&["--join-approval-admin", "admin", "--api-token", API_TOKEN])
+            .await;
+    let mut request = server.url("admin").into_client_request().unwrap();
+    request.headers_mut().insert(
+        "Authorization",
+        HeaderValue::from_str(&format!("Bearer {}", API_TOKEN)).unwrap(),
+    );
+
+    // when (操作):
+    let without_token = tokio_tungstenite::connect_async(server.url("admin")).await;
+    let with_token = tokio_tungstenite::connect_async(request).await;
+
+    // then (期待する結果):
+    match without_token {
+        Err(WsError::Http(response)) => assert_eq!(response.status(), 401),
+        other => panic!("Expected HTTP 401, got {:?}", other.map(|_| ())),
+    }
+    assert!(with_token.is_ok());
+}
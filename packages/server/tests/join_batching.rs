@@ -0,0 +1,61 @@
+//! Join batching integration tests.
+
+mod fixtures;
+
+use std::time::Duration;
+
+use fixtures::{TestServer, connect, wait_for_type};
+
+async fn start_server() -> TestServer {
+    TestServer::start_with_args(&[
+        "--join-batch-window-ms",
+        "200",
+        "--join-batch-threshold",
+        "2",
+    ])
+    .await
+}
+
+#[tokio::test]
+async fn test_simultaneous_joins_produce_batched_frame() {
+    // テスト項目: 時間窓の中の同時の入室は、1 つの participants-joined フレームにまとめて通知される
+    // given (前提条件):
+    let server = start_server().await;
+    let mut observer = connect(&server, "observer").await;
+    // observer 自身の入室の時間窓が閉じるまで待つ
+    tokio::time::sleep(Duration::from_millis(400)).await;
+
+    // when (操作):
+    let (_alice, _bob) = tokio::join!(connect(&server, "alice"), connect(&server, "bob"));
+
+    // then (期待する結果):
+    let frame = wait_for_type(&mut observer, "participants-joined", Duration::from_secs(2))
+        .await
+        .expect("Expected participants-joined");
+    let mut ids: Vec<&str> = frame["participants"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|p| p["client_id"].as_str().unwrap())
+        .collect();
+    ids.sort();
+    assert_eq!(ids, vec!["alice", "bob"]);
+}
+
+#[tokio::test]
+async fn test_lone_join_produces_single_event() {
+    // テスト項目: 時間窓の中の入室が 1 人だけの場合は、個別の participant-joined で通知される
+    // given (前提条件):
+    let server = start_server().await;
+    let mut observer = connect(&server, "observer").await;
+    tokio::time::sleep(Duration::from_millis(400)).await;
+
+    // when (操作):
+    let _alice = connect(&server, "alice").await;
+
+    // then (期待する結果):
+    let frame = wait_for_type(&mut observer, "participant-joined", Duration::from_secs(2))
+        .await
+        .expect("Expected participant-joined");
+    assert_eq!(frame["client_id"], "alice");
+}
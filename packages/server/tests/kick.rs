@@ -0,0 +1,87 @@
+//! Kick endpoint integration tests.
+
+mod fixtures;
+
+use std::time::Duration;
+
+use fixtures::{TestServer, connect, wait_for_type};
+use futures_util::StreamExt;
+use tokio_tungstenite::tungstenite::protocol::Message;
+
+/// Get the id of the room of the server
+async fn room_id(server: &TestServer) -> String {
+    let rooms: serde_json::Value = reqwest::get(format!("{}/api/rooms", server.base_url()))
+        .await
+        .expect("Failed to send request")
+        .json()
+        .await
+        .expect("Failed to parse JSON");
+    rooms[0]["id"].as_str().unwrap().to_string()
+}
+
+#[tokio::test]
+async fn test_kick_notifies_target_and_room() {
+    // テスト項目: キックされた参加者には理由付きの kicked が届いて接続が閉じられ、残りの参加者には participant-left が届く
+    // given (前提条件):
+    let server = TestServer::start().await;
+    let mut alice = connect(&server, "alice").await;
+    let mut bob = connect(&server, "bob").await;
+    wait_for_type(&mut alice, "participant-joined", Duration::from_secs(2)).await;
+    let room_id = room_id(&server).await;
+
+    // when (操作):
+    let response = reqwest::Client::new()
+        .post(format!(
+            "{}/api/rooms/{}/kick/bob",
+            server.base_url(),
+            room_id
+        ))
+        .json(&serde_json::json!({"reason": "spam"}))
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    // then (期待する結果):
+    assert_eq!(response.status(), 204);
+    let kicked = wait_for_type(&mut bob, "kicked", Duration::from_secs(2))
+        .await
+        .expect("Expected kicked frame for bob");
+    assert_eq!(kicked["reason"], "spam");
+    let closed = tokio::time::timeout(Duration::from_secs(2), async {
+        loop {
+            match bob.next().await {
+                None | Some(Err(_)) | Some(Ok(Message::Close(_))) => break,
+                Some(Ok(_)) => continue,
+            }
+        }
+    })
+    .await;
+    assert!(closed.is_ok(), "Expected bob's connection to be closed");
+
+    let left = wait_for_type(&mut alice, "participant-left", Duration::from_secs(2))
+        .await
+        .expect("Expected participant-left for alice");
+    assert_eq!(left["client_id"], "bob");
+}
+
+#[tokio::test]
+async fn test_kick_unknown_participant_is_not_found() {
+    // テスト項目: 接続していない参加者のキックは HTTP 404 になる
+    // given (前提条件):
+    let server = TestServer::start().await;
+    let room_id = room_id(&server).await;
+
+    // when (操作):
+    let response = reqwest::Client::new()
+        .post(format!(
+            "{}/api/rooms/{}/kick/dave",
+            server.base_url(),
+            room_id
+        ))
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    // then (期待する結果):
+    assert_eq!(response.status(), 404);
+}
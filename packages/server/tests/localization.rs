@@ -0,0 +1,54 @@
+//! Localized system text integration tests.
+
+mod fixtures;
+
+use std::time::Duration;
+
+use fixtures::{TestServer, connect_url, wait_for_type};
+use futures_util::SinkExt;
+use tokio_tungstenite::tungstenite::protocol::Message;
+
+/// Send a binary frame and return the message of the `unexpected_binary` error frame
+async fn unexpected_binary_message(server: &TestServer, client_id: &str, locale: &str) -> String {
+    let mut ws = connect_url(&format!("{}&locale={}", server.url(client_id), locale)).await;
+    ws.send(Message::Binary(vec![0x01].into()))
+        .await
+        .expect("Failed to send binary frame");
+    let error = wait_for_type(&mut ws, "error", Duration::from_secs(2))
+        .await
+        .expect("Expected error frame");
+    assert_eq!(error["code"], "unexpected_binary");
+    error["message"].as_str().unwrap().to_string()
+}
+
+#[tokio::test]
+async fn test_error_frame_localized_for_connection_locale() {
+    // テスト項目: locale=fr で接続したクライアントにはフランス語のエラーメッセージが届く
+    // given (前提条件):
+    let server = TestServer::start().await;
+
+    // when (操作):
+    let message = unexpected_binary_message(&server, "alice", "fr").await;
+
+    // then (期待する結果):
+    assert_eq!(
+        message,
+        "les trames binaires ne sont pas prises en charge sur cette connexion"
+    );
+}
+
+#[tokio::test]
+async fn test_unknown_locale_falls_back_to_default() {
+    // テスト項目: 未知のロケールで接続したクライアントにはデフォルトロケール（en）のエラーメッセージが届く
+    // given (前提条件):
+    let server = TestServer::start().await;
+
+    // when (操作):
+    let message = unexpected_binary_message(&server, "alice", "xx").await;
+
+    // then (期待する結果):
+    assert_eq!(
+        message,
+        "binary frames are not supported on this connection"
+    );
+}
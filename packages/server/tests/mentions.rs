@@ -0,0 +1,40 @@
+//! Mention notification integration tests.
+
+mod fixtures;
+
+use std::time::Duration;
+
+use fixtures::{TestServer, connect, next_json, send_chat, wait_for_type};
+
+#[tokio::test]
+async fn test_mention_delivers_mention_frame_to_mentioned_participant_only() {
+    // テスト項目: bob をメンションすると bob に mention フレームが届き、charlie には chat のみが届く
+    // given (前提条件):
+    let server = TestServer::start().await;
+    let mut alice = connect(&server, "alice").await;
+    let mut bob = connect(&server, "bob").await;
+    let mut charlie = connect(&server, "charlie").await;
+    wait_for_type(&mut alice, "participant-joined", Duration::from_secs(2)).await;
+    wait_for_type(&mut bob, "participant-joined", Duration::from_secs(2)).await;
+
+    // when (操作):
+    send_chat(&mut alice, "alice", "@bob @dave please review", 1000).await;
+
+    // then (期待する結果):
+    let mention = wait_for_type(&mut bob, "mention", Duration::from_secs(2))
+        .await
+        .expect("Expected mention frame for bob");
+    assert_eq!(mention["client_id"], "alice");
+    assert_eq!(mention["content"], "@bob @dave please review");
+    assert!(mention["message_id"].is_string());
+
+    let chat = wait_for_type(&mut charlie, "chat", Duration::from_secs(2))
+        .await
+        .expect("Expected chat message for charlie");
+    assert_eq!(chat["mentions"], serde_json::json!(["bob", "dave"]));
+    assert!(
+        next_json(&mut charlie, Duration::from_millis(300))
+            .await
+            .is_none()
+    );
+}
@@ -0,0 +1,100 @@
+//! Message edit and delete audit trail integration tests.
+
+mod fixtures;
+
+use std::time::Duration;
+
+use engawa_server::domain::{MessageAuditEntry, MessageContent};
+use fixtures::{TestServer, TestWebSocket, connect, send_chat, wait_for_type};
+use futures_util::SinkExt;
+use tokio_tungstenite::tungstenite::protocol::Message;
+
+/// Send an edit of the given message
+async fn send_edit(ws: &mut TestWebSocket, message_id: &str, content: &str) {
+    let message = serde_json::json!({
+        "type": "edit",
+        "message_id": message_id,
+        "content": content,
+    });
+    ws.send(Message::Text(message.to_string().into()))
+        .await
+        .expect("Failed to send edit");
+}
+
+/// SHA-256 (hex) of the given content
+fn hash(content: &str) -> String {
+    MessageAuditEntry::hash_content(&MessageContent::new(content.to_string()).unwrap())
+}
+
+#[tokio::test]
+async fn test_message_history_lists_edits_in_order() {
+    // テスト項目: メッセージを 2 回編集すると、履歴のエンドポイントが変更前後のハッシュを持つ監査エントリを順に 2 件返す
+    // given (前提条件):
+    let server = TestServer::start_with_args(&["--audit-message-edits"]).await;
+    let mut alice = connect(&server, "alice").await;
+    let mut bob = connect(&server, "bob").await;
+    send_chat(&mut alice, "alice", "v1", 0).await;
+    let chat = wait_for_type(&mut bob, "chat", Duration::from_secs(2))
+        .await
+        .expect("Expected chat message");
+    let message_id = chat["message_id"].as_str().unwrap();
+    let room_id = message_id.rsplit_once(':').unwrap().0;
+
+    // when (操作):
+    for content in ["v2", "v3"] {
+        send_edit(&mut alice, message_id, content).await;
+        wait_for_type(&mut bob, "edit", Duration::from_secs(2))
+            .await
+            .expect("Expected edit message");
+    }
+    let response = reqwest::get(format!(
+        "{}/api/rooms/{}/messages/{}/history",
+        server.base_url(),
+        room_id,
+        message_id
+    ))
+    .await
+    .expect("Failed to send request");
+
+    // then (期待する結果):
+    assert_eq!(response.status(), 200);
+    let history: serde_json::Value = response.json().await.expect("Failed to parse JSON");
+    assert_eq!(history["message_id"], message_id);
+    let entries = history["entries"].as_array().unwrap();
+    assert_eq!(entries.len(), 2);
+    for (entry, (before, after)) in entries.iter().zip([("v1", "v2"), ("v2", "v3")]) {
+        assert_eq!(entry["action"], "edit");
+        assert_eq!(entry["actor"], "alice");
+        assert_eq!(entry["before_hash"], hash(before));
+        assert_eq!(entry["after_hash"], hash(after));
+    }
+    assert!(entries[0]["at"].as_str().unwrap() <= entries[1]["at"].as_str().unwrap());
+}
+
+#[tokio::test]
+async fn test_message_history_not_found_without_audit() {
+    // テスト項目: 監査ログを記録していない場合、履歴のエンドポイントは HTTP 404 を返す
+    // given (前提条件):
+    let server = TestServer::start().await;
+    let mut alice = connect(&server, "alice").await;
+    let mut bob = connect(&server, "bob").await;
+    send_chat(&mut alice, "alice", "v1", 0).await;
+    let chat = wait_for_type(&mut bob, "chat", Duration::from_secs(2))
+        .await
+        .expect("Expected chat message");
+    let message_id = chat["message_id"].as_str().unwrap();
+    let room_id = message_id.rsplit_once(':').unwrap().0;
+
+    // when (操作):
+    let response = reqwest::get(format!(
+        "{}/api/rooms/{}/messages/{}/history",
+        server.base_url(),
+        room_id,
+        message_id
+    ))
+    .await
+    .expect("Failed to send request");
+
+    // then (期待する結果):
+    assert_eq!(response.status(), 404);
+}
@@ -0,0 +1,116 @@
+//! Message content policy integration tests.
+
+mod fixtures;
+
+use std::time::Duration;
+
+use engawa_server::domain::MESSAGE_CONTENT_MAX_LENGTH;
+use fixtures::{TestServer, connect, send_chat, wait_for_type};
+
+#[tokio::test]
+async fn test_collapsed_content_is_broadcast() {
+    // テスト項目: 空白をまとめる設定では、まとめた後の内容が他の参加者に配信される
+    // given (前提条件):
+    let server = TestServer::start_with_args(&["--collapse-whitespace"]).await;
+    let mut alice = connect(&server, "alice").await;
+    let mut bob = connect(&server, "bob").await;
+
+    // when (操作):
+    send_chat(&mut alice, "alice", "h   e   l   l   o\nworld", 1000).await;
+
+    // then (期待する結果):
+    let chat = wait_for_type(&mut bob, "chat", Duration::from_secs(2))
+        .await
+        .expect("Expected chat message");
+    assert_eq!(chat["content"], "h e l l o\nworld");
+}
+
+#[tokio::test]
+async fn test_mentions_attached_and_capped() {
+    // テスト項目: 配信される chat にメンションが付与され、上限を超えるメンションは error フレームで拒否される
+    // given (前提条件):
+    let server = TestServer::start_with_args(&["--max-mentions", "2"]).await;
+    let mut alice = connect(&server, "alice").await;
+    let mut bob = connect(&server, "bob").await;
+
+    // when (操作):
+    send_chat(&mut alice, "alice", "@bob @carol @dave look", 1000).await;
+    send_chat(&mut alice, "alice", "@bob look", 2000).await;
+
+    // then (期待する結果):
+    let error = wait_for_type(&mut alice, "error", Duration::from_secs(2))
+        .await
+        .expect("Expected error frame");
+    assert_eq!(error["code"], "invalid_content");
+    let chat = wait_for_type(&mut bob, "chat", Duration::from_secs(2))
+        .await
+        .expect("Expected chat message");
+    assert_eq!(chat["content"], "@bob look");
+    assert_eq!(chat["mentions"], serde_json::json!(["bob"]));
+}
+
+#[tokio::test]
+async fn test_pathological_mentions_are_bounded() {
+    // テスト項目: 数千個の `@` トークンを含むメッセージでも、配信される chat の mentions は件数・長さの上限に収まる
+    // given (前提条件):
+    let server =
+        TestServer::start_with_args(&["--max-parsed-mentions", "20", "--max-mention-length", "16"])
+            .await;
+    let mut alice = connect(&server, "alice").await;
+    let mut bob = connect(&server, "bob").await;
+
+    // when (操作):
+    let tokens: Vec<String> = (0..2000).map(|i| format!("@n{}", i)).collect();
+    let content = format!("@{} {}", "y".repeat(1000), tokens.join(" "));
+    send_chat(
+        &mut alice,
+        "alice",
+        &content[..MESSAGE_CONTENT_MAX_LENGTH],
+        1000,
+    )
+    .await;
+
+    // then (期待する結果):
+    let chat = wait_for_type(&mut bob, "chat", Duration::from_secs(2))
+        .await
+        .expect("Expected chat message");
+    let mentions = chat["mentions"].as_array().unwrap();
+    assert_eq!(mentions.len(), 20);
+    assert_eq!(mentions[0], "y".repeat(16));
+    assert!(
+        mentions
+            .iter()
+            .all(|mention| mention.as_str().unwrap().chars().count() <= 16)
+    );
+}
+
+#[tokio::test]
+async fn test_over_limit_content_rejected_before_broadcast() {
+    // テスト項目: 長さの上限を超えるメッセージは error フレームで拒否され、保存も他の参加者への配信も行われない
+    // given (前提条件):
+    let server = TestServer::start().await;
+    let mut alice = connect(&server, "alice").await;
+    let mut bob = connect(&server, "bob").await;
+
+    // when (操作):
+    let over_limit = "a".repeat(MESSAGE_CONTENT_MAX_LENGTH + 1);
+    send_chat(&mut alice, "alice", &over_limit, 1000).await;
+    send_chat(&mut alice, "alice", "after", 2000).await;
+
+    // then (期待する結果):
+    let error = wait_for_type(&mut alice, "error", Duration::from_secs(2))
+        .await
+        .expect("Expected error frame");
+    assert_eq!(error["code"], "invalid_content");
+    let chat = wait_for_type(&mut bob, "chat", Duration::from_secs(2))
+        .await
+        .expect("Expected chat message");
+    assert_eq!(chat["content"], "after");
+    let room: serde_json::Value = reqwest::get(format!("{}/debug/room", server.base_url()))
+        .await
+        .expect("Failed to send request")
+        .json()
+        .await
+        .expect("Failed to parse JSON");
+    assert_eq!(room["messages"].as_array().unwrap().len(), 1);
+}
@@ -0,0 +1,55 @@
+//! Metrics endpoint integration tests.
+
+mod fixtures;
+
+use std::time::Duration;
+
+use fixtures::{TestServer, connect, send_chat, wait_for_type};
+
+#[tokio::test]
+async fn test_metrics_expose_delivery_latency_histogram() {
+    // テスト項目: 遅延の計測を有効にすると、/metrics が配信したメッセージの件数を含む Prometheus 形式のヒストグラムを返す
+    // given (前提条件):
+    let server = TestServer::start_with_args(&["--latency-metrics"]).await;
+    let mut alice = connect(&server, "alice").await;
+    let mut bob = connect(&server, "bob").await;
+
+    // when (操作):
+    for content in ["first", "second"] {
+        send_chat(&mut alice, "alice", content, 0).await;
+        wait_for_type(&mut bob, "chat", Duration::from_secs(2))
+            .await
+            .expect("Expected chat message");
+    }
+    let response = reqwest::get(format!("{}/metrics", server.base_url()))
+        .await
+        .expect("Failed to request metrics");
+
+    // then (期待する結果):
+    assert_eq!(response.status(), 200);
+    assert!(
+        response.headers()["content-type"]
+            .to_str()
+            .unwrap()
+            .starts_with("text/plain")
+    );
+    let body = response.text().await.expect("Failed to read metrics");
+    assert!(body.contains("# TYPE engawa_message_delivery_latency_seconds histogram"));
+    assert!(body.contains("engawa_message_delivery_latency_seconds_bucket{le=\"+Inf\"} 2\n"));
+    assert!(body.contains("engawa_message_delivery_latency_seconds_count 2\n"));
+}
+
+#[tokio::test]
+async fn test_metrics_not_found_when_disabled() {
+    // テスト項目: 遅延の計測を有効にしない場合、/metrics は 404 を返す
+    // given (前提条件):
+    let server = TestServer::start().await;
+
+    // when (操作):
+    let response = reqwest::get(format!("{}/metrics", server.base_url()))
+        .await
+        .expect("Failed to request metrics");
+
+    // then (期待する結果):
+    assert_eq!(response.status(), 404);
+}
@@ -0,0 +1,129 @@
+//! Multiple room integration tests.
+
+mod fixtures;
+
+use std::time::Duration;
+
+use fixtures::{TestServer, connect, connect_url, next_json, send_chat, wait_for_type};
+
+/// Create a room and return its id
+async fn create_room(server: &TestServer) -> String {
+    let response = reqwest::Client::new()
+        .post(format!("{}/api/rooms", server.base_url()))
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), 201);
+    let room: serde_json::Value = response.json().await.expect("Failed to parse JSON");
+    room["id"].as_str().unwrap().to_string()
+}
+
+#[tokio::test]
+async fn test_messages_stay_in_their_room() {
+    // テスト項目: 作成したルームに入室したクライアントのメッセージは、同じルームの参加者にだけ届く
+    // given (前提条件):
+    let server = TestServer::start().await;
+    let room_id = create_room(&server).await;
+    let mut alice = connect(&server, "alice").await;
+    let mut bob = connect_url(&format!("{}&room_id={}", server.url("bob"), room_id)).await;
+    let mut charlie = connect_url(&format!("{}&room_id={}", server.url("charlie"), room_id)).await;
+    wait_for_type(&mut bob, "participant-joined", Duration::from_secs(2))
+        .await
+        .expect("Expected participant-joined for charlie");
+
+    // when (操作):
+    send_chat(&mut charlie, "charlie", "Hello room", 1000).await;
+
+    // then (期待する結果):
+    let chat = wait_for_type(&mut bob, "chat", Duration::from_secs(2))
+        .await
+        .expect("Expected chat in the created room");
+    assert_eq!(chat["content"], "Hello room");
+    while let Some(message) = next_json(&mut alice, Duration::from_millis(300)).await {
+        assert_ne!(message["type"], "chat", "alice is in another room");
+        assert_ne!(
+            message["type"], "participant-joined",
+            "alice is in another room"
+        );
+    }
+
+    let rooms: serde_json::Value = reqwest::get(format!("{}/api/rooms", server.base_url()))
+        .await
+        .expect("Failed to send request")
+        .json()
+        .await
+        .expect("Failed to parse JSON");
+    assert_eq!(rooms.as_array().unwrap().len(), 2);
+    assert_eq!(rooms[1]["id"], room_id);
+}
+
+#[tokio::test]
+async fn test_connect_to_unknown_room_is_not_found() {
+    // テスト項目: 存在しないルーム ID を指定した接続は HTTP 404 で拒否される
+    // given (前提条件):
+    let server = TestServer::start().await;
+    let url = format!(
+        "{}&room_id=00000000-0000-0000-0000-000000000000",
+        server.url("alice")
+    );
+
+    // when (操作):
+    let result = tokio_tungstenite::connect_async(url).await;
+
+    // then (期待する結果):
+    match result {
+        Err(tokio_tungstenite::tungstenite::Error::Http(response)) => {
+            assert_eq!(response.status(), 404);
+        }
+        other => panic!("Expected HTTP 404, got {:?}", other.map(|_| ())),
+    }
+}
+
+#[tokio::test]
+async fn test_connect_with_default_alias_joins_default_room() {
+    // テスト項目: room_id=default を指定した接続はデフォルトのルームに入室する
+    // given (前提条件):
+    let server = TestServer::start().await;
+    let mut alice = connect(&server, "alice").await;
+
+    // when (操作):
+    let mut bob = connect_url(&format!("{}&room_id=default", server.url("bob"))).await;
+    send_chat(&mut bob, "bob", "hello", 1000).await;
+
+    // then (期待する結果): 未指定で接続した参加者と同じルームにいる
+    let chat = wait_for_type(&mut alice, "chat", Duration::from_secs(2))
+        .await
+        .expect("Expected chat in the default room");
+    assert_eq!(chat["content"], "hello");
+}
+
+#[tokio::test]
+async fn test_create_room_rejects_tenant_over_room_limit() {
+    // テスト項目: --max-rooms-per-tenant に達したテナントのルーム作成は HTTP 429 で拒否され、テナントのない作成者は作成できる
+    // given (前提条件):
+    let server =
+        TestServer::start_with_args(&["--tenant", "acme", "--max-rooms-per-tenant", "1"]).await;
+    let client = reqwest::Client::new();
+    let create_as = |client_id: &'static str| {
+        client
+            .post(format!("{}/api/rooms", server.base_url()))
+            .json(&serde_json::json!({ "client_id": client_id }))
+            .send()
+    };
+
+    // when (操作):
+    let first = create_as("acme:alice")
+        .await
+        .expect("Failed to send request");
+    let over_limit = create_as("acme:bob").await.expect("Failed to send request");
+    let without_tenant = create_as("carol").await.expect("Failed to send request");
+    let other_tenant = create_as("other:dave")
+        .await
+        .expect("Failed to send request");
+
+    // then (期待する結果): 他のテナントの ID はテナントのポリシーで拒否される
+    assert_eq!(first.status(), 201);
+    assert_eq!(over_limit.status(), 429);
+    assert_eq!(without_tenant.status(), 201);
+    assert_eq!(other_tenant.status(), 400);
+}
@@ -0,0 +1,38 @@
+//! Participant count broadcast integration tests.
+
+mod fixtures;
+
+use std::time::Duration;
+
+use fixtures::{TestServer, TestWebSocket, connect, wait_for_type};
+
+/// Read `participant-count` frames until one with the given count arrives
+async fn wait_for_count(ws: &mut TestWebSocket, count: usize) -> bool {
+    while let Some(frame) = wait_for_type(ws, "participant-count", Duration::from_secs(2)).await {
+        if frame["count"] == count {
+            return true;
+        }
+    }
+    false
+}
+
+#[tokio::test]
+async fn test_participant_count_broadcast_on_join_and_leave() {
+    // テスト項目: 入室のたびに参加者数が全員に通知され、退室すると残りの参加者に減った数が通知される
+    // given (前提条件):
+    let server = TestServer::start().await;
+    let mut alice = connect(&server, "alice").await;
+
+    // when (操作):
+    let mut bob = connect(&server, "bob").await;
+
+    // then (期待する結果):
+    assert!(wait_for_count(&mut alice, 2).await);
+    assert!(wait_for_count(&mut bob, 2).await);
+
+    // when (操作):
+    drop(bob);
+
+    // then (期待する結果):
+    assert!(wait_for_count(&mut alice, 1).await);
+}
@@ -0,0 +1,82 @@
+//! Per-client message rate limiting integration tests.
+
+mod fixtures;
+
+use std::time::Duration;
+
+use fixtures::{TestServer, connect, send_chat, wait_for_type};
+
+#[tokio::test]
+async fn test_messages_beyond_burst_are_rejected_with_error_frame() {
+    // テスト項目: バーストを超えて連続送信した chat は配信されず、送信者に retry_after_ms 付きの rate_limited エラーが返る
+    // given (前提条件):
+    let server =
+        TestServer::start_with_args(&["--max-messages-per-sec", "1", "--message-burst", "2"]).await;
+    let mut alice = connect(&server, "alice").await;
+    let mut bob = connect(&server, "bob").await;
+
+    // when (操作):
+    for content in ["first", "second", "third"] {
+        send_chat(&mut alice, "alice", content, 0).await;
+    }
+
+    // then (期待する結果):
+    let error = wait_for_type(&mut alice, "error", Duration::from_secs(2))
+        .await
+        .expect("Expected error frame");
+    assert_eq!(error["code"], "rate_limited");
+    let retry_after_ms = error["retry_after_ms"].as_u64().unwrap();
+    assert!(retry_after_ms > 0 && retry_after_ms <= 1000);
+
+    for content in ["first", "second"] {
+        let chat = wait_for_type(&mut bob, "chat", Duration::from_secs(2))
+            .await
+            .expect("Expected chat message");
+        assert_eq!(chat["content"], content);
+    }
+    assert!(
+        wait_for_type(&mut bob, "chat", Duration::from_millis(200))
+            .await
+            .is_none()
+    );
+}
+
+#[tokio::test]
+async fn test_messages_beyond_burst_cap_are_rejected_with_error_frame() {
+    // テスト項目: 短いウィンドウ内の送信数の上限を超えた chat は配信されず、送信者に retry_after_ms 付きの rate_limited エラーが返る
+    // given (前提条件):
+    let server = TestServer::start_with_args(&[
+        "--max-messages-per-burst-window",
+        "2",
+        "--message-burst-window-ms",
+        "60000",
+    ])
+    .await;
+    let mut alice = connect(&server, "alice").await;
+    let mut bob = connect(&server, "bob").await;
+
+    // when (操作):
+    for content in ["first", "second", "third"] {
+        send_chat(&mut alice, "alice", content, 0).await;
+    }
+
+    // then (期待する結果):
+    let error = wait_for_type(&mut alice, "error", Duration::from_secs(2))
+        .await
+        .expect("Expected error frame");
+    assert_eq!(error["code"], "rate_limited");
+    let retry_after_ms = error["retry_after_ms"].as_u64().unwrap();
+    assert!(retry_after_ms > 0 && retry_after_ms <= 60_000);
+
+    for content in ["first", "second"] {
+        let chat = wait_for_type(&mut bob, "chat", Duration::from_secs(2))
+            .await
+            .expect("Expected chat message");
+        assert_eq!(chat["content"], content);
+    }
+    assert!(
+        wait_for_type(&mut bob, "chat", Duration::from_millis(200))
+            .await
+            .is_none()
+    );
+}
@@ -0,0 +1,47 @@
+//! Inbound read rate limit integration tests.
+
+mod fixtures;
+
+use std::time::{Duration, Instant};
+
+use fixtures::{TestServer, connect, next_json, send_chat};
+
+#[tokio::test]
+async fn test_flooding_client_is_throttled_while_others_are_not() {
+    // テスト項目: 受信レートの上限を超えて送信するクライアントの処理は上限に抑えられ、他のクライアントのメッセージは遅延なく配信される
+    // given (前提条件):
+    let server = TestServer::start_with_args(&["--max-inbound-frames-per-sec", "5"]).await;
+    let mut bob = connect(&server, "bob").await;
+    let mut mallory = connect(&server, "mallory").await;
+    let mut carol = connect(&server, "carol").await;
+
+    // when (操作):
+    for i in 0..30 {
+        send_chat(&mut mallory, "mallory", &format!("flood {}", i), 0).await;
+    }
+    send_chat(&mut carol, "carol", "hello", 0).await;
+
+    // then (期待する結果): 最初の 500ms に届く mallory のメッセージは上限（5 件）まで
+    let deadline = Instant::now() + Duration::from_millis(500);
+    let mut flood_count = 0;
+    let mut carol_received = false;
+    while let Some(remaining) = deadline.checked_duration_since(Instant::now()) {
+        let Some(frame) = next_json(&mut bob, remaining).await else {
+            break;
+        };
+        if frame["type"] != "chat" {
+            continue;
+        }
+        match frame["client_id"].as_str() {
+            Some("mallory") => flood_count += 1,
+            Some("carol") => carol_received = true,
+            _ => {}
+        }
+    }
+    assert!(
+        flood_count <= 5,
+        "Expected at most 5 flood messages, got {}",
+        flood_count
+    );
+    assert!(carol_received, "Expected carol's message to be delivered");
+}
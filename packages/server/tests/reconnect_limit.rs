@@ -0,0 +1,40 @@
+//! Reconnect rate limit integration tests.
+
+mod fixtures;
+
+use std::time::Duration;
+
+use engawa_server::ui::{ReconnectLimit, ServerConfig};
+use fixtures::{TestServer, connect};
+use tokio_tungstenite::tungstenite::Error as WsError;
+
+#[tokio::test]
+async fn test_reconnects_beyond_limit_rejected_with_429() {
+    // テスト項目: ウィンドウ内で上限を超える再接続は HTTP 429 で拒否され、時間をおけば再び接続できる
+    // given (前提条件):
+    let server = TestServer::start_with_config(ServerConfig {
+        reconnect_limit: Some(ReconnectLimit {
+            max_attempts: 2,
+            window: Duration::from_millis(500),
+        }),
+        ..ServerConfig::default()
+    })
+    .await;
+    for _ in 0..2 {
+        let mut ws = connect(&server, "alice").await;
+        ws.close(None).await.expect("Failed to close");
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+
+    // when (操作):
+    let rapid_result = tokio_tungstenite::connect_async(server.url("alice")).await;
+    tokio::time::sleep(Duration::from_millis(600)).await;
+    let spaced_result = tokio_tungstenite::connect_async(server.url("alice")).await;
+
+    // then (期待する結果):
+    match rapid_result {
+        Err(WsError::Http(response)) => assert_eq!(response.status(), 429),
+        other => panic!("Expected HTTP 429, got {:?}", other.map(|_| ())),
+    }
+    assert!(spaced_result.is_ok());
+}
@@ -0,0 +1,69 @@
+//! Room removal integration tests.
+
+mod fixtures;
+
+use std::time::Duration;
+
+use fixtures::{TestServer, connect};
+use futures_util::StreamExt;
+use tokio_tungstenite::tungstenite::protocol::Message;
+
+/// Get the id of the room of the server
+async fn room_id(server: &TestServer) -> String {
+    let rooms: serde_json::Value = reqwest::get(format!("{}/api/rooms", server.base_url()))
+        .await
+        .expect("Failed to send request")
+        .json()
+        .await
+        .expect("Failed to parse JSON");
+    rooms[0]["id"].as_str().unwrap().to_string()
+}
+
+async fn delete_room(server: &TestServer, room_id: &str) -> reqwest::Response {
+    reqwest::Client::new()
+        .delete(format!("{}/api/rooms/{}", server.base_url(), room_id))
+        .send()
+        .await
+        .expect("Failed to send request")
+}
+
+#[tokio::test]
+async fn test_remove_room_evicts_connected_clients() {
+    // テスト項目: ルームを削除すると参加していたクライアントの ID が返され、その接続が閉じられる
+    // given (前提条件):
+    let server = TestServer::start().await;
+    let mut alice = connect(&server, "alice").await;
+    let room_id = room_id(&server).await;
+
+    // when (操作):
+    let response = delete_room(&server, &room_id).await;
+
+    // then (期待する結果):
+    assert_eq!(response.status(), 200);
+    let body: serde_json::Value = response.json().await.expect("Failed to parse JSON");
+    assert_eq!(body["id"], room_id);
+    assert_eq!(body["evicted_client_ids"], serde_json::json!(["alice"]));
+    let closed = tokio::time::timeout(Duration::from_secs(2), async {
+        loop {
+            match alice.next().await {
+                None | Some(Err(_)) | Some(Ok(Message::Close(_))) => break,
+                Some(Ok(_)) => continue,
+            }
+        }
+    })
+    .await;
+    assert!(closed.is_ok(), "Expected alice's connection to be closed");
+}
+
+#[tokio::test]
+async fn test_remove_unknown_room_is_not_found() {
+    // テスト項目: 存在しないルームの削除は HTTP 404 になる
+    // given (前提条件):
+    let server = TestServer::start().await;
+
+    // when (操作):
+    let response = delete_room(&server, "00000000-0000-0000-0000-000000000000").await;
+
+    // then (期待する結果):
+    assert_eq!(response.status(), 404);
+}
@@ -0,0 +1,86 @@
+//! Room admin integration tests.
+
+mod fixtures;
+
+use std::time::Duration;
+
+use fixtures::{TestServer, connect, send_chat, wait_for_type};
+use tokio_tungstenite::tungstenite::{
+    Error as WsError, client::IntoClientRequest, http::HeaderValue,
+};
+
+const API_TOKEN: &str = "test-token";
+
+/// Lock the server's room through the room API
+async fn lock_room(server: &TestServer) {
+    let client = reqwest::Client::new();
+    let rooms: serde_json::Value = client
+        .get(format!("{}/api/rooms", server.base_url()))
+        .send()
+        .await
+        .expect("Failed to send request")
+        .json()
+        .await
+        .expect("Failed to parse JSON");
+    let room_id = rooms[0]["id"].as_str().unwrap();
+    let response = client
+        .patch(format!("{}/api/rooms/{}", server.base_url(), room_id))
+        .json(&serde_json::json!({ "locked": true }))
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), 200);
+}
+
+#[tokio::test]
+async fn test_room_admin_posts_in_locked_room_without_join_approval() {
+    // テスト項目: --room-admin で指定した管理者はロック中のルームにも投稿でき、入室の承認は有効にならない
+    // given (前提条件):
+    let server = TestServer::start_with_args(&["--room-admin", "admin"]).await;
+    let mut alice = connect(&server, "alice").await;
+    let mut admin = connect(&server, "admin").await;
+    lock_room(&server).await;
+    wait_for_type(&mut alice, "room-locked", Duration::from_secs(2))
+        .await
+        .expect("Expected room-locked for alice");
+
+    // when (操作):
+    send_chat(&mut admin, "admin", "announcement", 0).await;
+    send_chat(&mut alice, "alice", "hello", 0).await;
+
+    // then (期待する結果):
+    let chat = wait_for_type(&mut alice, "chat", Duration::from_secs(2))
+        .await
+        .expect("Expected chat from admin");
+    assert_eq!(chat["client_id"], "admin");
+    let error = wait_for_type(&mut alice, "error", Duration::from_secs(2))
+        .await
+        .expect("Expected error frame for alice");
+    assert_eq!(error["code"], "room_locked");
+}
+
+#[tokio::test]
+async fn test_room_admin_id_requires_api_token() {
+    // テスト項目: API トークンが設定されている場合、管理者の client_id での接続にはトークンが必要で、ない場合は HTTP 401 で拒否される
+    // given (前提条件):
+    let server =
+        TestServer::start_with_args(&["--room-admin", "admin", "--api-token", API_TOKEN]).await;
+    let mut request = server.url("admin").into_client_request().unwrap();
+    request.headers_mut().insert(
+        "Authorization",
+        HeaderValue::from_str(&format!("Bearer {}", API_TOKEN)).unwrap(),
+    );
+
+    // when (操作):
+    let without_token = tokio_tungstenite::connect_async(server.url("admin")).await;
+    let with_token = tokio_tungstenite::connect_async(request).await;
+    let other_client = tokio_tungstenite::connect_async(server.url("alice")).await;
+
+    // then (期待する結果):
+    match without_token {
+        Err(WsError::Http(response)) => assert_eq!(response.status(), 401),
+        other => panic!("Expected HTTP 401, got {:?}", other.map(|_| ())),
+    }
+    assert!(with_token.is_ok());
+    assert!(other_client.is_ok());
+}
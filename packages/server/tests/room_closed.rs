@@ -0,0 +1,65 @@
+//! Closed room integration tests.
+
+mod fixtures;
+
+use fixtures::{TestServer, connect};
+use tokio_tungstenite::tungstenite::Error as WsError;
+
+/// Close (`true`) or reopen (`false`) the room of the server
+async fn set_room_closed(server: &TestServer, closed: bool) {
+    let client = reqwest::Client::new();
+    let rooms: serde_json::Value = client
+        .get(format!("{}/api/rooms", server.base_url()))
+        .send()
+        .await
+        .expect("Failed to send request")
+        .json()
+        .await
+        .expect("Failed to parse JSON");
+    let room_id = rooms[0]["id"].as_str().unwrap();
+    let response = client
+        .patch(format!("{}/api/rooms/{}", server.base_url(), room_id))
+        .json(&serde_json::json!({ "closed": closed }))
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), 200);
+    let room: serde_json::Value = response.json().await.expect("Failed to parse JSON");
+    assert_eq!(room["closed"], closed);
+}
+
+#[tokio::test]
+async fn test_connect_to_closed_room_is_gone() {
+    // テスト項目: 閉じたルームへの接続は理由付きの HTTP 410 Gone で拒否される
+    // given (前提条件):
+    let server = TestServer::start().await;
+    set_room_closed(&server, true).await;
+
+    // when (操作):
+    let result = tokio_tungstenite::connect_async(server.url("alice")).await;
+
+    // then (期待する結果):
+    match result {
+        Err(WsError::Http(response)) => {
+            assert_eq!(response.status(), 410);
+            let body = response.body().clone().unwrap_or_default();
+            assert_eq!(String::from_utf8(body).unwrap(), "room is closed");
+        }
+        other => panic!("Expected HTTP 410, got {:?}", other.map(|_| ())),
+    }
+}
+
+#[tokio::test]
+async fn test_reopened_room_accepts_connects() {
+    // テスト項目: 閉じたルームを再開すると再び接続できる
+    // given (前提条件):
+    let server = TestServer::start().await;
+    set_room_closed(&server, true).await;
+
+    // when (操作):
+    set_room_closed(&server, false).await;
+
+    // then (期待する結果):
+    // connect は room-connected を受信できることを検証する
+    let _alice = connect(&server, "alice").await;
+}
@@ -0,0 +1,122 @@
+//! Room detail message history pagination integration tests.
+
+mod fixtures;
+
+use std::time::Duration;
+
+use fixtures::{TestServer, connect, send_chat, wait_for_type};
+
+/// Post `count` chat messages (`message 1`, `message 2`, ...) and wait until they are delivered
+async fn post_messages(server: &TestServer, count: usize) {
+    let mut alice = connect(server, "alice").await;
+    let mut bob = connect(server, "bob").await;
+    for i in 1..=count {
+        send_chat(&mut alice, "alice", &format!("message {}", i), 1000).await;
+        wait_for_type(&mut bob, "chat", Duration::from_secs(2))
+            .await
+            .expect("Expected chat message");
+    }
+}
+
+/// Get the room detail of the server's room with the given query string
+async fn get_room_detail(server: &TestServer, query: &str) -> serde_json::Value {
+    let client = reqwest::Client::new();
+    let rooms: serde_json::Value = client
+        .get(format!("{}/api/rooms", server.base_url()))
+        .send()
+        .await
+        .expect("Failed to send request")
+        .json()
+        .await
+        .expect("Failed to parse JSON");
+    let room_id = rooms[0]["id"].as_str().unwrap();
+    let response = client
+        .get(format!(
+            "{}/api/rooms/{}{}",
+            server.base_url(),
+            room_id,
+            query
+        ))
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), 200);
+    response.json().await.expect("Failed to parse JSON")
+}
+
+/// Contents of the messages in a room detail response
+fn contents(detail: &serde_json::Value) -> Vec<String> {
+    detail["messages"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|m| m["content"].as_str().unwrap().to_string())
+        .collect()
+}
+
+#[tokio::test]
+async fn test_room_detail_returns_most_recent_messages() {
+    // テスト項目: limit を指定すると、最新のメッセージから limit 件を古い順に返し、total に全件数を返す
+    // given (前提条件):
+    let server = TestServer::start().await;
+    post_messages(&server, 5).await;
+
+    // when (操作):
+    let detail = get_room_detail(&server, "?limit=2").await;
+
+    // then (期待する結果):
+    assert_eq!(contents(&detail), vec!["message 4", "message 5"]);
+    assert_eq!(detail["total"], 5);
+    assert_eq!(detail["messages"][0]["client_id"], "alice");
+}
+
+#[tokio::test]
+async fn test_room_detail_offset_pages_back() {
+    // テスト項目: offset を指定すると、最新から offset 件をさかのぼったページを返し、範囲外は空になる
+    // given (前提条件):
+    let server = TestServer::start().await;
+    post_messages(&server, 5).await;
+
+    // when (操作):
+    let page = get_room_detail(&server, "?limit=2&offset=2").await;
+    let last_page = get_room_detail(&server, "?limit=2&offset=4").await;
+    let beyond = get_room_detail(&server, "?offset=10").await;
+
+    // then (期待する結果):
+    assert_eq!(contents(&page), vec!["message 2", "message 3"]);
+    assert_eq!(contents(&last_page), vec!["message 1"]);
+    assert!(contents(&beyond).is_empty());
+    assert_eq!(beyond["total"], 5);
+}
+
+#[tokio::test]
+async fn test_room_detail_escapes_message_content() {
+    // テスト項目: ルーム詳細のメッセージの内容は HTML エスケープして返し、ドメインモデルには元の内容を保持する
+    // given (前提条件):
+    let server = TestServer::start().await;
+    let mut alice = connect(&server, "alice").await;
+    let mut bob = connect(&server, "bob").await;
+    send_chat(&mut alice, "alice", "<script>alert('x')</script>", 1000).await;
+    wait_for_type(&mut bob, "chat", Duration::from_secs(2))
+        .await
+        .expect("Expected chat message");
+
+    // when (操作):
+    let detail = get_room_detail(&server, "").await;
+    let room: serde_json::Value = reqwest::get(format!("{}/debug/room", server.base_url()))
+        .await
+        .expect("Failed to send request")
+        .json()
+        .await
+        .expect("Failed to parse JSON");
+
+    // then (期待する結果):
+    assert_eq!(
+        contents(&detail),
+        vec!["&lt;script&gt;alert(&#39;x&#39;)&lt;/script&gt;"]
+    );
+    assert_eq!(
+        room["messages"][0]["content"],
+        "<script>alert('x')</script>"
+    );
+}
@@ -0,0 +1,78 @@
+//! Room stats subscription integration tests.
+
+mod fixtures;
+
+use std::time::Duration;
+
+use fixtures::{TestServer, TestWebSocket, connect, send_chat, wait_for_type};
+use futures_util::SinkExt;
+use tokio_tungstenite::tungstenite::protocol::Message;
+
+async fn start_server() -> TestServer {
+    TestServer::start_with_args(&["--stats-interval-ms", "100"]).await
+}
+
+async fn send_type(ws: &mut TestWebSocket, r#type: &str) {
+    let frame = serde_json::json!({ "type": r#type });
+    ws.send(Message::Text(frame.to_string().into()))
+        .await
+        .expect("Failed to send frame");
+}
+
+#[tokio::test]
+async fn test_subscriber_receives_periodic_room_stats() {
+    // テスト項目: 購読したクライアントに room-stats が定期的に届き、メッセージ数と参加者数が含まれる
+    // given (前提条件):
+    let server = start_server().await;
+    let mut alice = connect(&server, "alice").await;
+    let mut bob = connect(&server, "bob").await;
+    send_chat(&mut alice, "alice", "Hello", 1).await;
+    wait_for_type(&mut bob, "chat", Duration::from_secs(2))
+        .await
+        .expect("Expected chat broadcast");
+
+    // when (操作):
+    send_type(&mut alice, "subscribe-stats").await;
+
+    // then (期待する結果):
+    for _ in 0..2 {
+        let stats = wait_for_type(&mut alice, "room-stats", Duration::from_secs(2))
+            .await
+            .expect("Expected room-stats for subscriber");
+        assert_eq!(stats["participant_count"], 2);
+        assert_eq!(stats["messages_per_minute"], 1);
+    }
+}
+
+#[tokio::test]
+async fn test_non_subscriber_does_not_receive_room_stats() {
+    // テスト項目: 購読していないクライアントや購読を解除したクライアントには room-stats が届かない
+    // given (前提条件):
+    let server = start_server().await;
+    let mut alice = connect(&server, "alice").await;
+    let mut bob = connect(&server, "bob").await;
+    send_type(&mut alice, "subscribe-stats").await;
+    wait_for_type(&mut alice, "room-stats", Duration::from_secs(2))
+        .await
+        .expect("Expected room-stats for subscriber");
+
+    // when (操作):
+    send_type(&mut alice, "unsubscribe-stats").await;
+    // 解除前に送られた room-stats を読み捨てる
+    while wait_for_type(&mut alice, "room-stats", Duration::from_millis(150))
+        .await
+        .is_some()
+    {}
+
+    // then (期待する結果):
+    assert!(
+        wait_for_type(&mut bob, "room-stats", Duration::from_millis(300))
+            .await
+            .is_none()
+    );
+    assert!(
+        wait_for_type(&mut alice, "room-stats", Duration::from_millis(300))
+            .await
+            .is_none()
+    );
+}
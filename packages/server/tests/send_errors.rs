@@ -0,0 +1,36 @@
+//! Integration tests for the error frames sent back when a chat message is rejected.
+
+mod fixtures;
+
+use std::time::Duration;
+
+use fixtures::{TestServer, connect, send_chat, wait_for_type};
+
+#[tokio::test]
+async fn test_message_beyond_capacity_is_answered_with_error_frame() {
+    // テスト項目: ルームのメッセージ容量を超えた chat は配信されず、送信者に message_capacity_exceeded のエラーフレームが返る
+    // given (前提条件):
+    let server = TestServer::start_with_args(&["--message-capacity", "1"]).await;
+    let mut alice = connect(&server, "alice").await;
+    let mut bob = connect(&server, "bob").await;
+    send_chat(&mut alice, "alice", "first", 0).await;
+    wait_for_type(&mut bob, "chat", Duration::from_secs(2))
+        .await
+        .expect("Expected chat message");
+
+    // when (操作):
+    send_chat(&mut alice, "alice", "second", 0).await;
+
+    // then (期待する結果):
+    let error = wait_for_type(&mut alice, "error", Duration::from_secs(2))
+        .await
+        .expect("Expected error frame");
+    assert_eq!(error["code"], "message_capacity_exceeded");
+    assert!(!error["message"].as_str().unwrap().is_empty());
+    assert!(error.get("retry_after_ms").is_none());
+    assert!(
+        wait_for_type(&mut bob, "chat", Duration::from_millis(200))
+            .await
+            .is_none()
+    );
+}
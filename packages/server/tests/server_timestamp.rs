@@ -0,0 +1,64 @@
+//! Server-side timestamp integration tests.
+
+mod fixtures;
+
+use std::time::Duration;
+
+use engawa_shared::time::get_jst_timestamp;
+use fixtures::{TestServer, connect, send_chat, wait_for_type};
+use futures_util::SinkExt;
+use tokio_tungstenite::tungstenite::protocol::Message;
+
+#[tokio::test]
+async fn test_client_timestamp_is_replaced_by_server_timestamp() {
+    // テスト項目: クライアントが送信した timestamp は無視され、配信される chat にはサーバの時刻が付与される
+    // given (前提条件):
+    let server = TestServer::start().await;
+    let mut alice = connect(&server, "alice").await;
+    let mut bob = connect(&server, "bob").await;
+    let before = get_jst_timestamp();
+
+    // when (操作):
+    send_chat(&mut alice, "alice", "Hello!", 0).await;
+
+    // then (期待する結果):
+    let chat = wait_for_type(&mut bob, "chat", Duration::from_secs(2))
+        .await
+        .expect("Expected chat message");
+    let after = get_jst_timestamp();
+    let timestamp = chat["timestamp"].as_i64().unwrap();
+    assert!(
+        (before..=after).contains(&timestamp),
+        "Expected server timestamp within [{}, {}], got {}",
+        before,
+        after,
+        timestamp
+    );
+}
+
+#[tokio::test]
+async fn test_chat_without_timestamp_is_accepted() {
+    // テスト項目: timestamp を含まない chat もそのまま受け付けられ、サーバの時刻が付与される
+    // given (前提条件):
+    let server = TestServer::start().await;
+    let mut alice = connect(&server, "alice").await;
+    let mut bob = connect(&server, "bob").await;
+
+    // when (操作):
+    let message = serde_json::json!({
+        "type": "chat",
+        "client_id": "alice",
+        "content": "Hello!",
+    });
+    alice
+        .send(Message::Text(message.to_string().into()))
+        .await
+        .expect("Failed to send message");
+
+    // then (期待する結果):
+    let chat = wait_for_type(&mut bob, "chat", Duration::from_secs(2))
+        .await
+        .expect("Expected chat message");
+    assert_eq!(chat["content"], "Hello!");
+    assert!(chat["timestamp"].as_i64().unwrap() > 0);
+}
@@ -0,0 +1,96 @@
+//! Phased shutdown integration tests.
+
+mod fixtures;
+
+use std::time::Duration;
+
+use fixtures::{TestServer, connect, next_json, wait_for_type};
+use futures_util::StreamExt;
+use tokio_tungstenite::tungstenite::{Error as WsError, protocol::Message};
+
+#[tokio::test]
+async fn test_shutdown_refuses_new_connections_and_notifies_existing() {
+    // テスト項目: 停止中は新規接続が拒否され、既存の接続には停止通知が届く
+    // given (前提条件):
+    let mut server = TestServer::start_with_args(&["--shutdown-grace-ms", "1000"]).await;
+    let mut alice = connect(&server, "alice").await;
+    wait_for_type(&mut alice, "participant-count", Duration::from_secs(2)).await;
+
+    // when (操作):
+    server.trigger_shutdown();
+
+    // then (期待する結果):
+    // 既存の接続には server-shutdown が届く
+    let notice = wait_for_type(&mut alice, "server-shutdown", Duration::from_secs(2))
+        .await
+        .expect("Expected server-shutdown message");
+    assert_eq!(notice["grace_period_ms"], 1000);
+
+    // 新規の WebSocket 接続は 503 で拒否される
+    match tokio_tungstenite::connect_async(server.url("bob")).await {
+        Err(WsError::Http(response)) => assert_eq!(response.status(), 503),
+        other => panic!("Expected HTTP 503, got {:?}", other.map(|_| ())),
+    }
+
+    // 新規の HTTP リクエストも 503 で拒否される
+    let client = reqwest::Client::new();
+    let response = client
+        .get(format!("{}/api/rooms", server.base_url()))
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), 503);
+
+    // readiness エンドポイントは停止中であることを返す
+    let response = client
+        .get(format!("{}/api/health", server.base_url()))
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), 503);
+    let body: serde_json::Value = response.json().await.expect("Failed to parse JSON");
+    assert_eq!(body["status"], "shutting_down");
+
+    // 猶予期間の経過後、残っている接続は閉じられサーバが停止する
+    assert!(
+        next_json(&mut alice, Duration::from_secs(3))
+            .await
+            .is_none()
+    );
+    server.wait_for_stop(Duration::from_secs(3)).await;
+}
+
+#[tokio::test]
+async fn test_connections_stay_open_for_grace_period_after_notice() {
+    // テスト項目: 停止通知の後、猶予期間の間は接続が維持され、猶予期間の経過後に閉じられる（通知 → 待機 → 切断の順）
+    // given (前提条件):
+    let mut server = TestServer::start_with_args(&["--shutdown-grace-ms", "800"]).await;
+    let mut alice = connect(&server, "alice").await;
+    wait_for_type(&mut alice, "participant-count", Duration::from_secs(2)).await;
+
+    // when (操作):
+    server.trigger_shutdown();
+    wait_for_type(&mut alice, "server-shutdown", Duration::from_secs(2))
+        .await
+        .expect("Expected server-shutdown message");
+
+    // then (期待する結果):
+    // 猶予期間の途中では接続は閉じられていない
+    let during_grace = tokio::time::timeout(Duration::from_millis(400), alice.next()).await;
+    assert!(
+        during_grace.is_err(),
+        "Expected the connection to stay open during the grace period"
+    );
+    // 猶予期間の経過後に接続が閉じられる
+    let closed = tokio::time::timeout(Duration::from_secs(3), async {
+        loop {
+            match alice.next().await {
+                None | Some(Err(_)) | Some(Ok(Message::Close(_))) => break,
+                Some(Ok(_)) => continue,
+            }
+        }
+    })
+    .await;
+    assert!(closed.is_ok(), "Expected the connection to be closed");
+    server.wait_for_stop(Duration::from_secs(3)).await;
+}
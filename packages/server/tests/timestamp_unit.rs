@@ -0,0 +1,179 @@
+//! Timestamp unit integration tests.
+
+mod fixtures;
+
+use std::{sync::Arc, time::Duration};
+
+use engawa_server::domain::FixedClock;
+use engawa_shared::time::{timestamp_to_jst_rfc3339, timestamp_to_rfc3339, utc_offset_from_hours};
+use fixtures::{TestServer, builder, connect, wait_for_type};
+use futures_util::SinkExt;
+use tokio_tungstenite::tungstenite::protocol::Message;
+
+#[tokio::test]
+async fn test_server_timestamps_use_configured_unit() {
+    // テスト項目: タイムスタンプの単位を秒にすると、サーバが生成する participant-joined の connected_at が秒で表される
+    // given (前提条件):
+    let server = TestServer::start_with_args(&["--timestamp-unit", "s"]).await;
+    let mut alice = connect(&server, "alice").await;
+    let now_secs = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs() as i64;
+
+    // when (操作):
+    let _bob = connect(&server, "bob").await;
+
+    // then (期待する結果):
+    let joined = wait_for_type(&mut alice, "participant-joined", Duration::from_secs(2))
+        .await
+        .expect("Expected participant-joined");
+    let connected_at = joined["connected_at"].as_i64().unwrap();
+    assert!(
+        (connected_at - now_secs).abs() < 60,
+        "Expected connected_at in seconds, got {}",
+        connected_at
+    );
+}
+
+#[tokio::test]
+async fn test_whisper_timestamp_uses_configured_unit() {
+    // テスト項目: タイムスタンプの単位を秒にすると、ウィスパーの timestamp が宛先と送信者のどちらにも秒で表される
+    // given (前提条件):
+    let server = TestServer::start_with_args(&["--timestamp-unit", "s"]).await;
+    let mut alice = connect(&server, "alice").await;
+    let mut bob = connect(&server, "bob").await;
+    wait_for_type(&mut alice, "participant-joined", Duration::from_secs(2)).await;
+    let now_secs = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs() as i64;
+
+    // when (操作):
+    alice
+        .send(Message::Text(
+            r#"{"type":"whisper","to":"bob","content":"psst"}"#.into(),
+        ))
+        .await
+        .expect("Failed to send whisper");
+
+    // then (期待する結果):
+    let received = wait_for_type(&mut bob, "whisper", Duration::from_secs(2))
+        .await
+        .expect("Expected whisper for bob");
+    let echoed = wait_for_type(&mut alice, "whisper", Duration::from_secs(2))
+        .await
+        .expect("Expected whisper echo for alice");
+    for frame in [&received, &echoed] {
+        let timestamp = frame["timestamp"].as_i64().unwrap();
+        assert!(
+            (timestamp - now_secs).abs() < 60,
+            "Expected timestamp in seconds, got {}",
+            timestamp
+        );
+    }
+}
+
+/// Assert that `frame[iso_field]` is the RFC 3339 form of the millisecond `frame[field]`
+fn assert_iso_consistent(frame: &serde_json::Value, field: &str, iso_field: &str) {
+    let millis = frame[field]
+        .as_i64()
+        .unwrap_or_else(|| panic!("Expected numeric {} in {}", field, frame));
+    assert_eq!(
+        frame[iso_field].as_str(),
+        Some(timestamp_to_jst_rfc3339(millis).as_str()),
+        "Expected {} consistent with {} in {}",
+        iso_field,
+        field,
+        frame
+    );
+}
+
+#[tokio::test]
+async fn test_participant_timestamps_include_rfc3339() {
+    // テスト項目: room-connected の参加者・participant-joined・participant-left に数値のタイムスタンプと、それと一致する RFC 3339 の文字列が両方含まれる
+    // given (前提条件):
+    let server = TestServer::start().await;
+    let mut alice = connect(&server, "alice").await;
+
+    // when (操作):
+    let (mut bob, _) = tokio_tungstenite::connect_async(server.url("bob"))
+        .await
+        .expect("Failed to connect");
+    let joined = wait_for_type(&mut alice, "participant-joined", Duration::from_secs(2))
+        .await
+        .expect("Expected participant-joined");
+    let connected = wait_for_type(&mut bob, "room-connected", Duration::from_secs(2))
+        .await
+        .expect("Expected room-connected");
+    drop(bob);
+    let left = wait_for_type(&mut alice, "participant-left", Duration::from_secs(2))
+        .await
+        .expect("Expected participant-left");
+
+    // then (期待する結果):
+    assert_iso_consistent(&joined, "connected_at", "connected_at_iso");
+    let participants = connected["participants"].as_array().unwrap();
+    assert_eq!(participants.len(), 2);
+    for participant in participants {
+        assert_iso_consistent(participant, "connected_at", "connected_at_iso");
+    }
+    assert_iso_consistent(&left, "disconnected_at", "disconnected_at_iso");
+}
+
+#[tokio::test]
+async fn test_participant_timestamps_use_injected_clock() {
+    // テスト項目: 注入した Clock の時刻が participant-joined の connected_at と participant-left の disconnected_at になる
+    // given (前提条件):
+    let clock = Arc::new(FixedClock::new(1_700_000_000_000));
+    let server = TestServer::start_with_builder(builder(&[]).with_clock(clock.clone())).await;
+    let mut alice = connect(&server, "alice").await;
+    let bob = connect(&server, "bob").await;
+    let joined = wait_for_type(&mut alice, "participant-joined", Duration::from_secs(2))
+        .await
+        .expect("Expected participant-joined");
+
+    // when (操作):
+    clock.set(1_700_000_005_000);
+    drop(bob);
+
+    // then (期待する結果):
+    let left = wait_for_type(&mut alice, "participant-left", Duration::from_secs(2))
+        .await
+        .expect("Expected participant-left");
+    assert_eq!(joined["connected_at"].as_i64(), Some(1_700_000_000_000));
+    assert_eq!(left["disconnected_at"].as_i64(), Some(1_700_000_005_000));
+}
+
+#[tokio::test]
+async fn test_rfc3339_timestamps_use_configured_offset() {
+    // テスト項目: UTC オフセットを設定すると、HTTP API と参加者フレームの RFC 3339 の文字列がそのオフセットで表される
+    // given (前提条件):
+    let server = TestServer::start_with_args(&["--tz-offset-hours", "1"]).await;
+    let mut alice = connect(&server, "alice").await;
+
+    // when (操作):
+    let _bob = connect(&server, "bob").await;
+    let rooms: serde_json::Value = reqwest::get(format!("{}/api/rooms", server.base_url()))
+        .await
+        .expect("Failed to send request")
+        .json()
+        .await
+        .expect("Failed to parse JSON");
+
+    // then (期待する結果):
+    assert!(rooms[0]["created_at"].as_str().unwrap().ends_with("+01:00"));
+    let joined = wait_for_type(&mut alice, "participant-joined", Duration::from_secs(2))
+        .await
+        .expect("Expected participant-joined");
+    assert_eq!(
+        joined["connected_at_iso"].as_str(),
+        Some(
+            timestamp_to_rfc3339(
+                joined["connected_at"].as_i64().unwrap(),
+                utc_offset_from_hours(1).unwrap()
+            )
+            .as_str()
+        )
+    );
+}
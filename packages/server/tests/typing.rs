@@ -0,0 +1,42 @@
+//! Typing indicator integration tests.
+
+mod fixtures;
+
+use std::time::Duration;
+
+use fixtures::{TestServer, connect, wait_for_type};
+use futures_util::SinkExt;
+use tokio_tungstenite::tungstenite::protocol::Message;
+
+#[tokio::test]
+async fn test_typing_is_relayed_without_history() {
+    // テスト項目: タイピング通知は接続の client_id で他の参加者に中継され、メッセージ履歴には追加されない
+    // given (前提条件):
+    let server = TestServer::start().await;
+    let mut alice = connect(&server, "alice").await;
+    let mut bob = connect(&server, "bob").await;
+    wait_for_type(&mut alice, "participant-joined", Duration::from_secs(2)).await;
+
+    // when (操作):
+    alice
+        .send(Message::Text(
+            r#"{"type":"typing","client_id":"mallory","is_typing":true}"#.into(),
+        ))
+        .await
+        .expect("Failed to send typing indicator");
+
+    // then (期待する結果):
+    let typing = wait_for_type(&mut bob, "typing", Duration::from_secs(2))
+        .await
+        .expect("Expected typing indicator for bob");
+    assert_eq!(typing["client_id"], "alice");
+    assert_eq!(typing["is_typing"], true);
+
+    let room: serde_json::Value = reqwest::get(format!("{}/debug/room", server.base_url()))
+        .await
+        .expect("Failed to request room")
+        .json()
+        .await
+        .expect("Failed to parse room");
+    assert_eq!(room["messages"], serde_json::json!([]));
+}
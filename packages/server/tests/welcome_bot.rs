@@ -0,0 +1,37 @@
+//! Welcome bot integration tests.
+
+mod fixtures;
+
+use std::time::Duration;
+
+use fixtures::{TestServer, connect, wait_for_type};
+
+#[tokio::test]
+async fn test_join_triggers_welcome_bot_greeting() {
+    // テスト項目: 参加者の入室でウェルカム bot の挨拶が送信され、新規参加者と既存の参加者の両方に届く
+    // given (前提条件):
+    let server = TestServer::start_with_args(&[
+        "--welcome-bot",
+        "welcome-bot",
+        "--welcome-message",
+        "Welcome, {name}!",
+    ])
+    .await;
+    let mut alice = connect(&server, "alice").await;
+    wait_for_type(&mut alice, "chat", Duration::from_secs(2))
+        .await
+        .expect("Expected greeting for alice");
+
+    // when (操作):
+    let mut bob = connect(&server, "bob").await;
+
+    // then (期待する結果):
+    for ws in [&mut alice, &mut bob] {
+        let greeting = wait_for_type(ws, "chat", Duration::from_secs(2))
+            .await
+            .expect("Expected greeting for bob");
+        assert_eq!(greeting["client_id"], "welcome-bot");
+        assert_eq!(greeting["content"], "Welcome, bob!");
+        assert!(greeting["message_id"].is_string());
+    }
+}
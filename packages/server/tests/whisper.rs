@@ -0,0 +1,80 @@
+//! Whisper (direct message) integration tests.
+
+mod fixtures;
+
+use std::time::Duration;
+
+use fixtures::{TestServer, connect, next_json, wait_for_type};
+use futures_util::SinkExt;
+use tokio_tungstenite::tungstenite::protocol::Message;
+
+#[tokio::test]
+async fn test_whisper_reaches_recipient_and_sender_only() {
+    // テスト項目: ウィスパーは宛先の bob と送信者の alice だけに届き、charlie には届かない
+    // given (前提条件):
+    let server = TestServer::start().await;
+    let mut alice = connect(&server, "alice").await;
+    let mut bob = connect(&server, "bob").await;
+    let mut charlie = connect(&server, "charlie").await;
+    wait_for_type(&mut alice, "participant-joined", Duration::from_secs(2)).await;
+    wait_for_type(&mut bob, "participant-joined", Duration::from_secs(2)).await;
+    wait_for_type(&mut charlie, "participant-count", Duration::from_secs(2)).await;
+
+    // when (操作):
+    alice
+        .send(Message::Text(
+            r#"{"type":"whisper","to":"bob","content":"psst"}"#.into(),
+        ))
+        .await
+        .expect("Failed to send whisper");
+
+    // then (期待する結果):
+    let received = wait_for_type(&mut bob, "whisper", Duration::from_secs(2))
+        .await
+        .expect("Expected whisper for bob");
+    assert_eq!(received["from"], "alice");
+    assert_eq!(received["to"], "bob");
+    assert_eq!(received["content"], "psst");
+
+    let echo = wait_for_type(&mut alice, "whisper", Duration::from_secs(2))
+        .await
+        .expect("Expected whisper echo for alice");
+    assert_eq!(echo, received);
+
+    assert!(
+        next_json(&mut charlie, Duration::from_millis(300))
+            .await
+            .is_none()
+    );
+
+    // Whispers are not added to the room's message history
+    let room: serde_json::Value = reqwest::get(format!("{}/debug/room", server.base_url()))
+        .await
+        .expect("Failed to request room")
+        .json()
+        .await
+        .expect("Failed to parse room");
+    assert_eq!(room["messages"], serde_json::json!([]));
+}
+
+#[tokio::test]
+async fn test_whisper_to_unknown_recipient_returns_error() {
+    // テスト項目: 接続していない宛先へのウィスパーは recipient_not_found のエラーフレームで拒否される
+    // given (前提条件):
+    let server = TestServer::start().await;
+    let mut alice = connect(&server, "alice").await;
+
+    // when (操作):
+    alice
+        .send(Message::Text(
+            r#"{"type":"whisper","to":"dave","content":"psst"}"#.into(),
+        ))
+        .await
+        .expect("Failed to send whisper");
+
+    // then (期待する結果):
+    let error = wait_for_type(&mut alice, "error", Duration::from_secs(2))
+        .await
+        .expect("Expected error frame");
+    assert_eq!(error["code"], "recipient_not_found");
+}
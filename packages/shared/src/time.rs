@@ -1,5 +1,7 @@
 //! Time-related utilities with clock abstraction for testability.
 
+use std::sync::atomic::{AtomicI64, Ordering};
+
 use chrono::{DateTime, FixedOffset, TimeZone, Utc};
 
 /// Clock trait for dependency injection and testing
@@ -18,44 +20,70 @@ impl Clock for SystemClock {
     }
 }
 
-/// Fixed clock implementation for testing (returns a fixed time)
-#[derive(Debug, Clone, Copy)]
+/// Fixed clock implementation for testing (returns a fixed time until `set` changes it)
+#[derive(Debug)]
 pub struct FixedClock {
-    fixed_time: i64,
+    fixed_time: AtomicI64,
 }
 
 impl FixedClock {
     /// Create a new fixed clock with the given timestamp
     pub fn new(fixed_time_millis: i64) -> Self {
         Self {
-            fixed_time: fixed_time_millis,
+            fixed_time: AtomicI64::new(fixed_time_millis),
         }
     }
+
+    /// Change the timestamp returned by the clock
+    pub fn set(&self, fixed_time_millis: i64) {
+        self.fixed_time.store(fixed_time_millis, Ordering::SeqCst);
+    }
 }
 
 impl Clock for FixedClock {
     fn now_jst_millis(&self) -> i64 {
-        self.fixed_time
+        self.fixed_time.load(Ordering::SeqCst)
     }
 }
 
+/// UTC offset of JST in hours (the default time zone)
+pub const JST_OFFSET_HOURS: i32 = 9;
+
+/// Get the UTC offset of JST (UTC+9)
+pub fn jst_offset() -> FixedOffset {
+    utc_offset_from_hours(JST_OFFSET_HOURS).unwrap()
+}
+
+/// Get a UTC offset from a whole number of hours (`None` if out of range)
+pub fn utc_offset_from_hours(hours: i32) -> Option<FixedOffset> {
+    FixedOffset::east_opt(hours.checked_mul(3600)?)
+}
+
+/// Get current Unix timestamp in the given time zone (milliseconds)
+pub fn get_timestamp(offset: FixedOffset) -> i64 {
+    let now_utc = Utc::now();
+    let now: DateTime<FixedOffset> = now_utc.with_timezone(&offset);
+    now.timestamp_millis()
+}
+
 /// Get current Unix timestamp in JST (milliseconds)
 pub fn get_jst_timestamp() -> i64 {
-    let jst_offset = FixedOffset::east_opt(9 * 3600).unwrap(); // JST is UTC+9
-    let now_utc = Utc::now();
-    let now_jst: DateTime<FixedOffset> = now_utc.with_timezone(&jst_offset);
-    now_jst.timestamp_millis()
+    get_timestamp(jst_offset())
 }
 
-/// Convert Unix timestamp (milliseconds) to JST RFC 3339 format
-pub fn timestamp_to_jst_rfc3339(timestamp_millis: i64) -> String {
-    let jst_offset = FixedOffset::east_opt(9 * 3600).unwrap(); // JST is UTC+9
+/// Convert Unix timestamp (milliseconds) to RFC 3339 format in the given time zone
+pub fn timestamp_to_rfc3339(timestamp_millis: i64, offset: FixedOffset) -> String {
     let seconds = timestamp_millis / 1000;
     let nanos = ((timestamp_millis % 1000) * 1_000_000) as u32;
-    let dt = jst_offset.timestamp_opt(seconds, nanos).unwrap();
+    let dt = offset.timestamp_opt(seconds, nanos).unwrap();
     dt.to_rfc3339()
 }
 
+/// Convert Unix timestamp (milliseconds) to JST RFC 3339 format
+pub fn timestamp_to_jst_rfc3339(timestamp_millis: i64) -> String {
+    timestamp_to_rfc3339(timestamp_millis, jst_offset())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -120,6 +148,19 @@ mod tests {
         assert_eq!(timestamp3, fixed_time);
     }
 
+    #[test]
+    fn test_fixed_clock_returns_timestamp_after_set() {
+        // テスト項目: FixedClock の時刻を変更すると、以降は変更後のタイムスタンプを返す
+        // given (前提条件):
+        let clock = FixedClock::new(1000);
+
+        // when (操作):
+        clock.set(2500);
+
+        // then (期待する結果):
+        assert_eq!(clock.now_jst_millis(), 2500);
+    }
+
     #[test]
     fn test_timestamp_to_jst_rfc3339_format() {
         // テスト項目: タイムスタンプが正しく RFC 3339 形式に変換される
@@ -149,6 +190,27 @@ mod tests {
         assert!(result.contains("+09:00"));
     }
 
+    #[test]
+    fn test_timestamp_to_rfc3339_with_offset() {
+        // テスト項目: 指定した UTC オフセットで RFC 3339 形式に変換され、同じ時刻を表す
+        // given (前提条件):
+        let timestamp = 1672498800000; // 2023-01-01 00:00:00 JST
+        let cet = utc_offset_from_hours(1).unwrap();
+
+        // when (操作):
+        let result = timestamp_to_rfc3339(timestamp, cet);
+
+        // then (期待する結果):
+        assert_eq!(result, "2022-12-31T16:00:00+01:00");
+        assert_eq!(
+            DateTime::parse_from_rfc3339(&result)
+                .unwrap()
+                .timestamp_millis(),
+            timestamp
+        );
+        assert!(utc_offset_from_hours(24).is_none());
+    }
+
     #[test]
     fn test_get_jst_timestamp_returns_positive_value() {
         // テスト項目: get_jst_timestamp が正の値を返す